@@ -1,5 +1,10 @@
-use crate::matchers::bool::{be_false, be_true};
-use crate::matchers::Should;
+use std::ops::RangeInclusive;
+
+use crate::matchers::bool::{
+    all_be_false, all_be_true, any_be_true, be_false, be_true, have_true_count,
+    have_true_count_in_inclusive_range,
+};
+use crate::matchers::{Should, ShouldNot};
 
 /// TrueFalseAssertion enables assertions about whether a boolean evaluates to true or false.
 pub trait TrueFalseAssertion {
@@ -40,6 +45,187 @@ impl TrueFalseAssertion for bool {
     }
 }
 
+/// BoolCollectionAssertion enables assertions about whether all (or any) of the booleans in a collection are true or false.
+///
+/// An empty collection is all true as well as all false, but it does not have any true value.
+///
+/// # Example
+/// ```
+/// use clearcheck::assertions::bool::BoolCollectionAssertion;
+///
+/// let flags = vec![true, false, true];
+/// flags.should_be_any_true();
+/// ```
+pub trait BoolCollectionAssertion {
+    /// - Asserts that all the booleans in the collection are true.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting the index of the first false value.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::bool::BoolCollectionAssertion;
+    ///
+    /// let flags = vec![true, true, true];
+    /// flags.should_be_all_true();
+    /// ```
+    fn should_be_all_true(&self) -> &Self;
+
+    /// - Asserts that all the booleans in the collection are false.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting the index of the first true value.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::bool::BoolCollectionAssertion;
+    ///
+    /// let flags = [false, false];
+    /// flags.should_be_all_false();
+    /// ```
+    fn should_be_all_false(&self) -> &Self;
+
+    /// - Asserts that at least one of the booleans in the collection is true.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::bool::BoolCollectionAssertion;
+    ///
+    /// let flags = vec![false, true, false];
+    /// flags.should_be_any_true();
+    /// ```
+    fn should_be_any_true(&self) -> &Self;
+
+    /// - Asserts that exactly the given number of booleans in the collection are true.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting the actual number of true values.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::bool::BoolCollectionAssertion;
+    ///
+    /// let flags = vec![true, false, true];
+    /// flags.should_have_true_count(2);
+    /// ```
+    fn should_have_true_count(&self, count: usize) -> &Self;
+
+    /// - Asserts that the number of true booleans in the collection is not the given count.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::bool::BoolCollectionAssertion;
+    ///
+    /// let flags = vec![true, false, true];
+    /// flags.should_not_have_true_count(1);
+    /// ```
+    fn should_not_have_true_count(&self, count: usize) -> &Self;
+
+    /// - Asserts that the number of true booleans in the collection falls within the given inclusive range.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting the actual number of true values.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::bool::BoolCollectionAssertion;
+    ///
+    /// let flags = vec![true, false, true];
+    /// flags.should_have_true_count_in_inclusive_range(1..=2);
+    /// ```
+    fn should_have_true_count_in_inclusive_range(&self, range: RangeInclusive<usize>) -> &Self;
+}
+
+impl BoolCollectionAssertion for Vec<bool> {
+    fn should_be_all_true(&self) -> &Self {
+        (self as &[bool]).should_be_all_true();
+        self
+    }
+
+    fn should_be_all_false(&self) -> &Self {
+        (self as &[bool]).should_be_all_false();
+        self
+    }
+
+    fn should_be_any_true(&self) -> &Self {
+        (self as &[bool]).should_be_any_true();
+        self
+    }
+
+    fn should_have_true_count(&self, count: usize) -> &Self {
+        (self as &[bool]).should_have_true_count(count);
+        self
+    }
+
+    fn should_not_have_true_count(&self, count: usize) -> &Self {
+        (self as &[bool]).should_not_have_true_count(count);
+        self
+    }
+
+    fn should_have_true_count_in_inclusive_range(&self, range: RangeInclusive<usize>) -> &Self {
+        (self as &[bool]).should_have_true_count_in_inclusive_range(range);
+        self
+    }
+}
+
+impl<const N: usize> BoolCollectionAssertion for [bool; N] {
+    fn should_be_all_true(&self) -> &Self {
+        (self as &[bool]).should_be_all_true();
+        self
+    }
+
+    fn should_be_all_false(&self) -> &Self {
+        (self as &[bool]).should_be_all_false();
+        self
+    }
+
+    fn should_be_any_true(&self) -> &Self {
+        (self as &[bool]).should_be_any_true();
+        self
+    }
+
+    fn should_have_true_count(&self, count: usize) -> &Self {
+        (self as &[bool]).should_have_true_count(count);
+        self
+    }
+
+    fn should_not_have_true_count(&self, count: usize) -> &Self {
+        (self as &[bool]).should_not_have_true_count(count);
+        self
+    }
+
+    fn should_have_true_count_in_inclusive_range(&self, range: RangeInclusive<usize>) -> &Self {
+        (self as &[bool]).should_have_true_count_in_inclusive_range(range);
+        self
+    }
+}
+
+impl BoolCollectionAssertion for [bool] {
+    fn should_be_all_true(&self) -> &Self {
+        self.should(&all_be_true());
+        self
+    }
+
+    fn should_be_all_false(&self) -> &Self {
+        self.should(&all_be_false());
+        self
+    }
+
+    fn should_be_any_true(&self) -> &Self {
+        self.should(&any_be_true());
+        self
+    }
+
+    fn should_have_true_count(&self, count: usize) -> &Self {
+        self.should(&have_true_count(count));
+        self
+    }
+
+    fn should_not_have_true_count(&self, count: usize) -> &Self {
+        self.should_not(&have_true_count(count));
+        self
+    }
+
+    fn should_have_true_count_in_inclusive_range(&self, range: RangeInclusive<usize>) -> &Self {
+        self.should(&have_true_count_in_inclusive_range(range));
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::assertions::bool::TrueFalseAssertion;
@@ -70,3 +256,117 @@ mod tests {
         value.should_be_false();
     }
 }
+
+#[cfg(test)]
+mod collection_tests {
+    use crate::assertions::bool::BoolCollectionAssertion;
+
+    #[test]
+    fn should_be_all_true() {
+        let flags = vec![true, true, true];
+        flags.should_be_all_true();
+    }
+
+    #[test]
+    fn should_be_all_true_given_an_empty_collection() {
+        let flags: Vec<bool> = vec![];
+        flags.should_be_all_true();
+    }
+
+    #[test]
+    #[should_panic(expected = "[true, false, true, false] should be all true but the value at index 1 was false")]
+    fn should_be_all_true_but_was_mixed() {
+        let flags = vec![true, false, true, false];
+        flags.should_be_all_true();
+    }
+
+    #[test]
+    #[should_panic(expected = "[false, false] should be all true but the value at index 0 was false")]
+    fn should_be_all_true_but_was_all_false() {
+        let flags = [false, false];
+        flags.should_be_all_true();
+    }
+
+    #[test]
+    fn should_be_all_false() {
+        let flags = [false, false];
+        flags.should_be_all_false();
+    }
+
+    #[test]
+    #[should_panic(expected = "[false, false, true] should be all false but the value at index 2 was true")]
+    fn should_be_all_false_but_was_mixed() {
+        let flags: &[bool] = &[false, false, true];
+        flags.should_be_all_false();
+    }
+
+    #[test]
+    fn should_be_any_true() {
+        let flags = vec![false, true, false];
+        flags.should_be_any_true();
+    }
+
+    #[test]
+    #[should_panic(expected = "[false, false] should have any true value but none was true")]
+    fn should_be_any_true_but_was_all_false() {
+        let flags = vec![false, false];
+        flags.should_be_any_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_any_true_but_was_empty() {
+        let flags: [bool; 0] = [];
+        flags.should_be_any_true();
+    }
+}
+
+#[cfg(test)]
+mod true_count_tests {
+    use crate::assertions::bool::BoolCollectionAssertion;
+
+    #[test]
+    fn should_have_true_count() {
+        let flags = vec![true, false, true, true];
+        flags.should_have_true_count(3);
+    }
+
+    #[test]
+    fn should_have_true_count_given_an_empty_collection() {
+        let flags: [bool; 0] = [];
+        flags.should_have_true_count(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "[true, false, true, true] should have 2 true values but had 3")]
+    fn should_have_true_count_but_was_not() {
+        let flags = vec![true, false, true, true];
+        flags.should_have_true_count(2);
+    }
+
+    #[test]
+    fn should_not_have_true_count() {
+        let flags = [true, false];
+        flags.should_not_have_true_count(2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_have_true_count_but_had() {
+        let flags = [true, false];
+        flags.should_not_have_true_count(1);
+    }
+
+    #[test]
+    fn should_have_true_count_in_inclusive_range() {
+        let flags: &[bool] = &[true, false, true];
+        flags.should_have_true_count_in_inclusive_range(2..=3);
+    }
+
+    #[test]
+    #[should_panic(expected = "[false, false, true] should have true values in the range 2..=3 but had 1")]
+    fn should_have_true_count_in_inclusive_range_but_was_not() {
+        let flags = vec![false, false, true];
+        flags.should_have_true_count_in_inclusive_range(2..=3);
+    }
+}
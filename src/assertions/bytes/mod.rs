@@ -0,0 +1,578 @@
+//! Byte sequences (`Vec<u8>`, `[u8; N]` and `[u8]`) are collections, so size and emptiness assertions come from
+//! [`SizeAssertion`](crate::assertions::collection::size::SizeAssertion) and
+//! [`MembershipAssertion`](crate::assertions::collection::membership::MembershipAssertion); the failure message reports
+//! the actual byte length.
+//!
+//! ```
+//! use clearcheck::assertions::bytes::Utf8Assertion;
+//! use clearcheck::assertions::collection::membership::MembershipAssertion;
+//! use clearcheck::assertions::collection::size::SizeAssertion;
+//!
+//! let bytes = "héllo".as_bytes();
+//! bytes
+//!     .should_not_be_empty()
+//!     .should_have_size(6)
+//!     .should_be_valid_utf8();
+//! ```
+
+use crate::matchers::bytes::{be_valid_utf8, contain_bytes, decode_to, equal_base64, equal_hex};
+use crate::matchers::{Should, ShouldNot};
+
+/// Utf8Assertion enables assertions about whether a sequence of bytes (`Vec<u8>`, `[u8; N]` or `[u8]`) is valid UTF-8.
+///
+/// On invalid UTF-8, the panic message includes the byte offset at which the invalid sequence starts.
+///
+/// # Example
+/// ```
+/// use clearcheck::assertions::bytes::Utf8Assertion;
+///
+/// let bytes = vec![104, 195, 169, 108, 108, 111];
+/// bytes
+///     .should_be_valid_utf8()
+///     .should_decode_to("héllo");
+/// ```
+pub trait Utf8Assertion {
+    /// - Asserts that the bytes are valid UTF-8.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::bytes::Utf8Assertion;
+    ///
+    /// let bytes = "clearcheck".as_bytes();
+    /// bytes.should_be_valid_utf8();
+    /// ```
+    fn should_be_valid_utf8(&self) -> &Self;
+
+    /// - Asserts that the bytes are not valid UTF-8.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::bytes::Utf8Assertion;
+    ///
+    /// let bytes = vec![104, 0xFF, 108];
+    /// bytes.should_not_be_valid_utf8();
+    /// ```
+    fn should_not_be_valid_utf8(&self) -> &Self;
+
+    /// - Asserts that the bytes are valid UTF-8 and decode to the given string.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::bytes::Utf8Assertion;
+    ///
+    /// let bytes = [104, 105];
+    /// bytes.should_decode_to("hi");
+    /// ```
+    fn should_decode_to(&self, expected: &str) -> &Self;
+
+    /// - Asserts that the bytes do not decode to the given string, either because they are not valid UTF-8 or because they decode to another string.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::bytes::Utf8Assertion;
+    ///
+    /// let bytes = [104, 105];
+    /// bytes.should_not_decode_to("ho");
+    /// ```
+    fn should_not_decode_to(&self, expected: &str) -> &Self;
+}
+
+impl Utf8Assertion for Vec<u8> {
+    fn should_be_valid_utf8(&self) -> &Self {
+        (self as &[u8]).should_be_valid_utf8();
+        self
+    }
+
+    fn should_not_be_valid_utf8(&self) -> &Self {
+        (self as &[u8]).should_not_be_valid_utf8();
+        self
+    }
+
+    fn should_decode_to(&self, expected: &str) -> &Self {
+        (self as &[u8]).should_decode_to(expected);
+        self
+    }
+
+    fn should_not_decode_to(&self, expected: &str) -> &Self {
+        (self as &[u8]).should_not_decode_to(expected);
+        self
+    }
+}
+
+impl<const N: usize> Utf8Assertion for [u8; N] {
+    fn should_be_valid_utf8(&self) -> &Self {
+        (self as &[u8]).should_be_valid_utf8();
+        self
+    }
+
+    fn should_not_be_valid_utf8(&self) -> &Self {
+        (self as &[u8]).should_not_be_valid_utf8();
+        self
+    }
+
+    fn should_decode_to(&self, expected: &str) -> &Self {
+        (self as &[u8]).should_decode_to(expected);
+        self
+    }
+
+    fn should_not_decode_to(&self, expected: &str) -> &Self {
+        (self as &[u8]).should_not_decode_to(expected);
+        self
+    }
+}
+
+impl Utf8Assertion for [u8] {
+    fn should_be_valid_utf8(&self) -> &Self {
+        self.should(&be_valid_utf8());
+        self
+    }
+
+    fn should_not_be_valid_utf8(&self) -> &Self {
+        self.should_not(&be_valid_utf8());
+        self
+    }
+
+    fn should_decode_to(&self, expected: &str) -> &Self {
+        self.should(&decode_to(expected));
+        self
+    }
+
+    fn should_not_decode_to(&self, expected: &str) -> &Self {
+        self.should_not(&decode_to(expected));
+        self
+    }
+}
+
+/// EncodingAssertion enables assertions about whether a sequence of bytes (`Vec<u8>`, `[u8; N]` or `[u8]`) equals the
+/// bytes represented by a hex or a base64 encoded string.
+///
+/// The panic message shows both the expected and the actual bytes in the same encoding.
+///
+/// # Example
+/// ```
+/// use clearcheck::assertions::bytes::EncodingAssertion;
+///
+/// let bytes = [0xDE, 0xAD, 0xBE, 0xEF];
+/// bytes
+///     .should_equal_hex("deadbeef")
+///     .should_equal_base64("3q2+7w==");
+/// ```
+pub trait EncodingAssertion {
+    /// - Asserts that the bytes equal the bytes represented by the given hex string (case-insensitive).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails or if the hex string is not valid.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::bytes::EncodingAssertion;
+    ///
+    /// let bytes = vec![0xCA, 0xFE];
+    /// bytes.should_equal_hex("cafe");
+    /// ```
+    fn should_equal_hex(&self, hex: &str) -> &Self;
+
+    /// - Asserts that the bytes equal the bytes represented by the given base64 string (standard alphabet, padding optional).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails or if the base64 string is not valid.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::bytes::EncodingAssertion;
+    ///
+    /// let bytes = "foobar".as_bytes();
+    /// bytes.should_equal_base64("Zm9vYmFy");
+    /// ```
+    fn should_equal_base64(&self, base64: &str) -> &Self;
+}
+
+impl EncodingAssertion for Vec<u8> {
+    fn should_equal_hex(&self, hex: &str) -> &Self {
+        (self as &[u8]).should_equal_hex(hex);
+        self
+    }
+
+    fn should_equal_base64(&self, base64: &str) -> &Self {
+        (self as &[u8]).should_equal_base64(base64);
+        self
+    }
+}
+
+impl<const N: usize> EncodingAssertion for [u8; N] {
+    fn should_equal_hex(&self, hex: &str) -> &Self {
+        (self as &[u8]).should_equal_hex(hex);
+        self
+    }
+
+    fn should_equal_base64(&self, base64: &str) -> &Self {
+        (self as &[u8]).should_equal_base64(base64);
+        self
+    }
+}
+
+impl EncodingAssertion for [u8] {
+    fn should_equal_hex(&self, hex: &str) -> &Self {
+        self.should(&equal_hex(hex));
+        self
+    }
+
+    fn should_equal_base64(&self, base64: &str) -> &Self {
+        self.should(&equal_base64(base64));
+        self
+    }
+}
+
+/// SubsliceAssertion enables assertions about whether a sequence of bytes (`Vec<u8>`, `[u8; N]` or `[u8]`) contains
+/// other bytes as a contiguous subsequence.
+///
+/// # Example
+/// ```
+/// use clearcheck::assertions::bytes::SubsliceAssertion;
+///
+/// let payload = b"HTTP/1.1 200 OK\r\n";
+/// payload
+///     .should_contain_bytes(b"200")
+///     .should_not_contain_bytes(b"404");
+/// ```
+pub trait SubsliceAssertion {
+    /// - Asserts that the bytes contain the given bytes as a contiguous subsequence. An empty needle is always contained.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::bytes::SubsliceAssertion;
+    ///
+    /// let bytes = vec![0xCA, 0xFE, 0xBA, 0xBE];
+    /// bytes.should_contain_bytes(&[0xFE, 0xBA]);
+    /// ```
+    fn should_contain_bytes(&self, needle: &[u8]) -> &Self;
+
+    /// - Asserts that the bytes do not contain the given bytes as a contiguous subsequence.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::bytes::SubsliceAssertion;
+    ///
+    /// let bytes = vec![0xCA, 0xFE, 0xBA, 0xBE];
+    /// bytes.should_not_contain_bytes(&[0xFE, 0xBE]);
+    /// ```
+    fn should_not_contain_bytes(&self, needle: &[u8]) -> &Self;
+}
+
+impl SubsliceAssertion for Vec<u8> {
+    fn should_contain_bytes(&self, needle: &[u8]) -> &Self {
+        (self as &[u8]).should_contain_bytes(needle);
+        self
+    }
+
+    fn should_not_contain_bytes(&self, needle: &[u8]) -> &Self {
+        (self as &[u8]).should_not_contain_bytes(needle);
+        self
+    }
+}
+
+impl<const N: usize> SubsliceAssertion for [u8; N] {
+    fn should_contain_bytes(&self, needle: &[u8]) -> &Self {
+        (self as &[u8]).should_contain_bytes(needle);
+        self
+    }
+
+    fn should_not_contain_bytes(&self, needle: &[u8]) -> &Self {
+        (self as &[u8]).should_not_contain_bytes(needle);
+        self
+    }
+}
+
+impl SubsliceAssertion for [u8] {
+    fn should_contain_bytes(&self, needle: &[u8]) -> &Self {
+        self.should(&contain_bytes(needle));
+        self
+    }
+
+    fn should_not_contain_bytes(&self, needle: &[u8]) -> &Self {
+        self.should_not(&contain_bytes(needle));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::bytes::Utf8Assertion;
+
+    #[test]
+    fn should_be_valid_utf8() {
+        let bytes = "héllo".as_bytes();
+        bytes.should_be_valid_utf8();
+    }
+
+    #[test]
+    fn should_be_valid_utf8_given_no_bytes() {
+        let bytes: Vec<u8> = vec![];
+        bytes.should_be_valid_utf8();
+    }
+
+    #[test]
+    #[should_panic(expected = "[104, 255, 108] should be valid UTF-8 but found an invalid byte sequence at offset 1")]
+    fn should_be_valid_utf8_but_had_an_invalid_byte() {
+        let bytes = vec![104, 0xFF, 108];
+        bytes.should_be_valid_utf8();
+    }
+
+    #[test]
+    #[should_panic(expected = "[104, 105, 237, 160, 128] should be valid UTF-8 but found an invalid byte sequence at offset 2")]
+    fn should_be_valid_utf8_but_had_an_encoded_surrogate() {
+        let bytes = [104, 105, 0xED, 0xA0, 0x80];
+        bytes.should_be_valid_utf8();
+    }
+
+    #[test]
+    #[should_panic(expected = "[104, 195] should be valid UTF-8 but found an incomplete byte sequence at offset 1")]
+    fn should_be_valid_utf8_but_ended_in_a_truncated_character() {
+        let bytes = vec![104, 0xC3];
+        bytes.should_be_valid_utf8();
+    }
+
+    #[test]
+    fn should_not_be_valid_utf8() {
+        let bytes = [0xC0, 0xAF];
+        bytes.should_not_be_valid_utf8();
+    }
+
+    #[test]
+    #[should_panic(expected = "[104, 105] should not be valid UTF-8")]
+    fn should_not_be_valid_utf8_but_was() {
+        let bytes = [104, 105];
+        bytes.should_not_be_valid_utf8();
+    }
+
+    #[test]
+    fn should_decode_to() {
+        let bytes = vec![104, 195, 169, 108, 108, 111];
+        bytes.should_decode_to("héllo");
+    }
+
+    #[test]
+    #[should_panic(expected = "[104, 105] should decode to \"ho\" but decoded to \"hi\"")]
+    fn should_decode_to_but_decoded_to_another_string() {
+        let bytes = vec![104, 105];
+        bytes.should_decode_to("ho");
+    }
+
+    #[test]
+    #[should_panic(expected = "[104, 105, 255] should decode to \"hi\" but found an invalid byte sequence at offset 2")]
+    fn should_decode_to_but_was_not_valid_utf8() {
+        let bytes: &[u8] = &[104, 105, 0xFF];
+        bytes.should_decode_to("hi");
+    }
+
+    #[test]
+    fn should_not_decode_to() {
+        let bytes = [104, 105];
+        bytes.should_not_decode_to("ho");
+    }
+
+    #[test]
+    fn should_not_decode_to_given_invalid_utf8() {
+        let bytes = [104, 0xFF];
+        bytes.should_not_decode_to("h");
+    }
+
+    #[test]
+    #[should_panic(expected = "[104, 105] should not decode to \"hi\"")]
+    fn should_not_decode_to_but_it_did() {
+        let bytes = [104, 105];
+        bytes.should_not_decode_to("hi");
+    }
+}
+
+#[cfg(test)]
+mod encoding_tests {
+    use crate::assertions::bytes::EncodingAssertion;
+
+    #[test]
+    fn should_equal_hex() {
+        let bytes = [0xDE, 0xAD, 0xBE, 0xEF];
+        bytes.should_equal_hex("deadbeef");
+    }
+
+    #[test]
+    fn should_equal_hex_ignoring_case() {
+        let bytes = vec![0xDE, 0xAD, 0xBE, 0xEF];
+        bytes.should_equal_hex("DEADBEEF");
+    }
+
+    #[test]
+    fn should_equal_hex_given_no_bytes() {
+        let bytes: &[u8] = &[];
+        bytes.should_equal_hex("");
+    }
+
+    #[test]
+    #[should_panic(expected = "Bytes should equal hex \"deadbeaf\" but were hex \"deadbeef\"")]
+    fn should_equal_hex_but_did_not() {
+        let bytes = [0xDE, 0xAD, 0xBE, 0xEF];
+        bytes.should_equal_hex("deadbeaf");
+    }
+
+    #[test]
+    #[should_panic(expected = "\"deadbee\" is not a valid hex string, it has an odd number of digits")]
+    fn should_equal_hex_but_the_hex_had_an_odd_number_of_digits() {
+        let bytes = [0xDE, 0xAD, 0xBE, 0xEF];
+        bytes.should_equal_hex("deadbee");
+    }
+
+    #[test]
+    #[should_panic(expected = "\"deadbeeg\" is not a valid hex string, it has an invalid character 'g' at index 7")]
+    fn should_equal_hex_but_the_hex_had_an_invalid_character() {
+        let bytes = [0xDE, 0xAD, 0xBE, 0xEF];
+        bytes.should_equal_hex("deadbeeg");
+    }
+
+    #[test]
+    fn should_equal_base64() {
+        let bytes = "foobar".as_bytes();
+        bytes.should_equal_base64("Zm9vYmFy");
+    }
+
+    #[test]
+    fn should_equal_padded_base64() {
+        let bytes = vec![0xDE, 0xAD, 0xBE, 0xEF];
+        bytes.should_equal_base64("3q2+7w==");
+    }
+
+    #[test]
+    fn should_equal_unpadded_base64() {
+        let bytes = vec![0xDE, 0xAD, 0xBE, 0xEF];
+        bytes.should_equal_base64("3q2+7w");
+    }
+
+    #[test]
+    #[should_panic(expected = "Bytes should equal base64 \"Zm9vYg==\" but were base64 \"Zm9vYmFy\"")]
+    fn should_equal_base64_but_did_not() {
+        let bytes = "foobar".as_bytes();
+        bytes.should_equal_base64("Zm9vYg==");
+    }
+
+    #[test]
+    #[should_panic(expected = "\"Zm9v*mFy\" is not a valid base64 string, it has an invalid character '*' at index 4")]
+    fn should_equal_base64_but_the_base64_had_an_invalid_character() {
+        let bytes = "foobar".as_bytes();
+        bytes.should_equal_base64("Zm9v*mFy");
+    }
+
+    #[test]
+    #[should_panic(expected = "\"Zg=\" is not a valid base64 string, it has invalid padding")]
+    fn should_equal_base64_but_the_base64_had_invalid_padding() {
+        let bytes = "f".as_bytes();
+        bytes.should_equal_base64("Zg=");
+    }
+}
+
+#[cfg(test)]
+mod size_tests {
+    use crate::assertions::collection::membership::MembershipAssertion;
+    use crate::assertions::collection::size::SizeAssertion;
+
+    #[test]
+    fn should_be_empty() {
+        let bytes: Vec<u8> = vec![];
+        bytes.should_be_empty();
+    }
+
+    #[test]
+    #[should_panic(expected = "Collection should be empty but had length 2")]
+    fn should_be_empty_but_was_not() {
+        let bytes = vec![0xCA, 0xFE];
+        bytes.should_be_empty();
+    }
+
+    #[test]
+    fn should_not_be_empty() {
+        let bytes: &[u8] = &[0xCA, 0xFE];
+        bytes.should_not_be_empty();
+    }
+
+    #[test]
+    fn should_have_size() {
+        let bytes = "héllo".as_bytes();
+        bytes.should_have_size(6);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"Collection\" length 6 should be 5")]
+    fn should_have_size_but_did_not() {
+        let bytes = "héllo".as_bytes();
+        bytes.should_have_size(5);
+    }
+
+    #[test]
+    fn should_have_size_given_no_bytes() {
+        let bytes: [u8; 0] = [];
+        bytes.should_have_size(0);
+    }
+}
+
+#[cfg(test)]
+mod subslice_tests {
+    use crate::assertions::bytes::SubsliceAssertion;
+
+    #[test]
+    fn should_contain_bytes() {
+        let bytes = vec![0xCA, 0xFE, 0xBA, 0xBE];
+        bytes.should_contain_bytes(&[0xFE, 0xBA]);
+    }
+
+    #[test]
+    fn should_contain_bytes_at_the_end() {
+        let bytes = [0xCA, 0xFE, 0xBA, 0xBE];
+        bytes.should_contain_bytes(&[0xBA, 0xBE]);
+    }
+
+    #[test]
+    fn should_contain_the_same_bytes() {
+        let bytes = b"clearcheck";
+        bytes.should_contain_bytes(b"clearcheck");
+    }
+
+    #[test]
+    fn should_contain_an_empty_needle() {
+        let bytes: &[u8] = &[];
+        bytes.should_contain_bytes(&[]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Bytes (hex) \"cafebabe\" should contain \"feba00\"")]
+    fn should_contain_bytes_but_did_not() {
+        let bytes = vec![0xCA, 0xFE, 0xBA, 0xBE];
+        bytes.should_contain_bytes(&[0xFE, 0xBA, 0x00]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Bytes (hex) \"cafe\" should contain \"cafebabe\"")]
+    fn should_contain_bytes_but_the_needle_was_longer() {
+        let bytes = [0xCA, 0xFE];
+        bytes.should_contain_bytes(&[0xCA, 0xFE, 0xBA, 0xBE]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Bytes (hex) \"0000000000000000000000000000000000000000000000000000000000000000...\" should contain \"ff\"")]
+    fn should_contain_bytes_but_did_not_with_truncated_bytes() {
+        let bytes = vec![0; 40];
+        bytes.should_contain_bytes(&[0xFF]);
+    }
+
+    #[test]
+    fn should_not_contain_bytes() {
+        let bytes = b"HTTP/1.1 200 OK";
+        bytes.should_not_contain_bytes(b"404");
+    }
+
+    #[test]
+    #[should_panic(expected = "Bytes (hex) \"cafebabe\" should not contain \"feba\"")]
+    fn should_not_contain_bytes_but_it_did() {
+        let bytes = vec![0xCA, 0xFE, 0xBA, 0xBE];
+        bytes.should_not_contain_bytes(&[0xFE, 0xBA]);
+    }
+}
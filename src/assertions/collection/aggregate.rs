@@ -0,0 +1,546 @@
+use std::collections::{BTreeMap, HashMap};
+use std::ops::RangeInclusive;
+
+use crate::matchers::collection::aggregate::{have_average, have_max_value, have_median, have_min_value, have_sum, have_sum_in_inclusive_range, Summable};
+use crate::matchers::{Should, ShouldNot};
+
+/// AggregateAssertion enables assertions about the sum and the average of the numeric elements in a collection,
+/// or of the values in a map.
+///
+/// Integers (up to 64 bits) are summed as i128, so the sum does not overflow. Floats are summed in their own type and the
+/// sum is compared exactly, matching native floating point arithmetic; use `should_have_sum_in_range` to allow for
+/// rounding errors. The panic message reports the actual sum, average, median, minimum or maximum.
+///
+/// Unlike [`crate::assertions::collection::min_max::MinMaxAssertion`], the median, the minimum and the maximum assertions do not
+/// require the elements to be `Ord`, so they work with floats. A collection containing NaN fails them.
+pub trait AggregateAssertion<T: Summable> {
+    /// - Asserts that the sum of the elements equals the given sum.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::aggregate::AggregateAssertion;
+    ///
+    /// let scores = vec![40, 35, 25];
+    /// scores.should_have_sum(100);
+    /// ```
+    fn should_have_sum(&self, sum: T) -> &Self;
+
+    /// - Asserts that the sum of the elements does not equal the given sum.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::aggregate::AggregateAssertion;
+    ///
+    /// let scores = vec![40, 35, 20];
+    /// scores.should_not_have_sum(100);
+    /// ```
+    fn should_not_have_sum(&self, sum: T) -> &Self;
+
+    /// - Asserts that the sum of the elements falls within the given inclusive range.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::aggregate::AggregateAssertion;
+    ///
+    /// let weights = [0.25, 0.5, 0.24];
+    /// weights.should_have_sum_in_range(0.95..=1.0);
+    /// ```
+    fn should_have_sum_in_range(&self, range: RangeInclusive<T>) -> &Self;
+
+    /// - Asserts that the collection is not empty and the average of its elements is within the tolerance (inclusive) of the given average.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::aggregate::AggregateAssertion;
+    ///
+    /// let scores = vec![1, 2, 3, 4];
+    /// scores.should_have_average(2.5, 0.0);
+    /// ```
+    fn should_have_average(&self, average: f64, tolerance: f64) -> &Self;
+
+    /// - Asserts that the collection is not empty, does not contain NaN and its minimum element equals the given value.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting the actual minimum.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::aggregate::AggregateAssertion;
+    ///
+    /// let readings = vec![2.5, -1.5, 0.25];
+    /// readings.should_have_min_value(-1.5);
+    /// ```
+    fn should_have_min_value(&self, value: T) -> &Self;
+
+    /// - Asserts that the collection is not empty, does not contain NaN and its maximum element equals the given value.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting the actual maximum.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::aggregate::AggregateAssertion;
+    ///
+    /// let readings = vec![2.5, -1.5, 0.25];
+    /// readings.should_have_max_value(2.5);
+    /// ```
+    fn should_have_max_value(&self, value: T) -> &Self;
+
+    /// - Asserts that the collection is not empty, does not contain NaN and its median is within the tolerance of the given median.
+    /// - The median is computed as f64; the median of an even number of elements is the average of the two middle elements.
+    /// - The tolerance allows for the error of widening floats to f64, like an f32 0.1 becoming 0.10000000149011612.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting the computed median.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::aggregate::AggregateAssertion;
+    ///
+    /// let data = vec![9, 1, 5, 4];
+    /// data.should_have_median(4.5, 0.0);
+    /// ```
+    fn should_have_median(&self, median: f64, tolerance: f64) -> &Self;
+}
+
+impl<T: Summable> AggregateAssertion<T> for Vec<T> {
+    fn should_have_sum(&self, sum: T) -> &Self {
+        (self as &[T]).should_have_sum(sum);
+        self
+    }
+
+    fn should_not_have_sum(&self, sum: T) -> &Self {
+        (self as &[T]).should_not_have_sum(sum);
+        self
+    }
+
+    fn should_have_sum_in_range(&self, range: RangeInclusive<T>) -> &Self {
+        (self as &[T]).should_have_sum_in_range(range);
+        self
+    }
+
+    fn should_have_average(&self, average: f64, tolerance: f64) -> &Self {
+        (self as &[T]).should_have_average(average, tolerance);
+        self
+    }
+
+    fn should_have_min_value(&self, value: T) -> &Self {
+        (self as &[T]).should_have_min_value(value);
+        self
+    }
+
+    fn should_have_max_value(&self, value: T) -> &Self {
+        (self as &[T]).should_have_max_value(value);
+        self
+    }
+
+    fn should_have_median(&self, median: f64, tolerance: f64) -> &Self {
+        (self as &[T]).should_have_median(median, tolerance);
+        self
+    }
+}
+
+impl<T: Summable, const N: usize> AggregateAssertion<T> for [T; N] {
+    fn should_have_sum(&self, sum: T) -> &Self {
+        (self as &[T]).should_have_sum(sum);
+        self
+    }
+
+    fn should_not_have_sum(&self, sum: T) -> &Self {
+        (self as &[T]).should_not_have_sum(sum);
+        self
+    }
+
+    fn should_have_sum_in_range(&self, range: RangeInclusive<T>) -> &Self {
+        (self as &[T]).should_have_sum_in_range(range);
+        self
+    }
+
+    fn should_have_average(&self, average: f64, tolerance: f64) -> &Self {
+        (self as &[T]).should_have_average(average, tolerance);
+        self
+    }
+
+    fn should_have_min_value(&self, value: T) -> &Self {
+        (self as &[T]).should_have_min_value(value);
+        self
+    }
+
+    fn should_have_max_value(&self, value: T) -> &Self {
+        (self as &[T]).should_have_max_value(value);
+        self
+    }
+
+    fn should_have_median(&self, median: f64, tolerance: f64) -> &Self {
+        (self as &[T]).should_have_median(median, tolerance);
+        self
+    }
+}
+
+impl<T: Summable> AggregateAssertion<T> for [T] {
+    fn should_have_sum(&self, sum: T) -> &Self {
+        self.should(&have_sum(sum));
+        self
+    }
+
+    fn should_not_have_sum(&self, sum: T) -> &Self {
+        self.should_not(&have_sum(sum));
+        self
+    }
+
+    fn should_have_sum_in_range(&self, range: RangeInclusive<T>) -> &Self {
+        self.should(&have_sum_in_inclusive_range(range));
+        self
+    }
+
+    fn should_have_average(&self, average: f64, tolerance: f64) -> &Self {
+        self.should(&have_average(average, tolerance));
+        self
+    }
+
+    fn should_have_min_value(&self, value: T) -> &Self {
+        self.should(&have_min_value(value));
+        self
+    }
+
+    fn should_have_max_value(&self, value: T) -> &Self {
+        self.should(&have_max_value(value));
+        self
+    }
+
+    fn should_have_median(&self, median: f64, tolerance: f64) -> &Self {
+        self.should(&have_median(median, tolerance));
+        self
+    }
+}
+
+impl<K, V: Summable> AggregateAssertion<V> for HashMap<K, V> {
+    fn should_have_sum(&self, sum: V) -> &Self {
+        map_values(self.values()).should_have_sum(sum);
+        self
+    }
+
+    fn should_not_have_sum(&self, sum: V) -> &Self {
+        map_values(self.values()).should_not_have_sum(sum);
+        self
+    }
+
+    fn should_have_sum_in_range(&self, range: RangeInclusive<V>) -> &Self {
+        map_values(self.values()).should_have_sum_in_range(range);
+        self
+    }
+
+    fn should_have_average(&self, average: f64, tolerance: f64) -> &Self {
+        map_values(self.values()).should_have_average(average, tolerance);
+        self
+    }
+
+    fn should_have_min_value(&self, value: V) -> &Self {
+        map_values(self.values()).should_have_min_value(value);
+        self
+    }
+
+    fn should_have_max_value(&self, value: V) -> &Self {
+        map_values(self.values()).should_have_max_value(value);
+        self
+    }
+
+    fn should_have_median(&self, median: f64, tolerance: f64) -> &Self {
+        map_values(self.values()).should_have_median(median, tolerance);
+        self
+    }
+}
+
+impl<K, V: Summable> AggregateAssertion<V> for BTreeMap<K, V> {
+    fn should_have_sum(&self, sum: V) -> &Self {
+        map_values(self.values()).should_have_sum(sum);
+        self
+    }
+
+    fn should_not_have_sum(&self, sum: V) -> &Self {
+        map_values(self.values()).should_not_have_sum(sum);
+        self
+    }
+
+    fn should_have_sum_in_range(&self, range: RangeInclusive<V>) -> &Self {
+        map_values(self.values()).should_have_sum_in_range(range);
+        self
+    }
+
+    fn should_have_average(&self, average: f64, tolerance: f64) -> &Self {
+        map_values(self.values()).should_have_average(average, tolerance);
+        self
+    }
+
+    fn should_have_min_value(&self, value: V) -> &Self {
+        map_values(self.values()).should_have_min_value(value);
+        self
+    }
+
+    fn should_have_max_value(&self, value: V) -> &Self {
+        map_values(self.values()).should_have_max_value(value);
+        self
+    }
+
+    fn should_have_median(&self, median: f64, tolerance: f64) -> &Self {
+        map_values(self.values()).should_have_median(median, tolerance);
+        self
+    }
+}
+
+fn map_values<'a, V: Summable + 'a>(values: impl Iterator<Item=&'a V>) -> Vec<V> {
+    values.copied().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::collection::aggregate::AggregateAssertion;
+
+    #[test]
+    fn should_have_sum() {
+        let scores = vec![40, 35, 25];
+        scores.should_have_sum(100);
+    }
+
+    #[test]
+    fn should_have_sum_of_an_empty_collection() {
+        let scores: Vec<i32> = vec![];
+        scores.should_have_sum(0);
+    }
+
+    #[test]
+    fn should_have_sum_without_overflowing() {
+        let bytes = [200_u8, 100];
+        bytes.should_not_have_sum(44);
+    }
+
+    #[test]
+    #[should_panic(expected = "[40, 35, 20] should have sum 100 but had 95")]
+    fn should_have_sum_but_it_did_not() {
+        let scores = vec![40, 35, 20];
+        scores.should_have_sum(100);
+    }
+
+    #[test]
+    #[should_panic(expected = "[200, 100] should have sum in range 0..=255 but had 300")]
+    fn should_have_sum_in_range_but_it_overflowed_the_element_type() {
+        let bytes = [200_u8, 100];
+        bytes.should_have_sum_in_range(0..=u8::MAX);
+    }
+
+    #[test]
+    fn should_have_average() {
+        let scores = vec![1, 2, 3, 4];
+        scores.should_have_average(2.5, 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "[1, 2, 3] should have average 2.5 within 0.1 but had 2.0")]
+    fn should_have_average_but_it_did_not() {
+        let scores = vec![1, 2, 3];
+        scores.should_have_average(2.5, 0.1);
+    }
+
+    #[test]
+    #[should_panic(expected = "[] should have average 0.0 within 0.5 but it was empty")]
+    fn should_have_average_but_it_was_empty() {
+        let scores: Vec<i64> = vec![];
+        scores.should_have_average(0.0, 0.5);
+    }
+}
+
+#[cfg(test)]
+mod float_tests {
+    use crate::assertions::collection::aggregate::AggregateAssertion;
+
+    #[test]
+    fn should_have_sum() {
+        let weights = vec![0.25, 0.5, 0.25];
+        weights.should_have_sum(1.0);
+    }
+
+    #[test]
+    fn should_have_sum_of_f32_elements_in_native_arithmetic() {
+        let weights = vec![0.1_f32, 0.2];
+        weights.should_have_sum(0.3_f32);
+    }
+
+    #[test]
+    #[should_panic(expected = "[0.1, 0.2] should have sum 0.3 but had 0.30000000000000004")]
+    fn should_have_sum_of_f64_elements_but_it_had_a_rounding_error() {
+        let weights = vec![0.1, 0.2];
+        weights.should_have_sum(0.3);
+    }
+
+    #[test]
+    fn should_have_sum_in_range() {
+        let weights = [0.1_f32, 0.2, 0.3];
+        weights.should_have_sum_in_range(0.59..=0.61);
+    }
+
+    #[test]
+    #[should_panic(expected = "[0.5, 0.75] should have sum in range 0.0..=1.0 but had 1.25")]
+    fn should_have_sum_in_range_but_it_did_not() {
+        let weights = vec![0.5, 0.75];
+        weights.should_have_sum_in_range(0.0..=1.0);
+    }
+
+    #[test]
+    fn should_have_average() {
+        let readings = vec![0.1, 0.2];
+        readings.should_have_average(0.15, 1e-9);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_average_but_it_did_not() {
+        let readings = [1.5, 2.5];
+        readings.should_have_average(2.5, 0.1);
+    }
+}
+
+#[cfg(test)]
+mod extremum_tests {
+    use crate::assertions::collection::aggregate::AggregateAssertion;
+
+    #[test]
+    fn should_have_min_value() {
+        let readings = vec![2.5, -1.5, 0.25];
+        readings.should_have_min_value(-1.5);
+    }
+
+    #[test]
+    fn should_have_max_value() {
+        let readings = [2.5_f32, -1.5, 0.25];
+        readings.should_have_max_value(2.5);
+    }
+
+    #[test]
+    fn should_have_max_value_of_integers() {
+        let scores = vec![40, 35, 25];
+        scores.should_have_max_value(40).should_have_min_value(25);
+    }
+
+    #[test]
+    #[should_panic(expected = "[2.5, -1.5, 0.25] should have min value 0.25 but had -1.5")]
+    fn should_have_min_value_but_it_did_not() {
+        let readings = vec![2.5, -1.5, 0.25];
+        readings.should_have_min_value(0.25);
+    }
+
+    #[test]
+    #[should_panic(expected = "[2.5, -1.5, 0.25] should have max value 3.0 but had 2.5")]
+    fn should_have_max_value_but_it_did_not() {
+        let readings = vec![2.5, -1.5, 0.25];
+        readings.should_have_max_value(3.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "[1.0, NaN, 3.0] should have max value 3.0 but it contained NaN")]
+    fn should_have_max_value_but_it_contained_nan() {
+        let readings = vec![1.0, f64::NAN, 3.0];
+        readings.should_have_max_value(3.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "[NaN, 1.0] should have min value 1.0 but it contained NaN")]
+    fn should_have_min_value_but_it_contained_nan() {
+        let readings = vec![f64::NAN, 1.0];
+        readings.should_have_min_value(1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "[] should have min value 1.0 but it was empty")]
+    fn should_have_min_value_but_it_was_empty() {
+        let readings: Vec<f64> = vec![];
+        readings.should_have_min_value(1.0);
+    }
+}
+
+#[cfg(test)]
+mod median_tests {
+    use crate::assertions::collection::aggregate::AggregateAssertion;
+
+    #[test]
+    fn should_have_median_of_odd_length() {
+        let data = vec![9, 1, 5];
+        data.should_have_median(5.0, 0.0);
+    }
+
+    #[test]
+    fn should_have_median_of_even_length() {
+        let data = vec![9, 1, 5, 4];
+        data.should_have_median(4.5, 0.0);
+    }
+
+    #[test]
+    fn should_have_median_of_floats() {
+        let data = [2.5, -1.0, 0.5, 8.0, 3.0];
+        data.should_have_median(2.5, 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "[9, 1, 5] should have median 9.0 within 0.0 but had 5.0")]
+    fn should_have_median_of_odd_length_but_it_did_not() {
+        let data = vec![9, 1, 5];
+        data.should_have_median(9.0, 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "[9, 1, 5, 4] should have median 5.0 within 0.0 but had 4.5")]
+    fn should_have_median_of_even_length_but_it_did_not() {
+        let data = vec![9, 1, 5, 4];
+        data.should_have_median(5.0, 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "[] should have median 0.0 within 0.0 but it was empty")]
+    fn should_have_median_but_it_was_empty() {
+        let data: Vec<u32> = vec![];
+        data.should_have_median(0.0, 0.0);
+    }
+
+    #[test]
+    fn should_have_median_of_f32_elements_within_tolerance() {
+        let data = vec![0.1_f32];
+        data.should_have_median(0.1, 1e-6);
+    }
+
+    #[test]
+    #[should_panic(expected = "[0.1] should have median 0.1 within 0.0 but had 0.10000000149011612")]
+    fn should_have_median_of_f32_elements_but_the_tolerance_was_too_small() {
+        let data = vec![0.1_f32];
+        data.should_have_median(0.1, 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "[1.0, NaN, 3.0] should have median 3.0 within 0.0 but it contained NaN")]
+    fn should_have_median_but_it_contained_nan() {
+        let data = vec![1.0, f64::NAN, 3.0];
+        data.should_have_median(3.0, 0.0);
+    }
+}
+
+#[cfg(test)]
+mod map_tests {
+    use std::collections::{BTreeMap, HashMap};
+
+    use crate::assertions::collection::aggregate::AggregateAssertion;
+
+    #[test]
+    fn should_have_sum_of_values() {
+        let mut scores = HashMap::new();
+        scores.insert("rust", 60);
+        scores.insert("java", 40);
+
+        scores.should_have_sum(100).should_have_average(50.0, 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "should have sum 100 but had 90")]
+    fn should_have_sum_of_values_but_it_did_not() {
+        let mut scores = BTreeMap::new();
+        scores.insert("rust", 60);
+        scores.insert("java", 30);
+
+        scores.should_have_sum(100);
+    }
+}
@@ -0,0 +1,121 @@
+use crate::matchers::collection::capacity::{have_atleast_capacity, have_same_capacity};
+use crate::matchers::Should;
+
+/// CapacityAssertion enables assertions about the capacity of vector and String.
+///
+/// Capacity is implementation-defined (the allocator may reserve more than requested),
+/// so [CapacityAssertion::should_have_capacity_at_least] is usually the more useful form.
+///
+/// It offers a fluent interface for chaining multiple assertions.
+///
+/// # Example
+/// ```
+/// use clearcheck::assertions::collection::capacity::CapacityAssertion;
+///
+/// let collection: Vec<i32> = Vec::with_capacity(10);
+/// collection.should_have_capacity_at_least(10);
+/// ```
+pub trait CapacityAssertion {
+    /// - Asserts that the capacity of the underlying collection is greater than or equal to the given capacity.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting the actual capacity.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::capacity::CapacityAssertion;
+    ///
+    /// let collection: Vec<&str> = Vec::with_capacity(8);
+    /// collection.should_have_capacity_at_least(8);
+    /// ```
+    fn should_have_capacity_at_least(&self, capacity: usize) -> &Self;
+
+    /// - Asserts that the capacity of the underlying collection is exactly the given capacity.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting the actual capacity.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::capacity::CapacityAssertion;
+    ///
+    /// let collection: Vec<&str> = Vec::new();
+    /// collection.should_have_capacity(0);
+    /// ```
+    fn should_have_capacity(&self, capacity: usize) -> &Self;
+}
+
+impl<T> CapacityAssertion for Vec<T> {
+    fn should_have_capacity_at_least(&self, capacity: usize) -> &Self {
+        self.should(&have_atleast_capacity(capacity));
+        self
+    }
+
+    fn should_have_capacity(&self, capacity: usize) -> &Self {
+        self.should(&have_same_capacity(capacity));
+        self
+    }
+}
+
+impl CapacityAssertion for String {
+    fn should_have_capacity_at_least(&self, capacity: usize) -> &Self {
+        self.should(&have_atleast_capacity(capacity));
+        self
+    }
+
+    fn should_have_capacity(&self, capacity: usize) -> &Self {
+        self.should(&have_same_capacity(capacity));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::collection::capacity::CapacityAssertion;
+
+    #[test]
+    fn should_have_capacity_at_least() {
+        let collection: Vec<i32> = Vec::with_capacity(10);
+        collection.should_have_capacity_at_least(10);
+    }
+
+    #[test]
+    #[should_panic(expected = "Capacity 0 should be atleast 10")]
+    fn should_have_capacity_at_least_but_was_not() {
+        let collection: Vec<i32> = Vec::new();
+        collection.should_have_capacity_at_least(10);
+    }
+
+    #[test]
+    fn should_have_capacity() {
+        let collection: Vec<i32> = Vec::new();
+        collection.should_have_capacity(0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_capacity_but_was_not() {
+        let collection: Vec<i32> = Vec::with_capacity(10);
+        collection.should_have_capacity(0);
+    }
+}
+
+#[cfg(test)]
+mod string_tests {
+    use crate::assertions::collection::capacity::CapacityAssertion;
+
+    #[test]
+    fn should_have_capacity_at_least() {
+        let value = String::with_capacity(32);
+        value.should_have_capacity_at_least(32);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_capacity_at_least_but_was_not() {
+        let value = String::new();
+        value.should_have_capacity_at_least(32);
+    }
+
+    #[test]
+    fn should_have_capacity() {
+        let value = String::new();
+        value.should_have_capacity(0);
+    }
+}
@@ -1,6 +1,12 @@
+use std::borrow::Borrow;
 use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::RangeInclusive;
 
-use crate::matchers::collection::duplicate::contain_duplicates;
+use crate::matchers::collection::duplicate::{
+    all_be_distinct, all_be_equal, contain_consecutive_duplicates, contain_duplicate_of, contain_duplicates,
+    contain_exactly_n_of, have_distinct_count, have_distinct_count_in_inclusive_range,
+};
 use crate::matchers::{Should, ShouldNot};
 
 /// DuplicateContentAssertion enables assertions about whether a collection contains duplicate elements.
@@ -28,6 +34,89 @@ pub trait DuplicateContentAssertion<T: Eq> {
     /// collection.should_not_contain_duplicates();
     /// ```
     fn should_not_contain_duplicates(&self) -> &Self;
+
+    /// - Asserts that the collection contains at least one pair of adjacent equal elements.
+    /// - Unlike `should_contain_duplicates`, duplicates that are not adjacent do not count.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::duplicate::DuplicateContentAssertion;
+    ///
+    /// let collection = [1, 1, 2];
+    /// collection.should_contain_consecutive_duplicates();
+    /// ```
+    fn should_contain_consecutive_duplicates(&self) -> &Self;
+
+    /// - Asserts that the collection does not contain any pair of adjacent equal elements.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting the index of the first consecutive pair.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::duplicate::DuplicateContentAssertion;
+    ///
+    /// let collection = [1, 2, 1];
+    /// collection.should_not_contain_consecutive_duplicates();
+    /// ```
+    fn should_not_contain_consecutive_duplicates(&self) -> &Self;
+
+    /// - Asserts that all the elements of the collection are equal to each other.
+    /// - An empty or a single element collection passes trivially.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, naming the first element that differs.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::duplicate::DuplicateContentAssertion;
+    ///
+    /// let collection = ["clearcheck", "clearcheck", "clearcheck"];
+    /// collection.should_all_be_equal();
+    /// ```
+    fn should_all_be_equal(&self) -> &Self;
+
+    /// - Asserts that all the elements of the collection are distinct.
+    /// - An empty or a single element collection passes trivially.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, naming the first duplicate pair.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::duplicate::DuplicateContentAssertion;
+    ///
+    /// let collection = ["junit", "testify", "clearcheck"];
+    /// collection.should_all_be_distinct();
+    /// ```
+    fn should_all_be_distinct(&self) -> &Self;
+
+    /// - Asserts that the given element occurs at least twice in the collection.
+    /// - Supports flexible comparison through the `Borrow<Q>` trait bound.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting the number of times the element occurred.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::duplicate::DuplicateContentAssertion;
+    ///
+    /// let collection = vec![String::from("junit"), String::from("testify"), String::from("junit")];
+    /// collection.should_contain_duplicate_of("junit");
+    /// ```
+    fn should_contain_duplicate_of<Q>(&self, element: &Q) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized;
+
+    /// - Asserts that the given element occurs exactly the given number of times in the collection.
+    /// - Supports flexible comparison through the `Borrow<Q>` trait bound.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting the number of times the element occurred.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::duplicate::DuplicateContentAssertion;
+    ///
+    /// let collection = ["junit", "testify", "junit", "junit"];
+    /// collection.should_contain_exactly_n_of(&"junit", 3);
+    /// ```
+    fn should_contain_exactly_n_of<Q>(&self, element: &Q, times: usize) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized;
 }
 
 impl<T> DuplicateContentAssertion<T> for Vec<T>
@@ -44,6 +133,44 @@ where
         (self as &[T]).should_not_contain_duplicates();
         self
     }
+
+    fn should_contain_consecutive_duplicates(&self) -> &Self {
+        (self as &[T]).should_contain_consecutive_duplicates();
+        self
+    }
+
+    fn should_not_contain_consecutive_duplicates(&self) -> &Self {
+        (self as &[T]).should_not_contain_consecutive_duplicates();
+        self
+    }
+
+    fn should_all_be_equal(&self) -> &Self {
+        (self as &[T]).should_all_be_equal();
+        self
+    }
+
+    fn should_all_be_distinct(&self) -> &Self {
+        (self as &[T]).should_all_be_distinct();
+        self
+    }
+
+    fn should_contain_duplicate_of<Q>(&self, element: &Q) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        (self as &[T]).should_contain_duplicate_of(element);
+        self
+    }
+
+    fn should_contain_exactly_n_of<Q>(&self, element: &Q, times: usize) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        (self as &[T]).should_contain_exactly_n_of(element, times);
+        self
+    }
 }
 
 impl<T, const N: usize> DuplicateContentAssertion<T> for [T; N]
@@ -60,6 +187,44 @@ where
         (self as &[T]).should_not_contain_duplicates();
         self
     }
+
+    fn should_contain_consecutive_duplicates(&self) -> &Self {
+        (self as &[T]).should_contain_consecutive_duplicates();
+        self
+    }
+
+    fn should_not_contain_consecutive_duplicates(&self) -> &Self {
+        (self as &[T]).should_not_contain_consecutive_duplicates();
+        self
+    }
+
+    fn should_all_be_equal(&self) -> &Self {
+        (self as &[T]).should_all_be_equal();
+        self
+    }
+
+    fn should_all_be_distinct(&self) -> &Self {
+        (self as &[T]).should_all_be_distinct();
+        self
+    }
+
+    fn should_contain_duplicate_of<Q>(&self, element: &Q) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        (self as &[T]).should_contain_duplicate_of(element);
+        self
+    }
+
+    fn should_contain_exactly_n_of<Q>(&self, element: &Q, times: usize) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        (self as &[T]).should_contain_exactly_n_of(element, times);
+        self
+    }
 }
 
 impl<T> DuplicateContentAssertion<T> for [T]
@@ -76,11 +241,185 @@ where
         self.should_not(&contain_duplicates());
         self
     }
+
+    fn should_contain_consecutive_duplicates(&self) -> &Self {
+        self.should(&contain_consecutive_duplicates());
+        self
+    }
+
+    fn should_not_contain_consecutive_duplicates(&self) -> &Self {
+        self.should_not(&contain_consecutive_duplicates());
+        self
+    }
+
+    fn should_all_be_equal(&self) -> &Self {
+        self.should(&all_be_equal());
+        self
+    }
+
+    fn should_all_be_distinct(&self) -> &Self {
+        self.should(&all_be_distinct());
+        self
+    }
+
+    fn should_contain_duplicate_of<Q>(&self, element: &Q) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        map(self).should(&contain_duplicate_of(element));
+        self
+    }
+
+    fn should_contain_exactly_n_of<Q>(&self, element: &Q, times: usize) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        map(self).should(&contain_exactly_n_of(element, times));
+        self
+    }
+}
+
+/// DistinctCountAssertion enables assertions about the number of distinct elements in a collection.
+pub trait DistinctCountAssertion<T: Eq + Hash> {
+    /// - Asserts that the collection contains exactly the given number of distinct elements.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::duplicate::DistinctCountAssertion;
+    ///
+    /// let categories = ["books", "music", "books", "games", "music"];
+    /// categories.should_have_distinct_count(3);
+    /// ```
+    fn should_have_distinct_count(&self, count: usize) -> &Self;
+
+    /// - Asserts that the collection does not contain exactly the given number of distinct elements.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::duplicate::DistinctCountAssertion;
+    ///
+    /// let categories = ["books", "music", "books"];
+    /// categories.should_not_have_distinct_count(3);
+    /// ```
+    fn should_not_have_distinct_count(&self, count: usize) -> &Self;
+
+    /// - Asserts that the number of distinct elements in the collection falls within the given inclusive range.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::duplicate::DistinctCountAssertion;
+    ///
+    /// let categories = ["books", "music", "books"];
+    /// categories.should_have_distinct_count_in_inclusive_range(1..=2);
+    /// ```
+    fn should_have_distinct_count_in_inclusive_range(&self, range: RangeInclusive<usize>) -> &Self;
+
+    /// - Asserts that the number of distinct elements in the collection does not fall within the given inclusive range.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::duplicate::DistinctCountAssertion;
+    ///
+    /// let categories = ["books", "music", "books"];
+    /// categories.should_not_have_distinct_count_in_inclusive_range(3..=5);
+    /// ```
+    fn should_not_have_distinct_count_in_inclusive_range(&self, range: RangeInclusive<usize>) -> &Self;
+}
+
+impl<T> DistinctCountAssertion<T> for Vec<T>
+where
+    T: Debug,
+    T: Eq + Hash,
+{
+    fn should_have_distinct_count(&self, count: usize) -> &Self {
+        (self as &[T]).should_have_distinct_count(count);
+        self
+    }
+
+    fn should_not_have_distinct_count(&self, count: usize) -> &Self {
+        (self as &[T]).should_not_have_distinct_count(count);
+        self
+    }
+
+    fn should_have_distinct_count_in_inclusive_range(&self, range: RangeInclusive<usize>) -> &Self {
+        (self as &[T]).should_have_distinct_count_in_inclusive_range(range);
+        self
+    }
+
+    fn should_not_have_distinct_count_in_inclusive_range(&self, range: RangeInclusive<usize>) -> &Self {
+        (self as &[T]).should_not_have_distinct_count_in_inclusive_range(range);
+        self
+    }
+}
+
+impl<T, const N: usize> DistinctCountAssertion<T> for [T; N]
+where
+    T: Debug,
+    T: Eq + Hash,
+{
+    fn should_have_distinct_count(&self, count: usize) -> &Self {
+        (self as &[T]).should_have_distinct_count(count);
+        self
+    }
+
+    fn should_not_have_distinct_count(&self, count: usize) -> &Self {
+        (self as &[T]).should_not_have_distinct_count(count);
+        self
+    }
+
+    fn should_have_distinct_count_in_inclusive_range(&self, range: RangeInclusive<usize>) -> &Self {
+        (self as &[T]).should_have_distinct_count_in_inclusive_range(range);
+        self
+    }
+
+    fn should_not_have_distinct_count_in_inclusive_range(&self, range: RangeInclusive<usize>) -> &Self {
+        (self as &[T]).should_not_have_distinct_count_in_inclusive_range(range);
+        self
+    }
+}
+
+impl<T> DistinctCountAssertion<T> for [T]
+where
+    T: Debug,
+    T: Eq + Hash,
+{
+    fn should_have_distinct_count(&self, count: usize) -> &Self {
+        self.should(&have_distinct_count(count));
+        self
+    }
+
+    fn should_not_have_distinct_count(&self, count: usize) -> &Self {
+        self.should_not(&have_distinct_count(count));
+        self
+    }
+
+    fn should_have_distinct_count_in_inclusive_range(&self, range: RangeInclusive<usize>) -> &Self {
+        self.should(&have_distinct_count_in_inclusive_range(range));
+        self
+    }
+
+    fn should_not_have_distinct_count_in_inclusive_range(&self, range: RangeInclusive<usize>) -> &Self {
+        self.should_not(&have_distinct_count_in_inclusive_range(range));
+        self
+    }
+}
+
+fn map<T, Q: ?Sized>(collection: &[T]) -> Vec<&Q>
+where
+    T: Borrow<Q>,
+{
+    collection.iter().map(|source| source.borrow()).collect()
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::assertions::collection::duplicate::DuplicateContentAssertion;
+    use crate::assertions::collection::duplicate::{DistinctCountAssertion, DuplicateContentAssertion};
 
     #[test]
     fn should_contain_duplicates() {
@@ -107,11 +446,146 @@ mod tests {
         let collection = vec!["junit", "testify", "assert4j", "testify"];
         collection.should_not_contain_duplicates();
     }
+
+    #[test]
+    fn should_have_distinct_count() {
+        let categories = vec!["books", "music", "books", "games", "music"];
+        categories.should_have_distinct_count(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "should have 2 distinct elements but had 3 [\"books\", \"music\", \"games\"]")]
+    fn should_have_distinct_count_but_it_did_not() {
+        let categories = vec!["books", "music", "books", "games", "music"];
+        categories.should_have_distinct_count(2);
+    }
+
+    #[test]
+    fn should_not_have_distinct_count() {
+        let categories = vec!["books", "music", "books"];
+        categories.should_not_have_distinct_count(3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_have_distinct_count_but_it_had() {
+        let categories = vec!["books", "music", "books"];
+        categories.should_not_have_distinct_count(2);
+    }
+
+    #[test]
+    fn should_have_distinct_count_in_inclusive_range() {
+        let categories = vec!["books", "music", "books", "games"];
+        categories.should_have_distinct_count_in_inclusive_range(2..=3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_distinct_count_in_inclusive_range_but_it_did_not() {
+        let categories = vec!["books", "books", "books"];
+        categories.should_have_distinct_count_in_inclusive_range(2..=3);
+    }
+
+    #[test]
+    fn should_not_have_distinct_count_in_inclusive_range() {
+        let categories = vec!["books", "books", "books"];
+        categories.should_not_have_distinct_count_in_inclusive_range(2..=3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_have_distinct_count_in_inclusive_range_but_it_had() {
+        let categories = vec!["books", "music", "books"];
+        categories.should_not_have_distinct_count_in_inclusive_range(2..=3);
+    }
+
+    #[test]
+    fn should_all_be_equal() {
+        let collection = vec!["junit", "junit", "junit"];
+        collection.should_all_be_equal();
+    }
+
+    #[test]
+    fn should_all_be_equal_for_a_single_element() {
+        let collection = vec!["junit"];
+        collection.should_all_be_equal();
+    }
+
+    #[test]
+    #[should_panic(expected = "but \"testify\" at index 2 differed from \"junit\"")]
+    fn should_all_be_equal_but_was_not() {
+        let collection = vec!["junit", "junit", "testify", "junit"];
+        collection.should_all_be_equal();
+    }
+
+    #[test]
+    fn should_all_be_distinct() {
+        let collection = vec!["junit", "testify", "assert4j"];
+        collection.should_all_be_distinct();
+    }
+
+    #[test]
+    #[should_panic(expected = "but \"testify\" at index 1 was repeated at index 3")]
+    fn should_all_be_distinct_but_was_not() {
+        let collection = vec!["junit", "testify", "assert4j", "testify"];
+        collection.should_all_be_distinct();
+    }
+
+    #[test]
+    fn should_contain_duplicate_of() {
+        let collection = vec!["junit", "testify", "junit"];
+        collection.should_contain_duplicate_of(&"junit");
+    }
+
+    #[test]
+    fn should_contain_duplicate_of_a_borrowed_element() {
+        let collection = vec![String::from("junit"), String::from("junit")];
+        collection.should_contain_duplicate_of("junit");
+    }
+
+    #[test]
+    #[should_panic(expected = "[\"junit\", \"testify\"] should contain \"junit\" at least twice but it occurred 1 time(s)")]
+    fn should_contain_duplicate_of_but_it_occurred_once() {
+        let collection = vec!["junit", "testify"];
+        collection.should_contain_duplicate_of(&"junit");
+    }
+
+    #[test]
+    #[should_panic(expected = "[\"junit\", \"testify\"] should contain \"catch2\" at least twice but it occurred 0 time(s)")]
+    fn should_contain_duplicate_of_but_it_was_absent() {
+        let collection = vec!["junit", "testify"];
+        collection.should_contain_duplicate_of(&"catch2");
+    }
+
+    #[test]
+    fn should_contain_exactly_n_of_given_a_single_occurrence() {
+        let collection = vec!["junit", "testify"];
+        collection.should_contain_exactly_n_of(&"junit", 1);
+    }
+
+    #[test]
+    fn should_contain_exactly_n_of_given_a_double_occurrence() {
+        let collection = vec!["junit", "testify", "junit"];
+        collection.should_contain_exactly_n_of(&"junit", 2);
+    }
+
+    #[test]
+    fn should_contain_exactly_n_of_given_an_absent_element() {
+        let collection = vec!["junit", "testify"];
+        collection.should_contain_exactly_n_of(&"catch2", 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "[\"junit\", \"testify\", \"junit\"] should contain \"junit\" exactly 1 time(s) but it occurred 2 time(s)")]
+    fn should_contain_exactly_n_of_but_it_did_not() {
+        let collection = vec!["junit", "testify", "junit"];
+        collection.should_contain_exactly_n_of(&"junit", 1);
+    }
 }
 
 #[cfg(test)]
 mod array_tests {
-    use crate::assertions::collection::duplicate::DuplicateContentAssertion;
+    use crate::assertions::collection::duplicate::{DistinctCountAssertion, DuplicateContentAssertion};
 
     #[test]
     fn should_contain_duplicates() {
@@ -138,4 +612,80 @@ mod array_tests {
         let collection = ["junit", "testify", "assert4j", "testify"];
         collection.should_not_contain_duplicates();
     }
+
+    #[test]
+    fn should_have_distinct_count() {
+        let categories = ["books", "music", "books", "games", "music"];
+        categories.should_have_distinct_count(3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_distinct_count_in_inclusive_range_but_it_did_not() {
+        let categories = ["books", "books", "books"];
+        categories.should_have_distinct_count_in_inclusive_range(2..=3);
+    }
+
+    #[test]
+    fn should_all_be_equal() {
+        let collection = ["junit", "junit"];
+        collection.should_all_be_equal();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_all_be_distinct_but_was_not() {
+        let collection = ["junit", "junit"];
+        collection.should_all_be_distinct();
+    }
+
+    #[test]
+    fn should_contain_duplicate_of() {
+        let collection = [1, 2, 1];
+        collection.should_contain_duplicate_of(&1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_exactly_n_of_but_it_did_not() {
+        let collection = [1, 2, 1];
+        collection.should_contain_exactly_n_of(&2, 2);
+    }
+}
+
+#[cfg(test)]
+mod consecutive_duplicate_tests {
+    use crate::assertions::collection::duplicate::DuplicateContentAssertion;
+
+    #[test]
+    fn should_contain_consecutive_duplicates() {
+        let collection = vec![1, 1, 2];
+        collection.should_contain_consecutive_duplicates();
+    }
+
+    #[test]
+    #[should_panic(expected = "[1, 2, 1] should contain consecutive duplicates")]
+    fn should_contain_consecutive_duplicates_but_the_duplicates_were_apart() {
+        let collection = vec![1, 2, 1];
+        collection.should_contain_duplicates().should_contain_consecutive_duplicates();
+    }
+
+    #[test]
+    fn should_not_contain_consecutive_duplicates() {
+        let collection = [1, 2, 1];
+        collection.should_not_contain_consecutive_duplicates();
+    }
+
+    #[test]
+    fn should_not_contain_consecutive_duplicates_given_an_empty_collection() {
+        let collection: Vec<i32> = vec![];
+        collection.should_not_contain_consecutive_duplicates();
+    }
+
+    #[test]
+    #[should_panic(expected = "[\"junit\", \"clearcheck\", \"clearcheck\"] should not contain consecutive duplicates but \"clearcheck\" at index 1 was repeated at index 2")]
+    fn should_not_contain_consecutive_duplicates_but_it_contained() {
+        let collection = ["junit", "clearcheck", "clearcheck"];
+        collection.should_not_contain_consecutive_duplicates();
+    }
 }
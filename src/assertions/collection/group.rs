@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::matchers::{Should, ShouldNot};
+use crate::matchers::collection::group::group_by;
+
+/// GroupAssertion enables assertions about the sizes of the groups formed by grouping the elements of a collection by a key function.
+///
+/// On failure, the panic message lists the actual and the expected group size for every key that differs.
+pub trait GroupAssertion<T> {
+    /// - Asserts that grouping the elements by the key function yields exactly the expected group sizes.
+    /// - Every key produced by the key function must be expected, and every expected key (with a non-zero size) must be produced.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use clearcheck::assertions::collection::group::GroupAssertion;
+    ///
+    /// let collection = vec!["junit", "jest", "clearcheck"];
+    /// collection.should_group_by(
+    ///     |element| element.chars().next(),
+    ///     HashMap::from([(Some('j'), 2), (Some('c'), 1)]),
+    /// );
+    /// ```
+    fn should_group_by<K, F>(&self, key_fn: F, expected: HashMap<K, usize>) -> &Self
+        where
+            F: Fn(&T) -> K,
+            K: Hash + Eq + Debug;
+
+    /// - Asserts that grouping the elements by the key function does not yield exactly the expected group sizes.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use clearcheck::assertions::collection::group::GroupAssertion;
+    ///
+    /// let collection = vec!["junit", "jest", "clearcheck"];
+    /// collection.should_not_group_by(
+    ///     |element| element.chars().next(),
+    ///     HashMap::from([(Some('j'), 3)]),
+    /// );
+    /// ```
+    fn should_not_group_by<K, F>(&self, key_fn: F, expected: HashMap<K, usize>) -> &Self
+        where
+            F: Fn(&T) -> K,
+            K: Hash + Eq + Debug;
+}
+
+impl<T: Debug> GroupAssertion<T> for Vec<T> {
+    fn should_group_by<K, F>(&self, key_fn: F, expected: HashMap<K, usize>) -> &Self
+        where
+            F: Fn(&T) -> K,
+            K: Hash + Eq + Debug,
+    {
+        (self as &[T]).should_group_by(key_fn, expected);
+        self
+    }
+
+    fn should_not_group_by<K, F>(&self, key_fn: F, expected: HashMap<K, usize>) -> &Self
+        where
+            F: Fn(&T) -> K,
+            K: Hash + Eq + Debug,
+    {
+        (self as &[T]).should_not_group_by(key_fn, expected);
+        self
+    }
+}
+
+impl<T: Debug, const N: usize> GroupAssertion<T> for [T; N] {
+    fn should_group_by<K, F>(&self, key_fn: F, expected: HashMap<K, usize>) -> &Self
+        where
+            F: Fn(&T) -> K,
+            K: Hash + Eq + Debug,
+    {
+        (self as &[T]).should_group_by(key_fn, expected);
+        self
+    }
+
+    fn should_not_group_by<K, F>(&self, key_fn: F, expected: HashMap<K, usize>) -> &Self
+        where
+            F: Fn(&T) -> K,
+            K: Hash + Eq + Debug,
+    {
+        (self as &[T]).should_not_group_by(key_fn, expected);
+        self
+    }
+}
+
+impl<T: Debug> GroupAssertion<T> for [T] {
+    fn should_group_by<K, F>(&self, key_fn: F, expected: HashMap<K, usize>) -> &Self
+        where
+            F: Fn(&T) -> K,
+            K: Hash + Eq + Debug,
+    {
+        self.should(&group_by(key_fn, expected));
+        self
+    }
+
+    fn should_not_group_by<K, F>(&self, key_fn: F, expected: HashMap<K, usize>) -> &Self
+        where
+            F: Fn(&T) -> K,
+            K: Hash + Eq + Debug,
+    {
+        self.should_not(&group_by(key_fn, expected));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::assertions::collection::group::GroupAssertion;
+
+    fn first_letter(word: &&str) -> char {
+        word.chars().next().unwrap_or_default()
+    }
+
+    #[test]
+    fn should_group_by_first_letter() {
+        let words = vec!["apple", "avocado", "banana", "cherry", "blueberry"];
+        words.should_group_by(first_letter, HashMap::from([('a', 2), ('b', 2), ('c', 1)]));
+    }
+
+    #[test]
+    fn should_group_by_given_an_expected_empty_group() {
+        let words = ["apple", "banana"];
+        words.should_group_by(first_letter, HashMap::from([('a', 1), ('b', 1), ('c', 0)]));
+    }
+
+    #[test]
+    fn should_group_an_empty_collection() {
+        let words: Vec<&str> = vec![];
+        words.should_group_by(first_letter, HashMap::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "[\"apple\", \"avocado\", \"banana\"] should group into the expected sizes but differed for 'a' (actual 2, expected 1), 'b' (actual 1, expected 2)")]
+    fn should_group_by_but_the_sizes_differed() {
+        let words = vec!["apple", "avocado", "banana"];
+        words.should_group_by(first_letter, HashMap::from([('a', 1), ('b', 2)]));
+    }
+
+    #[test]
+    #[should_panic(expected = "should group into the expected sizes but differed for 'b' (actual 1, expected 0), 'c' (actual 0, expected 1)")]
+    fn should_group_by_but_a_key_was_unexpected_and_a_key_was_missing() {
+        let words = vec!["apple", "banana"];
+        words.should_group_by(first_letter, HashMap::from([('a', 1), ('c', 1)]));
+    }
+
+    #[test]
+    fn should_not_group_by() {
+        let words = vec!["apple", "avocado", "banana"];
+        words.should_not_group_by(first_letter, HashMap::from([('a', 1), ('b', 2)]));
+    }
+
+    #[test]
+    #[should_panic(expected = "[\"apple\", \"banana\"] should not group into the expected sizes")]
+    fn should_not_group_by_but_it_did() {
+        let words = vec!["apple", "banana"];
+        words.should_not_group_by(first_letter, HashMap::from([('a', 1), ('b', 1)]));
+    }
+}
@@ -1,9 +1,11 @@
 use std::borrow::Borrow;
+use std::collections::VecDeque;
 use std::fmt::Debug;
+use std::hash::Hash;
 
 use crate::matchers::{Should, ShouldNot};
 use crate::matchers::collection::empty::be_empty;
-use crate::matchers::collection::membership::{contain, contain_all, contain_any};
+use crate::matchers::collection::membership::{all_be_equal_to, be_disjoint_from, be_permutation_of, contain, contain_all, contain_all_in_order_ignoring_case, contain_any, contain_exactly_once, contain_sequence};
 
 /// MembershipAssertion enables assertions about the presence or the absence of elements in a collection.
 pub trait MembershipAssertion<T>
@@ -45,7 +47,7 @@ where
     /// - Asserts that the collection contains all the given elements.
     /// - Supports flexible comparison through the `Borrow<Q>` trait bound.
     /// - Returns a reference to self for fluent chaining.
-    /// - Panics if the assertion fails.
+    /// - Panics if the assertion fails, listing every given element that was missing from the collection.
     /// # Example
     /// ```
     /// use clearcheck::assertions::collection::membership::MembershipAssertion;
@@ -106,6 +108,169 @@ where
         T: Borrow<Q>,
         Q: Eq + Debug + ?Sized;
 
+    /// - Asserts that the collection contains the given sequence as a contiguous run of elements, in the same order.
+    /// - An empty sequence is always contained; a sequence longer than the collection is never contained.
+    /// - Supports flexible comparison through the `Borrow<Q>` trait bound.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::membership::MembershipAssertion;
+    ///
+    /// let collection = vec!["junit", "assert4j", "clearcheck"];
+    /// collection.should_contain_sequence(vec!["assert4j", "clearcheck"]);
+    /// ```
+    fn should_contain_sequence<Q>(&self, sequence: Vec<&Q>) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized;
+
+    /// - Asserts that the collection does not contain the given sequence as a contiguous run of elements.
+    /// - Supports flexible comparison through the `Borrow<Q>` trait bound.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::membership::MembershipAssertion;
+    ///
+    /// let collection = vec!["junit", "assert4j", "clearcheck"];
+    /// collection.should_not_contain_sequence(vec!["junit", "clearcheck"]);
+    /// ```
+    fn should_not_contain_sequence<Q>(&self, sequence: Vec<&Q>) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized;
+
+    /// - Asserts that the collection shares no elements with the other collection.
+    /// - The other collection is collected into a set once, so its elements need to implement Hash.
+    /// - Empty collections are trivially disjoint.
+    /// - Supports flexible comparison through the `Borrow<Q>` trait bound.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, listing each shared element once.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::membership::MembershipAssertion;
+    ///
+    /// let collection = vec!["junit", "assert4j", "clearcheck"];
+    /// collection.should_be_disjoint_from(&["testify", "xunit"]);
+    /// ```
+    fn should_be_disjoint_from<Q>(&self, other: &[Q]) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Hash + Debug;
+
+    /// - Asserts that the collection shares at least one element with the other collection.
+    /// - Supports flexible comparison through the `Borrow<Q>` trait bound.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::membership::MembershipAssertion;
+    ///
+    /// let collection = vec!["junit", "assert4j", "clearcheck"];
+    /// collection.should_not_be_disjoint_from(&["clearcheck", "xunit"]);
+    /// ```
+    fn should_not_be_disjoint_from<Q>(&self, other: &[Q]) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Hash + Debug;
+
+    /// - Asserts that every element of the collection equals the given element.
+    /// - Supports flexible comparison through the `Borrow<Q>` trait bound.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting the first element that differed.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::membership::MembershipAssertion;
+    ///
+    /// let collection = vec!["junit", "junit", "junit"];
+    /// collection.should_all_equal("junit");
+    /// ```
+    fn should_all_equal<Q>(&self, element: &Q) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized;
+
+    /// - Asserts that at least one element of the collection does not equal the given element.
+    /// - Supports flexible comparison through the `Borrow<Q>` trait bound.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::membership::MembershipAssertion;
+    ///
+    /// let collection = vec!["junit", "junit", "clearcheck"];
+    /// collection.should_not_all_equal("junit");
+    /// ```
+    fn should_not_all_equal<Q>(&self, element: &Q) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized;
+
+    /// - Asserts that the collection contains the given element exactly once.
+    /// - Supports flexible comparison through the `Borrow<Q>` trait bound.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting whether the element was not found or how many times it was found.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::membership::MembershipAssertion;
+    ///
+    /// let collection = vec!["junit", "assert4j", "clearcheck"];
+    /// collection.should_contain_exactly_once("clearcheck");
+    /// ```
+    fn should_contain_exactly_once<Q>(&self, element: &Q) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized;
+
+    /// - Asserts that the collection does not contain the given element exactly once, that is, it is either absent or duplicated.
+    /// - Supports flexible comparison through the `Borrow<Q>` trait bound.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::membership::MembershipAssertion;
+    ///
+    /// let collection = vec!["junit", "clearcheck", "clearcheck"];
+    /// collection.should_not_contain_exactly_once("clearcheck");
+    /// ```
+    fn should_not_contain_exactly_once<Q>(&self, element: &Q) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized;
+
+    /// - Asserts that the collection is a permutation of the given elements: it contains the same elements with the same number of occurrences, in any order.
+    /// - Supports flexible comparison through the `Borrow<Q>` trait bound.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting every element whose number of occurrences differs.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::membership::MembershipAssertion;
+    ///
+    /// let collection = vec!["junit", "clearcheck", "junit"];
+    /// collection.should_be_a_permutation_of(vec!["junit", "junit", "clearcheck"]);
+    /// ```
+    fn should_be_a_permutation_of<Q>(&self, other: Vec<&Q>) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized;
+
+    /// - Asserts that the collection is not a permutation of the given elements.
+    /// - Supports flexible comparison through the `Borrow<Q>` trait bound.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::membership::MembershipAssertion;
+    ///
+    /// let collection = vec!["junit", "clearcheck", "junit"];
+    /// collection.should_not_be_a_permutation_of(vec!["junit", "clearcheck", "clearcheck"]);
+    /// ```
+    fn should_not_be_a_permutation_of<Q>(&self, other: Vec<&Q>) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized;
+
     /// - Asserts that the collection is empty.
     /// - Returns a reference to self for fluent chaining.
     /// - Panics if the assertion fails.
@@ -190,6 +355,96 @@ where
         self
     }
 
+    fn should_contain_sequence<Q>(&self, sequence: Vec<&Q>) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        (self as &[T]).should_contain_sequence(sequence);
+        self
+    }
+
+    fn should_not_contain_sequence<Q>(&self, sequence: Vec<&Q>) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        (self as &[T]).should_not_contain_sequence(sequence);
+        self
+    }
+
+    fn should_be_disjoint_from<Q>(&self, other: &[Q]) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Hash + Debug,
+    {
+        (self as &[T]).should_be_disjoint_from(other);
+        self
+    }
+
+    fn should_not_be_disjoint_from<Q>(&self, other: &[Q]) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Hash + Debug,
+    {
+        (self as &[T]).should_not_be_disjoint_from(other);
+        self
+    }
+
+    fn should_all_equal<Q>(&self, element: &Q) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        (self as &[T]).should_all_equal(element);
+        self
+    }
+
+    fn should_not_all_equal<Q>(&self, element: &Q) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        (self as &[T]).should_not_all_equal(element);
+        self
+    }
+
+    fn should_contain_exactly_once<Q>(&self, element: &Q) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        (self as &[T]).should_contain_exactly_once(element);
+        self
+    }
+
+    fn should_not_contain_exactly_once<Q>(&self, element: &Q) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        (self as &[T]).should_not_contain_exactly_once(element);
+        self
+    }
+
+    fn should_be_a_permutation_of<Q>(&self, other: Vec<&Q>) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        (self as &[T]).should_be_a_permutation_of(other);
+        self
+    }
+
+    fn should_not_be_a_permutation_of<Q>(&self, other: Vec<&Q>) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        (self as &[T]).should_not_be_a_permutation_of(other);
+        self
+    }
+
     fn should_be_empty(&self) -> &Self {
         (self as &[T]).should_be_empty();
         self
@@ -260,6 +515,96 @@ where
         self
     }
 
+    fn should_contain_sequence<Q>(&self, sequence: Vec<&Q>) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        (self as &[T]).should_contain_sequence(sequence);
+        self
+    }
+
+    fn should_not_contain_sequence<Q>(&self, sequence: Vec<&Q>) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        (self as &[T]).should_not_contain_sequence(sequence);
+        self
+    }
+
+    fn should_be_disjoint_from<Q>(&self, other: &[Q]) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Hash + Debug,
+    {
+        (self as &[T]).should_be_disjoint_from(other);
+        self
+    }
+
+    fn should_not_be_disjoint_from<Q>(&self, other: &[Q]) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Hash + Debug,
+    {
+        (self as &[T]).should_not_be_disjoint_from(other);
+        self
+    }
+
+    fn should_all_equal<Q>(&self, element: &Q) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        (self as &[T]).should_all_equal(element);
+        self
+    }
+
+    fn should_not_all_equal<Q>(&self, element: &Q) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        (self as &[T]).should_not_all_equal(element);
+        self
+    }
+
+    fn should_contain_exactly_once<Q>(&self, element: &Q) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        (self as &[T]).should_contain_exactly_once(element);
+        self
+    }
+
+    fn should_not_contain_exactly_once<Q>(&self, element: &Q) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        (self as &[T]).should_not_contain_exactly_once(element);
+        self
+    }
+
+    fn should_be_a_permutation_of<Q>(&self, other: Vec<&Q>) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        (self as &[T]).should_be_a_permutation_of(other);
+        self
+    }
+
+    fn should_not_be_a_permutation_of<Q>(&self, other: Vec<&Q>) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        (self as &[T]).should_not_be_a_permutation_of(other);
+        self
+    }
+
     fn should_be_empty(&self) -> &Self {
         (self as &[T]).should_be_empty();
         self
@@ -330,6 +675,96 @@ where
         self
     }
 
+    fn should_contain_sequence<Q>(&self, sequence: Vec<&Q>) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        map(self).should(&contain_sequence(sequence));
+        self
+    }
+
+    fn should_not_contain_sequence<Q>(&self, sequence: Vec<&Q>) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        map(self).should_not(&contain_sequence(sequence));
+        self
+    }
+
+    fn should_be_disjoint_from<Q>(&self, other: &[Q]) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Hash + Debug,
+    {
+        map(self).should(&be_disjoint_from(other.iter().collect()));
+        self
+    }
+
+    fn should_not_be_disjoint_from<Q>(&self, other: &[Q]) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Hash + Debug,
+    {
+        map(self).should_not(&be_disjoint_from(other.iter().collect()));
+        self
+    }
+
+    fn should_all_equal<Q>(&self, element: &Q) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        map(self).should(&all_be_equal_to(element));
+        self
+    }
+
+    fn should_not_all_equal<Q>(&self, element: &Q) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        map(self).should_not(&all_be_equal_to(element));
+        self
+    }
+
+    fn should_contain_exactly_once<Q>(&self, element: &Q) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        map(self).should(&contain_exactly_once(element));
+        self
+    }
+
+    fn should_not_contain_exactly_once<Q>(&self, element: &Q) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        map(self).should_not(&contain_exactly_once(element));
+        self
+    }
+
+    fn should_be_a_permutation_of<Q>(&self, other: Vec<&Q>) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        map(self).should(&be_permutation_of(other));
+        self
+    }
+
+    fn should_not_be_a_permutation_of<Q>(&self, other: Vec<&Q>) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        map(self).should_not(&be_permutation_of(other));
+        self
+    }
+
     fn should_be_empty(&self) -> &Self {
         self.should(&be_empty());
         self
@@ -341,6 +776,166 @@ where
     }
 }
 
+impl<T> MembershipAssertion<T> for VecDeque<T>
+where
+    T: Debug,
+    T: Eq,
+{
+    fn should_contain<Q>(&self, element: &Q) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        map_deque(self).should(&contain(element));
+        self
+    }
+
+    fn should_not_contain<Q>(&self, element: &Q) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        map_deque(self).should_not(&contain(element));
+        self
+    }
+
+    fn should_contain_all<Q>(&self, elements: Vec<&Q>) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        map_deque(self).should(&contain_all(elements));
+        self
+    }
+
+    fn should_not_contain_all<Q>(&self, elements: Vec<&Q>) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        map_deque(self).should_not(&contain_all(elements));
+        self
+    }
+
+    fn should_contain_any<Q>(&self, elements: Vec<&Q>) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        map_deque(self).should(&contain_any(elements));
+        self
+    }
+
+    fn should_not_contain_any<Q>(&self, elements: Vec<&Q>) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        map_deque(self).should_not(&contain_any(elements));
+        self
+    }
+
+    fn should_contain_sequence<Q>(&self, sequence: Vec<&Q>) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        map_deque(self).should(&contain_sequence(sequence));
+        self
+    }
+
+    fn should_not_contain_sequence<Q>(&self, sequence: Vec<&Q>) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        map_deque(self).should_not(&contain_sequence(sequence));
+        self
+    }
+
+    fn should_be_disjoint_from<Q>(&self, other: &[Q]) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Hash + Debug,
+    {
+        map_deque(self).should(&be_disjoint_from(other.iter().collect()));
+        self
+    }
+
+    fn should_not_be_disjoint_from<Q>(&self, other: &[Q]) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Hash + Debug,
+    {
+        map_deque(self).should_not(&be_disjoint_from(other.iter().collect()));
+        self
+    }
+
+    fn should_all_equal<Q>(&self, element: &Q) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        map_deque(self).should(&all_be_equal_to(element));
+        self
+    }
+
+    fn should_not_all_equal<Q>(&self, element: &Q) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        map_deque(self).should_not(&all_be_equal_to(element));
+        self
+    }
+
+    fn should_contain_exactly_once<Q>(&self, element: &Q) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        map_deque(self).should(&contain_exactly_once(element));
+        self
+    }
+
+    fn should_not_contain_exactly_once<Q>(&self, element: &Q) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        map_deque(self).should_not(&contain_exactly_once(element));
+        self
+    }
+
+    fn should_be_a_permutation_of<Q>(&self, other: Vec<&Q>) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        map_deque(self).should(&be_permutation_of(other));
+        self
+    }
+
+    fn should_not_be_a_permutation_of<Q>(&self, other: Vec<&Q>) -> &Self
+    where
+        T: Borrow<Q>,
+        Q: Eq + Debug + ?Sized,
+    {
+        map_deque(self).should_not(&be_permutation_of(other));
+        self
+    }
+
+    fn should_be_empty(&self) -> &Self {
+        self.iter().collect::<Vec<_>>().should(&be_empty());
+        self
+    }
+
+    fn should_not_be_empty(&self) -> &Self {
+        self.iter().collect::<Vec<_>>().should_not(&be_empty());
+        self
+    }
+}
+
 fn map<T, Q: ?Sized>(collection: &[T]) -> Vec<&Q>
 where
     T: Borrow<Q>,
@@ -348,6 +943,86 @@ where
     collection.iter().map(|source| source.borrow()).collect()
 }
 
+fn map_deque<T, Q: ?Sized>(collection: &VecDeque<T>) -> Vec<&Q>
+where
+    T: Borrow<Q>,
+{
+    collection.iter().map(|source| source.borrow()).collect()
+}
+
+/// IgnoreCaseMembershipAssertion enables assertions about the presence of elements in a collection of strings, ignoring case differences.
+pub trait IgnoreCaseMembershipAssertion {
+    /// - Asserts that the collection contains all the given elements in the same relative order, ignoring case differences.
+    /// - The elements need not be contiguous, other elements may appear between them.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::membership::IgnoreCaseMembershipAssertion;
+    ///
+    /// let log = vec!["STARTING server", "listening on 8080", "Ready", "shutdown"];
+    /// log.should_contain_all_in_order_ignoring_case(vec!["starting server", "READY"]);
+    /// ```
+    fn should_contain_all_in_order_ignoring_case(&self, elements: Vec<&str>) -> &Self;
+
+    /// - Asserts that the collection does not contain all the given elements in the same relative order, ignoring case differences.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::membership::IgnoreCaseMembershipAssertion;
+    ///
+    /// let log = vec!["STARTING server", "listening on 8080", "Ready", "shutdown"];
+    /// log.should_not_contain_all_in_order_ignoring_case(vec!["ready", "starting server"]);
+    /// ```
+    fn should_not_contain_all_in_order_ignoring_case(&self, elements: Vec<&str>) -> &Self;
+}
+
+impl<T> IgnoreCaseMembershipAssertion for Vec<T>
+where
+    T: AsRef<str> + Debug,
+{
+    fn should_contain_all_in_order_ignoring_case(&self, elements: Vec<&str>) -> &Self {
+        (self as &[T]).should_contain_all_in_order_ignoring_case(elements);
+        self
+    }
+
+    fn should_not_contain_all_in_order_ignoring_case(&self, elements: Vec<&str>) -> &Self {
+        (self as &[T]).should_not_contain_all_in_order_ignoring_case(elements);
+        self
+    }
+}
+
+impl<T, const N: usize> IgnoreCaseMembershipAssertion for [T; N]
+where
+    T: AsRef<str> + Debug,
+{
+    fn should_contain_all_in_order_ignoring_case(&self, elements: Vec<&str>) -> &Self {
+        (self as &[T]).should_contain_all_in_order_ignoring_case(elements);
+        self
+    }
+
+    fn should_not_contain_all_in_order_ignoring_case(&self, elements: Vec<&str>) -> &Self {
+        (self as &[T]).should_not_contain_all_in_order_ignoring_case(elements);
+        self
+    }
+}
+
+impl<T> IgnoreCaseMembershipAssertion for [T]
+where
+    T: AsRef<str> + Debug,
+{
+    fn should_contain_all_in_order_ignoring_case(&self, elements: Vec<&str>) -> &Self {
+        self.should(&contain_all_in_order_ignoring_case(elements));
+        self
+    }
+
+    fn should_not_contain_all_in_order_ignoring_case(&self, elements: Vec<&str>) -> &Self {
+        self.should_not(&contain_all_in_order_ignoring_case(elements));
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::assertions::collection::membership::MembershipAssertion;
@@ -419,6 +1094,14 @@ mod tests {
         collection.should_contain_all(to_be_contained);
     }
 
+    #[test]
+    #[should_panic(expected = "[\"junit\", \"assert4j\", \"catch2\"] should contain [\"xunit\", \"assert4j\", \"testify\"] but was missing [\"xunit\", \"testify\"]")]
+    fn should_contain_all_but_reported_all_the_missing_elements() {
+        let collection = vec!["junit", "assert4j", "catch2"];
+        let to_be_contained = vec!["xunit", "assert4j", "testify"];
+        collection.should_contain_all(to_be_contained);
+    }
+
     #[test]
     fn should_not_contain_all() {
         let collection = vec!["junit", "assert4j", "catch2"];
@@ -463,6 +1146,84 @@ mod tests {
         let to_be_contained = vec!["assert4j", "junit"];
         collection.should_not_contain_any(to_be_contained);
     }
+
+    #[test]
+    fn should_contain_sequence() {
+        let collection = vec![1, 2, 3, 4];
+        collection.should_contain_sequence(vec![&2, &3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "should contain the sequence [2, 4] contiguously but it was not found")]
+    fn should_contain_sequence_but_it_was_not_contiguous() {
+        let collection = vec![1, 2, 3, 4];
+        collection.should_contain_sequence(vec![&2, &4]);
+    }
+
+    #[test]
+    fn should_contain_an_empty_sequence() {
+        let collection = vec![1, 2, 3, 4];
+        collection.should_contain_sequence(vec![]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_sequence_but_it_was_longer_than_the_collection() {
+        let collection = vec![1, 2];
+        collection.should_contain_sequence(vec![&1, &2, &3]);
+    }
+
+    #[test]
+    fn should_not_contain_sequence() {
+        let collection = vec!["junit", "assert4j", "catch2"];
+        collection.should_not_contain_sequence(vec!["junit", "catch2"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_contain_sequence_but_it_did() {
+        let collection = vec!["junit", "assert4j", "catch2"];
+        collection.should_not_contain_sequence(vec!["assert4j", "catch2"]);
+    }
+
+    #[test]
+    fn should_be_disjoint_from() {
+        let collection = vec!["junit", "assert4j", "catch2"];
+        collection.should_be_disjoint_from(&["testify", "xunit"]);
+    }
+
+    #[test]
+    fn should_be_disjoint_from_an_empty_collection() {
+        let collection: Vec<i32> = vec![];
+        collection.should_be_disjoint_from(&[1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "but shared [\"assert4j\", \"catch2\"]")]
+    fn should_be_disjoint_from_but_it_was_not() {
+        let collection = vec!["junit", "assert4j", "catch2"];
+        collection.should_be_disjoint_from(&["catch2", "xunit", "assert4j"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "but shared [\"catch2\", \"junit\"]")]
+    fn should_be_disjoint_from_but_it_shared_repeated_elements() {
+        let collection = vec!["catch2", "junit", "catch2", "junit"];
+        collection.should_be_disjoint_from(&["junit", "catch2", "junit"]);
+    }
+
+    #[test]
+    fn should_not_be_disjoint_from() {
+        let collection = vec!["junit", "assert4j", "catch2"];
+        collection.should_not_be_disjoint_from(&["catch2", "xunit"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_disjoint_from_but_it_was() {
+        let collection = vec!["junit", "assert4j", "catch2"];
+        collection.should_not_be_disjoint_from(&["testify", "xunit"]);
+    }
 }
 
 #[cfg(test)]
@@ -580,4 +1341,305 @@ mod array_tests {
         let to_be_contained = vec!["assert4j", "junit"];
         collection.should_not_contain_any(to_be_contained);
     }
+
+    #[test]
+    fn should_contain_sequence() {
+        let collection = [1, 2, 3, 4];
+        collection.should_contain_sequence(vec![&2, &3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_sequence_but_it_was_not_contiguous() {
+        let collection = [1, 2, 3, 4];
+        collection.should_contain_sequence(vec![&2, &4]);
+    }
+
+    #[test]
+    fn should_be_disjoint_from() {
+        let collection = [1, 2, 3];
+        collection.should_be_disjoint_from(&[4, 5]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_disjoint_from_but_it_was_not() {
+        let collection = [1, 2, 3];
+        collection.should_be_disjoint_from(&[3, 4]);
+    }
+}
+
+#[cfg(test)]
+mod ignore_case_tests {
+    use crate::assertions::collection::membership::IgnoreCaseMembershipAssertion;
+
+    #[test]
+    fn should_contain_all_in_order_ignoring_case() {
+        let log = vec!["INFO Starting server", "DEBUG loading config", "info LISTENING on 8080", "WARN slow request", "Info Ready"];
+        log.should_contain_all_in_order_ignoring_case(vec!["info starting server", "INFO listening on 8080", "info ready"]);
+    }
+
+    #[test]
+    fn should_contain_all_in_order_ignoring_case_given_owned_strings() {
+        let log = vec![String::from("BEGIN"), String::from("commit"), String::from("End")];
+        log.should_contain_all_in_order_ignoring_case(vec!["begin", "END"]);
+    }
+
+    #[test]
+    fn should_contain_no_elements_in_order_ignoring_case() {
+        let log = vec!["BEGIN", "END"];
+        log.should_contain_all_in_order_ignoring_case(vec![]);
+    }
+
+    #[test]
+    #[should_panic(expected = "[\"BEGIN\", \"commit\", \"End\"] should contain [\"begin\", \"end\", \"commit\"] in order ignoring case but only 2 of 3 elements matched in order and \"commit\" was not found")]
+    fn should_contain_all_in_order_ignoring_case_but_the_order_was_different() {
+        let log = vec!["BEGIN", "commit", "End"];
+        log.should_contain_all_in_order_ignoring_case(vec!["begin", "end", "commit"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "only 0 of 1 elements matched in order and \"rollback\" was not found")]
+    fn should_contain_all_in_order_ignoring_case_but_an_element_was_missing() {
+        let log = vec!["BEGIN", "commit", "End"];
+        log.should_contain_all_in_order_ignoring_case(vec!["rollback"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_all_in_order_ignoring_case_but_an_element_was_matched_only_once() {
+        let log = vec!["retry", "done"];
+        log.should_contain_all_in_order_ignoring_case(vec!["RETRY", "retry"]);
+    }
+
+    #[test]
+    fn should_not_contain_all_in_order_ignoring_case() {
+        let log = ["BEGIN", "commit", "End"];
+        log.should_not_contain_all_in_order_ignoring_case(vec!["end", "begin"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_contain_all_in_order_ignoring_case_but_it_did() {
+        let log = ["BEGIN", "commit", "End"];
+        log.should_not_contain_all_in_order_ignoring_case(vec!["begin", "END"]);
+    }
+
+    #[test]
+    fn should_contain_all_in_order_ignoring_case_in_a_slice() {
+        let log: &[&str] = &["BEGIN", "commit", "End"];
+        log.should_contain_all_in_order_ignoring_case(vec!["commit", "end"]);
+    }
+}
+
+#[cfg(test)]
+mod vec_deque_tests {
+    use std::collections::VecDeque;
+
+    use crate::assertions::collection::membership::MembershipAssertion;
+
+    #[test]
+    fn should_contain() {
+        let queue = VecDeque::from(vec!["junit", "testify"]);
+        queue.should_contain(&"junit");
+    }
+
+    #[test]
+    fn should_contain_after_wrapping_around() {
+        let mut queue = VecDeque::with_capacity(3);
+        queue.push_back(1);
+        queue.push_back(2);
+        queue.push_back(3);
+        queue.pop_front();
+        queue.push_back(4);
+
+        queue.should_contain(&4).should_not_contain(&1).should_contain_sequence(vec![&3, &4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_but_it_did_not() {
+        let queue = VecDeque::from(vec!["junit", "testify"]);
+        queue.should_contain(&"gotest");
+    }
+
+    #[test]
+    fn should_contain_all_with_borrowed_elements() {
+        let queue = VecDeque::from(vec![String::from("junit"), String::from("testify")]);
+        queue.should_contain_all(vec!["testify", "junit"]);
+    }
+
+    #[test]
+    fn should_be_disjoint_from() {
+        let queue = VecDeque::from(vec![1, 2, 3]);
+        queue.should_be_disjoint_from(&[4, 5]);
+    }
+
+    #[test]
+    fn should_be_empty() {
+        let queue: VecDeque<i32> = VecDeque::new();
+        queue.should_be_empty();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_empty_but_was_not() {
+        let queue = VecDeque::from(vec![1]);
+        queue.should_be_empty();
+    }
+}
+
+#[cfg(test)]
+mod all_equal_tests {
+    use std::collections::VecDeque;
+
+    use crate::assertions::collection::membership::MembershipAssertion;
+
+    #[test]
+    fn should_all_equal() {
+        let collection = vec!["junit", "junit", "junit"];
+        collection.should_all_equal("junit");
+    }
+
+    #[test]
+    fn should_all_equal_given_an_empty_collection() {
+        let collection: Vec<i32> = vec![];
+        collection.should_all_equal(&10);
+    }
+
+    #[test]
+    #[should_panic(expected = "[\"junit\", \"clearcheck\", \"testify\"] should have all elements equal to \"junit\" but the element at index 1 was \"clearcheck\"")]
+    fn should_all_equal_but_an_element_was_different() {
+        let collection = ["junit", "clearcheck", "testify"];
+        collection.should_all_equal("junit");
+    }
+
+    #[test]
+    fn should_all_equal_owned_strings() {
+        let collection = vec![String::from("rust"), String::from("rust")];
+        collection.should_all_equal("rust");
+    }
+
+    #[test]
+    fn should_all_equal_in_a_deque() {
+        let queue = VecDeque::from(vec![4, 4]);
+        queue.should_all_equal(&4);
+    }
+
+    #[test]
+    fn should_not_all_equal() {
+        let collection: &[i32] = &[4, 4, 5];
+        collection.should_not_all_equal(&4);
+    }
+
+    #[test]
+    #[should_panic(expected = "[4, 4] should not have all elements equal to 4")]
+    fn should_not_all_equal_but_they_did() {
+        let collection = vec![4, 4];
+        collection.should_not_all_equal(&4);
+    }
+}
+
+#[cfg(test)]
+mod exactly_once_tests {
+    use std::collections::VecDeque;
+
+    use crate::assertions::collection::membership::MembershipAssertion;
+
+    #[test]
+    fn should_contain_exactly_once() {
+        let collection = vec!["junit", "assert4j", "clearcheck"];
+        collection.should_contain_exactly_once("clearcheck");
+    }
+
+    #[test]
+    #[should_panic(expected = "[\"junit\", \"assert4j\"] should contain \"clearcheck\" exactly once but it was not found")]
+    fn should_contain_exactly_once_but_it_was_not_found() {
+        let collection = ["junit", "assert4j"];
+        collection.should_contain_exactly_once("clearcheck");
+    }
+
+    #[test]
+    #[should_panic(expected = "[1, 2, 1] should contain 1 exactly once but it was found 2 times")]
+    fn should_contain_exactly_once_but_it_was_found_twice() {
+        let collection = vec![1, 2, 1];
+        collection.should_contain_exactly_once(&1);
+    }
+
+    #[test]
+    fn should_contain_exactly_once_in_a_deque() {
+        let queue = VecDeque::from(vec![String::from("rust"), String::from("go")]);
+        queue.should_contain_exactly_once("go");
+    }
+
+    #[test]
+    fn should_not_contain_exactly_once_given_the_element_was_absent() {
+        let collection: &[i32] = &[2, 3];
+        collection.should_not_contain_exactly_once(&1);
+    }
+
+    #[test]
+    fn should_not_contain_exactly_once_given_the_element_was_duplicated() {
+        let collection = vec![1, 2, 1];
+        collection.should_not_contain_exactly_once(&1);
+    }
+
+    #[test]
+    #[should_panic(expected = "[1, 2] should not contain 1 exactly once")]
+    fn should_not_contain_exactly_once_but_it_did() {
+        let collection = vec![1, 2];
+        collection.should_not_contain_exactly_once(&1);
+    }
+}
+
+#[cfg(test)]
+mod permutation_tests {
+    use std::collections::VecDeque;
+
+    use crate::assertions::collection::membership::MembershipAssertion;
+
+    #[test]
+    fn should_be_a_permutation_of() {
+        let collection = vec![3, 1, 2, 1];
+        collection.should_be_a_permutation_of(vec![&1, &1, &2, &3]);
+    }
+
+    #[test]
+    fn should_be_a_permutation_of_given_borrowed_elements() {
+        let collection = [String::from("junit"), String::from("clearcheck")];
+        collection.should_be_a_permutation_of(vec!["clearcheck", "junit"]);
+    }
+
+    #[test]
+    fn should_be_a_permutation_of_in_a_deque() {
+        let queue = VecDeque::from(vec!["rust", "go"]);
+        queue.should_be_a_permutation_of(vec![&"go", &"rust"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "[1, 1, 2] should be a permutation of [1, 2, 2] but it was not: 1 (found 2, expected 1), 2 (found 1, expected 2)")]
+    fn should_be_a_permutation_of_but_the_counts_differed() {
+        let collection = vec![1, 1, 2];
+        collection.should_be_a_permutation_of(vec![&1, &2, &2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "[\"junit\"] should be a permutation of [\"junit\", \"clearcheck\"] but it was not: \"clearcheck\" (found 0, expected 1)")]
+    fn should_be_a_permutation_of_but_an_element_was_missing() {
+        let collection = vec!["junit"];
+        collection.should_be_a_permutation_of(vec!["junit", "clearcheck"]);
+    }
+
+    #[test]
+    fn should_not_be_a_permutation_of() {
+        let collection = vec![1, 1, 2];
+        collection.should_not_be_a_permutation_of(vec![&1, &2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "[2, 1] should not be a permutation of [1, 2]")]
+    fn should_not_be_a_permutation_of_but_it_was() {
+        let collection = [2, 1];
+        collection.should_not_be_a_permutation_of(vec![&1, &2]);
+    }
 }
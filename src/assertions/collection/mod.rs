@@ -1,6 +1,9 @@
+pub mod aggregate;
 pub mod bound;
+pub mod capacity;
 pub mod duplicate;
 pub mod equal;
+pub mod group;
 pub mod increasing_decreasing;
 pub mod membership;
 pub mod size;
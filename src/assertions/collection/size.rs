@@ -1,3 +1,4 @@
+use std::collections::VecDeque;
 use std::ops::{Range, RangeInclusive};
 
 use crate::matchers::collection::length::{
@@ -281,6 +282,58 @@ where
     }
 }
 
+impl<T> SizeAssertion for VecDeque<T>
+where
+    T: std::fmt::Debug,
+{
+    fn should_have_size(&self, size: usize) -> &Self {
+        self.iter().collect::<Vec<_>>().should(&have_same_length(size));
+        self
+    }
+
+    fn should_not_have_size(&self, size: usize) -> &Self {
+        self.iter().collect::<Vec<_>>().should_not(&have_same_length(size));
+        self
+    }
+
+    fn should_have_at_least_size(&self, size: usize) -> &Self {
+        self.iter().collect::<Vec<_>>().should(&have_atleast_same_length(size));
+        self
+    }
+
+    fn should_have_at_most_size(&self, size: usize) -> &Self {
+        self.iter().collect::<Vec<_>>().should(&have_atmost_same_length(size));
+        self
+    }
+
+    fn should_be_same_size_as<U>(&self, other: &[U]) -> &Self {
+        self.iter().collect::<Vec<_>>().should(&have_same_length(other.len()));
+        self
+    }
+
+    fn should_have_size_in_inclusive_range(&self, range: RangeInclusive<usize>) -> &Self {
+        self.len().should(&have_length_in_inclusive_range(range));
+        self
+    }
+
+    fn should_not_have_size_in_inclusive_range(&self, range: RangeInclusive<usize>) -> &Self {
+        self.len()
+            .should_not(&have_length_in_inclusive_range(range));
+        self
+    }
+
+    fn should_have_size_in_exclusive_range(&self, range: Range<usize>) -> &Self {
+        self.len().should(&have_length_in_exclusive_range(range));
+        self
+    }
+
+    fn should_not_have_size_in_exclusive_range(&self, range: Range<usize>) -> &Self {
+        self.len()
+            .should_not(&have_length_in_exclusive_range(range));
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::assertions::collection::size::SizeAssertion;
@@ -524,3 +577,48 @@ mod array_tests {
         collection.should_not_have_size_in_exclusive_range(1..9);
     }
 }
+
+#[cfg(test)]
+mod vec_deque_tests {
+    use std::collections::VecDeque;
+
+    use crate::assertions::collection::size::SizeAssertion;
+
+    #[test]
+    fn should_have_size() {
+        let queue = VecDeque::from(vec!["junit", "testify"]);
+        queue.should_have_size(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"Collection\" length 2 should be 3")]
+    fn should_have_size_but_was_not() {
+        let queue = VecDeque::from(vec!["junit", "testify"]);
+        queue.should_have_size(3);
+    }
+
+    #[test]
+    fn should_have_at_least_and_at_most_size() {
+        let queue = VecDeque::from(vec![1, 2, 3]);
+        queue.should_have_at_least_size(3).should_have_at_most_size(3);
+    }
+
+    #[test]
+    fn should_be_same_size_as() {
+        let queue = VecDeque::from(vec![1, 2, 3]);
+        queue.should_be_same_size_as(&["a", "b", "c"]);
+    }
+
+    #[test]
+    fn should_have_size_in_inclusive_range() {
+        let queue = VecDeque::from(vec![1, 2, 3]);
+        queue.should_have_size_in_inclusive_range(1..=3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_size_in_exclusive_range_but_was_not() {
+        let queue = VecDeque::from(vec![1, 2, 3]);
+        queue.should_have_size_in_exclusive_range(1..3);
+    }
+}
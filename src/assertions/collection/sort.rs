@@ -1,5 +1,11 @@
-use crate::matchers::collection::sort::{be_sorted_ascending, be_sorted_descending};
-use crate::matchers::Should;
+use std::collections::VecDeque;
+use std::fmt::Debug;
+
+use crate::matchers::collection::sort::{
+    be_monotonic, be_sorted_ascending, be_sorted_ascending_by_key, be_sorted_ascending_ignoring_case,
+    be_sorted_descending, be_sorted_descending_by_key, have_at_most_inversions,
+};
+use crate::matchers::{Should, ShouldNot};
 
 /// SortAssertion enables assertions about whether a collection's elements are sorted in a specific order.
 pub trait SortAssertion<T>
@@ -29,11 +35,85 @@ where
     /// collection.should_be_sorted_descending();
     /// ```
     fn should_be_sorted_descending(&self) -> &Self;
+
+    /// - Asserts that the elements of the collection are monotonic: either entirely non-decreasing or entirely non-increasing.
+    /// - Empty and single-element collections are considered monotonic.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting the first index where the order breaks in both directions.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::sort::SortAssertion;
+    ///
+    /// let collection = vec![9, 7, 7, 2];
+    /// collection.should_be_monotonic();
+    /// ```
+    fn should_be_monotonic(&self) -> &Self;
+
+    /// - Asserts that the elements of the collection are in ascending order (non-decreasing) of the key extracted by the given function.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting the first index where the keys are out of order.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::sort::SortAssertion;
+    ///
+    /// #[derive(Debug, PartialEq, PartialOrd)]
+    /// struct Book {
+    ///     title: &'static str,
+    ///     pages: usize,
+    /// }
+    ///
+    /// let books = vec![
+    ///     Book { title: "Rust in action", pages: 456 },
+    ///     Book { title: "Database internals", pages: 500 },
+    /// ];
+    /// books.should_be_sorted_ascending_by_key(|book| book.pages);
+    /// ```
+    fn should_be_sorted_ascending_by_key<K, F>(&self, key_fn: F) -> &Self
+    where
+        K: Ord + Debug,
+        F: Fn(&T) -> K;
+
+    /// - Asserts that the elements of the collection are in descending order (non-increasing) of the key extracted by the given function.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting the first index where the keys are out of order.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::sort::SortAssertion;
+    ///
+    /// #[derive(Debug, PartialEq, PartialOrd)]
+    /// struct Book {
+    ///     title: &'static str,
+    ///     pages: usize,
+    /// }
+    ///
+    /// let books = vec![
+    ///     Book { title: "Database internals", pages: 500 },
+    ///     Book { title: "Rust in action", pages: 456 },
+    /// ];
+    /// books.should_be_sorted_descending_by_key(|book| book.pages);
+    /// ```
+    fn should_be_sorted_descending_by_key<K, F>(&self, key_fn: F) -> &Self
+    where
+        K: Ord + Debug,
+        F: Fn(&T) -> K;
+
+    /// - Asserts that the collection is approximately sorted ascending, having at most the given number of inversions.
+    /// - An inversion is any pair of elements, not necessarily adjacent, where the earlier element is greater than the later one, so a sorted collection has no inversions.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting the actual number of inversions.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::sort::SortAssertion;
+    ///
+    /// let collection = vec![1, 3, 2, 4, 5];
+    /// collection.should_have_at_most_inversions(1);
+    /// ```
+    fn should_have_at_most_inversions(&self, inversions: usize) -> &Self;
 }
 
 impl<T> SortAssertion<T> for Vec<T>
 where
-    T: std::fmt::Debug + PartialOrd,
+    T: Debug + PartialOrd,
 {
     fn should_be_sorted_ascending(&self) -> &Self {
         (self as &[T]).should_be_sorted_ascending();
@@ -44,11 +124,39 @@ where
         (self as &[T]).should_be_sorted_descending();
         self
     }
+
+    fn should_be_monotonic(&self) -> &Self {
+        (self as &[T]).should_be_monotonic();
+        self
+    }
+
+    fn should_be_sorted_ascending_by_key<K, F>(&self, key_fn: F) -> &Self
+    where
+        K: Ord + Debug,
+        F: Fn(&T) -> K,
+    {
+        (self as &[T]).should_be_sorted_ascending_by_key(key_fn);
+        self
+    }
+
+    fn should_be_sorted_descending_by_key<K, F>(&self, key_fn: F) -> &Self
+    where
+        K: Ord + Debug,
+        F: Fn(&T) -> K,
+    {
+        (self as &[T]).should_be_sorted_descending_by_key(key_fn);
+        self
+    }
+
+    fn should_have_at_most_inversions(&self, inversions: usize) -> &Self {
+        (self as &[T]).should_have_at_most_inversions(inversions);
+        self
+    }
 }
 
 impl<T, const N: usize> SortAssertion<T> for [T; N]
 where
-    T: std::fmt::Debug + PartialOrd,
+    T: Debug + PartialOrd,
 {
     fn should_be_sorted_ascending(&self) -> &Self {
         (self as &[T]).should_be_sorted_ascending();
@@ -59,11 +167,39 @@ where
         (self as &[T]).should_be_sorted_descending();
         self
     }
+
+    fn should_be_monotonic(&self) -> &Self {
+        (self as &[T]).should_be_monotonic();
+        self
+    }
+
+    fn should_be_sorted_ascending_by_key<K, F>(&self, key_fn: F) -> &Self
+    where
+        K: Ord + Debug,
+        F: Fn(&T) -> K,
+    {
+        (self as &[T]).should_be_sorted_ascending_by_key(key_fn);
+        self
+    }
+
+    fn should_be_sorted_descending_by_key<K, F>(&self, key_fn: F) -> &Self
+    where
+        K: Ord + Debug,
+        F: Fn(&T) -> K,
+    {
+        (self as &[T]).should_be_sorted_descending_by_key(key_fn);
+        self
+    }
+
+    fn should_have_at_most_inversions(&self, inversions: usize) -> &Self {
+        (self as &[T]).should_have_at_most_inversions(inversions);
+        self
+    }
 }
 
 impl<T> SortAssertion<T> for [T]
 where
-    T: std::fmt::Debug + PartialOrd,
+    T: Debug + PartialOrd,
 {
     fn should_be_sorted_ascending(&self) -> &Self {
         self.should(&be_sorted_ascending());
@@ -74,6 +210,153 @@ where
         self.should(&be_sorted_descending());
         self
     }
+
+    fn should_be_monotonic(&self) -> &Self {
+        self.should(&be_monotonic());
+        self
+    }
+
+    fn should_be_sorted_ascending_by_key<K, F>(&self, key_fn: F) -> &Self
+    where
+        K: Ord + Debug,
+        F: Fn(&T) -> K,
+    {
+        self.should(&be_sorted_ascending_by_key(key_fn));
+        self
+    }
+
+    fn should_be_sorted_descending_by_key<K, F>(&self, key_fn: F) -> &Self
+    where
+        K: Ord + Debug,
+        F: Fn(&T) -> K,
+    {
+        self.should(&be_sorted_descending_by_key(key_fn));
+        self
+    }
+
+    fn should_have_at_most_inversions(&self, inversions: usize) -> &Self {
+        self.should(&have_at_most_inversions(inversions));
+        self
+    }
+}
+
+impl<T> SortAssertion<T> for VecDeque<T>
+where
+    T: Debug + PartialOrd,
+{
+    fn should_be_sorted_ascending(&self) -> &Self {
+        self.iter().collect::<Vec<_>>().should(&be_sorted_ascending());
+        self
+    }
+
+    fn should_be_sorted_descending(&self) -> &Self {
+        self.iter().collect::<Vec<_>>().should(&be_sorted_descending());
+        self
+    }
+
+    fn should_be_monotonic(&self) -> &Self {
+        self.iter().collect::<Vec<_>>().should(&be_monotonic());
+        self
+    }
+
+    fn should_be_sorted_ascending_by_key<K, F>(&self, key_fn: F) -> &Self
+    where
+        K: Ord + Debug,
+        F: Fn(&T) -> K,
+    {
+        self.iter()
+            .collect::<Vec<_>>()
+            .should(&be_sorted_ascending_by_key(|element: &&T| key_fn(element)));
+        self
+    }
+
+    fn should_be_sorted_descending_by_key<K, F>(&self, key_fn: F) -> &Self
+    where
+        K: Ord + Debug,
+        F: Fn(&T) -> K,
+    {
+        self.iter()
+            .collect::<Vec<_>>()
+            .should(&be_sorted_descending_by_key(|element: &&T| key_fn(element)));
+        self
+    }
+
+    fn should_have_at_most_inversions(&self, inversions: usize) -> &Self {
+        self.iter().collect::<Vec<_>>().should(&have_at_most_inversions(inversions));
+        self
+    }
+}
+
+/// IgnoreCaseSortAssertion enables assertions about whether a collection of strings is sorted, ignoring case differences.
+pub trait IgnoreCaseSortAssertion {
+    /// - Asserts that the elements of the collection are in ascending order (non-decreasing) of their lowercased forms.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting the first pair of elements out of order.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::sort::IgnoreCaseSortAssertion;
+    ///
+    /// let labels = vec!["apple", "Banana", "cherry"];
+    /// labels.should_be_sorted_ascending_ignoring_case();
+    /// ```
+    fn should_be_sorted_ascending_ignoring_case(&self) -> &Self;
+
+    /// - Asserts that the elements of the collection are not in ascending order (non-decreasing) of their lowercased forms.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::sort::IgnoreCaseSortAssertion;
+    ///
+    /// let labels = vec!["Banana", "apple"];
+    /// labels.should_not_be_sorted_ascending_ignoring_case();
+    /// ```
+    fn should_not_be_sorted_ascending_ignoring_case(&self) -> &Self;
+}
+
+impl<T> IgnoreCaseSortAssertion for Vec<T>
+where
+    T: AsRef<str> + Debug,
+{
+    fn should_be_sorted_ascending_ignoring_case(&self) -> &Self {
+        (self as &[T]).should_be_sorted_ascending_ignoring_case();
+        self
+    }
+
+    fn should_not_be_sorted_ascending_ignoring_case(&self) -> &Self {
+        (self as &[T]).should_not_be_sorted_ascending_ignoring_case();
+        self
+    }
+}
+
+impl<T, const N: usize> IgnoreCaseSortAssertion for [T; N]
+where
+    T: AsRef<str> + Debug,
+{
+    fn should_be_sorted_ascending_ignoring_case(&self) -> &Self {
+        (self as &[T]).should_be_sorted_ascending_ignoring_case();
+        self
+    }
+
+    fn should_not_be_sorted_ascending_ignoring_case(&self) -> &Self {
+        (self as &[T]).should_not_be_sorted_ascending_ignoring_case();
+        self
+    }
+}
+
+impl<T> IgnoreCaseSortAssertion for [T]
+where
+    T: AsRef<str> + Debug,
+{
+    fn should_be_sorted_ascending_ignoring_case(&self) -> &Self {
+        self.should(&be_sorted_ascending_ignoring_case());
+        self
+    }
+
+    fn should_not_be_sorted_ascending_ignoring_case(&self) -> &Self {
+        self.should_not(&be_sorted_ascending_ignoring_case());
+        self
+    }
 }
 
 #[cfg(test)]
@@ -105,6 +388,96 @@ mod tests {
         let collection = vec!["actual", "testify", "catch"];
         collection.should_be_sorted_descending();
     }
+
+    #[derive(Debug, PartialEq, PartialOrd)]
+    struct Book {
+        title: &'static str,
+        pages: usize,
+    }
+
+    #[test]
+    fn should_be_sorted_ascending_by_key() {
+        let books = vec![
+            Book { title: "Rust in action", pages: 456 },
+            Book { title: "Patterns of distributed systems", pages: 456 },
+            Book { title: "Database internals", pages: 500 },
+        ];
+        books.should_be_sorted_ascending_by_key(|book| book.pages);
+    }
+
+    #[test]
+    #[should_panic(expected = "but the key 500 at index 1 was greater than the key 456 at index 2")]
+    fn should_be_sorted_ascending_by_key_but_was_not() {
+        let books = vec![
+            Book { title: "Designing data intensive applications", pages: 300 },
+            Book { title: "Database internals", pages: 500 },
+            Book { title: "Rust in action", pages: 456 },
+        ];
+        books.should_be_sorted_ascending_by_key(|book| book.pages);
+    }
+
+    #[test]
+    fn should_be_sorted_descending_by_key() {
+        let books = vec![
+            Book { title: "Database internals", pages: 500 },
+            Book { title: "Rust in action", pages: 456 },
+        ];
+        books.should_be_sorted_descending_by_key(|book| book.pages);
+    }
+
+    #[test]
+    #[should_panic(expected = "but the key 456 at index 0 was less than the key 500 at index 1")]
+    fn should_be_sorted_descending_by_key_but_was_not() {
+        let books = vec![
+            Book { title: "Rust in action", pages: 456 },
+            Book { title: "Database internals", pages: 500 },
+        ];
+        books.should_be_sorted_descending_by_key(|book| book.pages);
+    }
+
+    #[test]
+    fn should_be_monotonic_given_an_ascending_collection() {
+        let collection = vec![1, 2, 2, 5, 8];
+        collection.should_be_monotonic();
+    }
+
+    #[test]
+    fn should_be_monotonic_given_a_descending_collection() {
+        let collection = vec!["testify", "catch", "assert", "actual"];
+        collection.should_be_monotonic();
+    }
+
+    #[test]
+    fn should_be_monotonic_given_equal_elements() {
+        let collection = vec![4, 4, 4];
+        collection.should_be_monotonic();
+    }
+
+    #[test]
+    fn should_be_monotonic_given_a_single_element() {
+        let collection = vec![4];
+        collection.should_be_monotonic();
+    }
+
+    #[test]
+    fn should_be_monotonic_given_an_empty_collection() {
+        let collection: Vec<i32> = vec![];
+        collection.should_be_monotonic();
+    }
+
+    #[test]
+    #[should_panic(expected = "[1, 5, 3, 7] should be monotonic (sorted ascending or descending) but the order broke in both directions at index 2")]
+    fn should_be_monotonic_but_was_a_zig_zag() {
+        let collection = vec![1, 5, 3, 7];
+        collection.should_be_monotonic();
+    }
+
+    #[test]
+    #[should_panic(expected = "[9, 9, 4, 6] should be monotonic (sorted ascending or descending) but the order broke in both directions at index 3")]
+    fn should_be_monotonic_but_turned_after_descending() {
+        let collection = vec![9, 9, 4, 6];
+        collection.should_be_monotonic();
+    }
 }
 
 #[cfg(test)]
@@ -136,4 +509,174 @@ mod array_tests {
         let collection = ["actual", "testify", "catch"];
         collection.should_be_sorted_descending();
     }
+
+    #[test]
+    fn should_be_sorted_ascending_by_key() {
+        let collection = [(2, "assert"), (1, "junit")];
+        collection.should_be_sorted_ascending_by_key(|pair| pair.1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_sorted_descending_by_key_but_was_not() {
+        let collection = [(2, "assert"), (1, "junit")];
+        collection.should_be_sorted_descending_by_key(|pair| pair.1);
+    }
+
+    #[test]
+    fn should_be_monotonic() {
+        let collection = [8, 5, 5, 1];
+        collection.should_be_monotonic();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_monotonic_but_was_not() {
+        let collection = [1, 5, 3, 7];
+        collection.should_be_monotonic();
+    }
+}
+
+#[cfg(test)]
+mod vec_deque_tests {
+    use std::collections::VecDeque;
+
+    use crate::assertions::collection::sort::SortAssertion;
+
+    #[test]
+    fn should_be_sorted_ascending() {
+        let queue = VecDeque::from(vec![1, 2, 3]);
+        queue.should_be_sorted_ascending().should_be_monotonic();
+    }
+
+    #[test]
+    fn should_be_sorted_ascending_after_pushing_to_the_front() {
+        let mut queue = VecDeque::from(vec![2, 3]);
+        queue.push_front(1);
+        queue.should_be_sorted_ascending();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_sorted_ascending_but_was_not() {
+        let mut queue = VecDeque::from(vec![2, 3]);
+        queue.push_front(4);
+        queue.should_be_sorted_ascending();
+    }
+
+    #[test]
+    fn should_be_sorted_descending() {
+        let queue = VecDeque::from(vec!["testify", "junit", "assert"]);
+        queue.should_be_sorted_descending();
+    }
+
+    #[test]
+    fn should_be_sorted_ascending_by_key() {
+        let queue = VecDeque::from(vec!["go", "rust", "kotlin"]);
+        queue.should_be_sorted_ascending_by_key(|language| language.len());
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_sorted_descending_by_key_but_was_not() {
+        let queue = VecDeque::from(vec!["go", "rust", "kotlin"]);
+        queue.should_be_sorted_descending_by_key(|language| language.len());
+    }
+}
+
+#[cfg(test)]
+mod inversion_tests {
+    use std::collections::VecDeque;
+
+    use crate::assertions::collection::sort::SortAssertion;
+
+    #[test]
+    fn should_have_no_inversions_given_a_sorted_collection() {
+        let collection = vec![1, 2, 2, 3, 5];
+        collection.should_have_at_most_inversions(0);
+    }
+
+    #[test]
+    fn should_have_no_inversions_given_an_empty_collection() {
+        let collection: Vec<i32> = vec![];
+        collection.should_have_at_most_inversions(0);
+    }
+
+    #[test]
+    fn should_have_at_most_inversions_given_a_slightly_shuffled_collection() {
+        let collection = [1, 3, 2, 4, 6, 5];
+        collection.should_have_at_most_inversions(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "[1, 3, 2, 4, 6, 5] should have at most 1 inversions but had 2")]
+    fn should_have_at_most_inversions_but_had_more() {
+        let collection = vec![1, 3, 2, 4, 6, 5];
+        collection.should_have_at_most_inversions(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "[4, 1, 2, 3] should have at most 1 inversions but had 3")]
+    fn should_count_inversions_between_non_adjacent_elements() {
+        let collection = vec![4, 1, 2, 3];
+        collection.should_have_at_most_inversions(1);
+    }
+
+    #[test]
+    fn should_have_at_most_inversions_given_a_reversed_collection() {
+        let collection: &[i32] = &[4, 3, 2, 1];
+        collection.should_have_at_most_inversions(6);
+    }
+
+    #[test]
+    fn should_have_at_most_inversions_in_vec_deque() {
+        let queue = VecDeque::from(vec![2, 1, 3]);
+        queue.should_have_at_most_inversions(1);
+    }
+}
+
+#[cfg(test)]
+mod ignore_case_tests {
+    use crate::assertions::collection::sort::{IgnoreCaseSortAssertion, SortAssertion};
+
+    #[test]
+    fn should_be_sorted_ascending_ignoring_case() {
+        let labels = vec!["apple", "Banana", "cherry"];
+        labels.should_be_sorted_ascending_ignoring_case();
+    }
+
+    #[test]
+    fn should_be_sorted_ascending_ignoring_case_given_owned_strings() {
+        let labels = [String::from("Delta"), String::from("echo"), String::from("FOXTROT")];
+        labels.should_be_sorted_ascending_ignoring_case();
+    }
+
+    #[test]
+    #[should_panic(expected = "[\"Banana\", \"apple\"] should be sorted ascending ignoring case but \"Banana\" at index 0 should not come before \"apple\" at index 1")]
+    fn should_be_sorted_ascending_ignoring_case_but_was_only_sorted_case_sensitively() {
+        let labels = vec!["Banana", "apple"];
+        labels.should_be_sorted_ascending();
+        labels.should_be_sorted_ascending_ignoring_case();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_sorted_ascending_but_was_only_sorted_ignoring_case() {
+        let labels = vec!["apple", "Banana"];
+        labels.should_be_sorted_ascending_ignoring_case();
+        labels.should_be_sorted_ascending();
+    }
+
+    #[test]
+    fn should_not_be_sorted_ascending_ignoring_case() {
+        let labels: &[&str] = &["cherry", "Apple"];
+        labels.should_not_be_sorted_ascending_ignoring_case();
+    }
+
+    #[test]
+    #[should_panic(expected = "[\"apple\", \"BANANA\"] should not be sorted ascending ignoring case")]
+    fn should_not_be_sorted_ascending_ignoring_case_but_was() {
+        let labels = vec!["apple", "BANANA"];
+        labels.should_not_be_sorted_ascending_ignoring_case();
+    }
 }
@@ -1,6 +1,11 @@
-use chrono::{Datelike, NaiveDate};
+use std::ops::RangeInclusive;
 
-use crate::matchers::date::{be_a_leap_year, have_same_day, have_same_month, have_same_year};
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::matchers::date::{
+    be_a_leap_year, be_after, be_age_at_least, be_before, be_between, be_on_weekday,
+    be_weekday, be_weekend, have_same_day, have_same_month, have_same_year,
+};
 use crate::matchers::{Should, ShouldNot};
 
 /// DateAssertion enables assertions about various properties of NaiveDate.
@@ -201,6 +206,100 @@ pub trait DateAssertion {
     /// date.should_not_be_a_leap_year();
     /// ```
     fn should_not_be_a_leap_year(&self) -> &Self;
+
+    /// - Asserts that the date is strictly before the other date.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use clearcheck::assertions::date::DateAssertion;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+    /// date.should_be_before(&NaiveDate::from_ymd_opt(2024, 1, 11).unwrap());
+    /// ```
+    fn should_be_before(&self, other: &NaiveDate) -> &Self;
+
+    /// - Asserts that the date is strictly after the other date.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use clearcheck::assertions::date::DateAssertion;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+    /// date.should_be_after(&NaiveDate::from_ymd_opt(2023, 12, 31).unwrap());
+    /// ```
+    fn should_be_after(&self, other: &NaiveDate) -> &Self;
+
+    /// - Asserts that the date falls within the given inclusive range of dates.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use clearcheck::assertions::date::DateAssertion;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+    /// date.should_be_between(
+    ///     NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()..=NaiveDate::from_ymd_opt(2024, 1, 31).unwrap()
+    /// );
+    /// ```
+    fn should_be_between(&self, range: RangeInclusive<NaiveDate>) -> &Self;
+
+    /// - Asserts that the date falls on the given day of the week.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    /// use clearcheck::assertions::date::DateAssertion;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 6).unwrap();
+    /// date.should_be_on_weekday(Weekday::Sat);
+    /// ```
+    fn should_be_on_weekday(&self, weekday: Weekday) -> &Self;
+
+    /// - Asserts that the date falls on a weekend (Saturday or Sunday).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use clearcheck::assertions::date::DateAssertion;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 6).unwrap();
+    /// date.should_be_weekend();
+    /// ```
+    fn should_be_weekend(&self) -> &Self;
+
+    /// - Asserts that the date falls on a weekday (Monday to Friday).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use clearcheck::assertions::date::DateAssertion;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 8).unwrap();
+    /// date.should_be_weekday();
+    /// ```
+    fn should_be_weekday(&self) -> &Self;
+
+    /// - Asserts that the date, treated as a birthdate, is an age of at least the given number of years as of the reference date.
+    /// - The age is counted in full years, so a year is only counted once the birthday has passed in the reference year.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use clearcheck::assertions::date::DateAssertion;
+    ///
+    /// let birthdate = NaiveDate::from_ymd_opt(2006, 3, 15).unwrap();
+    /// birthdate.should_be_age_at_least(18, &NaiveDate::from_ymd_opt(2024, 3, 15).unwrap());
+    /// ```
+    fn should_be_age_at_least(&self, years: u32, as_of: &NaiveDate) -> &Self;
 }
 
 impl DateAssertion for NaiveDate {
@@ -267,6 +366,41 @@ impl DateAssertion for NaiveDate {
         self.should_not(&be_a_leap_year());
         self
     }
+
+    fn should_be_before(&self, other: &NaiveDate) -> &Self {
+        self.should(&be_before(*other));
+        self
+    }
+
+    fn should_be_after(&self, other: &NaiveDate) -> &Self {
+        self.should(&be_after(*other));
+        self
+    }
+
+    fn should_be_between(&self, range: RangeInclusive<NaiveDate>) -> &Self {
+        self.should(&be_between(range));
+        self
+    }
+
+    fn should_be_on_weekday(&self, weekday: Weekday) -> &Self {
+        self.should(&be_on_weekday(weekday));
+        self
+    }
+
+    fn should_be_weekend(&self) -> &Self {
+        self.should(&be_weekend());
+        self
+    }
+
+    fn should_be_weekday(&self) -> &Self {
+        self.should(&be_weekday());
+        self
+    }
+
+    fn should_be_age_at_least(&self, years: u32, as_of: &NaiveDate) -> &Self {
+        self.should(&be_age_at_least(years, *as_of));
+        self
+    }
 }
 
 #[cfg(all(test, feature = "date"))]
@@ -457,3 +591,158 @@ mod tests {
         date.should_not_be_a_leap_year();
     }
 }
+
+#[cfg(all(test, feature = "date"))]
+mod ordering_tests {
+    use crate::assertions::date::DateAssertion;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn should_be_before() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+        date.should_be_before(&NaiveDate::from_ymd_opt(2024, 1, 11).unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "date 2024-01-10 should be before 2024-01-10")]
+    fn should_be_before_but_was_the_same_date() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+        date.should_be_before(&NaiveDate::from_ymd_opt(2024, 1, 10).unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "date 2024-01-10 should be before 2023-12-31")]
+    fn should_be_before_but_was_after() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+        date.should_be_before(&NaiveDate::from_ymd_opt(2023, 12, 31).unwrap());
+    }
+
+    #[test]
+    fn should_be_after() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        date.should_be_after(&NaiveDate::from_ymd_opt(2023, 12, 31).unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "date 2024-01-10 should be after 2024-01-10")]
+    fn should_be_after_but_was_the_same_date() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+        date.should_be_after(&NaiveDate::from_ymd_opt(2024, 1, 10).unwrap());
+    }
+
+    #[test]
+    fn should_be_between() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+        date.should_be_between(
+            NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()..=NaiveDate::from_ymd_opt(2024, 1, 31).unwrap(),
+        );
+    }
+
+    #[test]
+    fn should_be_between_given_the_date_is_on_the_boundaries() {
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+        start.should_be_between(start..=end);
+        end.should_be_between(start..=end);
+    }
+
+    #[test]
+    #[should_panic(expected = "date 2024-02-01 should be between 2024-01-01 and 2024-01-31 (inclusive)")]
+    fn should_be_between_but_was_not() {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
+        date.should_be_between(
+            NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()..=NaiveDate::from_ymd_opt(2024, 1, 31).unwrap(),
+        );
+    }
+}
+
+#[cfg(all(test, feature = "date"))]
+mod weekday_tests {
+    use crate::assertions::date::DateAssertion;
+    use chrono::{NaiveDate, Weekday};
+
+    #[test]
+    fn should_be_on_weekday() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 6).unwrap();
+        date.should_be_on_weekday(Weekday::Sat);
+    }
+
+    #[test]
+    #[should_panic(expected = "date 2024-01-06 should be on Fri but was on Sat")]
+    fn should_be_on_weekday_but_was_not() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 6).unwrap();
+        date.should_be_on_weekday(Weekday::Fri);
+    }
+
+    #[test]
+    fn should_be_weekend_on_saturday_and_sunday() {
+        NaiveDate::from_ymd_opt(2024, 1, 6).unwrap().should_be_weekend();
+        NaiveDate::from_ymd_opt(2024, 1, 7).unwrap().should_be_weekend();
+    }
+
+    #[test]
+    #[should_panic(expected = "date 2024-01-08 should be on a weekend but was on Mon")]
+    fn should_be_weekend_but_was_not() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 8).unwrap();
+        date.should_be_weekend();
+    }
+
+    #[test]
+    fn should_be_weekday_on_monday_and_friday() {
+        NaiveDate::from_ymd_opt(2024, 1, 8).unwrap().should_be_weekday();
+        NaiveDate::from_ymd_opt(2024, 1, 12).unwrap().should_be_weekday();
+    }
+
+    #[test]
+    #[should_panic(expected = "date 2024-01-07 should be on a weekday but was on Sun")]
+    fn should_be_weekday_but_was_not() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 7).unwrap();
+        date.should_be_weekday();
+    }
+}
+
+#[cfg(all(test, feature = "date"))]
+mod age_tests {
+    use crate::assertions::date::DateAssertion;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn should_be_age_at_least_on_the_birthday() {
+        let birthdate = NaiveDate::from_ymd_opt(2006, 3, 15).unwrap();
+        birthdate.should_be_age_at_least(18, &NaiveDate::from_ymd_opt(2024, 3, 15).unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "date 2006-03-15 should be an age of at least 18 years as of 2024-03-14 but the age was 17")]
+    fn should_be_age_at_least_but_it_was_the_day_before_the_birthday() {
+        let birthdate = NaiveDate::from_ymd_opt(2006, 3, 15).unwrap();
+        birthdate.should_be_age_at_least(18, &NaiveDate::from_ymd_opt(2024, 3, 14).unwrap());
+    }
+
+    #[test]
+    fn should_be_age_at_least_after_the_birthday() {
+        let birthdate = NaiveDate::from_ymd_opt(2006, 3, 15).unwrap();
+        birthdate.should_be_age_at_least(18, &NaiveDate::from_ymd_opt(2030, 1, 1).unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "date 2006-12-31 should be an age of at least 18 years as of 2024-12-30 but the age was 17")]
+    fn should_be_age_at_least_but_the_birthday_had_not_passed_in_the_reference_year() {
+        let birthdate = NaiveDate::from_ymd_opt(2006, 12, 31).unwrap();
+        birthdate.should_be_age_at_least(18, &NaiveDate::from_ymd_opt(2024, 12, 30).unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "but the age was -1")]
+    fn should_be_age_at_least_but_the_reference_date_was_before_the_birthdate() {
+        let birthdate = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+        birthdate.should_be_age_at_least(0, &NaiveDate::from_ymd_opt(2024, 3, 14).unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "date 2024-03-14 should be an age of at least 4294967295 years as of 2024-03-15 but the age was 0")]
+    fn should_be_age_at_least_but_the_years_exceeded_the_range_of_an_age() {
+        let birthdate = NaiveDate::from_ymd_opt(2024, 3, 14).unwrap();
+        birthdate.should_be_age_at_least(u32::MAX, &NaiveDate::from_ymd_opt(2024, 3, 15).unwrap());
+    }
+}
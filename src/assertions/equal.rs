@@ -2,10 +2,12 @@ use std::borrow::Borrow;
 use std::fmt::Debug;
 
 use crate::matchers::{Should, ShouldNot};
-use crate::matchers::equal::be_equal;
+use crate::matchers::equal::{be_default, be_equal, be_equal_by, be_equal_to_any, be_variant};
 
-/// EqualityAssertion enables assertions about the equality of two values of type T: Eq.
-pub trait EqualityAssertion<T: Eq> {
+/// EqualityAssertion enables assertions about the equality of two values of type T: PartialEq.
+///
+/// It is implemented for any T: PartialEq + Debug, including integers, floats, strings, collections and user-defined types.
+pub trait EqualityAssertion<T: PartialEq> {
     /// - Asserts that the value held by self is equal to other.
     /// - Returns a reference to self for fluent chaining.
     /// - Panics if the assertion fails.
@@ -31,7 +33,7 @@ pub trait EqualityAssertion<T: Eq> {
     fn should_equal<Q>(&self, other: &Q) -> &Self
         where
             T: Borrow<Q>,
-            Q: Eq + Debug + ?Sized;
+            Q: PartialEq + Debug + ?Sized;
 
     /// - Asserts that the value held by self is not equal to other.
     /// - Returns a reference to self for fluent chaining.
@@ -57,14 +59,44 @@ pub trait EqualityAssertion<T: Eq> {
     fn should_not_equal<Q>(&self, other: &Q) -> &Self
         where
             T: Borrow<Q>,
-            Q: Eq + Debug + ?Sized;
+            Q: PartialEq + Debug + ?Sized;
+
+    /// - Asserts that the value held by self is equal to any of the given values.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, listing the values it was compared against.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::equal::EqualityAssertion;
+    ///
+    /// let value = 2;
+    /// value.should_be_equal_to_any(vec![&1, &2, &3]);
+    /// ```
+    fn should_be_equal_to_any<Q>(&self, others: Vec<&Q>) -> &Self
+        where
+            T: Borrow<Q>,
+            Q: PartialEq + Debug + ?Sized;
+
+    /// - Asserts that the value held by self is equal to none of the given values.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::equal::EqualityAssertion;
+    ///
+    /// let value = 5;
+    /// value.should_not_be_equal_to_any(vec![&1, &2, &3]);
+    /// ```
+    fn should_not_be_equal_to_any<Q>(&self, others: Vec<&Q>) -> &Self
+        where
+            T: Borrow<Q>,
+            Q: PartialEq + Debug + ?Sized;
 }
 
-impl<T: Eq + Debug> EqualityAssertion<T> for T {
+impl<T: PartialEq + Debug> EqualityAssertion<T> for T {
     fn should_equal<Q>(&self, other: &Q) -> &Self
         where
             T: Borrow<Q>,
-            Q: Eq + Debug + ?Sized,
+            Q: PartialEq + Debug + ?Sized,
     {
         self.borrow().should(&be_equal(other));
         self
@@ -73,11 +105,193 @@ impl<T: Eq + Debug> EqualityAssertion<T> for T {
     fn should_not_equal<Q>(&self, other: &Q) -> &Self
         where
             T: Borrow<Q>,
-            Q: Eq + Debug + ?Sized,
+            Q: PartialEq + Debug + ?Sized,
     {
         self.borrow().should_not(&be_equal(other));
         self
     }
+
+    fn should_be_equal_to_any<Q>(&self, others: Vec<&Q>) -> &Self
+        where
+            T: Borrow<Q>,
+            Q: PartialEq + Debug + ?Sized,
+    {
+        self.borrow().should(&be_equal_to_any(others));
+        self
+    }
+
+    fn should_not_be_equal_to_any<Q>(&self, others: Vec<&Q>) -> &Self
+        where
+            T: Borrow<Q>,
+            Q: PartialEq + Debug + ?Sized,
+    {
+        self.borrow().should_not(&be_equal_to_any(others));
+        self
+    }
+}
+
+/// CustomEqualityAssertion enables assertions about the equality of two values using a custom comparator.
+///
+/// It is implemented for any T: Debug, so it works with types that do not implement PartialEq, or whose
+/// domain-specific equality differs from PartialEq. The description of the comparator is included in the failure messages.
+pub trait CustomEqualityAssertion {
+    /// - Asserts that the value held by self is equal to other, as decided by the comparator.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, showing both the values and the description of the comparator.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::equal::CustomEqualityAssertion;
+    ///
+    /// let measured: f64 = 10.0;
+    /// measured.should_equal_by(&10.001, "within a tolerance of 0.01", |a, b| (a - b).abs() <= 0.01);
+    /// ```
+    fn should_equal_by<F>(&self, other: &Self, description: &str, comparator: F) -> &Self
+        where F: Fn(&Self, &Self) -> bool;
+
+    /// - Asserts that the value held by self is not equal to other, as decided by the comparator.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, showing both the values and the description of the comparator.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::equal::CustomEqualityAssertion;
+    ///
+    /// let measured: f64 = 10.0;
+    /// measured.should_not_equal_by(&10.5, "within a tolerance of 0.01", |a, b| (a - b).abs() <= 0.01);
+    /// ```
+    fn should_not_equal_by<F>(&self, other: &Self, description: &str, comparator: F) -> &Self
+        where F: Fn(&Self, &Self) -> bool;
+}
+
+impl<T: Debug> CustomEqualityAssertion for T {
+    fn should_equal_by<F>(&self, other: &Self, description: &str, comparator: F) -> &Self
+        where F: Fn(&Self, &Self) -> bool
+    {
+        self.should(&be_equal_by(other, description, comparator));
+        self
+    }
+
+    fn should_not_equal_by<F>(&self, other: &Self, description: &str, comparator: F) -> &Self
+        where F: Fn(&Self, &Self) -> bool
+    {
+        self.should_not(&be_equal_by(other, description, comparator));
+        self
+    }
+}
+
+/// VariantAssertion enables assertions about the variant of a value, typically an enum, without comparing its payload.
+///
+/// It is implemented for any T: Debug, so the type does not need to implement PartialEq. The variant is decided by a
+/// predicate, usually written with [matches!], and the variant name is only used in the failure messages.
+///
+/// The methods are named should_be_variant and should_not_be_variant, rather than should_match, so that they do not clash with
+/// `should_match` from `RegularExpressionAssertion` (enabled by the 'regex' feature) on strings.
+pub trait VariantAssertion {
+    /// - Asserts that the value held by self is of the given variant, as decided by the predicate.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, naming the expected variant and showing the actual value.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::equal::VariantAssertion;
+    ///
+    /// #[derive(Debug)]
+    /// enum Shape {
+    ///     Circle(f64),
+    ///     Square(f64),
+    /// }
+    ///
+    /// let shape = Shape::Circle(2.5);
+    /// shape.should_be_variant(|shape| matches!(shape, Shape::Circle(_)), "Shape::Circle");
+    /// ```
+    fn should_be_variant<F>(&self, predicate: F, variant_name: &str) -> &Self
+        where F: Fn(&Self) -> bool;
+
+    /// - Asserts that the value held by self is not of the given variant, as decided by the predicate.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, naming the variant and showing the actual value.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::equal::VariantAssertion;
+    ///
+    /// #[derive(Debug)]
+    /// enum Shape {
+    ///     Circle(f64),
+    ///     Square(f64),
+    /// }
+    ///
+    /// let shape = Shape::Square(4.0);
+    /// shape.should_not_be_variant(|shape| matches!(shape, Shape::Circle(_)), "Shape::Circle");
+    /// ```
+    fn should_not_be_variant<F>(&self, predicate: F, variant_name: &str) -> &Self
+        where F: Fn(&Self) -> bool;
+}
+
+impl<T: Debug> VariantAssertion for T {
+    fn should_be_variant<F>(&self, predicate: F, variant_name: &str) -> &Self
+        where F: Fn(&Self) -> bool
+    {
+        self.should(&be_variant(predicate, variant_name));
+        self
+    }
+
+    fn should_not_be_variant<F>(&self, predicate: F, variant_name: &str) -> &Self
+        where F: Fn(&Self) -> bool
+    {
+        self.should_not(&be_variant(predicate, variant_name));
+        self
+    }
+}
+
+/// DefaultAssertion enables assertions about whether a value equals the default value of its type.
+///
+/// It is implemented for any T: Default + PartialEq + Debug.
+pub trait DefaultAssertion {
+    /// - Asserts that the value held by self is equal to the default value of its type.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::equal::DefaultAssertion;
+    ///
+    /// #[derive(Debug, Default, PartialEq)]
+    /// struct Config {
+    ///     retries: u32,
+    ///     verbose: bool,
+    /// }
+    ///
+    /// let config = Config::default();
+    /// config.should_be_default();
+    /// ```
+    fn should_be_default(&self) -> &Self;
+
+    /// - Asserts that the value held by self is not equal to the default value of its type.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::equal::DefaultAssertion;
+    ///
+    /// #[derive(Debug, Default, PartialEq)]
+    /// struct Config {
+    ///     retries: u32,
+    ///     verbose: bool,
+    /// }
+    ///
+    /// let config = Config { retries: 3, verbose: false };
+    /// config.should_not_be_default();
+    /// ```
+    fn should_not_be_default(&self) -> &Self;
+}
+
+impl<T: Default + PartialEq + Debug> DefaultAssertion for T {
+    fn should_be_default(&self) -> &Self {
+        self.should(&be_default());
+        self
+    }
+
+    fn should_not_be_default(&self) -> &Self {
+        self.should_not(&be_default());
+        self
+    }
 }
 
 #[cfg(test)]
@@ -175,4 +389,293 @@ mod tests {
         let name = "junit";
         name.should_equal("junit");
     }
+
+    #[test]
+    fn should_equal_integers() {
+        let value = 6658;
+        value.should_equal(&6658);
+    }
+
+    #[test]
+    #[should_panic(expected = "6658 should equal 6659")]
+    fn should_equal_integers_but_was_not() {
+        let value = 6658;
+        value.should_equal(&6659);
+    }
+
+    #[test]
+    fn should_equal_owned_strings() {
+        let value = String::from("6658");
+        value.should_equal("6658");
+    }
+
+    #[test]
+    #[should_panic(expected = "\"6658\" should equal \"6659\"")]
+    fn should_equal_owned_strings_but_was_not() {
+        let value = String::from("6658");
+        value.should_equal("6659");
+    }
+
+    #[test]
+    fn should_not_equal_strings() {
+        let value = "6658";
+        value.should_not_equal(&"6659");
+    }
+
+    #[test]
+    fn should_equal_floats() {
+        let value: f64 = 0.5;
+        value.should_equal(&0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "NaN should equal NaN")]
+    fn should_equal_nan_but_nan_is_not_equal_to_itself() {
+        let value = f64::NAN;
+        value.should_equal(&f64::NAN);
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Price {
+        amount: f64,
+        currency: &'static str,
+    }
+
+    #[test]
+    fn should_equal_a_partial_eq_struct() {
+        let price = Price { amount: 10.5, currency: "EUR" };
+        price.should_equal(&Price { amount: 10.5, currency: "EUR" });
+    }
+
+    #[test]
+    #[should_panic(expected = "Price { amount: 10.5, currency: \"EUR\" } should equal Price { amount: 10.5, currency: \"USD\" }")]
+    fn should_equal_a_partial_eq_struct_but_was_not() {
+        let price = Price { amount: 10.5, currency: "EUR" };
+        price.should_equal(&Price { amount: 10.5, currency: "USD" });
+    }
+
+    #[test]
+    #[should_panic(expected = "Price { amount: 10.5, currency: \"EUR\" } should not equal Price { amount: 10.5, currency: \"EUR\" }")]
+    fn should_not_equal_a_partial_eq_struct_but_was() {
+        let price = Price { amount: 10.5, currency: "EUR" };
+        price.should_not_equal(&Price { amount: 10.5, currency: "EUR" });
+    }
+}
+
+#[cfg(test)]
+mod any_equality_tests {
+    use crate::assertions::equal::EqualityAssertion;
+
+    #[test]
+    fn should_be_equal_to_any() {
+        let value = 2;
+        value.should_be_equal_to_any(vec![&1, &2, &3]);
+    }
+
+    #[test]
+    fn should_be_equal_to_any_owned_string() {
+        let value = String::from("clearcheck");
+        value.should_be_equal_to_any(vec!["junit", "clearcheck"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "5 should equal any of [1, 2, 3]")]
+    fn should_be_equal_to_any_but_was_not() {
+        let value = 5;
+        value.should_be_equal_to_any(vec![&1, &2, &3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "5 should equal any of []")]
+    fn should_be_equal_to_any_given_no_values() {
+        let value = 5;
+        value.should_be_equal_to_any(vec![]);
+    }
+
+    #[test]
+    fn should_not_be_equal_to_any() {
+        let value = "testify";
+        value.should_not_be_equal_to_any(vec![&"junit", &"clearcheck"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"junit\" should not equal any of [\"junit\", \"clearcheck\"]")]
+    fn should_not_be_equal_to_any_but_was() {
+        let value = "junit";
+        value.should_not_be_equal_to_any(vec![&"junit", &"clearcheck"]);
+    }
+}
+
+#[cfg(test)]
+mod custom_equality_tests {
+    use crate::assertions::equal::CustomEqualityAssertion;
+
+    fn within_tolerance(a: &f64, b: &f64) -> bool {
+        (a - b).abs() <= 0.01
+    }
+
+    #[test]
+    fn should_equal_by_a_tolerance() {
+        let measured = 10.0;
+        measured.should_equal_by(&10.005, "within a tolerance of 0.01", within_tolerance);
+    }
+
+    #[test]
+    #[should_panic(expected = "10.0 should equal 10.5 (within a tolerance of 0.01)")]
+    fn should_equal_by_a_tolerance_but_was_not() {
+        let measured = 10.0;
+        measured.should_equal_by(&10.5, "within a tolerance of 0.01", within_tolerance);
+    }
+
+    #[test]
+    fn should_not_equal_by_a_tolerance() {
+        let measured = 10.0;
+        measured.should_not_equal_by(&10.5, "within a tolerance of 0.01", within_tolerance);
+    }
+
+    #[test]
+    #[should_panic(expected = "10.0 should not equal 10.005 (within a tolerance of 0.01)")]
+    fn should_not_equal_by_a_tolerance_but_was() {
+        let measured = 10.0;
+        measured.should_not_equal_by(&10.005, "within a tolerance of 0.01", within_tolerance);
+    }
+
+    #[test]
+    #[should_panic(expected = "10.0 should equal 10.5 (within a tolerance of 0.1)")]
+    fn should_equal_by_a_tolerance_described_at_runtime_but_was_not() {
+        let tolerance = 0.1;
+        let measured = 10.0;
+        measured.should_equal_by(&10.5, &format!("within a tolerance of {}", tolerance), |a: &f64, b: &f64| (a - b).abs() <= tolerance);
+    }
+
+    #[derive(Debug)]
+    struct Reading {
+        sensor: &'static str,
+        value: f64,
+    }
+
+    #[test]
+    fn should_equal_by_a_type_without_partial_eq() {
+        let reading = Reading { sensor: "thermometer", value: 21.504 };
+        reading.should_equal_by(
+            &Reading { sensor: "thermometer", value: 21.5 },
+            "same sensor with the value within a tolerance of 0.01",
+            |a, b| a.sensor == b.sensor && within_tolerance(&a.value, &b.value),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Reading { sensor: \"thermometer\", value: 21.504 } should equal Reading { sensor: \"barometer\", value: 21.5 } (same sensor)")]
+    fn should_equal_by_a_type_without_partial_eq_but_was_not() {
+        let reading = Reading { sensor: "thermometer", value: 21.504 };
+        reading.should_equal_by(&Reading { sensor: "barometer", value: 21.5 }, "same sensor", |a, b| a.sensor == b.sensor);
+    }
+}
+
+#[cfg(test)]
+mod variant_tests {
+    use crate::assertions::equal::VariantAssertion;
+
+    #[derive(Debug)]
+    enum Event {
+        Started { id: u32 },
+        Progressed(f64),
+        Finished,
+    }
+
+    #[test]
+    fn should_be_variant_with_named_fields() {
+        let event = Event::Started { id: 7 };
+        event.should_be_variant(|event| matches!(event, Event::Started { .. }), "Event::Started");
+    }
+
+    #[test]
+    fn should_be_variant_ignoring_the_payload() {
+        let event = Event::Progressed(0.5);
+        event.should_be_variant(|event| matches!(event, Event::Progressed(_)), "Event::Progressed");
+    }
+
+    #[test]
+    #[should_panic(expected = "Progressed(0.5) should be the variant Event::Finished")]
+    fn should_be_variant_but_was_not() {
+        let event = Event::Progressed(0.5);
+        event.should_be_variant(|event| matches!(event, Event::Finished), "Event::Finished");
+    }
+
+    #[test]
+    fn should_not_be_variant() {
+        let event = Event::Finished;
+        event.should_not_be_variant(|event| matches!(event, Event::Started { .. }), "Event::Started");
+    }
+
+    #[test]
+    #[should_panic(expected = "Started { id: 7 } should not be the variant Event::Started")]
+    fn should_not_be_variant_but_was() {
+        let event = Event::Started { id: 7 };
+        event.should_not_be_variant(|event| matches!(event, Event::Started { .. }), "Event::Started");
+    }
+
+    #[test]
+    fn should_be_variant_with_a_guard_on_the_payload() {
+        let event = Event::Started { id: 7 };
+        event.should_be_variant(|event| matches!(event, Event::Started { id } if *id > 5), "Event::Started with id > 5");
+    }
+
+    #[test]
+    #[should_panic(expected = "Progressed(0.5) should be the variant Event::Progressed beyond halfway")]
+    fn should_be_variant_with_a_guard_on_the_payload_but_the_guard_failed() {
+        let event = Event::Progressed(0.5);
+        event.should_be_variant(|event| matches!(event, Event::Progressed(ratio) if *ratio > 0.5), "Event::Progressed beyond halfway");
+    }
+}
+
+#[cfg(test)]
+mod default_tests {
+    use crate::assertions::equal::DefaultAssertion;
+
+    #[derive(Debug, Default, PartialEq)]
+    struct Config {
+        retries: u32,
+        verbose: bool,
+        name: String,
+    }
+
+    #[test]
+    fn should_be_default() {
+        let config = Config::default();
+        config.should_be_default();
+    }
+
+    #[test]
+    #[should_panic(expected = "Config { retries: 3, verbose: false, name: \"\" } should be the default value Config { retries: 0, verbose: false, name: \"\" }")]
+    fn should_be_default_but_was_not() {
+        let config = Config { retries: 3, ..Default::default() };
+        config.should_be_default();
+    }
+
+    #[test]
+    fn should_not_be_default() {
+        let config = Config { verbose: true, ..Default::default() };
+        config.should_not_be_default();
+    }
+
+    #[test]
+    #[should_panic(expected = "Config { retries: 0, verbose: false, name: \"\" } should not be the default value Config { retries: 0, verbose: false, name: \"\" }")]
+    fn should_not_be_default_but_was() {
+        let config = Config::default();
+        config.should_not_be_default();
+    }
+
+    #[test]
+    fn should_be_default_for_primitives() {
+        0.should_be_default();
+        String::new().should_be_default();
+        Vec::<i32>::new().should_be_default();
+    }
+
+    #[test]
+    #[should_panic(expected = "10 should be the default value 0")]
+    fn should_be_default_for_an_integer_but_was_not() {
+        10.should_be_default();
+    }
 }
@@ -1,12 +1,18 @@
 use std::fmt::Debug;
+use std::ops::RangeInclusive;
 use std::path::Path;
 
 use crate::matchers::{Should, ShouldNot};
 use crate::matchers::file::{
-    be_a_directory, be_a_file, be_a_symbolic_link, be_absolute, be_readonly, be_relative,
-    be_writable, be_zero_sized, contain_all_file_names, contain_any_file_names, contain_file_name,
-    have_extension,
+    be_a_directory, be_a_file, be_a_symbolic_link, be_absolute, be_an_empty_directory, be_modified_after, be_modified_before,
+    be_readonly, be_relative, be_same_file_as,
+    be_writable, be_zero_sized, contain_all_file_names, contain_any_file_names, contain_file_name, contain_file_name_at_depth,
+    contain_file_name_following_symlinks, contain_file_name_matching, contain_line, contain_text,
+    have_extension, have_extension_ignoring_case, have_file_size, have_file_size_at_least, have_file_size_at_most,
+    have_file_size_in_inclusive_range,
 };
+#[cfg(unix)]
+use crate::matchers::file::{be_a_named_pipe, be_a_socket};
 
 /// FileAssertion enables assertions about various properties of file or path.
 ///
@@ -56,6 +62,86 @@ pub trait FileAssertion {
     /// - Panics if the assertion fails.
     fn should_not_be_zero_sized(&self) -> &Self;
 
+    /// - Asserts that the path corresponds to a directory without any entries.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, or if the path is not a directory.
+    fn should_be_an_empty_directory(&self) -> &Self;
+
+    /// - Asserts that the path does not correspond to a directory without any entries.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_not_be_an_empty_directory(&self) -> &Self;
+
+    /// - Asserts that the path corresponds to a file of exactly the given size in bytes.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, or if the size of the file can not be read.
+    fn should_have_file_size(&self, bytes: u64) -> &Self;
+
+    /// - Asserts that the path does not correspond to a file of the given size in bytes.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_not_have_file_size(&self, bytes: u64) -> &Self;
+
+    /// - Asserts that the path corresponds to a file with a size greater than or equal to the given bytes.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, or if the size of the file can not be read.
+    fn should_have_file_size_at_least(&self, bytes: u64) -> &Self;
+
+    /// - Asserts that the path corresponds to a file with a size less than or equal to the given bytes.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, or if the size of the file can not be read.
+    fn should_have_file_size_at_most(&self, bytes: u64) -> &Self;
+
+    /// - Asserts that the path corresponds to a file with a size (in bytes) within the given inclusive range.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, or if the size of the file can not be read.
+    fn should_have_file_size_in_inclusive_range(&self, range: RangeInclusive<u64>) -> &Self;
+
+    /// - Asserts that the path does not correspond to a file with a size (in bytes) within the given inclusive range.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_not_have_file_size_in_inclusive_range(&self, range: RangeInclusive<u64>) -> &Self;
+
+    /// - Asserts that the path corresponds to a file modified strictly after the given file.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, or if the modification time of either file can not be read.
+    fn should_be_modified_after(&self, other: &Path) -> &Self;
+
+    /// - Asserts that the path corresponds to a file modified strictly before the given file.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, or if the modification time of either file can not be read.
+    fn should_be_modified_before(&self, other: &Path) -> &Self;
+
+    /// - Asserts that the path corresponds to a file containing the given line, compared without its line terminator.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, or if the file can not be read as UTF-8 text.
+    fn should_contain_line(&self, line: &'static str) -> &Self;
+
+    /// - Asserts that the path corresponds to a file that does not contain the given line.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_not_contain_line(&self, line: &'static str) -> &Self;
+
+    /// - Asserts that the path corresponds to a file containing the given text anywhere in its content.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, or if the file can not be read as UTF-8 text.
+    fn should_contain_text(&self, text: &'static str) -> &Self;
+
+    /// - Asserts that the path corresponds to a file that does not contain the given text.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_not_contain_text(&self, text: &'static str) -> &Self;
+
+    /// - Asserts that the path refers to the same file as the given path, resolving `.`, `..` and symbolic links.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, or if either path does not exist.
+    fn should_be_same_file_as(&self, other: &Path) -> &Self;
+
+    /// - Asserts that the path does not refer to the same file as the given path.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_not_be_same_file_as(&self, other: &Path) -> &Self;
+
     /// - Asserts that the path corresponds to a readonly file.
     /// - Returns a reference to self for fluent chaining.
     /// - Panics if the assertion fails.
@@ -66,6 +152,18 @@ pub trait FileAssertion {
     /// - Panics if the assertion fails.
     fn should_be_writable(&self) -> &Self;
 
+    /// - Asserts that the path corresponds to a named pipe (FIFO).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    #[cfg(unix)]
+    fn should_be_a_named_pipe(&self) -> &Self;
+
+    /// - Asserts that the path corresponds to a unix domain socket.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    #[cfg(unix)]
+    fn should_be_a_socket(&self) -> &Self;
+
     /// - Asserts that the path is absolute.
     /// - Returns a reference to self for fluent chaining.
     /// - Panics if the assertion fails.
@@ -86,6 +184,16 @@ pub trait FileAssertion {
     /// - Panics if the assertion fails.
     fn should_not_have_extension(&self, extension: &'static str) -> &Self;
 
+    /// - Asserts that the path corresponds to a file with the given extension, ignoring case differences.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_have_extension_ignoring_case(&self, extension: &'static str) -> &Self;
+
+    /// - Asserts that the path corresponds to a file that does not have the given extension, ignoring case differences.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_not_have_extension_ignoring_case(&self, extension: &'static str) -> &Self;
+
     /// - Asserts that the path corresponds to a directory that contains the given file name.
     /// - Returns a reference to self for fluent chaining.
     /// - Panics if the assertion fails.
@@ -115,6 +223,36 @@ pub trait FileAssertion {
     /// - Returns a reference to self for fluent chaining.
     /// - Panics if the assertion fails.
     fn should_not_contain_any_of_file_names(&self, names: Vec<&'static str>) -> &Self;
+
+    /// - Asserts that the path corresponds to a directory that contains a file name matching the given glob pattern (for example: `*.rs`).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails or if the pattern is not a valid glob.
+    fn should_contain_file_matching(&self, pattern: &'static str) -> &Self;
+
+    /// - Asserts that the path corresponds to a directory that does not contain any file name matching the given glob pattern.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails or if the pattern is not a valid glob.
+    fn should_not_contain_file_matching(&self, pattern: &'static str) -> &Self;
+
+    /// - Asserts that the path corresponds to a directory that contains the given file name, descending at most depth levels (1 means only the direct children).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_contain_file_name_within_depth(&self, name: &'static str, depth: usize) -> &Self;
+
+    /// - Asserts that the path corresponds to a directory that does not contain the given file name, descending at most depth levels (1 means only the direct children).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_not_contain_file_name_within_depth(&self, name: &'static str, depth: usize) -> &Self;
+
+    /// - Asserts that the path corresponds to a directory that contains the given file name, following symbolic links while walking the tree.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails or if a symbolic link introduces a file system loop.
+    fn should_contain_file_name_following_symlinks(&self, name: &'static str) -> &Self;
+
+    /// - Asserts that the path corresponds to a directory that does not contain the given file name, following symbolic links while walking the tree.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails or if a symbolic link introduces a file system loop.
+    fn should_not_contain_file_name_following_symlinks(&self, name: &'static str) -> &Self;
 }
 
 impl<T: AsRef<Path> + Debug> FileAssertion for T {
@@ -143,6 +281,86 @@ impl<T: AsRef<Path> + Debug> FileAssertion for T {
         self
     }
 
+    fn should_be_an_empty_directory(&self) -> &Self {
+        self.should(&be_an_empty_directory());
+        self
+    }
+
+    fn should_not_be_an_empty_directory(&self) -> &Self {
+        self.should_not(&be_an_empty_directory());
+        self
+    }
+
+    fn should_have_file_size(&self, bytes: u64) -> &Self {
+        self.should(&have_file_size(bytes));
+        self
+    }
+
+    fn should_not_have_file_size(&self, bytes: u64) -> &Self {
+        self.should_not(&have_file_size(bytes));
+        self
+    }
+
+    fn should_have_file_size_at_least(&self, bytes: u64) -> &Self {
+        self.should(&have_file_size_at_least(bytes));
+        self
+    }
+
+    fn should_have_file_size_at_most(&self, bytes: u64) -> &Self {
+        self.should(&have_file_size_at_most(bytes));
+        self
+    }
+
+    fn should_have_file_size_in_inclusive_range(&self, range: RangeInclusive<u64>) -> &Self {
+        self.should(&have_file_size_in_inclusive_range(range));
+        self
+    }
+
+    fn should_not_have_file_size_in_inclusive_range(&self, range: RangeInclusive<u64>) -> &Self {
+        self.should_not(&have_file_size_in_inclusive_range(range));
+        self
+    }
+
+    fn should_be_modified_after(&self, other: &Path) -> &Self {
+        self.should(&be_modified_after(other));
+        self
+    }
+
+    fn should_be_modified_before(&self, other: &Path) -> &Self {
+        self.should(&be_modified_before(other));
+        self
+    }
+
+    fn should_contain_line(&self, line: &'static str) -> &Self {
+        self.should(&contain_line(line));
+        self
+    }
+
+    fn should_not_contain_line(&self, line: &'static str) -> &Self {
+        self.should_not(&contain_line(line));
+        self
+    }
+
+    fn should_contain_text(&self, text: &'static str) -> &Self {
+        self.should(&contain_text(text));
+        self
+    }
+
+    fn should_not_contain_text(&self, text: &'static str) -> &Self {
+        self.should_not(&contain_text(text));
+        self
+    }
+
+    fn should_be_same_file_as(&self, other: &Path) -> &Self {
+        self.should(&be_same_file_as(other));
+        self
+    }
+
+    fn should_not_be_same_file_as(&self, other: &Path) -> &Self {
+        self.should_not(&be_same_file_as(other));
+        self
+    }
+
     fn should_be_readonly(&self) -> &Self {
         self.should(&be_readonly());
         self
@@ -153,6 +371,18 @@ impl<T: AsRef<Path> + Debug> FileAssertion for T {
         self
     }
 
+    #[cfg(unix)]
+    fn should_be_a_named_pipe(&self) -> &Self {
+        self.should(&be_a_named_pipe());
+        self
+    }
+
+    #[cfg(unix)]
+    fn should_be_a_socket(&self) -> &Self {
+        self.should(&be_a_socket());
+        self
+    }
+
     fn should_be_absolute(&self) -> &Self {
         self.should(&be_absolute());
         self
@@ -173,6 +403,16 @@ impl<T: AsRef<Path> + Debug> FileAssertion for T {
         self
     }
 
+    fn should_have_extension_ignoring_case(&self, extension: &'static str) -> &Self {
+        self.should(&have_extension_ignoring_case(extension));
+        self
+    }
+
+    fn should_not_have_extension_ignoring_case(&self, extension: &'static str) -> &Self {
+        self.should_not(&have_extension_ignoring_case(extension));
+        self
+    }
+
     fn should_contain_file_name(&self, name: &'static str) -> &Self {
         self.should(&contain_file_name(name));
         self
@@ -202,16 +442,48 @@ impl<T: AsRef<Path> + Debug> FileAssertion for T {
         self.should_not(&contain_any_file_names(names));
         self
     }
+
+    fn should_contain_file_matching(&self, pattern: &'static str) -> &Self {
+        self.should(&contain_file_name_matching(pattern));
+        self
+    }
+
+    fn should_not_contain_file_matching(&self, pattern: &'static str) -> &Self {
+        self.should_not(&contain_file_name_matching(pattern));
+        self
+    }
+
+    fn should_contain_file_name_within_depth(&self, name: &'static str, depth: usize) -> &Self {
+        self.should(&contain_file_name_at_depth(name, depth));
+        self
+    }
+
+    fn should_not_contain_file_name_within_depth(&self, name: &'static str, depth: usize) -> &Self {
+        self.should_not(&contain_file_name_at_depth(name, depth));
+        self
+    }
+
+    fn should_contain_file_name_following_symlinks(&self, name: &'static str) -> &Self {
+        self.should(&contain_file_name_following_symlinks(name));
+        self
+    }
+
+    fn should_not_contain_file_name_following_symlinks(&self, name: &'static str) -> &Self {
+        self.should_not(&contain_file_name_following_symlinks(name));
+        self
+    }
 }
 
 #[cfg(all(test, feature = "file"))]
 mod tests {
+    use std::fs;
     use std::fs::File;
     use std::io::Write;
     use std::path::Path;
 
     use tempdir::TempDir;
 
+    use crate::assertions::bool::TrueFalseAssertion;
     use crate::assertions::file::FileAssertion;
 
     #[test]
@@ -279,6 +551,46 @@ mod tests {
         path.should_not_be_zero_sized();
     }
 
+    #[test]
+    fn should_be_an_empty_directory() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        temporary_directory.path().should_be_an_empty_directory();
+    }
+
+    #[test]
+    #[should_panic(expected = "should be an empty directory but it had entries")]
+    fn should_be_an_empty_directory_but_it_had_a_file() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let _ = File::create(temporary_directory.path().join("temporary.txt")).unwrap();
+
+        temporary_directory.path().should_be_an_empty_directory();
+    }
+
+    #[test]
+    #[should_panic(expected = "should be an empty directory but it was not a directory")]
+    fn should_be_an_empty_directory_but_it_was_a_file() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = temporary_directory.path().join("temporary.txt");
+        let _ = File::create(file_path.clone()).unwrap();
+
+        file_path.should_be_an_empty_directory();
+    }
+
+    #[test]
+    fn should_not_be_an_empty_directory() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let _ = File::create(temporary_directory.path().join("temporary.txt")).unwrap();
+
+        temporary_directory.path().should_not_be_an_empty_directory();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_an_empty_directory_but_it_was() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        temporary_directory.path().should_not_be_an_empty_directory();
+    }
+
     #[test]
     fn should_be_readonly() {
         let temporary_directory = TempDir::new(".").unwrap();
@@ -387,6 +699,39 @@ mod tests {
         path.should_not_have_extension("zip");
     }
 
+    #[test]
+    fn should_have_extension_ignoring_case() {
+        let path = Path::new("/etc/sample.TXT");
+        path.should_have_extension_ignoring_case("txt");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_exact_extension_but_case_was_different() {
+        let path = Path::new("/etc/sample.TXT");
+        path.should_have_extension("txt");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_extension_ignoring_case_but_was_not() {
+        let path = Path::new("/etc/sample.TXT");
+        path.should_have_extension_ignoring_case("zip");
+    }
+
+    #[test]
+    fn should_not_have_extension_ignoring_case() {
+        let path = Path::new("/etc/sample.TXT");
+        path.should_not_have_extension_ignoring_case("zip");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_have_extension_ignoring_case_but_was() {
+        let path = Path::new("/etc/sample.ZIP");
+        path.should_not_have_extension_ignoring_case("zip");
+    }
+
     #[test]
     fn should_contain_a_file() {
         let temporary_directory = TempDir::new(".").unwrap();
@@ -537,4 +882,542 @@ mod tests {
         let directory_path = temporary_directory.path();
         directory_path.should_not_contain_any_of_file_names(vec!["junit.txt", "gotest.txt"]);
     }
+
+    #[test]
+    fn should_contain_file_matching() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let _ = File::create(temporary_directory.path().join("a.rs")).unwrap();
+        let _ = File::create(temporary_directory.path().join("b.txt")).unwrap();
+
+        let directory_path = temporary_directory.path();
+        directory_path.should_contain_file_matching("*.rs");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_file_matching_but_it_did_not() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let _ = File::create(temporary_directory.path().join("b.txt")).unwrap();
+
+        let directory_path = temporary_directory.path();
+        directory_path.should_contain_file_matching("*.rs");
+    }
+
+    #[test]
+    fn should_not_contain_file_matching() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let _ = File::create(temporary_directory.path().join("b.txt")).unwrap();
+
+        let directory_path = temporary_directory.path();
+        directory_path.should_not_contain_file_matching("*.rs");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid glob pattern \"[*.rs\"")]
+    fn should_contain_file_matching_an_invalid_glob() {
+        let temporary_directory = TempDir::new(".").unwrap();
+
+        let directory_path = temporary_directory.path();
+        directory_path.should_contain_file_matching("[*.rs");
+    }
+
+    #[test]
+    fn should_contain_file_name_within_depth() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let nested_directory = temporary_directory.path().join("nested");
+        fs::create_dir(&nested_directory).unwrap();
+        let _ = File::create(nested_directory.join("junit.txt")).unwrap();
+
+        let directory_path = temporary_directory.path();
+        directory_path.should_contain_file_name_within_depth("junit.txt", 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_file_name_within_depth_but_it_was_deeper() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let nested_directory = temporary_directory.path().join("nested");
+        fs::create_dir(&nested_directory).unwrap();
+        let _ = File::create(nested_directory.join("junit.txt")).unwrap();
+
+        let directory_path = temporary_directory.path();
+        directory_path.should_contain_file_name_within_depth("junit.txt", 1);
+    }
+
+    #[test]
+    fn should_not_contain_file_name_within_depth() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let nested_directory = temporary_directory.path().join("nested");
+        fs::create_dir(&nested_directory).unwrap();
+        let _ = File::create(nested_directory.join("junit.txt")).unwrap();
+
+        let directory_path = temporary_directory.path();
+        directory_path.should_not_contain_file_name_within_depth("junit.txt", 1);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn should_contain_file_name_following_symlinks() {
+        let target_directory = TempDir::new(".").unwrap();
+        let _ = File::create(target_directory.path().join("junit.txt")).unwrap();
+
+        let temporary_directory = TempDir::new(".").unwrap();
+        std::os::unix::fs::symlink(
+            target_directory.path().canonicalize().unwrap(),
+            temporary_directory.path().join("linked"),
+        )
+        .unwrap();
+
+        let directory_path = temporary_directory.path();
+        directory_path.should_contain_file_name_following_symlinks("junit.txt");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    #[should_panic]
+    fn should_contain_file_name_following_symlinks_but_it_did_not() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let directory_path = temporary_directory.path();
+        directory_path.should_contain_file_name_following_symlinks("junit.txt");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn should_not_contain_file_name_following_symlinks() {
+        let target_directory = TempDir::new(".").unwrap();
+        let _ = File::create(target_directory.path().join("junit.txt")).unwrap();
+
+        let temporary_directory = TempDir::new(".").unwrap();
+        std::os::unix::fs::symlink(
+            target_directory.path().canonicalize().unwrap(),
+            temporary_directory.path().join("linked"),
+        )
+        .unwrap();
+
+        let directory_path = temporary_directory.path();
+        directory_path.should_not_contain_file_name_following_symlinks("clearcheck.txt");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    #[should_panic]
+    fn should_not_contain_file_name_following_symlinks_but_it_did() {
+        let target_directory = TempDir::new(".").unwrap();
+        let _ = File::create(target_directory.path().join("junit.txt")).unwrap();
+
+        let temporary_directory = TempDir::new(".").unwrap();
+        std::os::unix::fs::symlink(
+            target_directory.path().canonicalize().unwrap(),
+            temporary_directory.path().join("linked"),
+        )
+        .unwrap();
+
+        let directory_path = temporary_directory.path();
+        directory_path.should_not_contain_file_name_following_symlinks("junit.txt");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn should_be_a_named_pipe() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let pipe_path = temporary_directory.path().join("junit.fifo");
+        let status = std::process::Command::new("mkfifo").arg(&pipe_path).status().unwrap();
+        status.success().should_be_true();
+
+        pipe_path.should_be_a_named_pipe();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    #[should_panic]
+    fn should_be_a_named_pipe_but_was_not() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = temporary_directory.path().join("junit.txt");
+        let _ = File::create(&file_path).unwrap();
+
+        file_path.should_be_a_named_pipe();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn should_be_a_socket() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let socket_path = temporary_directory.path().join("junit.sock");
+        let _listener = std::os::unix::net::UnixListener::bind(&socket_path).unwrap();
+
+        socket_path.should_be_a_socket();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    #[should_panic]
+    fn should_be_a_socket_but_was_not() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = temporary_directory.path().join("junit.txt");
+        let _ = File::create(&file_path).unwrap();
+
+        file_path.should_be_a_socket();
+    }
+}
+
+#[cfg(all(test, feature = "file"))]
+mod file_size_tests {
+    use std::fs::File;
+    use std::io::Write;
+    use std::path::PathBuf;
+
+    use tempdir::TempDir;
+
+    use crate::assertions::file::FileAssertion;
+
+    fn file_with_content(directory: &TempDir, content: &str) -> PathBuf {
+        let file_path = directory.path().join("temporary.txt");
+        let mut file = File::create(file_path.clone()).unwrap();
+        write!(file, "{}", content).unwrap();
+        file_path
+    }
+
+    #[test]
+    fn should_have_file_size() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = file_with_content(&temporary_directory, "clearcheck");
+
+        file_path.should_have_file_size(10);
+    }
+
+    #[test]
+    #[should_panic(expected = "should have a size of 12 bytes but was 10 bytes")]
+    fn should_have_file_size_but_was_not() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = file_with_content(&temporary_directory, "clearcheck");
+
+        file_path.should_have_file_size(12);
+    }
+
+    #[test]
+    #[should_panic(expected = "should have a size of 0 bytes but its size could not be read")]
+    fn should_have_file_size_but_the_file_was_missing() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = temporary_directory.path().join("missing.txt");
+
+        file_path.should_have_file_size(0);
+    }
+
+    #[test]
+    fn should_not_have_file_size() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = file_with_content(&temporary_directory, "clearcheck");
+
+        file_path.should_not_have_file_size(12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_have_file_size_but_had() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = file_with_content(&temporary_directory, "clearcheck");
+
+        file_path.should_not_have_file_size(10);
+    }
+
+    #[test]
+    fn should_have_file_size_at_least() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = file_with_content(&temporary_directory, "clearcheck");
+
+        file_path.should_have_file_size_at_least(10);
+    }
+
+    #[test]
+    #[should_panic(expected = "should have a size of at least 11 bytes but was 10 bytes")]
+    fn should_have_file_size_at_least_but_was_smaller() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = file_with_content(&temporary_directory, "clearcheck");
+
+        file_path.should_have_file_size_at_least(11);
+    }
+
+    #[test]
+    fn should_have_file_size_at_most() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = file_with_content(&temporary_directory, "");
+
+        file_path.should_have_file_size_at_most(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "should have a size of at most 9 bytes but was 10 bytes")]
+    fn should_have_file_size_at_most_but_was_larger() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = file_with_content(&temporary_directory, "clearcheck");
+
+        file_path.should_have_file_size_at_most(9);
+    }
+
+    #[test]
+    fn should_have_file_size_in_inclusive_range() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = file_with_content(&temporary_directory, "clearcheck");
+
+        file_path.should_have_file_size_in_inclusive_range(1..=10);
+    }
+
+    #[test]
+    #[should_panic(expected = "should have a size in the range 11..=20 bytes but was 10 bytes")]
+    fn should_have_file_size_in_inclusive_range_but_was_not() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = file_with_content(&temporary_directory, "clearcheck");
+
+        file_path.should_have_file_size_in_inclusive_range(11..=20);
+    }
+
+    #[test]
+    fn should_not_have_file_size_in_inclusive_range() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = file_with_content(&temporary_directory, "clearcheck");
+
+        file_path.should_not_have_file_size_in_inclusive_range(11..=20);
+    }
+}
+
+#[cfg(all(test, feature = "file"))]
+mod modification_time_tests {
+    use std::fs::File;
+    use std::path::PathBuf;
+    use std::time::{Duration, SystemTime};
+
+    use tempdir::TempDir;
+
+    use crate::assertions::file::FileAssertion;
+
+    fn older_and_newer_files(directory: &TempDir) -> (PathBuf, PathBuf) {
+        let older_path = directory.path().join("older.txt");
+        let newer_path = directory.path().join("newer.txt");
+
+        let older = File::create(older_path.clone()).unwrap();
+        older.set_modified(SystemTime::now() - Duration::from_secs(10)).unwrap();
+        let _ = File::create(newer_path.clone()).unwrap();
+
+        (older_path, newer_path)
+    }
+
+    #[test]
+    fn should_be_modified_after() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let (older_path, newer_path) = older_and_newer_files(&temporary_directory);
+
+        newer_path.should_be_modified_after(&older_path);
+    }
+
+    #[test]
+    #[should_panic(expected = "before it")]
+    fn should_be_modified_after_but_was_modified_before() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let (older_path, newer_path) = older_and_newer_files(&temporary_directory);
+
+        older_path.should_be_modified_after(&newer_path);
+    }
+
+    #[test]
+    fn should_be_modified_before() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let (older_path, newer_path) = older_and_newer_files(&temporary_directory);
+
+        older_path.should_be_modified_before(&newer_path);
+    }
+
+    #[test]
+    #[should_panic(expected = "after it")]
+    fn should_be_modified_before_but_was_modified_after() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let (older_path, newer_path) = older_and_newer_files(&temporary_directory);
+
+        newer_path.should_be_modified_before(&older_path);
+    }
+
+    #[test]
+    #[should_panic(expected = "could not be read")]
+    fn should_be_modified_after_but_the_other_file_was_missing() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let (_, newer_path) = older_and_newer_files(&temporary_directory);
+
+        newer_path.should_be_modified_after(&temporary_directory.path().join("missing.txt"));
+    }
+
+    #[test]
+    #[should_panic(expected = "could not be read")]
+    fn should_be_modified_before_but_the_file_was_missing() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let (older_path, _) = older_and_newer_files(&temporary_directory);
+
+        temporary_directory.path().join("missing.txt").should_be_modified_before(&older_path);
+    }
+}
+
+#[cfg(all(test, feature = "file"))]
+mod file_content_tests {
+    use std::fs::File;
+    use std::io::Write;
+    use std::path::PathBuf;
+
+    use tempdir::TempDir;
+
+    use crate::assertions::file::FileAssertion;
+
+    fn config_file(directory: &TempDir) -> PathBuf {
+        let file_path = directory.path().join("config.properties");
+        let mut file = File::create(file_path.clone()).unwrap();
+        writeln!(file, "name=clearcheck").unwrap();
+        writeln!(file, "enabled=true").unwrap();
+        file_path
+    }
+
+    #[test]
+    fn should_contain_line() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = config_file(&temporary_directory);
+
+        file_path.should_contain_line("enabled=true");
+    }
+
+    #[test]
+    #[should_panic(expected = "should contain the line \"enabled=false\"")]
+    fn should_contain_line_but_it_did_not() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = config_file(&temporary_directory);
+
+        file_path.should_contain_line("enabled=false");
+    }
+
+    #[test]
+    #[should_panic(expected = "should contain the line \"enabled\"")]
+    fn should_contain_line_but_it_was_only_a_part_of_a_line() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = config_file(&temporary_directory);
+
+        file_path.should_contain_line("enabled");
+    }
+
+    #[test]
+    fn should_not_contain_line() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = config_file(&temporary_directory);
+
+        file_path.should_not_contain_line("enabled=false");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_contain_line_but_it_did() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = config_file(&temporary_directory);
+
+        file_path.should_not_contain_line("name=clearcheck");
+    }
+
+    #[test]
+    fn should_contain_text() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = config_file(&temporary_directory);
+
+        file_path.should_contain_text("clearcheck\nenabled");
+    }
+
+    #[test]
+    fn should_not_contain_text() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = config_file(&temporary_directory);
+
+        file_path.should_not_contain_text("disabled");
+    }
+
+    #[test]
+    #[should_panic(expected = "should contain the text \"enabled\" but it could not be read")]
+    fn should_contain_text_but_the_file_was_missing() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = temporary_directory.path().join("missing.properties");
+
+        file_path.should_contain_text("enabled");
+    }
+
+    #[test]
+    #[should_panic(expected = "should contain the text \"enabled\" but its content was not valid UTF-8")]
+    fn should_contain_text_but_the_file_was_not_utf8() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = temporary_directory.path().join("binary.bin");
+        let mut file = File::create(file_path.clone()).unwrap();
+        file.write_all(&[0x65, 0x6e, 0xff, 0xfe]).unwrap();
+
+        file_path.should_contain_text("enabled");
+    }
+}
+
+#[cfg(all(test, feature = "file"))]
+mod same_file_tests {
+    use std::fs;
+    use std::fs::File;
+
+    use tempdir::TempDir;
+
+    use crate::assertions::file::FileAssertion;
+
+    fn directory_with_file() -> TempDir {
+        let temporary_directory = TempDir::new(".").unwrap();
+        fs::create_dir(temporary_directory.path().join("dir")).unwrap();
+        let _ = File::create(temporary_directory.path().join("file.txt")).unwrap();
+        temporary_directory
+    }
+
+    #[test]
+    fn should_be_same_file_as() {
+        let temporary_directory = directory_with_file();
+        let path = temporary_directory.path().join("./dir/../file.txt");
+
+        path.should_be_same_file_as(&temporary_directory.path().join("./file.txt"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn should_be_same_file_as_following_a_symbolic_link() {
+        let temporary_directory = directory_with_file();
+        let link_path = temporary_directory.path().join("dir/link.txt");
+        std::os::unix::fs::symlink(temporary_directory.path().join("file.txt"), &link_path).unwrap();
+
+        link_path.should_be_same_file_as(&temporary_directory.path().join("file.txt"));
+    }
+
+    #[test]
+    #[should_panic(expected = "but they resolved to")]
+    fn should_be_same_file_as_but_was_not() {
+        let temporary_directory = directory_with_file();
+        let path = temporary_directory.path().join("file.txt");
+
+        path.should_be_same_file_as(&temporary_directory.path().join("dir"));
+    }
+
+    #[test]
+    #[should_panic(expected = "could not be resolved")]
+    fn should_be_same_file_as_but_the_other_path_did_not_exist() {
+        let temporary_directory = directory_with_file();
+        let path = temporary_directory.path().join("file.txt");
+
+        path.should_be_same_file_as(&temporary_directory.path().join("dir/../missing.txt"));
+    }
+
+    #[test]
+    fn should_not_be_same_file_as() {
+        let temporary_directory = directory_with_file();
+        let path = temporary_directory.path().join("file.txt");
+
+        path.should_not_be_same_file_as(&temporary_directory.path().join("dir"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_same_file_as_but_was() {
+        let temporary_directory = directory_with_file();
+        let path = temporary_directory.path().join("dir/../file.txt");
+
+        path.should_not_be_same_file_as(&temporary_directory.path().join("file.txt"));
+    }
 }
@@ -1,7 +1,7 @@
 use std::fmt::Debug;
 use std::ops::{Range, RangeInclusive};
 
-use crate::matchers::float::{be_nan, be_negative, be_positive, be_zero};
+use crate::matchers::float::{be_close_to_any, be_finite, be_infinite, be_integer_valued, be_nan, be_negative, be_negative_zero, be_non_negative, be_non_positive, be_positive, be_positive_zero, be_zero, have_fractional_part, have_opposite_sign_from, have_same_sign_as, round_to};
 use crate::matchers::range::{be_in_exclusive_range, be_in_inclusive_range};
 use crate::matchers::{Should, ShouldNot};
 
@@ -92,6 +92,80 @@ pub trait FloatAssertion<T: num::Float + Default + PartialEq> {
     /// ```
     fn should_be_negative(&self) -> &Self;
 
+    /// - Asserts that the floating-point value is non-negative (greater than or equal to zero).
+    /// - Negative zero (-0.0) is non-negative; NaN is not.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::float::FloatAssertion;
+    ///
+    /// let value: f64 = 0.0;
+    /// value.should_be_non_negative();
+    /// ```
+    fn should_be_non_negative(&self) -> &Self;
+
+    /// - Asserts that the floating-point value is non-positive (less than or equal to zero).
+    /// - Positive zero (+0.0) is non-positive; NaN is not.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::float::FloatAssertion;
+    ///
+    /// let value: f64 = -1.23;
+    /// value.should_be_non_positive();
+    /// ```
+    fn should_be_non_positive(&self) -> &Self;
+
+    /// - Asserts that the floating-point value is positive zero (+0.0).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::float::FloatAssertion;
+    ///
+    /// let value: f64 = 0.0;
+    /// value.should_be_positive_zero();
+    /// ```
+    fn should_be_positive_zero(&self) -> &Self;
+
+    /// - Asserts that the floating-point value is negative zero (-0.0).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::float::FloatAssertion;
+    ///
+    /// let value: f64 = -0.0;
+    /// value.should_be_negative_zero();
+    /// ```
+    fn should_be_negative_zero(&self) -> &Self;
+
+    /// - Asserts that the floating-point value is finite (neither infinite nor NaN).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::float::FloatAssertion;
+    ///
+    /// let value: f64 = 1.23;
+    /// value.should_be_finite();
+    /// ```
+    fn should_be_finite(&self) -> &Self;
+
+    /// - Asserts that the floating-point value is positive or negative infinity.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::float::FloatAssertion;
+    ///
+    /// let value: f64 = f64::INFINITY;
+    /// value.should_be_infinite();
+    /// ```
+    fn should_be_infinite(&self) -> &Self;
+
     /// - Asserts that the floating-point value falls within the given inclusive range with tolerance.
     /// - Returns a reference to self for fluent chaining.
     /// - Panics if the assertion fails.
@@ -151,6 +225,99 @@ pub trait FloatAssertion<T: num::Float + Default + PartialEq> {
         range: Range<T>,
         tolerance: T,
     ) -> &Self;
+
+    /// - Asserts that the floating-point value has the same sign as the given value.
+    /// - Positive zero and negative zero are both treated as zero, which has the same sign only as zero, and the opposite sign from no value.
+    /// - NaN has no sign, so the assertion always fails if either value is NaN.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::float::FloatAssertion;
+    ///
+    /// let value: f64 = -1.5;
+    /// value.should_have_same_sign_as(-0.25);
+    /// ```
+    fn should_have_same_sign_as(&self, other: T) -> &Self;
+
+    /// - Asserts that the floating-point value has the opposite sign from the given value.
+    /// - Positive zero and negative zero are both treated as zero, which has the same sign only as zero, and the opposite sign from no value.
+    /// - NaN has no sign, so the assertion always fails if either value is NaN.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::float::FloatAssertion;
+    ///
+    /// let value: f64 = 1.5;
+    /// value.should_have_opposite_sign_from(-0.25);
+    /// ```
+    fn should_have_opposite_sign_from(&self, other: T) -> &Self;
+
+    /// - Asserts that the floating-point value rounds to the expected value at the given number of decimal places.
+    /// - Rounding follows the std `round`, which rounds half-way cases away from zero (not half-to-even).
+    /// - Only the value is rounded, so an expected value with more decimal places than `places` never matches.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, or if 10^places is not representable in the floating type.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::float::FloatAssertion;
+    ///
+    /// let value: f64 = 12.34567;
+    /// value.should_round_to(12.35, 2);
+    /// ```
+    fn should_round_to(&self, expected: T, places: u32) -> &Self;
+
+    /// - Asserts that the floating-point value has no fractional part (like 4.0).
+    /// - NaN and infinities are not integer valued.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::float::FloatAssertion;
+    ///
+    /// let value: f64 = 4.0;
+    /// value.should_be_integer_valued();
+    /// ```
+    fn should_be_integer_valued(&self) -> &Self;
+
+    /// - Asserts that the floating-point value has a non-zero fractional part (like 4.5).
+    /// - NaN and infinities do not have a fractional part.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::float::FloatAssertion;
+    ///
+    /// let value: f64 = 4.5;
+    /// value.should_have_fractional_part();
+    /// ```
+    fn should_have_fractional_part(&self) -> &Self;
+
+    /// - Asserts that the floating-point value is within the tolerance (inclusive) of any of the candidate values.
+    /// - NaN is never close to any candidate.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, listing the candidates and the distance to the closest one.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::float::FloatAssertion;
+    ///
+    /// let value: f64 = 2.004;
+    /// value.should_be_close_to_any(vec![1.0, 2.0], 0.01);
+    /// ```
+    fn should_be_close_to_any(&self, candidates: Vec<T>, tolerance: T) -> &Self;
+
+    /// - Asserts that the floating-point value is not within the tolerance of any of the candidate values.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::float::FloatAssertion;
+    ///
+    /// let value: f64 = 2.5;
+    /// value.should_not_be_close_to_any(vec![1.0, 2.0], 0.01);
+    /// ```
+    fn should_not_be_close_to_any(&self, candidates: Vec<T>, tolerance: T) -> &Self;
 }
 
 impl<T: num::Float + Debug + Default + PartialEq> FloatAssertion<T> for T {
@@ -184,6 +351,36 @@ impl<T: num::Float + Debug + Default + PartialEq> FloatAssertion<T> for T {
         self
     }
 
+    fn should_be_non_negative(&self) -> &Self {
+        self.should(&be_non_negative());
+        self
+    }
+
+    fn should_be_non_positive(&self) -> &Self {
+        self.should(&be_non_positive());
+        self
+    }
+
+    fn should_be_positive_zero(&self) -> &Self {
+        self.should(&be_positive_zero());
+        self
+    }
+
+    fn should_be_negative_zero(&self) -> &Self {
+        self.should(&be_negative_zero());
+        self
+    }
+
+    fn should_be_finite(&self) -> &Self {
+        self.should(&be_finite());
+        self
+    }
+
+    fn should_be_infinite(&self) -> &Self {
+        self.should(&be_infinite());
+        self
+    }
+
     fn should_be_in_inclusive_range_with_tolerance(
         &self,
         range: RangeInclusive<T>,
@@ -223,6 +420,41 @@ impl<T: num::Float + Debug + Default + PartialEq> FloatAssertion<T> for T {
         self.should_not(&be_in_exclusive_range(range_with_tolerance));
         self
     }
+
+    fn should_have_same_sign_as(&self, other: T) -> &Self {
+        self.should(&have_same_sign_as(other));
+        self
+    }
+
+    fn should_have_opposite_sign_from(&self, other: T) -> &Self {
+        self.should(&have_opposite_sign_from(other));
+        self
+    }
+
+    fn should_round_to(&self, expected: T, places: u32) -> &Self {
+        self.should(&round_to(expected, places));
+        self
+    }
+
+    fn should_be_integer_valued(&self) -> &Self {
+        self.should(&be_integer_valued());
+        self
+    }
+
+    fn should_have_fractional_part(&self) -> &Self {
+        self.should(&have_fractional_part());
+        self
+    }
+
+    fn should_be_close_to_any(&self, candidates: Vec<T>, tolerance: T) -> &Self {
+        self.should(&be_close_to_any(candidates, tolerance));
+        self
+    }
+
+    fn should_not_be_close_to_any(&self, candidates: Vec<T>, tolerance: T) -> &Self {
+        self.should_not(&be_close_to_any(candidates, tolerance));
+        self
+    }
 }
 
 #[cfg(all(test, feature = "num"))]
@@ -280,6 +512,64 @@ mod tests {
         value.should_be_negative();
     }
 
+    #[test]
+    fn should_be_non_negative() {
+        let value: f64 = 0.123;
+        value.should_be_non_negative();
+    }
+
+    #[test]
+    fn should_be_non_negative_given_zeros() {
+        let value: f64 = 0.0;
+        value.should_be_non_negative();
+
+        let value: f64 = -0.0;
+        value.should_be_non_negative();
+    }
+
+    #[test]
+    #[should_panic(expected = "-1.23 should be non-negative (zero or positive)")]
+    fn should_be_non_negative_but_was_negative() {
+        let value: f64 = -1.23;
+        value.should_be_non_negative();
+    }
+
+    #[test]
+    #[should_panic(expected = "NaN should be non-negative (zero or positive)")]
+    fn should_be_non_negative_but_was_nan() {
+        let value: f64 = num::Float::nan();
+        value.should_be_non_negative();
+    }
+
+    #[test]
+    fn should_be_non_positive() {
+        let value: f64 = -1.23;
+        value.should_be_non_positive();
+    }
+
+    #[test]
+    fn should_be_non_positive_given_zeros() {
+        let value: f64 = 0.0;
+        value.should_be_non_positive();
+
+        let value: f64 = -0.0;
+        value.should_be_non_positive();
+    }
+
+    #[test]
+    #[should_panic(expected = "0.123 should be non-positive (zero or negative)")]
+    fn should_be_non_positive_but_was_positive() {
+        let value: f64 = 0.123;
+        value.should_be_non_positive();
+    }
+
+    #[test]
+    #[should_panic(expected = "NaN should be non-positive (zero or negative)")]
+    fn should_be_non_positive_but_was_nan() {
+        let value: f64 = num::Float::nan();
+        value.should_be_non_positive();
+    }
+
     #[test]
     fn should_be_in_inclusive_range_with_tolerance() {
         let value: f64 = 8.123;
@@ -331,4 +621,334 @@ mod tests {
         let value: f64 = 8.123;
         value.should_not_be_in_exclusive_range_with_tolerance(6.10..8.20, 0.123);
     }
+
+    #[test]
+    fn should_be_positive_zero() {
+        let value: f64 = 0.0;
+        value.should_be_positive_zero();
+    }
+
+    #[test]
+    #[should_panic(expected = "-0.0 should be positive zero but was negative zero")]
+    fn should_be_positive_zero_but_was_negative_zero() {
+        let value: f64 = -0.0;
+        value.should_be_positive_zero();
+    }
+
+    #[test]
+    fn should_be_negative_zero() {
+        let value: f64 = -0.0;
+        value.should_be_negative_zero();
+    }
+
+    #[test]
+    #[should_panic(expected = "0.0 should be negative zero but was positive zero")]
+    fn should_be_negative_zero_but_was_positive_zero() {
+        let value: f64 = 0.0;
+        value.should_be_negative_zero();
+    }
+
+    #[test]
+    fn should_be_finite() {
+        let value: f64 = -0.0;
+        value.should_be_finite();
+    }
+
+    #[test]
+    #[should_panic(expected = "inf should be finite but was infinite")]
+    fn should_be_finite_but_was_infinite() {
+        let value: f64 = f64::INFINITY;
+        value.should_be_finite();
+    }
+
+    #[test]
+    #[should_panic(expected = "NaN should be finite but was NaN")]
+    fn should_be_finite_but_was_nan() {
+        let value: f64 = f64::NAN;
+        value.should_be_finite();
+    }
+
+    #[test]
+    fn should_be_infinite() {
+        let value: f64 = f64::INFINITY;
+        value.should_be_infinite();
+    }
+
+    #[test]
+    #[should_panic(expected = "NaN should be infinite but was NaN")]
+    fn should_be_infinite_but_was_nan() {
+        let value: f64 = f64::NAN;
+        value.should_be_infinite();
+    }
+}
+
+#[cfg(all(test, feature = "num"))]
+mod sign_tests {
+    use crate::assertions::float::FloatAssertion;
+
+    #[test]
+    fn should_have_same_sign_as_given_positive_values() {
+        let value: f64 = 1.5;
+        value.should_have_same_sign_as(0.25);
+    }
+
+    #[test]
+    fn should_have_same_sign_as_given_zeros_of_different_signs() {
+        let value: f64 = -0.0;
+        value.should_have_same_sign_as(0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "1.5 should have the same sign as -0.25 but 1.5 is positive and -0.25 is negative")]
+    fn should_have_same_sign_as_but_had_the_opposite_sign() {
+        let value: f64 = 1.5;
+        value.should_have_same_sign_as(-0.25);
+    }
+
+    #[test]
+    #[should_panic(expected = "0.0 should have the same sign as 0.25 but 0.0 is zero and 0.25 is positive")]
+    fn should_have_same_sign_as_but_was_zero() {
+        let value: f64 = 0.0;
+        value.should_have_same_sign_as(0.25);
+    }
+
+    #[test]
+    #[should_panic(expected = "NaN should have the same sign as NaN but NaN is NaN and NaN is NaN")]
+    fn should_have_same_sign_as_but_was_nan() {
+        let value: f64 = f64::NAN;
+        value.should_have_same_sign_as(f64::NAN);
+    }
+
+    #[test]
+    fn should_have_opposite_sign_from() {
+        let value: f32 = 1.5;
+        value.should_have_opposite_sign_from(f32::NEG_INFINITY);
+    }
+
+    #[test]
+    #[should_panic(expected = "1.5 should have the opposite sign from 0.25 but 1.5 is positive and 0.25 is positive")]
+    fn should_have_opposite_sign_from_but_had_the_same_sign() {
+        let value: f64 = 1.5;
+        value.should_have_opposite_sign_from(0.25);
+    }
+
+    #[test]
+    #[should_panic(expected = "-0.0 should have the opposite sign from 0.25 but -0.0 is zero and 0.25 is positive")]
+    fn should_have_opposite_sign_from_but_was_negative_zero() {
+        let value: f64 = -0.0;
+        value.should_have_opposite_sign_from(0.25);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_opposite_sign_from_nan() {
+        let value: f64 = -1.5;
+        value.should_have_opposite_sign_from(f64::NAN);
+    }
+}
+
+#[cfg(all(test, feature = "num"))]
+mod rounding_tests {
+    use crate::assertions::float::FloatAssertion;
+
+    #[test]
+    fn should_round_to() {
+        let value: f64 = 12.34567;
+        value.should_round_to(12.35, 2);
+    }
+
+    #[test]
+    fn should_round_to_zero_decimal_places() {
+        let value: f32 = 3.7;
+        value.should_round_to(4.0, 0);
+    }
+
+    #[test]
+    fn should_round_to_given_a_negative_value() {
+        let value: f64 = -12.34567;
+        value.should_round_to(-12.346, 3);
+    }
+
+    #[test]
+    fn should_round_half_away_from_zero() {
+        let value: f64 = 2.5;
+        value.should_round_to(3.0, 0);
+    }
+
+    #[test]
+    fn should_round_half_away_from_zero_given_a_negative_value() {
+        let value: f64 = -2.5;
+        value.should_round_to(-3.0, 0);
+    }
+
+    #[test]
+    fn should_round_half_away_from_zero_at_decimal_places() {
+        let value: f64 = 0.125;
+        value.should_round_to(0.13, 2);
+    }
+
+    #[test]
+    fn should_round_a_value_just_below_half_down() {
+        let value: f64 = 1.005;
+        value.should_round_to(1.0, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "2.5 should round to 2.0 at 0 decimal places but rounded to 3.0")]
+    fn should_round_to_half_even_but_rounded_away_from_zero() {
+        let value: f64 = 2.5;
+        value.should_round_to(2.0, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "1.23456 should round to 1.235 at 2 decimal places but rounded to 1.23")]
+    fn should_round_to_but_expected_value_had_more_decimal_places() {
+        let value: f64 = 1.23456;
+        value.should_round_to(1.235, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "400 decimal places exceed the range of the floating type")]
+    fn should_round_to_but_decimal_places_exceeded_the_range() {
+        let value: f64 = 1.0;
+        value.should_round_to(2.0, 400);
+    }
+
+    #[test]
+    #[should_panic(expected = "4294967295 decimal places exceed the range of the floating type")]
+    fn should_round_to_but_decimal_places_overflowed() {
+        let value: f32 = 1.0;
+        value.should_round_to(1.0, u32::MAX);
+    }
+
+    #[test]
+    fn should_round_to_given_a_value_too_large_to_scale() {
+        let value: f64 = 1.5e300;
+        value.should_round_to(1.5e300, 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "12.34567 should round to 12.34 at 2 decimal places but rounded to 12.35")]
+    fn should_round_to_but_it_did_not() {
+        let value: f64 = 12.34567;
+        value.should_round_to(12.34, 2);
+    }
+}
+
+#[cfg(all(test, feature = "num"))]
+mod fractional_tests {
+    use crate::assertions::float::FloatAssertion;
+
+    #[test]
+    fn should_be_integer_valued() {
+        let value: f64 = 4.0;
+        value.should_be_integer_valued();
+    }
+
+    #[test]
+    fn should_be_integer_valued_given_a_negative_value() {
+        let value: f32 = -12.0;
+        value.should_be_integer_valued();
+    }
+
+    #[test]
+    #[should_panic(expected = "4.5 should be integer valued but had the fractional part 0.5")]
+    fn should_be_integer_valued_but_had_a_fractional_part() {
+        let value: f64 = 4.5;
+        value.should_be_integer_valued();
+    }
+
+    #[test]
+    #[should_panic(expected = "inf should be integer valued")]
+    fn should_be_integer_valued_but_was_infinite() {
+        let value: f64 = f64::INFINITY;
+        value.should_be_integer_valued();
+    }
+
+    #[test]
+    #[should_panic(expected = "NaN should be integer valued")]
+    fn should_be_integer_valued_but_was_nan() {
+        let value: f64 = f64::NAN;
+        value.should_be_integer_valued();
+    }
+
+    #[test]
+    fn should_have_fractional_part() {
+        let value: f64 = 4.5;
+        value.should_have_fractional_part();
+    }
+
+    #[test]
+    #[should_panic(expected = "4.0 should have a fractional part but had the fractional part 0.0")]
+    fn should_have_fractional_part_but_did_not() {
+        let value: f64 = 4.0;
+        value.should_have_fractional_part();
+    }
+
+    #[test]
+    #[should_panic(expected = "-inf should have a fractional part")]
+    fn should_have_fractional_part_but_was_infinite() {
+        let value: f64 = f64::NEG_INFINITY;
+        value.should_have_fractional_part();
+    }
+
+    #[test]
+    #[should_panic(expected = "NaN should have a fractional part")]
+    fn should_have_fractional_part_but_was_nan() {
+        let value: f64 = f64::NAN;
+        value.should_have_fractional_part();
+    }
+}
+
+#[cfg(all(test, feature = "num"))]
+mod close_to_any_tests {
+    use num::Float;
+
+    use crate::assertions::float::FloatAssertion;
+
+    #[test]
+    fn should_be_close_to_any() {
+        let value: f64 = 2.004;
+        value.should_be_close_to_any(vec![1.0, 2.0], 0.01);
+    }
+
+    #[test]
+    fn should_be_close_to_any_given_the_distance_equals_the_tolerance() {
+        let value: f32 = 1.5;
+        value.should_be_close_to_any(vec![1.0, 2.0], 0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "2.5 should be within 0.1 of any of [1.0, 2.25] but the closest distance was 0.25")]
+    fn should_be_close_to_any_but_it_was_far_from_all() {
+        let value: f64 = 2.5;
+        value.should_be_close_to_any(vec![1.0, 2.25], 0.1);
+    }
+
+    #[test]
+    #[should_panic(expected = "NaN should be within 0.1 of any of [1.0] but the closest distance was NaN")]
+    fn should_be_close_to_any_but_it_was_nan() {
+        let value: f64 = Float::nan();
+        value.should_be_close_to_any(vec![1.0], 0.1);
+    }
+
+    #[test]
+    #[should_panic(expected = "1.0 should be within 0.1 of any of [] but there were no candidates")]
+    fn should_be_close_to_any_but_there_were_no_candidates() {
+        let value: f64 = 1.0;
+        value.should_be_close_to_any(vec![], 0.1);
+    }
+
+    #[test]
+    fn should_not_be_close_to_any() {
+        let value: f64 = 2.5;
+        value.should_not_be_close_to_any(vec![1.0, 2.0], 0.1);
+    }
+
+    #[test]
+    #[should_panic(expected = "2.0 should not be within 0.1 of any of [1.0, 2.0] but the closest distance was 0.0")]
+    fn should_not_be_close_to_any_but_it_was() {
+        let value: f64 = 2.0;
+        value.should_not_be_close_to_any(vec![1.0, 2.0], 0.1);
+    }
 }
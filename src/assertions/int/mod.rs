@@ -1,8 +1,13 @@
-use std::fmt::Debug;
+use std::fmt::{Binary, Debug};
+use std::ops::RangeInclusive;
 
-use num::Integer;
+use num::{Integer, PrimInt};
 
-use crate::matchers::int::{be_even, be_negative, be_odd, be_positive, be_zero};
+use crate::matchers::int::{
+    be_close_to, be_even, be_in_range_with_step, be_multiple_of, be_negative, be_non_negative,
+    be_non_positive, be_odd, be_positive, be_prime, be_zero, have_bit_set, have_bits_set, have_no_bits_set,
+    have_opposite_sign_from, have_same_sign_as,
+};
 use crate::matchers::{Should, ShouldNot};
 
 /// IntAssertion enables assertions about various properties of integers.
@@ -45,6 +50,30 @@ pub trait IntAssertion<T: Integer + PartialEq + Default> {
     /// ```
     fn should_be_negative(&self) -> &Self;
 
+    /// - Asserts that the integer value is non-negative (zero or positive).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::int::IntAssertion;
+    ///
+    /// let value = 0;
+    /// value.should_be_non_negative();
+    /// ```
+    fn should_be_non_negative(&self) -> &Self;
+
+    /// - Asserts that the integer value is non-positive (zero or negative).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::int::IntAssertion;
+    ///
+    /// let value = -10;
+    /// value.should_be_non_positive();
+    /// ```
+    fn should_be_non_positive(&self) -> &Self;
+
     /// - Asserts that the integer value is even.
     /// - Returns a reference to self for fluent chaining.
     /// - Panics if the assertion fails.
@@ -92,6 +121,132 @@ pub trait IntAssertion<T: Integer + PartialEq + Default> {
     /// value.should_not_be_zero();
     /// ```
     fn should_not_be_zero(&self) -> &Self;
+
+    /// - Asserts that the integer value is prime. Zero, one and negative values are not prime.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::int::IntAssertion;
+    ///
+    /// let value = 97;
+    /// value.should_be_prime();
+    /// ```
+    fn should_be_prime(&self) -> &Self;
+
+    /// - Asserts that the integer value is not prime.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::int::IntAssertion;
+    ///
+    /// let value = 91;
+    /// value.should_not_be_prime();
+    /// ```
+    fn should_not_be_prime(&self) -> &Self;
+
+    /// - Asserts that the integer value is a multiple of the given step.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the step is zero or if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::int::IntAssertion;
+    ///
+    /// let value = 45;
+    /// value.should_be_multiple_of(5);
+    /// ```
+    fn should_be_multiple_of(&self, step: T) -> &Self;
+
+    /// - Asserts that the integer value is not a multiple of the given step.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the step is zero or if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::int::IntAssertion;
+    ///
+    /// let value = 47;
+    /// value.should_not_be_multiple_of(5);
+    /// ```
+    fn should_not_be_multiple_of(&self, step: T) -> &Self;
+
+    /// - Asserts that the integer value falls within the given inclusive range and is reachable from the start of the range in increments of the given step.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the step is zero or if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::int::IntAssertion;
+    ///
+    /// let value = 35;
+    /// value.should_be_in_range_with_step(0..=100, 5);
+    /// ```
+    fn should_be_in_range_with_step(&self, range: RangeInclusive<T>, step: T) -> &Self;
+
+    /// - Asserts that the integer value either falls outside the given inclusive range or is not reachable from the start of the range in increments of the given step.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the step is zero or if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::int::IntAssertion;
+    ///
+    /// let value = 36;
+    /// value.should_not_be_in_range_with_step(0..=100, 5);
+    /// ```
+    fn should_not_be_in_range_with_step(&self, range: RangeInclusive<T>, step: T) -> &Self;
+
+    /// - Asserts that the integer value has the same sign as the given value.
+    /// - Zero has the same sign only as zero, and the opposite sign from no value.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::int::IntAssertion;
+    ///
+    /// let value = -10;
+    /// value.should_have_same_sign_as(-3);
+    /// ```
+    fn should_have_same_sign_as(&self, other: T) -> &Self;
+
+    /// - Asserts that the integer value has the opposite sign from the given value.
+    /// - Zero has the same sign only as zero, and the opposite sign from no value.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::int::IntAssertion;
+    ///
+    /// let value = -10;
+    /// value.should_have_opposite_sign_from(3);
+    /// ```
+    fn should_have_opposite_sign_from(&self, other: T) -> &Self;
+
+    /// - Asserts that the integer value is within the given absolute delta of the expected value.
+    /// - The absolute difference is computed without overflowing, even for values near the minimum and the maximum of the type.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails or if the delta is negative.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::int::IntAssertion;
+    ///
+    /// let elapsed_ticks = 1020;
+    /// elapsed_ticks.should_be_close_to(1000, 25);
+    /// ```
+    fn should_be_close_to(&self, expected: T, delta: T) -> &Self
+        where T: PrimInt;
+
+    /// - Asserts that the integer value is not within the given absolute delta of the expected value.
+    /// - The absolute difference is computed without overflowing, even for values near the minimum and the maximum of the type.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails or if the delta is negative.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::int::IntAssertion;
+    ///
+    /// let elapsed_ticks = 1030;
+    /// elapsed_ticks.should_not_be_close_to(1000, 25);
+    /// ```
+    fn should_not_be_close_to(&self, expected: T, delta: T) -> &Self
+        where T: PrimInt;
 }
 
 impl<T: Integer + Debug + PartialEq + Default> IntAssertion<T> for T {
@@ -105,6 +260,16 @@ impl<T: Integer + Debug + PartialEq + Default> IntAssertion<T> for T {
         self
     }
 
+    fn should_be_non_negative(&self) -> &Self {
+        self.should(&be_non_negative());
+        self
+    }
+
+    fn should_be_non_positive(&self) -> &Self {
+        self.should(&be_non_positive());
+        self
+    }
+
     fn should_be_even(&self) -> &Self {
         self.should(&be_even());
         self
@@ -124,6 +289,146 @@ impl<T: Integer + Debug + PartialEq + Default> IntAssertion<T> for T {
         self.should_not(&be_zero());
         self
     }
+
+    fn should_be_prime(&self) -> &Self {
+        self.should(&be_prime());
+        self
+    }
+
+    fn should_not_be_prime(&self) -> &Self {
+        self.should_not(&be_prime());
+        self
+    }
+
+    fn should_be_multiple_of(&self, step: T) -> &Self {
+        self.should(&be_multiple_of(step));
+        self
+    }
+
+    fn should_not_be_multiple_of(&self, step: T) -> &Self {
+        self.should_not(&be_multiple_of(step));
+        self
+    }
+
+    fn should_be_in_range_with_step(&self, range: RangeInclusive<T>, step: T) -> &Self {
+        self.should(&be_in_range_with_step(range, step));
+        self
+    }
+
+    fn should_not_be_in_range_with_step(&self, range: RangeInclusive<T>, step: T) -> &Self {
+        self.should_not(&be_in_range_with_step(range, step));
+        self
+    }
+
+    fn should_have_same_sign_as(&self, other: T) -> &Self {
+        self.should(&have_same_sign_as(other));
+        self
+    }
+
+    fn should_have_opposite_sign_from(&self, other: T) -> &Self {
+        self.should(&have_opposite_sign_from(other));
+        self
+    }
+
+    fn should_be_close_to(&self, expected: T, delta: T) -> &Self
+        where T: PrimInt,
+    {
+        self.should(&be_close_to(expected, delta));
+        self
+    }
+
+    fn should_not_be_close_to(&self, expected: T, delta: T) -> &Self
+        where T: PrimInt,
+    {
+        self.should_not(&be_close_to(expected, delta));
+        self
+    }
+}
+
+/// BitAssertion enables assertions about the bits of primitive integer values, typically used as flags.
+///
+/// It offers a fluent interface for chaining multiple assertions.
+///
+/// # Example
+/// ```
+/// use clearcheck::assertions::int::BitAssertion;
+///
+/// let flags: u8 = 0b1010_0001;
+/// flags
+///     .should_have_bit_set(7)
+///     .should_have_bits_set(0b0010_0001)
+///     .should_have_no_bits_set(0b0000_0110);
+/// ```
+pub trait BitAssertion<T: PrimInt> {
+    /// - Asserts that the bit at the given index (0 being the least significant bit) is set.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the index is not within the bit width of the integer type, or if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::int::BitAssertion;
+    ///
+    /// let flags: u8 = 0b0100;
+    /// flags.should_have_bit_set(2);
+    /// ```
+    fn should_have_bit_set(&self, index: u32) -> &Self;
+
+    /// - Asserts that the bit at the given index (0 being the least significant bit) is not set.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the index is not within the bit width of the integer type, or if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::int::BitAssertion;
+    ///
+    /// let flags: u8 = 0b0100;
+    /// flags.should_not_have_bit_set(1);
+    /// ```
+    fn should_not_have_bit_set(&self, index: u32) -> &Self;
+
+    /// - Asserts that all the bits of the given mask are set.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::int::BitAssertion;
+    ///
+    /// let flags = 0b1011;
+    /// flags.should_have_bits_set(0b0011);
+    /// ```
+    fn should_have_bits_set(&self, mask: T) -> &Self;
+
+    /// - Asserts that none of the bits of the given mask are set.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::int::BitAssertion;
+    ///
+    /// let flags = 0b1011;
+    /// flags.should_have_no_bits_set(0b0100);
+    /// ```
+    fn should_have_no_bits_set(&self, mask: T) -> &Self;
+}
+
+impl<T: PrimInt + Binary> BitAssertion<T> for T {
+    fn should_have_bit_set(&self, index: u32) -> &Self {
+        self.should(&have_bit_set(index));
+        self
+    }
+
+    fn should_not_have_bit_set(&self, index: u32) -> &Self {
+        self.should_not(&have_bit_set(index));
+        self
+    }
+
+    fn should_have_bits_set(&self, mask: T) -> &Self {
+        self.should(&have_bits_set(mask));
+        self
+    }
+
+    fn should_have_no_bits_set(&self, mask: T) -> &Self {
+        self.should(&have_no_bits_set(mask));
+        self
+    }
 }
 
 #[cfg(all(test, feature = "num"))]
@@ -156,6 +461,44 @@ mod tests {
         value.should_be_negative();
     }
 
+    #[test]
+    fn should_be_non_negative() {
+        let value = 10;
+        value.should_be_non_negative();
+    }
+
+    #[test]
+    fn should_be_non_negative_given_zero() {
+        let value = 0;
+        value.should_be_non_negative();
+    }
+
+    #[test]
+    #[should_panic(expected = "-10 should be non-negative (zero or positive)")]
+    fn should_be_non_negative_but_was_negative() {
+        let value = -10;
+        value.should_be_non_negative();
+    }
+
+    #[test]
+    fn should_be_non_positive() {
+        let value = -10;
+        value.should_be_non_positive();
+    }
+
+    #[test]
+    fn should_be_non_positive_given_zero() {
+        let value = 0;
+        value.should_be_non_positive();
+    }
+
+    #[test]
+    #[should_panic(expected = "10 should be non-positive (zero or negative)")]
+    fn should_be_non_positive_but_was_positive() {
+        let value = 10;
+        value.should_be_non_positive();
+    }
+
     #[test]
     fn should_be_even() {
         let value = 10;
@@ -207,4 +550,351 @@ mod tests {
         let value = 0;
         value.should_not_be_zero();
     }
+
+    #[test]
+    fn should_be_multiple_of() {
+        let value = 45;
+        value.should_be_multiple_of(5);
+    }
+
+    #[test]
+    fn should_be_multiple_of_given_a_negative_value() {
+        let value: i64 = -45;
+        value.should_be_multiple_of(5);
+    }
+
+    #[test]
+    #[should_panic(expected = "47 should be a multiple of 5")]
+    fn should_be_multiple_of_but_was_not() {
+        let value = 47;
+        value.should_be_multiple_of(5);
+    }
+
+    #[test]
+    #[should_panic(expected = "step should not be zero")]
+    fn should_be_multiple_of_zero() {
+        let value = 0;
+        value.should_be_multiple_of(0);
+    }
+
+    #[test]
+    fn should_not_be_multiple_of() {
+        let value = 47;
+        value.should_not_be_multiple_of(5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_multiple_of_but_was() {
+        let value = 45;
+        value.should_not_be_multiple_of(5);
+    }
+
+    #[test]
+    fn should_be_in_range_with_step() {
+        let value = 35;
+        value.should_be_in_range_with_step(0..=100, 5);
+    }
+
+    #[test]
+    fn should_be_in_range_with_step_starting_at_an_offset() {
+        let value = 8;
+        value.should_be_in_range_with_step(3..=23, 5);
+    }
+
+    #[test]
+    fn should_be_in_range_with_step_at_the_end_of_the_range() {
+        let value: u8 = 100;
+        value.should_be_in_range_with_step(0..=100, 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "36 should be in the range 0..=100 with step 5 but it was not aligned to the step")]
+    fn should_be_in_range_with_step_but_was_misaligned() {
+        let value = 36;
+        value.should_be_in_range_with_step(0..=100, 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "105 should be in the range 0..=100 with step 5 but it was outside the range")]
+    fn should_be_in_range_with_step_but_was_outside_the_range() {
+        let value = 105;
+        value.should_be_in_range_with_step(0..=100, 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "step should not be zero")]
+    fn should_be_in_range_with_zero_step() {
+        let value = 5;
+        value.should_be_in_range_with_step(0..=100, 0);
+    }
+
+    #[test]
+    fn should_not_be_in_range_with_step() {
+        let value = 36;
+        value.should_not_be_in_range_with_step(0..=100, 5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_in_range_with_step_but_was() {
+        let value = 35;
+        value.should_not_be_in_range_with_step(0..=100, 5);
+    }
+
+    #[test]
+    fn should_be_prime() {
+        let value = 2;
+        value.should_be_prime();
+    }
+
+    #[test]
+    fn should_be_prime_given_a_large_prime() {
+        let value: i64 = 1_000_000_007;
+        value.should_be_prime();
+    }
+
+    #[test]
+    fn should_be_prime_given_the_largest_u8_prime() {
+        let value: u8 = 251;
+        value.should_be_prime();
+    }
+
+    #[test]
+    #[should_panic(expected = "1 should be prime")]
+    fn should_be_prime_but_was_one() {
+        let value = 1;
+        value.should_be_prime();
+    }
+
+    #[test]
+    #[should_panic(expected = "0 should be prime")]
+    fn should_be_prime_but_was_zero() {
+        let value = 0;
+        value.should_be_prime();
+    }
+
+    #[test]
+    #[should_panic(expected = "-7 should be prime")]
+    fn should_be_prime_but_was_negative() {
+        let value = -7;
+        value.should_be_prime();
+    }
+
+    #[test]
+    #[should_panic(expected = "49 should be prime")]
+    fn should_be_prime_but_was_a_square_of_a_prime() {
+        let value = 49;
+        value.should_be_prime();
+    }
+
+    #[test]
+    fn should_not_be_prime() {
+        let value = 1;
+        value.should_not_be_prime();
+    }
+
+    #[test]
+    fn should_not_be_prime_given_a_negative_value() {
+        let value = -7;
+        value.should_not_be_prime();
+    }
+
+    #[test]
+    #[should_panic(expected = "2 should not be prime but it is a prime")]
+    fn should_not_be_prime_but_was() {
+        let value = 2;
+        value.should_not_be_prime();
+    }
+}
+
+#[cfg(all(test, feature = "num"))]
+mod sign_tests {
+    use crate::assertions::int::IntAssertion;
+
+    #[test]
+    fn should_have_same_sign_as_given_positive_values() {
+        let value = 10;
+        value.should_have_same_sign_as(3);
+    }
+
+    #[test]
+    fn should_have_same_sign_as_given_zeros() {
+        let value = 0;
+        value.should_have_same_sign_as(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "10 should have the same sign as -3 but 10 is positive and -3 is negative")]
+    fn should_have_same_sign_as_but_had_the_opposite_sign() {
+        let value = 10;
+        value.should_have_same_sign_as(-3);
+    }
+
+    #[test]
+    #[should_panic(expected = "0 should have the same sign as 3 but 0 is zero and 3 is positive")]
+    fn should_have_same_sign_as_but_was_zero() {
+        let value = 0;
+        value.should_have_same_sign_as(3);
+    }
+
+    #[test]
+    fn should_have_opposite_sign_from() {
+        let value: i64 = 10;
+        value.should_have_opposite_sign_from(-3);
+    }
+
+    #[test]
+    #[should_panic(expected = "10 should have the opposite sign from 3 but 10 is positive and 3 is positive")]
+    fn should_have_opposite_sign_from_but_had_the_same_sign() {
+        let value = 10;
+        value.should_have_opposite_sign_from(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "-3 should have the opposite sign from 0 but -3 is negative and 0 is zero")]
+    fn should_have_opposite_sign_from_zero() {
+        let value = -3;
+        value.should_have_opposite_sign_from(0);
+    }
+}
+
+#[cfg(all(test, feature = "num"))]
+mod close_to_tests {
+    use crate::assertions::int::IntAssertion;
+
+    #[test]
+    fn should_be_close_to() {
+        let value = 98;
+        value.should_be_close_to(100, 2);
+    }
+
+    #[test]
+    fn should_be_close_to_given_an_unsigned_value_below_the_expected_value() {
+        let value: u32 = 3;
+        value.should_be_close_to(5, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "97 should be close to 100 within a delta of 2 but the difference was 3")]
+    fn should_be_close_to_but_was_not() {
+        let value = 97;
+        value.should_be_close_to(100, 2);
+    }
+
+    #[test]
+    fn should_be_close_to_near_the_maximum() {
+        let value = i32::MAX;
+        value.should_be_close_to(i32::MAX - 10, 10);
+    }
+
+    #[test]
+    fn should_be_close_to_given_the_maximum_delta() {
+        let value = i32::MAX;
+        value.should_be_close_to(0, i32::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "-2147483648 should be close to 2147483647 within a delta of 2147483647 but the difference was 4294967295")]
+    fn should_be_close_to_but_the_difference_overflowed() {
+        let value = i32::MIN;
+        value.should_be_close_to(i32::MAX, i32::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "-170141183460469231731687303715884105728 should be close to 170141183460469231731687303715884105727 within a delta of 1 but the difference was 340282366920938463463374607431768211455")]
+    fn should_be_close_to_but_the_difference_overflowed_an_i128() {
+        let value = i128::MIN;
+        value.should_be_close_to(i128::MAX, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "delta should not be negative")]
+    fn should_be_close_to_with_a_negative_delta() {
+        let value = 10;
+        value.should_be_close_to(10, -1);
+    }
+
+    #[test]
+    fn should_not_be_close_to() {
+        let value = i32::MIN;
+        value.should_not_be_close_to(i32::MAX, 100);
+    }
+
+    #[test]
+    #[should_panic(expected = "98 should not be close to 100 within a delta of 2 but the difference was 2")]
+    fn should_not_be_close_to_but_was() {
+        let value = 98;
+        value.should_not_be_close_to(100, 2);
+    }
+}
+
+#[cfg(all(test, feature = "num"))]
+mod bit_tests {
+    use crate::assertions::int::BitAssertion;
+
+    #[test]
+    fn should_have_bit_set() {
+        let flags: u8 = 0b1000_0100;
+        flags.should_have_bit_set(2).should_have_bit_set(7);
+    }
+
+    #[test]
+    #[should_panic(expected = "0b10000100 should have bit 1 set")]
+    fn should_have_bit_set_but_it_was_cleared() {
+        let flags: u8 = 0b1000_0100;
+        flags.should_have_bit_set(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "bit index 32 is out of range for a 32-bit integer")]
+    fn should_have_bit_set_with_an_index_beyond_the_bit_width() {
+        let flags: i32 = 1;
+        flags.should_have_bit_set(32);
+    }
+
+    #[test]
+    fn should_have_the_sign_bit_set() {
+        let flags: i8 = -1;
+        flags.should_have_bit_set(7);
+    }
+
+    #[test]
+    fn should_not_have_bit_set() {
+        let flags: u8 = 0b1000_0100;
+        flags.should_not_have_bit_set(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "0b10000100 should not have bit 2 set")]
+    fn should_not_have_bit_set_but_it_was_set() {
+        let flags: u8 = 0b1000_0100;
+        flags.should_not_have_bit_set(2);
+    }
+
+    #[test]
+    fn should_have_bits_set() {
+        let flags: u16 = 0b1011;
+        flags.should_have_bits_set(0b1010);
+    }
+
+    #[test]
+    #[should_panic(expected = "0b1011 should have all the bits of the mask 0b110 set but 0b100 were not set")]
+    fn should_have_bits_set_but_they_were_not() {
+        let flags: u16 = 0b1011;
+        flags.should_have_bits_set(0b0110);
+    }
+
+    #[test]
+    fn should_have_no_bits_set() {
+        let flags: u16 = 0b1011;
+        flags.should_have_no_bits_set(0b0100);
+    }
+
+    #[test]
+    #[should_panic(expected = "0b1011 should have none of the bits of the mask 0b110 set but 0b10 were set")]
+    fn should_have_no_bits_set_but_they_were() {
+        let flags: u16 = 0b1011;
+        flags.should_have_no_bits_set(0b0110);
+    }
 }
@@ -0,0 +1,209 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::matchers::{Should, ShouldNot};
+use crate::matchers::map::equal::equal_map;
+
+/// MapEqualityAssertion enables assertions about whether a HashMap (or a BTreeMap) has exactly the same entries as another map.
+///
+/// Unlike `should_contain_all`, which allows extra entries, these assertions compare the complete set of entries.
+/// On failure, the panic message lists the missing keys, the extra keys and the keys with different values separately.
+pub trait MapEqualityAssertion {
+    /// - Asserts that the map has exactly the same key-value entries as the other map.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use clearcheck::assertions::map::equal::MapEqualityAssertion;
+    ///
+    /// let mut key_value = HashMap::new();
+    /// key_value.insert("rust", "clearcheck");
+    /// key_value.insert("java", "junit");
+    ///
+    /// let mut other = HashMap::new();
+    /// other.insert("java", "junit");
+    /// other.insert("rust", "clearcheck");
+    ///
+    /// key_value.should_equal_map(&other);
+    /// ```
+    fn should_equal_map(&self, other: &Self) -> &Self;
+
+    /// - Asserts that the map does not have exactly the same key-value entries as the other map.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use clearcheck::assertions::map::equal::MapEqualityAssertion;
+    ///
+    /// let mut key_value = HashMap::new();
+    /// key_value.insert("rust", "clearcheck");
+    ///
+    /// let mut other = HashMap::new();
+    /// other.insert("rust", "clearcheck");
+    /// other.insert("java", "junit");
+    ///
+    /// key_value.should_not_equal_map(&other);
+    /// ```
+    fn should_not_equal_map(&self, other: &Self) -> &Self;
+}
+
+impl<K, V> MapEqualityAssertion for HashMap<K, V>
+    where
+        K: Hash + Eq + Debug,
+        V: PartialEq + Debug,
+{
+    fn should_equal_map(&self, other: &Self) -> &Self {
+        self.should(&equal_map(other));
+        self
+    }
+
+    fn should_not_equal_map(&self, other: &Self) -> &Self {
+        self.should_not(&equal_map(other));
+        self
+    }
+}
+
+impl<K, V> MapEqualityAssertion for BTreeMap<K, V>
+    where
+        K: Ord + Debug,
+        V: PartialEq + Debug,
+{
+    fn should_equal_map(&self, other: &Self) -> &Self {
+        self.should(&equal_map(other));
+        self
+    }
+
+    fn should_not_equal_map(&self, other: &Self) -> &Self {
+        self.should_not(&equal_map(other));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::assertions::map::equal::MapEqualityAssertion;
+
+    #[test]
+    fn should_equal_map() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "clearcheck");
+        key_value.insert("java", "junit");
+
+        let mut other = HashMap::new();
+        other.insert("java", "junit");
+        other.insert("rust", "clearcheck");
+
+        key_value.should_equal_map(&other);
+    }
+
+    #[test]
+    fn should_equal_empty_map() {
+        let key_value: HashMap<&str, i32> = HashMap::new();
+        key_value.should_equal_map(&HashMap::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "should equal {\"rust\": \"clearcheck\"} but had extra keys [\"java\"]")]
+    fn should_equal_map_but_had_an_extra_key() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "clearcheck");
+        key_value.insert("java", "junit");
+
+        let mut other = HashMap::new();
+        other.insert("rust", "clearcheck");
+
+        key_value.should_equal_map(&other);
+    }
+
+    #[test]
+    #[should_panic(expected = "{\"rust\": \"clearcheck\"} should equal {\"java\": \"junit\"} but had missing keys [\"java\"], extra keys [\"rust\"]")]
+    fn should_equal_map_but_had_a_missing_key() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "clearcheck");
+
+        let mut other = HashMap::new();
+        other.insert("java", "junit");
+
+        key_value.should_equal_map(&other);
+    }
+
+    #[test]
+    #[should_panic(expected = "{\"rust\": \"clearcheck\"} should equal {\"rust\": \"assert\"} but had different values for keys [\"rust\"]")]
+    fn should_equal_map_but_had_a_different_value() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "clearcheck");
+
+        let mut other = HashMap::new();
+        other.insert("rust", "assert");
+
+        key_value.should_equal_map(&other);
+    }
+
+    #[test]
+    fn should_not_equal_map() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "clearcheck");
+
+        let mut other = HashMap::new();
+        other.insert("rust", "assert");
+
+        key_value.should_not_equal_map(&other);
+    }
+
+    #[test]
+    #[should_panic(expected = "{\"rust\": \"clearcheck\"} should not equal {\"rust\": \"clearcheck\"}")]
+    fn should_not_equal_map_but_it_did() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "clearcheck");
+
+        key_value.should_not_equal_map(&key_value.clone());
+    }
+}
+
+#[cfg(test)]
+mod btree_tests {
+    use std::collections::BTreeMap;
+
+    use crate::assertions::map::equal::MapEqualityAssertion;
+
+    #[test]
+    fn should_equal_map() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert("rust", 10);
+        key_value.insert("java", 8);
+
+        let mut other = BTreeMap::new();
+        other.insert("java", 8);
+        other.insert("rust", 10);
+
+        key_value.should_equal_map(&other);
+    }
+
+    #[test]
+    #[should_panic(expected = "{\"go\": 5, \"java\": 8} should equal {\"java\": 9, \"rust\": 10} but had missing keys [\"rust\"], extra keys [\"go\"], different values for keys [\"java\"]")]
+    fn should_equal_map_but_had_every_kind_of_difference() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert("go", 5);
+        key_value.insert("java", 8);
+
+        let mut other = BTreeMap::new();
+        other.insert("java", 9);
+        other.insert("rust", 10);
+
+        key_value.should_equal_map(&other);
+    }
+
+    #[test]
+    fn should_not_equal_map() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert("rust", 10);
+
+        let other = BTreeMap::new();
+        key_value.should_not_equal_map(&other);
+    }
+}
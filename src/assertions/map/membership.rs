@@ -1,20 +1,22 @@
-//! Membership related assertions enable assertions about the presence or the absence of keys, values or key/value pairs in a HashMap.
+//! Membership related assertions enable assertions about the presence or the absence of keys, values or key/value pairs in a HashMap or a BTreeMap.
 
 use std::borrow::Borrow;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Debug;
 use std::hash::Hash;
 
 use crate::matchers::{Should, ShouldNot};
 use crate::matchers::map::empty::be_empty;
 use crate::matchers::map::membership::{
-    contain_all_key_values, contain_all_keys, contain_all_values, contain_any_of_key_values,
-    contain_any_of_keys, contain_any_of_values, contain_key, contain_key_value, contain_value,
+    contain_all_key_values, contain_all_keys, contain_all_ordered_key_values, contain_all_values,
+    contain_any_of_key_values, contain_any_of_keys, contain_any_of_ordered_key_values, contain_any_of_values,
+    contain_key, contain_key_value, contain_ordered_key_value, contain_value,
 };
+use crate::matchers::map::predicate::{satisfy_for_all_entries, satisfy_for_any_entry, satisfy_for_any_value};
 
-/// NoMembershipAssertion enables assertions about the emptiness or non-emptiness of the [`HashMap`].
+/// NoMembershipAssertion enables assertions about the emptiness or non-emptiness of the [`HashMap`] or the [`BTreeMap`].
 pub trait NoMembershipAssertion {
-    /// - Asserts that the HashMap is empty.
+    /// - Asserts that the map is empty.
     /// - Returns a reference to self for fluent chaining.
     /// - Panics if the assertion fails.
     /// # Example
@@ -27,7 +29,7 @@ pub trait NoMembershipAssertion {
     /// ```
     fn should_be_empty(&self) -> &Self;
 
-    /// - Asserts that the HashMap is not empty.
+    /// - Asserts that the map is not empty.
     /// - Returns a reference to self for fluent chaining.
     /// - Panics if the assertion fails.
     /// # Example
@@ -44,6 +46,8 @@ pub trait NoMembershipAssertion {
 }
 
 /// KeyMembershipAssertion enables assertions about the presence or the absence of keys in the [`HashMap`].
+///
+/// Use [`OrderedKeyMembershipAssertion`] for the [`BTreeMap`].
 pub trait KeyMembershipAssertion<K>
     where K: Eq + Hash
 {
@@ -126,6 +130,7 @@ pub trait KeyMembershipAssertion<K>
             Q: Hash + Eq + Debug + ?Sized;
 
     /// - Asserts that the HashMap contains any of the given keys.
+    /// - Fails if the given keys are empty, as no key can match.
     /// - Supports flexible key comparison through the `Borrow<Q>` trait bound, allowing for various key types and reference types.
     /// - Returns a reference to self for fluent chaining.
     /// - Panics if the assertion fails.
@@ -164,9 +169,30 @@ pub trait KeyMembershipAssertion<K>
         where
             K: Borrow<Q>,
             Q: Hash + Eq + Debug + ?Sized;
+
+    /// - Asserts that the HashMap contains none of the given keys; an alias of `should_not_contain_any_of_keys`.
+    /// - Passes trivially if the given keys are empty.
+    /// - Supports flexible key comparison through the `Borrow<Q>` trait bound, allowing for various key types and reference types.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use clearcheck::assertions::map::membership::KeyMembershipAssertion;
+    ///
+    /// let mut key_value = HashMap::new();
+    /// key_value.insert("rust", "clearcheck");
+    /// key_value.insert("java", "junit");
+    ///
+    /// key_value.should_contain_none_of_keys(vec!["golang", "scala"]);
+    /// ```
+    fn should_contain_none_of_keys<Q>(&self, keys: Vec<&Q>) -> &Self
+        where
+            K: Borrow<Q>,
+            Q: Hash + Eq + Debug + ?Sized;
 }
 
-/// ValueMembershipAssertion enables assertions about the presence or the absence of values in the [`HashMap`].
+/// ValueMembershipAssertion enables assertions about the presence or the absence of values in the [`HashMap`] or the [`BTreeMap`].
 pub trait ValueMembershipAssertion<V>
     where V: Eq
 {
@@ -287,9 +313,47 @@ pub trait ValueMembershipAssertion<V>
         where
             V: Eq + Borrow<S>,
             S: Debug + ?Sized + Eq;
+
+    /// - Asserts that the HashMap contains at least one value satisfying the given predicate, regardless of its key.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use clearcheck::assertions::map::membership::ValueMembershipAssertion;
+    ///
+    /// let mut key_value = HashMap::new();
+    /// key_value.insert("rust", "clearcheck");
+    /// key_value.insert("java", "junit");
+    ///
+    /// key_value.should_contain_value_satisfying(|value| value.starts_with("clear"));
+    /// ```
+    fn should_contain_value_satisfying<F>(&self, predicate: F) -> &Self
+        where
+            F: Fn(&V) -> bool;
+
+    /// - Asserts that the HashMap does not contain any value satisfying the given predicate.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use clearcheck::assertions::map::membership::ValueMembershipAssertion;
+    ///
+    /// let mut key_value = HashMap::new();
+    /// key_value.insert("rust", "clearcheck");
+    /// key_value.insert("java", "junit");
+    ///
+    /// key_value.should_contain_no_value_satisfying(|value| value.is_empty());
+    /// ```
+    fn should_contain_no_value_satisfying<F>(&self, predicate: F) -> &Self
+        where
+            F: Fn(&V) -> bool;
 }
 
 /// KeyValueMembershipAssertion enables assertions about the presence or the absence of keys and values in the [`HashMap`].
+///
+/// Use [`OrderedKeyValueMembershipAssertion`] for the [`BTreeMap`].
 pub trait KeyValueMembershipAssertion<K, V>
     where K: Hash + Eq,
 {
@@ -435,6 +499,167 @@ pub trait KeyValueMembershipAssertion<K, V>
             V: Borrow<S>,
             Q: Debug + ?Sized + Hash + Eq,
             S: Debug + ?Sized + Eq;
+
+    /// - Asserts that the map contains at least one entry whose key and value together satisfy the given predicate.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use clearcheck::assertions::map::membership::KeyValueMembershipAssertion;
+    ///
+    /// let mut scores = HashMap::new();
+    /// scores.insert("rust", 10);
+    /// scores.insert("java", 8);
+    ///
+    /// scores.should_contain_entry_satisfying(|language, score| language.starts_with('r') && *score > 9);
+    /// ```
+    fn should_contain_entry_satisfying<F>(&self, predicate: F) -> &Self
+        where
+            F: Fn(&K, &V) -> bool;
+
+    /// - Asserts that every entry in the map satisfies the given predicate over its key and value.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting the first entry that did not satisfy the predicate.
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use clearcheck::assertions::map::membership::KeyValueMembershipAssertion;
+    ///
+    /// let mut scores = HashMap::new();
+    /// scores.insert("rust", 10);
+    /// scores.insert("java", 8);
+    ///
+    /// scores.should_have_all_entries_satisfying(|language, score| language.len() == 4 && *score > 5);
+    /// ```
+    fn should_have_all_entries_satisfying<F>(&self, predicate: F) -> &Self
+        where
+            F: Fn(&K, &V) -> bool;
+}
+
+/// OrderedKeyMembershipAssertion enables assertions about the presence or the absence of keys in the [`BTreeMap`].
+///
+/// It offers the methods of [`KeyMembershipAssertion`], with the same behavior, but compares the keys through their Ord
+/// implementation, so they need not implement Hash.
+///
+/// # Example
+/// ```
+/// use std::collections::BTreeMap;
+/// use clearcheck::assertions::map::membership::OrderedKeyMembershipAssertion;
+///
+/// let mut key_value = BTreeMap::new();
+/// key_value.insert("rust", "clearcheck");
+/// key_value.insert("java", "junit");
+///
+/// key_value.should_contain_key("rust").should_not_contain_any_of_keys(vec!["golang", "scala"]);
+/// ```
+pub trait OrderedKeyMembershipAssertion<K>
+    where K: Ord
+{
+    fn should_contain_key<Q>(&self, key: &Q) -> &Self
+        where
+            K: Borrow<Q>,
+            Q: Ord + Debug + ?Sized;
+
+    fn should_not_contain_key<Q>(&self, key: &Q) -> &Self
+        where
+            K: Borrow<Q>,
+            Q: Ord + Debug + ?Sized;
+
+    fn should_contain_all_keys<Q>(&self, keys: Vec<&Q>) -> &Self
+        where
+            K: Borrow<Q>,
+            Q: Ord + Debug + ?Sized;
+
+    fn should_not_contain_all_keys<Q>(&self, keys: Vec<&Q>) -> &Self
+        where
+            K: Borrow<Q>,
+            Q: Ord + Debug + ?Sized;
+
+    fn should_contain_any_of_keys<Q>(&self, keys: Vec<&Q>) -> &Self
+        where
+            K: Borrow<Q>,
+            Q: Ord + Debug + ?Sized;
+
+    fn should_not_contain_any_of_keys<Q>(&self, keys: Vec<&Q>) -> &Self
+        where
+            K: Borrow<Q>,
+            Q: Ord + Debug + ?Sized;
+
+    fn should_contain_none_of_keys<Q>(&self, keys: Vec<&Q>) -> &Self
+        where
+            K: Borrow<Q>,
+            Q: Ord + Debug + ?Sized;
+}
+
+/// OrderedKeyValueMembershipAssertion enables assertions about the presence or the absence of keys and values in the [`BTreeMap`].
+///
+/// It offers the methods of [`KeyValueMembershipAssertion`], with the same behavior, but compares the keys through their
+/// Ord implementation, so they need not implement Hash.
+///
+/// # Example
+/// ```
+/// use std::collections::BTreeMap;
+/// use clearcheck::assertions::map::membership::OrderedKeyValueMembershipAssertion;
+///
+/// let mut key_value = BTreeMap::new();
+/// key_value.insert("rust", "clearcheck");
+/// key_value.insert("java", "junit");
+///
+/// key_value.should_contain("rust", &"clearcheck").should_not_contain("java", &"assert4j");
+/// ```
+pub trait OrderedKeyValueMembershipAssertion<K, V>
+    where K: Ord,
+{
+    fn should_contain<Q, S>(&self, key: &Q, value: &S) -> &Self
+        where
+            K: Borrow<Q>,
+            V: Borrow<S>,
+            Q: Debug + ?Sized + Ord,
+            S: Debug + ?Sized + Eq;
+
+    fn should_not_contain<Q, S>(&self, key: &Q, value: &S) -> &Self
+        where
+            K: Borrow<Q>,
+            V: Borrow<S>,
+            Q: Debug + ?Sized + Ord,
+            S: Debug + ?Sized + Eq;
+
+    fn should_contain_all<Q, S>(&self, entries: BTreeMap<&Q, &S>) -> &Self
+        where
+            K: Borrow<Q>,
+            V: Borrow<S>,
+            Q: Debug + ?Sized + Ord,
+            S: Debug + ?Sized + Eq;
+
+    fn should_not_contain_all<Q, S>(&self, entries: BTreeMap<&Q, &S>) -> &Self
+        where
+            K: Borrow<Q>,
+            V: Borrow<S>,
+            Q: Debug + ?Sized + Ord,
+            S: Debug + ?Sized + Eq;
+
+    fn should_contain_any<Q, S>(&self, entries: BTreeMap<&Q, &S>) -> &Self
+        where
+            K: Borrow<Q>,
+            V: Borrow<S>,
+            Q: Debug + ?Sized + Ord,
+            S: Debug + ?Sized + Eq;
+
+    fn should_not_contain_any<Q, S>(&self, entries: BTreeMap<&Q, &S>) -> &Self
+        where
+            K: Borrow<Q>,
+            V: Borrow<S>,
+            Q: Debug + ?Sized + Ord,
+            S: Debug + ?Sized + Eq;
+
+    fn should_contain_entry_satisfying<F>(&self, predicate: F) -> &Self
+        where
+            F: Fn(&K, &V) -> bool;
+
+    fn should_have_all_entries_satisfying<F>(&self, predicate: F) -> &Self
+        where
+            F: Fn(&K, &V) -> bool;
 }
 
 impl<K, V> NoMembershipAssertion for HashMap<K, V>
@@ -509,6 +734,14 @@ impl<K, V> KeyMembershipAssertion<K> for HashMap<K, V>
         map_keys(self).should_not(&contain_any_of_keys(keys));
         self
     }
+
+    fn should_contain_none_of_keys<Q>(&self, keys: Vec<&Q>) -> &Self
+        where
+            K: Borrow<Q>,
+            Q: Hash + Eq + Debug + ?Sized,
+    {
+        self.should_not_contain_any_of_keys(keys)
+    }
 }
 
 impl<K, V> ValueMembershipAssertion<V> for HashMap<K, V>
@@ -569,6 +802,22 @@ impl<K, V> ValueMembershipAssertion<V> for HashMap<K, V>
         map_values(self).should_not(&contain_any_of_values(values));
         self
     }
+
+    fn should_contain_value_satisfying<F>(&self, predicate: F) -> &Self
+        where
+            F: Fn(&V) -> bool,
+    {
+        self.should(&satisfy_for_any_value(predicate));
+        self
+    }
+
+    fn should_contain_no_value_satisfying<F>(&self, predicate: F) -> &Self
+        where
+            F: Fn(&V) -> bool,
+    {
+        self.should_not(&satisfy_for_any_value(predicate));
+        self
+    }
 }
 
 impl<K, V> KeyValueMembershipAssertion<K, V> for HashMap<K, V>
@@ -641,105 +890,856 @@ impl<K, V> KeyValueMembershipAssertion<K, V> for HashMap<K, V>
         map_key_value(self).should_not(&contain_any_of_key_values(entries));
         self
     }
-}
 
-fn map_keys<K, V, Q>(collection: &HashMap<K, V>) -> HashMap<&Q, &V>
-    where
-        K: Hash + Eq,
-        K: Borrow<Q>,
-        Q: Hash + Eq + ?Sized,
-{
-    collection
-        .iter()
-        .map(|key_value| (key_value.0.borrow(), key_value.1))
-        .collect::<HashMap<_, _>>()
+    fn should_contain_entry_satisfying<F>(&self, predicate: F) -> &Self
+        where
+            F: Fn(&K, &V) -> bool,
+    {
+        self.should(&satisfy_for_any_entry(predicate));
+        self
+    }
+
+    fn should_have_all_entries_satisfying<F>(&self, predicate: F) -> &Self
+        where
+            F: Fn(&K, &V) -> bool,
+    {
+        self.should(&satisfy_for_all_entries(predicate));
+        self
+    }
 }
 
-fn map_values<K, V, S>(collection: &HashMap<K, V>) -> HashMap<&K, &S>
+impl<K, V> NoMembershipAssertion for BTreeMap<K, V>
     where
-        K: Hash + Eq,
-        V: Borrow<S>,
-        S: Eq + ?Sized,
+        K: Ord,
 {
-    collection
-        .iter()
-        .map(|key_value| (key_value.0, key_value.1.borrow()))
-        .collect::<HashMap<_, _>>()
+    fn should_be_empty(&self) -> &Self {
+        self.should(&be_empty());
+        self
+    }
+
+    fn should_not_be_empty(&self) -> &Self {
+        self.should_not(&be_empty());
+        self
+    }
 }
 
-fn map_key_value<K, V, Q, S>(collection: &HashMap<K, V>) -> HashMap<&Q, &S>
+impl<K, V> OrderedKeyMembershipAssertion<K> for BTreeMap<K, V>
     where
-        K: Hash + Eq,
-        K: Borrow<Q>,
-        V: Borrow<S>,
-        Q: Hash + Eq + ?Sized,
-        S: Eq + ?Sized,
+        K: Ord + Debug,
 {
-    collection
-        .iter()
-        .map(|key_value| (key_value.0.borrow(), key_value.1.borrow()))
-        .collect::<HashMap<_, _>>()
-}
-
-#[cfg(test)]
-mod empty_tests {
-    use std::collections::HashMap;
+    fn should_contain_key<Q>(&self, key: &Q) -> &Self
+        where
+            K: Borrow<Q>,
+            Q: Ord + Debug + ?Sized,
+    {
+        map_ordered_keys(self).should(&contain_key(key));
+        self
+    }
 
-    use crate::assertions::map::membership::NoMembershipAssertion;
+    fn should_not_contain_key<Q>(&self, key: &Q) -> &Self
+        where
+            K: Borrow<Q>,
+            Q: Ord + Debug + ?Sized,
+    {
+        map_ordered_keys(self).should_not(&contain_key(key));
+        self
+    }
 
-    #[test]
-    fn should_be_empty() {
-        let key_value: HashMap<i32, i32> = HashMap::new();
-        key_value.should_be_empty();
+    fn should_contain_all_keys<Q>(&self, keys: Vec<&Q>) -> &Self
+        where
+            K: Borrow<Q>,
+            Q: Ord + Debug + ?Sized,
+    {
+        map_ordered_keys(self).should(&contain_all_keys(keys));
+        self
     }
 
-    #[test]
-    #[should_panic]
-    fn should_be_empty_but_was_not() {
-        let mut key_value = HashMap::new();
-        key_value.insert("rust", "assert");
-        key_value.should_be_empty();
+    fn should_not_contain_all_keys<Q>(&self, keys: Vec<&Q>) -> &Self
+        where
+            K: Borrow<Q>,
+            Q: Ord + Debug + ?Sized,
+    {
+        map_ordered_keys(self).should_not(&contain_all_keys(keys));
+        self
     }
 
-    #[test]
-    fn should_not_be_empty() {
-        let mut key_value = HashMap::new();
-        key_value.insert("rust", "assert");
-        key_value.should_not_be_empty();
+    fn should_contain_any_of_keys<Q>(&self, keys: Vec<&Q>) -> &Self
+        where
+            K: Borrow<Q>,
+            Q: Ord + Debug + ?Sized,
+    {
+        map_ordered_keys(self).should(&contain_any_of_keys(keys));
+        self
     }
 
-    #[test]
-    #[should_panic]
-    fn should_not_be_empty_but_was() {
-        let key_value: HashMap<i32, i32> = HashMap::new();
-        key_value.should_not_be_empty();
+    fn should_not_contain_any_of_keys<Q>(&self, keys: Vec<&Q>) -> &Self
+        where
+            K: Borrow<Q>,
+            Q: Ord + Debug + ?Sized,
+    {
+        map_ordered_keys(self).should_not(&contain_any_of_keys(keys));
+        self
     }
-}
 
-#[cfg(test)]
+    fn should_contain_none_of_keys<Q>(&self, keys: Vec<&Q>) -> &Self
+        where
+            K: Borrow<Q>,
+            Q: Ord + Debug + ?Sized,
+    {
+        self.should_not_contain_any_of_keys(keys)
+    }
+}
+
+impl<K, V> ValueMembershipAssertion<V> for BTreeMap<K, V>
+    where
+        K: Ord + Debug,
+        V: Eq + Debug,
+{
+    fn should_contain_value<S>(&self, value: &S) -> &Self
+        where
+            V: Eq + Borrow<S>,
+            S: Debug + ?Sized + Eq,
+    {
+        map_ordered_values(self).should(&contain_value(value));
+        self
+    }
+
+    fn should_not_contain_value<S>(&self, value: &S) -> &Self
+        where
+            V: Eq + Borrow<S>,
+            S: Debug + ?Sized + Eq,
+    {
+        map_ordered_values(self).should_not(&contain_value(value));
+        self
+    }
+
+    fn should_contain_all_values<S>(&self, values: Vec<&S>) -> &Self
+        where
+            V: Eq + Borrow<S>,
+            S: Debug + ?Sized + Eq,
+    {
+        map_ordered_values(self).should(&contain_all_values(values));
+        self
+    }
+
+    fn should_not_contain_all_values<S>(&self, values: Vec<&S>) -> &Self
+        where
+            V: Eq + Borrow<S>,
+            S: Debug + ?Sized + Eq,
+    {
+        map_ordered_values(self).should_not(&contain_all_values(values));
+        self
+    }
+
+    fn should_contain_any_of_values<S>(&self, values: Vec<&S>) -> &Self
+        where
+            V: Eq + Borrow<S>,
+            S: Debug + ?Sized + Eq,
+    {
+        map_ordered_values(self).should(&contain_any_of_values(values));
+        self
+    }
+
+    fn should_not_contain_any_of_values<S>(&self, values: Vec<&S>) -> &Self
+        where
+            V: Eq + Borrow<S>,
+            S: Debug + ?Sized + Eq,
+    {
+        map_ordered_values(self).should_not(&contain_any_of_values(values));
+        self
+    }
+
+    fn should_contain_value_satisfying<F>(&self, predicate: F) -> &Self
+        where
+            F: Fn(&V) -> bool,
+    {
+        self.should(&satisfy_for_any_value(predicate));
+        self
+    }
+
+    fn should_contain_no_value_satisfying<F>(&self, predicate: F) -> &Self
+        where
+            F: Fn(&V) -> bool,
+    {
+        self.should_not(&satisfy_for_any_value(predicate));
+        self
+    }
+}
+
+impl<K, V> OrderedKeyValueMembershipAssertion<K, V> for BTreeMap<K, V>
+    where
+        K: Ord + Debug,
+        V: Debug,
+{
+    fn should_contain<Q, S>(&self, key: &Q, value: &S) -> &Self
+        where
+            K: Borrow<Q>,
+            V: Borrow<S>,
+            Q: Debug + ?Sized + Ord,
+            S: Debug + ?Sized + Eq,
+    {
+        map_ordered_key_value(self).should(&contain_ordered_key_value(key, value));
+        self
+    }
+
+    fn should_not_contain<Q, S>(&self, key: &Q, value: &S) -> &Self
+        where
+            K: Borrow<Q>,
+            V: Borrow<S>,
+            Q: Debug + ?Sized + Ord,
+            S: Debug + ?Sized + Eq,
+    {
+        map_ordered_key_value(self).should_not(&contain_ordered_key_value(key, value));
+        self
+    }
+
+    fn should_contain_all<Q, S>(&self, entries: BTreeMap<&Q, &S>) -> &Self
+        where
+            K: Borrow<Q>,
+            V: Borrow<S>,
+            Q: Debug + ?Sized + Ord,
+            S: Debug + ?Sized + Eq,
+    {
+        map_ordered_key_value(self).should(&contain_all_ordered_key_values(entries));
+        self
+    }
+
+    fn should_not_contain_all<Q, S>(&self, entries: BTreeMap<&Q, &S>) -> &Self
+        where
+            K: Borrow<Q>,
+            V: Borrow<S>,
+            Q: Debug + ?Sized + Ord,
+            S: Debug + ?Sized + Eq,
+    {
+        map_ordered_key_value(self).should_not(&contain_all_ordered_key_values(entries));
+        self
+    }
+
+    fn should_contain_any<Q, S>(&self, entries: BTreeMap<&Q, &S>) -> &Self
+        where
+            K: Borrow<Q>,
+            V: Borrow<S>,
+            Q: Debug + ?Sized + Ord,
+            S: Debug + ?Sized + Eq,
+    {
+        map_ordered_key_value(self).should(&contain_any_of_ordered_key_values(entries));
+        self
+    }
+
+    fn should_not_contain_any<Q, S>(&self, entries: BTreeMap<&Q, &S>) -> &Self
+        where
+            K: Borrow<Q>,
+            V: Borrow<S>,
+            Q: Debug + ?Sized + Ord,
+            S: Debug + ?Sized + Eq,
+    {
+        map_ordered_key_value(self).should_not(&contain_any_of_ordered_key_values(entries));
+        self
+    }
+
+    fn should_contain_entry_satisfying<F>(&self, predicate: F) -> &Self
+        where
+            F: Fn(&K, &V) -> bool,
+    {
+        self.should(&satisfy_for_any_entry(predicate));
+        self
+    }
+
+    fn should_have_all_entries_satisfying<F>(&self, predicate: F) -> &Self
+        where
+            F: Fn(&K, &V) -> bool,
+    {
+        self.should(&satisfy_for_all_entries(predicate));
+        self
+    }
+}
+
+fn map_keys<K, V, Q>(collection: &HashMap<K, V>) -> HashMap<&Q, &V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+{
+    collection
+        .iter()
+        .map(|key_value| (key_value.0.borrow(), key_value.1))
+        .collect::<HashMap<_, _>>()
+}
+
+fn map_ordered_keys<K, V, Q>(collection: &BTreeMap<K, V>) -> BTreeMap<&Q, &V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+{
+    collection
+        .iter()
+        .map(|key_value| (key_value.0.borrow(), key_value.1))
+        .collect::<BTreeMap<_, _>>()
+}
+
+fn map_values<K, V, S>(collection: &HashMap<K, V>) -> HashMap<&K, &S>
+    where
+        K: Hash + Eq,
+        V: Borrow<S>,
+        S: Eq + ?Sized,
+{
+    collection
+        .iter()
+        .map(|key_value| (key_value.0, key_value.1.borrow()))
+        .collect::<HashMap<_, _>>()
+}
+
+fn map_ordered_values<K, V, S>(collection: &BTreeMap<K, V>) -> BTreeMap<&K, &S>
+    where
+        K: Ord,
+        V: Borrow<S>,
+        S: Eq + ?Sized,
+{
+    collection
+        .iter()
+        .map(|key_value| (key_value.0, key_value.1.borrow()))
+        .collect::<BTreeMap<_, _>>()
+}
+
+fn map_key_value<K, V, Q, S>(collection: &HashMap<K, V>) -> HashMap<&Q, &S>
+    where
+        K: Borrow<Q>,
+        V: Borrow<S>,
+        Q: Hash + Eq + ?Sized,
+        S: Eq + ?Sized,
+{
+    collection
+        .iter()
+        .map(|key_value| (key_value.0.borrow(), key_value.1.borrow()))
+        .collect::<HashMap<_, _>>()
+}
+
+fn map_ordered_key_value<K, V, Q, S>(collection: &BTreeMap<K, V>) -> BTreeMap<&Q, &S>
+    where
+        K: Borrow<Q>,
+        V: Borrow<S>,
+        Q: Ord + ?Sized,
+        S: Eq + ?Sized,
+{
+    collection
+        .iter()
+        .map(|key_value| (key_value.0.borrow(), key_value.1.borrow()))
+        .collect::<BTreeMap<_, _>>()
+}
+
+#[cfg(test)]
+mod empty_tests {
+    use std::collections::HashMap;
+
+    use crate::assertions::map::membership::NoMembershipAssertion;
+
+    #[test]
+    fn should_be_empty() {
+        let key_value: HashMap<i32, i32> = HashMap::new();
+        key_value.should_be_empty();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_empty_but_was_not() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_be_empty();
+    }
+
+    #[test]
+    fn should_not_be_empty() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_not_be_empty();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_empty_but_was() {
+        let key_value: HashMap<i32, i32> = HashMap::new();
+        key_value.should_not_be_empty();
+    }
+}
+
+#[cfg(test)]
 mod key_contains_tests {
     use std::collections::HashMap;
 
-    use crate::assertions::map::membership::KeyMembershipAssertion;
+    use crate::assertions::map::membership::KeyMembershipAssertion;
+
+    #[test]
+    fn should_contain_key() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_contain_key("rust");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_key_but_it_did_not() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_contain_key("java");
+    }
+
+    #[test]
+    fn should_not_contain_key() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_not_contain_key("junit");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_contain_key_but_it_contained() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_not_contain_key("rust");
+    }
+
+    #[test]
+    fn should_contain_all_keys() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.insert("java", "junit");
+        key_value.should_contain_all_keys(vec!["rust", "java"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_all_keys_but_it_did_not() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_contain_all_keys(vec!["rust", "java"]);
+    }
+
+    #[test]
+    fn should_not_contain_all_keys() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_not_contain_all_keys(vec!["rust", "java"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_contain_all_keys_but_it_did() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.insert("java", "junit");
+        key_value.should_not_contain_all_keys(vec!["rust", "java"]);
+    }
+
+    #[test]
+    fn should_contain_any_of_keys() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.insert("java", "junit");
+        key_value.should_contain_any_of_keys(vec!["rust", "scala"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_any_of_keys_but_it_did_not() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_contain_any_of_keys(vec!["golang", "scala"]);
+    }
+
+    #[test]
+    fn should_not_contain_any_of_keys() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_not_contain_any_of_keys(vec!["scala", "golang"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_contain_any_of_keys_but_it_did() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.insert("java", "junit");
+        key_value.should_not_contain_any_of_keys(vec!["rust", "scala"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_any_of_keys_but_the_keys_were_empty() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_contain_any_of_keys(Vec::<&str>::new());
+    }
+
+    #[test]
+    fn should_contain_none_of_keys() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_contain_none_of_keys(vec!["scala", "golang"]);
+    }
+
+    #[test]
+    fn should_contain_none_of_keys_given_the_keys_were_empty() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_contain_none_of_keys(Vec::<&str>::new());
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_none_of_keys_but_it_did() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.insert("java", "junit");
+        key_value.should_contain_none_of_keys(vec!["rust", "scala"]);
+    }
+}
+
+#[cfg(test)]
+mod value_contains_tests {
+    use std::collections::HashMap;
+
+    use crate::assertions::map::membership::ValueMembershipAssertion;
+
+    #[test]
+    fn should_contain_value() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_contain_value("assert");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_value_but_it_did_not() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_contain_value("java");
+    }
+
+    #[test]
+    fn should_not_contain_value() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_not_contain_value("catch");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_contain_value_but_it_contained() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_not_contain_value("assert");
+    }
+
+    #[test]
+    fn should_contain_all_values() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.insert("java", "junit");
+        key_value.should_contain_all_values(vec!["assert", "junit"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_all_values_but_it_did_not() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_contain_all_values(vec!["java", "xunit"]);
+    }
+
+    #[test]
+    fn should_not_contain_all_values() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.insert("java", "junit");
+        key_value.should_not_contain_all_values(vec!["catch", "junit"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_contain_all_values_but_it_contained() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_not_contain_all_values(vec!["assert", "assert"]);
+    }
+
+    #[test]
+    fn should_contain_any_of_values() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.insert("java", "junit");
+        key_value.should_contain_any_of_values(vec!["assert", "xunit"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_any_of_values_but_it_did_not() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_contain_any_of_values(vec!["catch", "xunit"]);
+    }
+
+    #[test]
+    fn should_not_contain_any_of_values() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.insert("java", "junit");
+        key_value.should_not_contain_any_of_values(vec!["catch", "xunit"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_contain_any_of_values_but_it_contained() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_not_contain_any_of_values(vec!["assert", "junit"]);
+    }
+
+    #[test]
+    fn should_contain_value_satisfying() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "clearcheck");
+        key_value.insert("java", "junit");
+        key_value.should_contain_value_satisfying(|value| value.starts_with("clear"));
+    }
+
+    #[test]
+    #[should_panic(expected = "should contain a value satisfying the given predicate but no value did")]
+    fn should_contain_value_satisfying_but_it_did_not() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "clearcheck");
+        key_value.insert("java", "junit");
+        key_value.should_contain_value_satisfying(|value| value.is_empty());
+    }
+
+    #[test]
+    fn should_contain_no_value_satisfying() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "clearcheck");
+        key_value.insert("java", "junit");
+        key_value.should_contain_no_value_satisfying(|value| value.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "but the entry with key \"java\" and value \"junit\" did")]
+    fn should_contain_no_value_satisfying_but_it_did() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "clearcheck");
+        key_value.insert("java", "junit");
+        key_value.should_contain_no_value_satisfying(|value| value.starts_with("jun"));
+    }
+}
+
+#[cfg(test)]
+mod key_value_contains_tests {
+    use std::collections::HashMap;
+
+    use crate::assertions::map::membership::KeyValueMembershipAssertion;
+
+    #[test]
+    fn should_contain_key_value() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_contain("rust", "assert");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_key_value_but_it_did_not_1() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_contain("rust", "catch");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_key_value_but_it_did_not_2() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_contain("java", "catch");
+    }
+
+    #[test]
+    fn should_not_contain_key_value() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_not_contain("rust", "catch");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_contain_key_value_but_it_did() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_not_contain("rust", "assert");
+    }
+
+    #[test]
+    fn should_contain_all_key_values() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.insert("java", "junit");
+
+        let mut to_contain = HashMap::new();
+        to_contain.insert("rust", "assert");
+        to_contain.insert("java", "junit");
+
+        key_value.should_contain_all(to_contain);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_all_key_values_but_it_did_not() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.insert("java", "junit");
+
+        let mut to_contain = HashMap::new();
+        to_contain.insert("rust", "assert");
+        to_contain.insert("java", "xunit");
+
+        key_value.should_contain_all(to_contain);
+    }
 
     #[test]
-    fn should_contain_key() {
+    fn should_not_contain_all_key_values() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.insert("java", "junit");
+
+        let mut to_contain = HashMap::new();
+        to_contain.insert("rust", "assert");
+        to_contain.insert("java", "xunit");
+
+        key_value.should_not_contain_all(to_contain);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_contain_all_key_values_but_it_did() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.insert("java", "junit");
+
+        let mut to_contain = HashMap::new();
+        to_contain.insert("rust", "assert");
+        to_contain.insert("java", "junit");
+
+        key_value.should_not_contain_all(to_contain);
+    }
+
+    #[test]
+    fn should_contain_any_key_values() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.insert("java", "junit");
+
+        let mut to_contain = HashMap::new();
+        to_contain.insert("rust", "assert");
+        to_contain.insert("golang", "gotest");
+
+        key_value.should_contain_any(to_contain);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_any_key_values_but_it_did_not() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.insert("java", "junit");
+
+        let mut to_contain = HashMap::new();
+        to_contain.insert("rust", "clearcheck");
+        to_contain.insert("java", "xunit");
+
+        key_value.should_contain_any(to_contain);
+    }
+
+    #[test]
+    fn should_not_contain_any_key_values() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "assert");
+        key_value.insert("java", "junit");
+
+        let mut to_contain = HashMap::new();
+        to_contain.insert("rust", "clearcheck");
+        to_contain.insert("java", "xunit");
+
+        key_value.should_not_contain_any(to_contain);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_contain_any_key_values_but_it_did() {
         let mut key_value = HashMap::new();
         key_value.insert("rust", "assert");
+        key_value.insert("java", "junit");
+
+        let mut to_contain = HashMap::new();
+        to_contain.insert("rust", "assert");
+        to_contain.insert("java", "junit");
+
+        key_value.should_not_contain_any(to_contain);
+    }
+}
+
+#[cfg(test)]
+mod btree_empty_tests {
+    use std::collections::BTreeMap;
+
+    use crate::assertions::map::membership::NoMembershipAssertion;
+
+    #[test]
+    fn should_be_empty() {
+        let key_value: BTreeMap<i32, i32> = BTreeMap::new();
+        key_value.should_be_empty();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_empty_but_was_not() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_be_empty();
+    }
+
+    #[test]
+    fn should_not_be_empty() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_not_be_empty();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_empty_but_was() {
+        let key_value: BTreeMap<i32, i32> = BTreeMap::new();
+        key_value.should_not_be_empty();
+    }
+}
+
+#[cfg(test)]
+mod btree_key_contains_tests {
+    use std::collections::BTreeMap;
+
+    use crate::assertions::map::membership::OrderedKeyMembershipAssertion;
+
+    #[test]
+    fn should_contain_key() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert("rust", "assert");
         key_value.should_contain_key("rust");
     }
 
     #[test]
     #[should_panic]
     fn should_contain_key_but_it_did_not() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.should_contain_key("java");
     }
 
     #[test]
     fn should_not_contain_key() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.should_not_contain_key("junit");
     }
@@ -747,14 +1747,14 @@ mod key_contains_tests {
     #[test]
     #[should_panic]
     fn should_not_contain_key_but_it_contained() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.should_not_contain_key("rust");
     }
 
     #[test]
     fn should_contain_all_keys() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.insert("java", "junit");
         key_value.should_contain_all_keys(vec!["rust", "java"]);
@@ -763,14 +1763,14 @@ mod key_contains_tests {
     #[test]
     #[should_panic]
     fn should_contain_all_keys_but_it_did_not() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.should_contain_all_keys(vec!["rust", "java"]);
     }
 
     #[test]
     fn should_not_contain_all_keys() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.should_not_contain_all_keys(vec!["rust", "java"]);
     }
@@ -778,7 +1778,7 @@ mod key_contains_tests {
     #[test]
     #[should_panic]
     fn should_not_contain_all_keys_but_it_did() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.insert("java", "junit");
         key_value.should_not_contain_all_keys(vec!["rust", "java"]);
@@ -786,7 +1786,7 @@ mod key_contains_tests {
 
     #[test]
     fn should_contain_any_of_keys() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.insert("java", "junit");
         key_value.should_contain_any_of_keys(vec!["rust", "scala"]);
@@ -795,14 +1795,14 @@ mod key_contains_tests {
     #[test]
     #[should_panic]
     fn should_contain_any_of_keys_but_it_did_not() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.should_contain_any_of_keys(vec!["golang", "scala"]);
     }
 
     #[test]
     fn should_not_contain_any_of_keys() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.should_not_contain_any_of_keys(vec!["scala", "golang"]);
     }
@@ -810,22 +1810,53 @@ mod key_contains_tests {
     #[test]
     #[should_panic]
     fn should_not_contain_any_of_keys_but_it_did() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.insert("java", "junit");
         key_value.should_not_contain_any_of_keys(vec!["rust", "scala"]);
     }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_any_of_keys_but_the_keys_were_empty() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_contain_any_of_keys(Vec::<&str>::new());
+    }
+
+    #[test]
+    fn should_contain_none_of_keys() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_contain_none_of_keys(vec!["scala", "golang"]);
+    }
+
+    #[test]
+    fn should_contain_none_of_keys_given_the_keys_were_empty() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_contain_none_of_keys(Vec::<&str>::new());
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_none_of_keys_but_it_did() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert("rust", "assert");
+        key_value.insert("java", "junit");
+        key_value.should_contain_none_of_keys(vec!["rust", "scala"]);
+    }
 }
 
 #[cfg(test)]
-mod value_contains_tests {
-    use std::collections::HashMap;
+mod btree_value_contains_tests {
+    use std::collections::BTreeMap;
 
     use crate::assertions::map::membership::ValueMembershipAssertion;
 
     #[test]
     fn should_contain_value() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.should_contain_value("assert");
     }
@@ -833,14 +1864,14 @@ mod value_contains_tests {
     #[test]
     #[should_panic]
     fn should_contain_value_but_it_did_not() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.should_contain_value("java");
     }
 
     #[test]
     fn should_not_contain_value() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.should_not_contain_value("catch");
     }
@@ -848,14 +1879,14 @@ mod value_contains_tests {
     #[test]
     #[should_panic]
     fn should_not_contain_value_but_it_contained() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.should_not_contain_value("assert");
     }
 
     #[test]
     fn should_contain_all_values() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.insert("java", "junit");
         key_value.should_contain_all_values(vec!["assert", "junit"]);
@@ -864,14 +1895,14 @@ mod value_contains_tests {
     #[test]
     #[should_panic]
     fn should_contain_all_values_but_it_did_not() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.should_contain_all_values(vec!["java", "xunit"]);
     }
 
     #[test]
     fn should_not_contain_all_values() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.insert("java", "junit");
         key_value.should_not_contain_all_values(vec!["catch", "junit"]);
@@ -880,14 +1911,14 @@ mod value_contains_tests {
     #[test]
     #[should_panic]
     fn should_not_contain_all_values_but_it_contained() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.should_not_contain_all_values(vec!["assert", "assert"]);
     }
 
     #[test]
     fn should_contain_any_of_values() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.insert("java", "junit");
         key_value.should_contain_any_of_values(vec!["assert", "xunit"]);
@@ -896,14 +1927,14 @@ mod value_contains_tests {
     #[test]
     #[should_panic]
     fn should_contain_any_of_values_but_it_did_not() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.should_contain_any_of_values(vec!["catch", "xunit"]);
     }
 
     #[test]
     fn should_not_contain_any_of_values() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.insert("java", "junit");
         key_value.should_not_contain_any_of_values(vec!["catch", "xunit"]);
@@ -912,21 +1943,55 @@ mod value_contains_tests {
     #[test]
     #[should_panic]
     fn should_not_contain_any_of_values_but_it_contained() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.should_not_contain_any_of_values(vec!["assert", "junit"]);
     }
+
+    #[test]
+    fn should_contain_value_satisfying() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert("rust", "clearcheck");
+        key_value.insert("java", "junit");
+        key_value.should_contain_value_satisfying(|value| value.starts_with("clear"));
+    }
+
+    #[test]
+    #[should_panic(expected = "should contain a value satisfying the given predicate but no value did")]
+    fn should_contain_value_satisfying_but_it_did_not() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert("rust", "clearcheck");
+        key_value.insert("java", "junit");
+        key_value.should_contain_value_satisfying(|value| value.is_empty());
+    }
+
+    #[test]
+    fn should_contain_no_value_satisfying() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert("rust", "clearcheck");
+        key_value.insert("java", "junit");
+        key_value.should_contain_no_value_satisfying(|value| value.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "but the entry with key \"java\" and value \"junit\" did")]
+    fn should_contain_no_value_satisfying_but_it_did() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert("rust", "clearcheck");
+        key_value.insert("java", "junit");
+        key_value.should_contain_no_value_satisfying(|value| value.starts_with("jun"));
+    }
 }
 
 #[cfg(test)]
-mod key_value_contains_tests {
-    use std::collections::HashMap;
+mod btree_key_value_contains_tests {
+    use std::collections::BTreeMap;
 
-    use crate::assertions::map::membership::KeyValueMembershipAssertion;
+    use crate::assertions::map::membership::OrderedKeyValueMembershipAssertion;
 
     #[test]
     fn should_contain_key_value() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.should_contain("rust", "assert");
     }
@@ -934,7 +1999,7 @@ mod key_value_contains_tests {
     #[test]
     #[should_panic]
     fn should_contain_key_value_but_it_did_not_1() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.should_contain("rust", "catch");
     }
@@ -942,14 +2007,14 @@ mod key_value_contains_tests {
     #[test]
     #[should_panic]
     fn should_contain_key_value_but_it_did_not_2() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.should_contain("java", "catch");
     }
 
     #[test]
     fn should_not_contain_key_value() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.should_not_contain("rust", "catch");
     }
@@ -957,18 +2022,18 @@ mod key_value_contains_tests {
     #[test]
     #[should_panic]
     fn should_not_contain_key_value_but_it_did() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.should_not_contain("rust", "assert");
     }
 
     #[test]
     fn should_contain_all_key_values() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.insert("java", "junit");
 
-        let mut to_contain = HashMap::new();
+        let mut to_contain = BTreeMap::new();
         to_contain.insert("rust", "assert");
         to_contain.insert("java", "junit");
 
@@ -978,11 +2043,11 @@ mod key_value_contains_tests {
     #[test]
     #[should_panic]
     fn should_contain_all_key_values_but_it_did_not() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.insert("java", "junit");
 
-        let mut to_contain = HashMap::new();
+        let mut to_contain = BTreeMap::new();
         to_contain.insert("rust", "assert");
         to_contain.insert("java", "xunit");
 
@@ -991,11 +2056,11 @@ mod key_value_contains_tests {
 
     #[test]
     fn should_not_contain_all_key_values() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.insert("java", "junit");
 
-        let mut to_contain = HashMap::new();
+        let mut to_contain = BTreeMap::new();
         to_contain.insert("rust", "assert");
         to_contain.insert("java", "xunit");
 
@@ -1005,11 +2070,11 @@ mod key_value_contains_tests {
     #[test]
     #[should_panic]
     fn should_not_contain_all_key_values_but_it_did() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.insert("java", "junit");
 
-        let mut to_contain = HashMap::new();
+        let mut to_contain = BTreeMap::new();
         to_contain.insert("rust", "assert");
         to_contain.insert("java", "junit");
 
@@ -1018,11 +2083,11 @@ mod key_value_contains_tests {
 
     #[test]
     fn should_contain_any_key_values() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.insert("java", "junit");
 
-        let mut to_contain = HashMap::new();
+        let mut to_contain = BTreeMap::new();
         to_contain.insert("rust", "assert");
         to_contain.insert("golang", "gotest");
 
@@ -1032,11 +2097,11 @@ mod key_value_contains_tests {
     #[test]
     #[should_panic]
     fn should_contain_any_key_values_but_it_did_not() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.insert("java", "junit");
 
-        let mut to_contain = HashMap::new();
+        let mut to_contain = BTreeMap::new();
         to_contain.insert("rust", "clearcheck");
         to_contain.insert("java", "xunit");
 
@@ -1045,11 +2110,11 @@ mod key_value_contains_tests {
 
     #[test]
     fn should_not_contain_any_key_values() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.insert("java", "junit");
 
-        let mut to_contain = HashMap::new();
+        let mut to_contain = BTreeMap::new();
         to_contain.insert("rust", "clearcheck");
         to_contain.insert("java", "xunit");
 
@@ -1059,14 +2124,141 @@ mod key_value_contains_tests {
     #[test]
     #[should_panic]
     fn should_not_contain_any_key_values_but_it_did() {
-        let mut key_value = HashMap::new();
+        let mut key_value = BTreeMap::new();
         key_value.insert("rust", "assert");
         key_value.insert("java", "junit");
 
-        let mut to_contain = HashMap::new();
+        let mut to_contain = BTreeMap::new();
         to_contain.insert("rust", "assert");
         to_contain.insert("java", "junit");
 
         key_value.should_not_contain_any(to_contain);
     }
 }
+
+#[cfg(test)]
+mod entry_predicate_tests {
+    use std::collections::{BTreeMap, HashMap};
+
+    use crate::assertions::map::membership::{KeyValueMembershipAssertion, OrderedKeyValueMembershipAssertion};
+
+    #[test]
+    fn should_contain_entry_satisfying() {
+        let mut scores = HashMap::new();
+        scores.insert("rust", 10);
+        scores.insert("java", 8);
+
+        scores.should_contain_entry_satisfying(|language, score| language.starts_with('r') && *score > 9);
+    }
+
+    #[test]
+    #[should_panic(expected = "should contain an entry satisfying the given predicate but no entry did")]
+    fn should_contain_entry_satisfying_but_it_did_not() {
+        let mut scores = HashMap::new();
+        scores.insert("rust", 10);
+        scores.insert("java", 8);
+
+        scores.should_contain_entry_satisfying(|language, score| language.starts_with('j') && *score > 9);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_entry_satisfying_but_it_was_empty() {
+        let scores: HashMap<&str, i32> = HashMap::new();
+        scores.should_contain_entry_satisfying(|_, _| true);
+    }
+
+    #[test]
+    fn should_have_all_entries_satisfying() {
+        let mut scores = HashMap::new();
+        scores.insert("rust", 10);
+        scores.insert("java", 8);
+
+        scores.should_have_all_entries_satisfying(|language, score| language.len() == 4 && *score > 5);
+    }
+
+    #[test]
+    fn should_have_all_entries_satisfying_given_an_empty_map() {
+        let scores: HashMap<&str, i32> = HashMap::new();
+        scores.should_have_all_entries_satisfying(|_, _| false);
+    }
+
+    #[test]
+    #[should_panic(expected = "should satisfy the given predicate but the entry with key \"java\" and value 2 did not")]
+    fn should_have_all_entries_satisfying_but_one_did_not() {
+        let mut scores = HashMap::new();
+        scores.insert("rust", 10);
+        scores.insert("java", 2);
+
+        scores.should_have_all_entries_satisfying(|language, score| language.len() == 4 && *score > 5);
+    }
+
+    #[test]
+    fn should_contain_entry_satisfying_in_btree_map() {
+        let mut scores = BTreeMap::new();
+        scores.insert("rust", 10);
+        scores.insert("java", 8);
+
+        scores
+            .should_contain_entry_satisfying(|language, score| *language == "java" && *score == 8)
+            .should_have_all_entries_satisfying(|_, score| *score >= 8);
+    }
+
+    #[test]
+    #[should_panic(expected = "All the entries in the map {\"go\": 1, \"java\": 2, \"rust\": 10} should satisfy the given predicate but the entry with key \"go\" and value 1 did not")]
+    fn should_have_all_entries_satisfying_in_btree_map_but_reported_the_first_offending_entry() {
+        let mut scores = BTreeMap::new();
+        scores.insert("rust", 10);
+        scores.insert("java", 2);
+        scores.insert("go", 1);
+
+        scores.should_have_all_entries_satisfying(|_, score| *score > 5);
+    }
+}
+
+#[cfg(test)]
+mod ordered_key_tests {
+    use std::collections::BTreeMap;
+
+    use crate::assertions::map::membership::{OrderedKeyMembershipAssertion, OrderedKeyValueMembershipAssertion};
+
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct Id(u32);
+
+    #[test]
+    fn should_contain_key_which_is_not_hashable() {
+        let mut users = BTreeMap::new();
+        users.insert(Id(1), "rust");
+        users.insert(Id(2), "java");
+
+        users.should_contain_key(&Id(1)).should_contain_all_keys(vec![&Id(1), &Id(2)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Keys [Id(1)] in the map should contain Id(2)")]
+    fn should_contain_key_which_is_not_hashable_but_it_did_not() {
+        let mut users = BTreeMap::new();
+        users.insert(Id(1), "rust");
+        users.should_contain_key(&Id(2));
+    }
+
+    #[test]
+    fn should_contain_key_value_which_is_not_hashable() {
+        let mut users = BTreeMap::new();
+        users.insert(Id(1), "rust");
+        users.insert(Id(2), "java");
+
+        let mut entries = BTreeMap::new();
+        entries.insert(&Id(2), &"java");
+
+        users.should_contain(&Id(1), &"rust").should_contain_all(entries);
+    }
+
+    #[test]
+    #[should_panic(expected = "Map {Id(1): \"rust\"} should contain key Id(1) and value \"java\"")]
+    fn should_contain_key_value_which_is_not_hashable_but_it_did_not() {
+        let mut users = BTreeMap::new();
+        users.insert(Id(1), "rust");
+        users.should_contain(&Id(1), &"java");
+    }
+}
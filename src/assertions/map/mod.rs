@@ -1,2 +1,5 @@
+pub mod equal;
 pub mod membership;
+pub mod order;
+pub mod predicate;
 pub mod size;
@@ -0,0 +1,160 @@
+use std::borrow::Borrow;
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+
+use crate::matchers::{Should, ShouldNot};
+use crate::matchers::map::order::have_keys_in_order;
+
+/// KeyOrderAssertion enables assertions about the iteration order of keys in the [`BTreeMap`].
+pub trait KeyOrderAssertion<K>
+    where K: Ord
+{
+    /// - Asserts that the keys of the BTreeMap iterate in exactly the given sequence.
+    /// - Supports flexible key comparison through the `Borrow<Q>` trait bound, allowing for various key types and reference types.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use clearcheck::assertions::map::order::KeyOrderAssertion;
+    ///
+    /// let mut key_value = BTreeMap::new();
+    /// key_value.insert(String::from("rust"), "clearcheck");
+    /// key_value.insert(String::from("java"), "junit");
+    ///
+    /// key_value.should_have_keys_in_order(vec!["java", "rust"]);
+    /// ```
+    fn should_have_keys_in_order<Q>(&self, expected: Vec<&Q>) -> &Self
+        where
+            K: Borrow<Q>,
+            Q: Ord + Debug + ?Sized;
+
+    /// - Asserts that the keys of the BTreeMap do not iterate in the given sequence.
+    /// - Supports flexible key comparison through the `Borrow<Q>` trait bound, allowing for various key types and reference types.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use clearcheck::assertions::map::order::KeyOrderAssertion;
+    ///
+    /// let mut key_value = BTreeMap::new();
+    /// key_value.insert(String::from("rust"), "clearcheck");
+    /// key_value.insert(String::from("java"), "junit");
+    ///
+    /// key_value.should_not_have_keys_in_order(vec!["rust", "java"]);
+    /// ```
+    fn should_not_have_keys_in_order<Q>(&self, expected: Vec<&Q>) -> &Self
+        where
+            K: Borrow<Q>,
+            Q: Ord + Debug + ?Sized;
+}
+
+impl<K, V> KeyOrderAssertion<K> for BTreeMap<K, V>
+    where
+        K: Ord + Debug,
+{
+    fn should_have_keys_in_order<Q>(&self, expected: Vec<&Q>) -> &Self
+        where
+            K: Borrow<Q>,
+            Q: Ord + Debug + ?Sized,
+    {
+        map_keys(self).should(&have_keys_in_order(expected));
+        self
+    }
+
+    fn should_not_have_keys_in_order<Q>(&self, expected: Vec<&Q>) -> &Self
+        where
+            K: Borrow<Q>,
+            Q: Ord + Debug + ?Sized,
+    {
+        map_keys(self).should_not(&have_keys_in_order(expected));
+        self
+    }
+}
+
+fn map_keys<K, V, Q>(collection: &BTreeMap<K, V>) -> BTreeMap<&Q, &V>
+    where
+        K: Ord + Borrow<Q>,
+        Q: Ord + ?Sized,
+{
+    collection
+        .iter()
+        .map(|key_value| (key_value.0.borrow(), key_value.1))
+        .collect::<BTreeMap<_, _>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::assertions::map::order::KeyOrderAssertion;
+
+    #[test]
+    fn should_have_keys_in_ascending_order() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert("rust", "clearcheck");
+        key_value.insert("go", "testify");
+        key_value.insert("java", "junit");
+
+        key_value.should_have_keys_in_order(vec![&"go", &"java", &"rust"]);
+    }
+
+    #[test]
+    fn should_have_numeric_keys_in_ascending_order() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert(30, "thirty");
+        key_value.insert(10, "ten");
+        key_value.insert(20, "twenty");
+
+        key_value.should_have_keys_in_order(vec![&10, &20, &30]);
+    }
+
+    #[test]
+    fn should_have_borrowed_keys_in_ascending_order() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert(String::from("rust"), "clearcheck");
+        key_value.insert(String::from("java"), "junit");
+
+        key_value.should_have_keys_in_order(vec!["java", "rust"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Keys in the map should be in the order [\"rust\", \"java\"] but were in the order [\"java\", \"rust\"]")]
+    fn should_have_keys_in_order_but_they_were_not() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert(String::from("rust"), "clearcheck");
+        key_value.insert(String::from("java"), "junit");
+
+        key_value.should_have_keys_in_order(vec!["rust", "java"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_keys_in_order_but_a_key_was_missing() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert(String::from("rust"), "clearcheck");
+        key_value.insert(String::from("java"), "junit");
+
+        key_value.should_have_keys_in_order(vec!["java"]);
+    }
+
+    #[test]
+    fn should_not_have_keys_in_order() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert(String::from("rust"), "clearcheck");
+        key_value.insert(String::from("java"), "junit");
+
+        key_value.should_not_have_keys_in_order(vec!["rust", "java"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_have_keys_in_order_but_they_were() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert(String::from("rust"), "clearcheck");
+        key_value.insert(String::from("java"), "junit");
+
+        key_value.should_not_have_keys_in_order(vec!["java", "rust"]);
+    }
+}
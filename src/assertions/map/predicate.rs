@@ -0,0 +1,110 @@
+//! PredicateAssertion enables assertions about whether all the keys or all the values of a HashMap satisfy a predicate.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::matchers::map::predicate::{satisfy_for_all_keys, satisfy_for_all_values};
+use crate::matchers::Should;
+
+/// PredicateAssertion enables assertions about whether all the keys or all the values of the [`HashMap`] satisfy the given predicate.
+pub trait PredicateAssertion<K, V> {
+    /// - Asserts that all the keys in the HashMap satisfy the given predicate.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting an entry whose key does not satisfy the predicate.
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use clearcheck::assertions::map::predicate::PredicateAssertion;
+    ///
+    /// let mut scores = HashMap::new();
+    /// scores.insert("rust", 10);
+    /// scores.insert("java", 8);
+    ///
+    /// scores.should_have_all_keys_satisfying(|key| key.len() == 4);
+    /// ```
+    fn should_have_all_keys_satisfying<F>(&self, predicate: F) -> &Self
+        where F: Fn(&K) -> bool;
+
+    /// - Asserts that all the values in the HashMap satisfy the given predicate.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting an entry whose value does not satisfy the predicate.
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use clearcheck::assertions::map::predicate::PredicateAssertion;
+    ///
+    /// let mut scores = HashMap::new();
+    /// scores.insert("rust", 10);
+    /// scores.insert("java", 8);
+    ///
+    /// scores.should_have_all_values_satisfying(|score| *score >= 0);
+    /// ```
+    fn should_have_all_values_satisfying<F>(&self, predicate: F) -> &Self
+        where F: Fn(&V) -> bool;
+}
+
+impl<K, V> PredicateAssertion<K, V> for HashMap<K, V>
+    where
+        K: Hash + Eq + Debug,
+        V: Debug,
+{
+    fn should_have_all_keys_satisfying<F>(&self, predicate: F) -> &Self
+        where F: Fn(&K) -> bool
+    {
+        self.should(&satisfy_for_all_keys(predicate));
+        self
+    }
+
+    fn should_have_all_values_satisfying<F>(&self, predicate: F) -> &Self
+        where F: Fn(&V) -> bool
+    {
+        self.should(&satisfy_for_all_values(predicate));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::assertions::map::predicate::PredicateAssertion;
+
+    #[test]
+    fn should_have_all_keys_satisfying() {
+        let mut scores = HashMap::new();
+        scores.insert("rust", 10);
+        scores.insert("java", 8);
+
+        scores.should_have_all_keys_satisfying(|key| key.starts_with(['r', 'j']));
+    }
+
+    #[test]
+    #[should_panic(expected = "but the entry with key \"golang\" and value 8 did not")]
+    fn should_have_all_keys_satisfying_but_it_did_not() {
+        let mut scores = HashMap::new();
+        scores.insert("rust", 10);
+        scores.insert("golang", 8);
+
+        scores.should_have_all_keys_satisfying(|key| key.len() == 4);
+    }
+
+    #[test]
+    fn should_have_all_values_satisfying() {
+        let mut scores = HashMap::new();
+        scores.insert("rust", 10);
+        scores.insert("java", 8);
+
+        scores.should_have_all_values_satisfying(|score| *score >= 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "but the entry with key \"java\" and value -8 did not")]
+    fn should_have_all_values_satisfying_but_it_did_not() {
+        let mut scores = HashMap::new();
+        scores.insert("rust", 10);
+        scores.insert("java", -8);
+
+        scores.should_have_all_values_satisfying(|score| *score >= 0);
+    }
+}
@@ -1,4 +1,4 @@
-//! SizeAssertion enables assertions about the size of the HashMap.
+//! SizeAssertion enables assertions about the size of the HashMap and the BTreeMap.
 //!
 //! It offers a fluent interface for chaining multiple assertions.
 //!
@@ -16,7 +16,7 @@
 //!
 //! Refer to the trait [SizeAssertion].
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::hash::Hash;
 use std::ops::{Range, RangeInclusive};
 
@@ -79,6 +79,58 @@ impl<K, V> SizeAssertion for HashMap<K, V>
     }
 }
 
+impl<K, V> SizeAssertion for BTreeMap<K, V>
+    where
+        K: Ord
+{
+    fn should_have_size(&self, size: usize) -> &Self {
+        self.should(&have_same_length(size));
+        self
+    }
+
+    fn should_not_have_size(&self, size: usize) -> &Self {
+        self.should_not(&have_same_length(size));
+        self
+    }
+
+    fn should_have_at_least_size(&self, size: usize) -> &Self {
+        self.should(&have_atleast_same_length(size));
+        self
+    }
+
+    fn should_have_at_most_size(&self, size: usize) -> &Self {
+        self.should(&have_atmost_same_length(size));
+        self
+    }
+
+    fn should_be_same_size_as<U>(&self, other: &[U]) -> &Self {
+        self.should_have_size(other.len());
+        self
+    }
+
+    fn should_have_size_in_inclusive_range(&self, range: RangeInclusive<usize>) -> &Self {
+        self.len().should(&have_length_in_inclusive_range(range));
+        self
+    }
+
+    fn should_not_have_size_in_inclusive_range(&self, range: RangeInclusive<usize>) -> &Self {
+        self.len()
+            .should_not(&have_length_in_inclusive_range(range));
+        self
+    }
+
+    fn should_have_size_in_exclusive_range(&self, range: Range<usize>) -> &Self {
+        self.len().should(&have_length_in_exclusive_range(range));
+        self
+    }
+
+    fn should_not_have_size_in_exclusive_range(&self, range: Range<usize>) -> &Self {
+        self.len()
+            .should_not(&have_length_in_exclusive_range(range));
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
@@ -221,3 +273,92 @@ mod tests {
         key_value.should_not_have_size_in_exclusive_range(1..9);
     }
 }
+
+#[cfg(test)]
+mod btree_tests {
+    use std::collections::BTreeMap;
+
+    use crate::assertions::collection::size::SizeAssertion;
+
+    #[test]
+    fn should_have_size_as_2() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert("rust", "assert");
+        key_value.insert("java", "junit");
+        key_value.should_have_size(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"Map\" length 1 should be 3")]
+    fn should_have_size_3_but_was_not() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_have_size(3);
+    }
+
+    #[test]
+    fn should_not_have_size_3() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_not_have_size(3);
+    }
+
+    #[test]
+    fn should_have_at_least_size_1() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_have_at_least_size(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"Map\" length 1 should be atleast 2")]
+    fn should_have_at_least_size_2_but_was_not() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_have_at_least_size(2);
+    }
+
+    #[test]
+    fn should_have_at_most_size_1() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_have_at_most_size(1);
+    }
+
+    #[test]
+    fn should_be_same_size_as_other() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_be_same_size_as(&[1]);
+    }
+
+    #[test]
+    fn should_have_size_in_the_inclusive_range() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_have_size_in_inclusive_range(1..=8);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_size_in_the_inclusive_range_but_was_not() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_have_size_in_inclusive_range(3..=8);
+    }
+
+    #[test]
+    fn should_not_have_size_in_the_exclusive_range() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_not_have_size_in_exclusive_range(3..4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_size_in_the_exclusive_range_but_was_not() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert("rust", "assert");
+        key_value.should_have_size_in_exclusive_range(3..8);
+    }
+}
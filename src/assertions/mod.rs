@@ -18,6 +18,7 @@
 //! ```
 
 pub mod bool;
+pub mod bytes;
 pub mod char;
 pub mod collection;
 #[cfg(feature = "date")]
@@ -188,6 +188,32 @@ pub trait OrderedAssertion<T: PartialOrd> {
     /// name.should_not_be_in_exclusive_range("clearcheck".."gotest");
     /// ```
     fn should_not_be_in_exclusive_range(&self, range: Range<T>) -> &Self;
+
+    /// - Asserts that the self value falls within the inclusive range formed by the given bounds.
+    /// - Borrows the bounds, so expensive values need not be moved or cloned into a range.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::ordered::OrderedAssertion;
+    ///
+    /// let name = String::from("junit");
+    /// name.should_be_within(&String::from("clearcheck"), &String::from("scalatest"));
+    /// ```
+    fn should_be_within(&self, start: &T, end: &T) -> &Self;
+
+    /// - Asserts that the self value does not fall within the inclusive range formed by the given bounds.
+    /// - Borrows the bounds, so expensive values need not be moved or cloned into a range.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::ordered::OrderedAssertion;
+    ///
+    /// let name = String::from("junit");
+    /// name.should_not_be_within(&String::from("clearcheck"), &String::from("gotest"));
+    /// ```
+    fn should_not_be_within(&self, start: &T, end: &T) -> &Self;
 }
 
 impl<T: PartialOrd + Debug> OrderedAssertion<T> for T {
@@ -282,6 +308,16 @@ impl<T: PartialOrd + Debug> OrderedAssertion<T> for T {
         self.should_not(&be_in_exclusive_range(range));
         self
     }
+
+    fn should_be_within(&self, start: &T, end: &T) -> &Self {
+        (&self).should(&be_in_inclusive_range(start..=end));
+        self
+    }
+
+    fn should_not_be_within(&self, start: &T, end: &T) -> &Self {
+        (&self).should_not(&be_in_inclusive_range(start..=end));
+        self
+    }
 }
 
 #[cfg(test)]
@@ -472,4 +508,68 @@ mod string_tests {
         let name = "junit";
         name.should_be_in_exclusive_range("clearcheck".."scalatest");
     }
+
+    #[test]
+    fn should_be_within() {
+        let name = String::from("junit");
+        name.should_be_within(&String::from("clearcheck"), &String::from("scalatest"));
+    }
+
+    #[test]
+    fn should_be_within_given_the_value_is_a_bound() {
+        let name = String::from("junit");
+        name.should_be_within(&String::from("clearcheck"), &String::from("junit"));
+    }
+
+    #[test]
+    #[should_panic(expected = "\"Value\" \"junit\" should fall in the range \"clearcheck\"..=\"gotest\"")]
+    fn should_be_within_but_was_not() {
+        let name = String::from("junit");
+        name.should_be_within(&String::from("clearcheck"), &String::from("gotest"));
+    }
+
+    #[test]
+    fn should_not_be_within() {
+        let name = String::from("junit");
+        name.should_not_be_within(&String::from("clearcheck"), &String::from("gotest"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_within_but_was() {
+        let name = String::from("junit");
+        name.should_not_be_within(&String::from("clearcheck"), &String::from("scalatest"));
+    }
+}
+
+#[cfg(all(test, feature = "date"))]
+mod date_tests {
+    use chrono::NaiveDate;
+
+    use crate::assertions::ordered::OrderedAssertion;
+
+    #[test]
+    fn should_be_within() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+        date.should_be_within(&start, &end);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"Value\" 2024-02-10 should fall in the range 2024-01-01..=2024-01-31")]
+    fn should_be_within_but_was_not() {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 10).unwrap();
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+        date.should_be_within(&start, &end);
+    }
+
+    #[test]
+    fn should_not_be_within() {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 10).unwrap();
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+        date.should_not_be_within(&start, &end);
+    }
 }
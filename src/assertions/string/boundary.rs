@@ -1,5 +1,7 @@
 use crate::matchers::{Should, ShouldNot};
-use crate::matchers::string::boundary::{begin_with, end_with};
+use crate::matchers::string::boundary::{
+    begin_with, begin_with_ignoring_case, end_with, end_with_ignoring_case, start_and_end_with,
+};
 
 /// BoundaryAssertion enables assertions about the beginning and the ending boundaries of string (or str) values.
 ///
@@ -62,6 +64,79 @@ pub trait BoundaryAssertion {
     /// value.should_not_end_with("test");
     /// ```
     fn should_not_end_with(&self, suffix: &'static str) -> &Self;
+
+    /// - Asserts that the string begins with the given prefix, ignoring case differences.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::boundary::BoundaryAssertion;
+    ///
+    /// let url = "HTTP://clearcheck";
+    /// url.should_begin_with_ignoring_case("http://");
+    /// ```
+    fn should_begin_with_ignoring_case(&self, prefix: &'static str) -> &Self;
+
+    /// - Asserts that the string does not begin with the given prefix, ignoring case differences.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::boundary::BoundaryAssertion;
+    ///
+    /// let url = "HTTPS://clearcheck";
+    /// url.should_not_begin_with_ignoring_case("http://");
+    /// ```
+    fn should_not_begin_with_ignoring_case(&self, prefix: &'static str) -> &Self;
+
+    /// - Asserts that the string ends with the given suffix, ignoring case differences.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::boundary::BoundaryAssertion;
+    ///
+    /// let file_name = "README.MD";
+    /// file_name.should_end_with_ignoring_case(".md");
+    /// ```
+    fn should_end_with_ignoring_case(&self, suffix: &'static str) -> &Self;
+
+    /// - Asserts that the string does not end with the given suffix, ignoring case differences.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::boundary::BoundaryAssertion;
+    ///
+    /// let file_name = "README.MD";
+    /// file_name.should_not_end_with_ignoring_case(".txt");
+    /// ```
+    fn should_not_end_with_ignoring_case(&self, suffix: &'static str) -> &Self;
+
+    /// - Asserts that the string both begins and ends with the given affix.
+    /// - The beginning and the ending may overlap: a string equal to the affix passes, while a string shorter than the affix never does.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting which of the ends did not match.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::boundary::BoundaryAssertion;
+    ///
+    /// let token = "\"clearcheck\"";
+    /// token.should_start_and_end_with("\"");
+    /// ```
+    fn should_start_and_end_with(&self, affix: &'static str) -> &Self;
+
+    /// - Asserts that the string does not both begin and end with the given affix.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::boundary::BoundaryAssertion;
+    ///
+    /// let token = "\"clearcheck";
+    /// token.should_not_start_and_end_with("\"");
+    /// ```
+    fn should_not_start_and_end_with(&self, affix: &'static str) -> &Self;
 }
 
 impl<T> BoundaryAssertion for T
@@ -85,6 +160,36 @@ impl<T> BoundaryAssertion for T
         self.should_not(&end_with(suffix));
         self
     }
+
+    fn should_begin_with_ignoring_case(&self, prefix: &'static str) -> &Self {
+        self.should(&begin_with_ignoring_case(prefix));
+        self
+    }
+
+    fn should_not_begin_with_ignoring_case(&self, prefix: &'static str) -> &Self {
+        self.should_not(&begin_with_ignoring_case(prefix));
+        self
+    }
+
+    fn should_end_with_ignoring_case(&self, suffix: &'static str) -> &Self {
+        self.should(&end_with_ignoring_case(suffix));
+        self
+    }
+
+    fn should_not_end_with_ignoring_case(&self, suffix: &'static str) -> &Self {
+        self.should_not(&end_with_ignoring_case(suffix));
+        self
+    }
+
+    fn should_start_and_end_with(&self, affix: &'static str) -> &Self {
+        self.should(&start_and_end_with(affix));
+        self
+    }
+
+    fn should_not_start_and_end_with(&self, affix: &'static str) -> &Self {
+        self.should_not(&start_and_end_with(affix));
+        self
+    }
 }
 
 #[cfg(test)]
@@ -142,6 +247,58 @@ mod tests {
         let library = "junit";
         library.should_not_end_with("unit");
     }
+
+    #[test]
+    fn should_begin_with_ignoring_case() {
+        let url = "HTTP://x";
+        url.should_begin_with_ignoring_case("http://");
+    }
+
+    #[test]
+    #[should_panic(expected = "\"HTTPS://x\" should begin with \"http://\" ignoring case")]
+    fn should_begin_with_ignoring_case_but_it_did_not() {
+        let url = "HTTPS://x";
+        url.should_begin_with_ignoring_case("http://");
+    }
+
+    #[test]
+    fn should_not_begin_with_ignoring_case() {
+        let url = "HTTPS://x";
+        url.should_not_begin_with_ignoring_case("http://");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_begin_with_ignoring_case_but_it_did() {
+        let url = "HTTP://x";
+        url.should_not_begin_with_ignoring_case("http://");
+    }
+
+    #[test]
+    fn should_end_with_ignoring_case() {
+        let file_name = "README.MD";
+        file_name.should_end_with_ignoring_case(".md");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_end_with_ignoring_case_but_it_did_not() {
+        let file_name = "README.MD";
+        file_name.should_end_with_ignoring_case(".txt");
+    }
+
+    #[test]
+    fn should_not_end_with_ignoring_case() {
+        let file_name = "README.MD";
+        file_name.should_not_end_with_ignoring_case(".txt");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_end_with_ignoring_case_but_it_did() {
+        let file_name = "README.MD";
+        file_name.should_not_end_with_ignoring_case(".md");
+    }
 }
 
 #[cfg(test)]
@@ -199,4 +356,68 @@ mod string_tests {
         let library = String::from("junit");
         library.should_not_end_with("unit");
     }
+
+    #[test]
+    fn should_begin_with_ignoring_case() {
+        let url = String::from("HTTP://x");
+        url.should_begin_with_ignoring_case("http://");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_end_with_ignoring_case_but_it_did_not() {
+        let file_name = String::from("README.MD");
+        file_name.should_end_with_ignoring_case(".txt");
+    }
+}
+
+#[cfg(test)]
+mod start_and_end_tests {
+    use crate::assertions::string::boundary::BoundaryAssertion;
+
+    #[test]
+    fn should_start_and_end_with() {
+        let token = "\"clearcheck\"";
+        token.should_start_and_end_with("\"");
+    }
+
+    #[test]
+    fn should_start_and_end_with_given_the_value_equals_the_affix() {
+        let token = String::from("--");
+        token.should_start_and_end_with("--");
+    }
+
+    #[test]
+    #[should_panic(expected = "\"-\" should begin and end with \"--\" but it neither began nor ended with it")]
+    fn should_start_and_end_with_but_the_value_was_shorter_than_the_affix() {
+        let token = "-";
+        token.should_start_and_end_with("--");
+    }
+
+    #[test]
+    #[should_panic(expected = "\"clearcheck\\\"\" should begin and end with \"\\\"\" but it did not begin with it")]
+    fn should_start_and_end_with_but_it_did_not_begin_with() {
+        let token = "clearcheck\"";
+        token.should_start_and_end_with("\"");
+    }
+
+    #[test]
+    #[should_panic(expected = "\"\\\"clearcheck\" should begin and end with \"\\\"\" but it did not end with it")]
+    fn should_start_and_end_with_but_it_did_not_end_with() {
+        let token = "\"clearcheck";
+        token.should_start_and_end_with("\"");
+    }
+
+    #[test]
+    fn should_not_start_and_end_with() {
+        let token = "\"clearcheck";
+        token.should_not_start_and_end_with("\"");
+    }
+
+    #[test]
+    #[should_panic(expected = "\"'clearcheck'\" should not begin and end with \"'\"")]
+    fn should_not_start_and_end_with_but_it_did() {
+        let token = "'clearcheck'";
+        token.should_not_start_and_end_with("'");
+    }
 }
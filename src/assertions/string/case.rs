@@ -1,7 +1,13 @@
 use crate::matchers::Should;
-use crate::matchers::string::case::{be_lowercase, be_uppercase};
+use crate::matchers::string::case::{
+    be_camel_case, be_kebab_case, be_lowercase, be_pascal_case, be_screaming_snake_case, be_snake_case,
+    be_uppercase,
+};
 
-/// CaseAssertion enables assertions about whether a string (or str) is lowercase or uppercase.
+/// CaseAssertion enables assertions about whether a string (or str) is lowercase or uppercase,
+/// or follows an identifier casing style like snake_case or camelCase.
+///
+/// Refer to [`CaseStyleMatcher`](crate::matchers::string::case::CaseStyleMatcher) for the rules of each casing style.
 pub trait CaseAssertion {
     /// - Asserts that the string is lowercase.
     /// - Returns a reference to self for fluent chaining.
@@ -26,6 +32,66 @@ pub trait CaseAssertion {
     /// name.should_be_upper_case();
     /// ```
     fn should_be_upper_case(&self) -> &Self;
+
+    /// - Asserts that the string is in snake_case.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::case::CaseAssertion;
+    ///
+    /// let name = "max_retry_count";
+    /// name.should_be_snake_case();
+    /// ```
+    fn should_be_snake_case(&self) -> &Self;
+
+    /// - Asserts that the string is in kebab-case.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::case::CaseAssertion;
+    ///
+    /// let name = "max-retry-count";
+    /// name.should_be_kebab_case();
+    /// ```
+    fn should_be_kebab_case(&self) -> &Self;
+
+    /// - Asserts that the string is in SCREAMING_SNAKE_CASE.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::case::CaseAssertion;
+    ///
+    /// let name = "MAX_RETRY_COUNT";
+    /// name.should_be_screaming_snake_case();
+    /// ```
+    fn should_be_screaming_snake_case(&self) -> &Self;
+
+    /// - Asserts that the string is in PascalCase.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::case::CaseAssertion;
+    ///
+    /// let name = "MaxRetryCount";
+    /// name.should_be_pascal_case();
+    /// ```
+    fn should_be_pascal_case(&self) -> &Self;
+
+    /// - Asserts that the string is in camelCase.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::case::CaseAssertion;
+    ///
+    /// let name = "maxRetryCount";
+    /// name.should_be_camel_case();
+    /// ```
+    fn should_be_camel_case(&self) -> &Self;
 }
 
 impl<T> CaseAssertion for T
@@ -39,6 +105,31 @@ impl<T> CaseAssertion for T
         self.should(&be_uppercase());
         self
     }
+
+    fn should_be_snake_case(&self) -> &Self {
+        self.should(&be_snake_case());
+        self
+    }
+
+    fn should_be_kebab_case(&self) -> &Self {
+        self.should(&be_kebab_case());
+        self
+    }
+
+    fn should_be_screaming_snake_case(&self) -> &Self {
+        self.should(&be_screaming_snake_case());
+        self
+    }
+
+    fn should_be_pascal_case(&self) -> &Self {
+        self.should(&be_pascal_case());
+        self
+    }
+
+    fn should_be_camel_case(&self) -> &Self {
+        self.should(&be_camel_case());
+        self
+    }
 }
 
 #[cfg(test)]
@@ -70,6 +161,99 @@ mod tests {
         let name = "assert4J";
         name.should_be_upper_case();
     }
+
+    #[test]
+    fn should_be_snake_case() {
+        let name = "max_retry_count";
+        name.should_be_snake_case();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"max__retry\" should be in snake_case but the separator '_' at index 4 follows another separator")]
+    fn should_be_snake_case_but_had_consecutive_separators() {
+        let name = "max__retry";
+        name.should_be_snake_case();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"_max_retry\" should be in snake_case but the character '_' at index 0 should be a lowercase letter")]
+    fn should_be_snake_case_but_began_with_a_separator() {
+        let name = "_max_retry";
+        name.should_be_snake_case();
+    }
+
+    #[test]
+    fn should_be_kebab_case() {
+        let name = "max-retry-count";
+        name.should_be_kebab_case();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"max-retry-\" should be in kebab-case but the separator '-' at index 9 ends the string")]
+    fn should_be_kebab_case_but_had_a_trailing_separator() {
+        let name = "max-retry-";
+        name.should_be_kebab_case();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"max_retry\" should be in kebab-case but the character '_' at index 3 is not allowed (allowed: lowercase letters, digits and '-')")]
+    fn should_be_kebab_case_but_had_an_underscore() {
+        let name = "max_retry";
+        name.should_be_kebab_case();
+    }
+
+    #[test]
+    fn should_be_screaming_snake_case() {
+        let name = "MAX_RETRY_COUNT2";
+        name.should_be_screaming_snake_case();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"MAX_Retry\" should be in SCREAMING_SNAKE_CASE but the character 'e' at index 5 is not allowed (allowed: uppercase letters, digits and '_')")]
+    fn should_be_screaming_snake_case_but_had_a_lowercase_letter() {
+        let name = "MAX_Retry";
+        name.should_be_screaming_snake_case();
+    }
+
+    #[test]
+    fn should_be_pascal_case() {
+        let name = "MaxRetryCount";
+        name.should_be_pascal_case();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"maxRetryCount\" should be in PascalCase but the character 'm' at index 0 should be an uppercase letter")]
+    fn should_be_pascal_case_but_began_with_a_lowercase_letter() {
+        let name = "maxRetryCount";
+        name.should_be_pascal_case();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"Max_Retry\" should be in PascalCase but the character '_' at index 3 is not allowed (allowed: letters and digits)")]
+    fn should_be_pascal_case_but_had_a_separator() {
+        let name = "Max_Retry";
+        name.should_be_pascal_case();
+    }
+
+    #[test]
+    fn should_be_camel_case() {
+        let name = "maxRetryCount";
+        name.should_be_camel_case();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"\" should be in camelCase but it was empty")]
+    fn should_be_camel_case_but_was_empty() {
+        let name = "";
+        name.should_be_camel_case();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"1maxRetry\" should be in camelCase but the character '1' at index 0 should be a lowercase letter")]
+    fn should_be_camel_case_but_began_with_a_digit() {
+        let name = "1maxRetry";
+        name.should_be_camel_case();
+    }
 }
 
 #[cfg(test)]
@@ -101,4 +285,17 @@ mod string_tests {
         let name = String::from("assert4J");
         name.should_be_upper_case();
     }
+
+    #[test]
+    fn should_be_snake_case() {
+        let name = String::from("max_retry_count");
+        name.should_be_snake_case();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_camel_case_but_was_not() {
+        let name = String::from("max-retry-count");
+        name.should_be_camel_case();
+    }
 }
@@ -1,7 +1,11 @@
 use crate::matchers::{Should, ShouldNot};
 use crate::matchers::equal::be_equal_ignoring_case;
+use crate::matchers::string::equal::{be_equal_ignoring_unicode_case, be_equal_ignoring_whitespace};
 
 /// IgnoreCaseEqualityAssertion enables assertions about whether a string (or str) equals other string, with case ignored.
+///
+/// `should_be_equal_ignoring_case` ignores ASCII case only, while `should_be_equal_ignoring_unicode_case` compares the
+/// Unicode lowercase forms ([str::to_lowercase]) of both the strings.
 pub trait IgnoreCaseEqualityAssertion {
     /// - Asserts that the string equals other string, with case ignored.
     /// - Returns a reference to self for fluent chaining.
@@ -26,6 +30,31 @@ pub trait IgnoreCaseEqualityAssertion {
     /// name.should_not_be_equal_ignoring_case("CLEARCHECK-001");
     /// ```
     fn should_not_be_equal_ignoring_case(&self, other: &str) -> &Self;
+
+    /// - Asserts that the string equals other string, ignoring Unicode case differences.
+    /// - Lowercasing is not locale-aware, so the Turkish 'İ' does not equal 'i'.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::equal::IgnoreCaseEqualityAssertion;
+    ///
+    /// let street = "STRASSE ÜBER";
+    /// street.should_be_equal_ignoring_unicode_case("strasse über");
+    /// ```
+    fn should_be_equal_ignoring_unicode_case(&self, other: &str) -> &Self;
+
+    /// - Asserts that the string does not equal other string, ignoring Unicode case differences.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::equal::IgnoreCaseEqualityAssertion;
+    ///
+    /// let street = "STRASSE ÜBER";
+    /// street.should_not_be_equal_ignoring_unicode_case("strasse uber");
+    /// ```
+    fn should_not_be_equal_ignoring_unicode_case(&self, other: &str) -> &Self;
 }
 
 impl<T> IgnoreCaseEqualityAssertion for T
@@ -39,11 +68,63 @@ impl<T> IgnoreCaseEqualityAssertion for T
         self.should_not(&be_equal_ignoring_case(other));
         self
     }
+
+    fn should_be_equal_ignoring_unicode_case(&self, other: &str) -> &Self {
+        self.should(&be_equal_ignoring_unicode_case(other));
+        self
+    }
+
+    fn should_not_be_equal_ignoring_unicode_case(&self, other: &str) -> &Self {
+        self.should_not(&be_equal_ignoring_unicode_case(other));
+        self
+    }
+}
+
+/// IgnoreWhitespaceEqualityAssertion enables assertions about whether a string (or str) equals other string, ignoring insignificant whitespace differences.
+///
+/// Both the strings are trimmed and every run of whitespace (including newlines and tabs) is collapsed into a single space before comparison.
+pub trait IgnoreWhitespaceEqualityAssertion {
+    /// - Asserts that the string equals other string, ignoring insignificant whitespace differences.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::equal::IgnoreWhitespaceEqualityAssertion;
+    ///
+    /// let rendered = "fn main() {\n\tprintln!();\n}\n";
+    /// rendered.should_be_equal_ignoring_whitespace("fn main() { println!(); }");
+    /// ```
+    fn should_be_equal_ignoring_whitespace(&self, other: &str) -> &Self;
+
+    /// - Asserts that the string does not equal other string, ignoring insignificant whitespace differences.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::equal::IgnoreWhitespaceEqualityAssertion;
+    ///
+    /// let rendered = "fn main() {}";
+    /// rendered.should_not_be_equal_ignoring_whitespace("fnmain(){}");
+    /// ```
+    fn should_not_be_equal_ignoring_whitespace(&self, other: &str) -> &Self;
+}
+
+impl<T> IgnoreWhitespaceEqualityAssertion for T
+    where T: AsRef<str> {
+    fn should_be_equal_ignoring_whitespace(&self, other: &str) -> &Self {
+        self.should(&be_equal_ignoring_whitespace(other));
+        self
+    }
+
+    fn should_not_be_equal_ignoring_whitespace(&self, other: &str) -> &Self {
+        self.should_not(&be_equal_ignoring_whitespace(other));
+        self
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::assertions::string::equal::IgnoreCaseEqualityAssertion;
+    use crate::assertions::string::equal::{IgnoreCaseEqualityAssertion, IgnoreWhitespaceEqualityAssertion};
 
     #[test]
     fn should_be_equal() {
@@ -58,6 +139,52 @@ mod tests {
         name.should_be_equal_ignoring_case("JOHN");
     }
 
+    #[test]
+    fn should_be_equal_given_an_uppercase_string() {
+        let greeting = "HELLO";
+        greeting.should_be_equal_ignoring_case("hello");
+    }
+
+    #[test]
+    #[should_panic(expected = "\"STRASSE ÜBER\" should equal \"strasse über\"")]
+    fn should_be_equal_but_non_ascii_characters_differed_in_case() {
+        let word = String::from("STRASSE ÜBER");
+        word.should_be_equal_ignoring_case("strasse über");
+    }
+
+    #[test]
+    fn should_be_equal_ignoring_unicode_case() {
+        let word = String::from("STRASSE ÜBER");
+        word.should_be_equal_ignoring_unicode_case("strasse über");
+    }
+
+    #[test]
+    #[should_panic(expected = "\"İ\" should equal \"i\" ignoring Unicode case")]
+    fn should_be_equal_ignoring_unicode_case_but_turkish_dotted_capital_i_was_not() {
+        let letter = "İ";
+        letter.should_be_equal_ignoring_unicode_case("i");
+    }
+
+    #[test]
+    fn should_not_be_equal_ignoring_unicode_case() {
+        let word = "ÜBER";
+        word.should_not_be_equal_ignoring_unicode_case("uber");
+    }
+
+    #[test]
+    #[should_panic(expected = "\"ÜBER\" should not equal \"über\" ignoring Unicode case")]
+    fn should_not_be_equal_ignoring_unicode_case_but_was() {
+        let word = "ÜBER";
+        word.should_not_be_equal_ignoring_unicode_case("über");
+    }
+
+    #[test]
+    #[should_panic(expected = "\"HELLO\" should equal \"world\"")]
+    fn should_be_equal_but_was_not_with_both_operands_in_message() {
+        let greeting = "HELLO";
+        greeting.should_be_equal_ignoring_case("world");
+    }
+
     #[test]
     fn should_not_be_equal() {
         let name = "john";
@@ -70,11 +197,43 @@ mod tests {
         let name = "john";
         name.should_not_be_equal_ignoring_case("JOHN");
     }
+
+    #[test]
+    fn should_be_equal_ignoring_whitespace_with_tabs_and_spaces() {
+        let rendered = "name:\tclearcheck";
+        rendered.should_be_equal_ignoring_whitespace("name:   clearcheck");
+    }
+
+    #[test]
+    fn should_be_equal_ignoring_whitespace_with_trailing_newline() {
+        let rendered = "name: clearcheck\n";
+        rendered.should_be_equal_ignoring_whitespace("name: clearcheck");
+    }
+
+    #[test]
+    #[should_panic(expected = "(normalized: \"name: clear check\" and \"name: clearcheck\")")]
+    fn should_be_equal_ignoring_whitespace_but_was_not() {
+        let rendered = "name:\tclear check\n";
+        rendered.should_be_equal_ignoring_whitespace("name: clearcheck");
+    }
+
+    #[test]
+    fn should_not_be_equal_ignoring_whitespace() {
+        let rendered = "name: clear check";
+        rendered.should_not_be_equal_ignoring_whitespace("name: clearcheck");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_equal_ignoring_whitespace_but_was() {
+        let rendered = "name:\n  clearcheck";
+        rendered.should_not_be_equal_ignoring_whitespace("name: clearcheck");
+    }
 }
 
 #[cfg(test)]
 mod string_tests {
-    use crate::assertions::string::equal::IgnoreCaseEqualityAssertion;
+    use crate::assertions::string::equal::{IgnoreCaseEqualityAssertion, IgnoreWhitespaceEqualityAssertion};
 
     #[test]
     fn should_be_equal() {
@@ -101,4 +260,17 @@ mod string_tests {
         let name = String::from("john");
         name.should_not_be_equal_ignoring_case("JOHN");
     }
+
+    #[test]
+    fn should_be_equal_ignoring_whitespace() {
+        let rendered = String::from("  name:\t\tclearcheck\r\n");
+        rendered.should_be_equal_ignoring_whitespace("name: clearcheck");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_equal_ignoring_whitespace_but_was_not() {
+        let rendered = String::from("name: clearcheck");
+        rendered.should_be_equal_ignoring_whitespace("name:clearcheck");
+    }
 }
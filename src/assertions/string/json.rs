@@ -0,0 +1,219 @@
+use crate::matchers::{Should, ShouldNot};
+use crate::matchers::string::json::{be_json_equivalent, have_json_value_at};
+
+/// JsonAssertion enables assertions about the structure of JSON string (or str) values.
+///
+/// Both the strings are parsed and compared as JSON trees, so the order of object keys and the insignificant
+/// whitespace do not matter. The order of array elements does matter.
+pub trait JsonAssertion {
+    /// - Asserts that the string is a JSON structurally equivalent to the given JSON string.
+    /// - Fails if either side is not a valid JSON, reporting the parse error.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::json::JsonAssertion;
+    ///
+    /// let response = r#"{"stars": 10, "name": "clearcheck"}"#;
+    /// response.should_be_json_equivalent(r#"{"name":"clearcheck","stars":10}"#);
+    /// ```
+    fn should_be_json_equivalent(&self, other: &str) -> &Self;
+
+    /// - Asserts that the string is a JSON that is not structurally equivalent to the given JSON string.
+    /// - Passes if either side is not a valid JSON.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::json::JsonAssertion;
+    ///
+    /// let response = r#"{"stars": 10, "name": "clearcheck"}"#;
+    /// response.should_not_be_json_equivalent(r#"{"name":"clearcheck","stars":11}"#);
+    /// ```
+    fn should_not_be_json_equivalent(&self, other: &str) -> &Self;
+
+    /// - Asserts that the string is a JSON containing the given JSON value at the given dotted path (like `$.data.id` or `items.0.name`).
+    /// - A numeric segment indexes into an array, any other segment is looked up as an object key.
+    /// - Fails if either the string or the expected value is not a valid JSON, reporting the parse error.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::json::JsonAssertion;
+    ///
+    /// let response = r#"{"data": {"id": 42, "tags": ["rust", "test"]}}"#;
+    /// response
+    ///     .should_have_json_value_at("$.data.id", "42")
+    ///     .should_have_json_value_at("data.tags.1", r#""test""#);
+    /// ```
+    fn should_have_json_value_at(&self, path: &str, expected: &str) -> &Self;
+
+    /// - Asserts that the string is a JSON that does not contain the given JSON value at the given dotted path.
+    /// - Passes if the path does not exist, or if either the string or the expected value is not a valid JSON.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::json::JsonAssertion;
+    ///
+    /// let response = r#"{"data": {"id": 42}}"#;
+    /// response.should_not_have_json_value_at("$.data.id", "43");
+    /// ```
+    fn should_not_have_json_value_at(&self, path: &str, expected: &str) -> &Self;
+}
+
+impl<T> JsonAssertion for T
+    where T: AsRef<str> {
+    fn should_be_json_equivalent(&self, other: &str) -> &Self {
+        self.should(&be_json_equivalent(other));
+        self
+    }
+
+    fn should_not_be_json_equivalent(&self, other: &str) -> &Self {
+        self.should_not(&be_json_equivalent(other));
+        self
+    }
+
+    fn should_have_json_value_at(&self, path: &str, expected: &str) -> &Self {
+        self.should(&have_json_value_at(path, expected));
+        self
+    }
+
+    fn should_not_have_json_value_at(&self, path: &str, expected: &str) -> &Self {
+        self.should_not(&have_json_value_at(path, expected));
+        self
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod tests {
+    use crate::assertions::string::json::JsonAssertion;
+
+    #[test]
+    fn should_be_json_equivalent_with_reordered_keys() {
+        let response = r#"{"name": "clearcheck", "owner": {"id": 1, "name": "sarthak"}}"#;
+        response.should_be_json_equivalent(r#"{"owner":{"name":"sarthak","id":1},"name":"clearcheck"}"#);
+    }
+
+    #[test]
+    #[should_panic(expected = "but differed at $.owner.id")]
+    fn should_be_json_equivalent_but_a_nested_value_differed() {
+        let response = r#"{"name": "clearcheck", "owner": {"id": 1}}"#;
+        response.should_be_json_equivalent(r#"{"name": "clearcheck", "owner": {"id": 2}}"#);
+    }
+
+    #[test]
+    #[should_panic(expected = "but differed at $.tags[1]")]
+    fn should_be_json_equivalent_but_an_array_element_differed() {
+        let response = r#"{"tags": ["rust", "test"]}"#;
+        response.should_be_json_equivalent(r#"{"tags": ["rust", "assert"]}"#);
+    }
+
+    #[test]
+    #[should_panic(expected = "but differed at $.stars")]
+    fn should_be_json_equivalent_but_a_key_was_missing() {
+        let response = r#"{"name": "clearcheck"}"#;
+        response.should_be_json_equivalent(r#"{"name": "clearcheck", "stars": 10}"#);
+    }
+
+    #[test]
+    fn should_not_be_json_equivalent() {
+        let response = r#"{"stars": 10}"#;
+        response.should_not_be_json_equivalent(r#"{"stars": 11}"#);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_json_equivalent_but_was() {
+        let response = r#"{"stars": 10, "name": "clearcheck"}"#;
+        response.should_not_be_json_equivalent(r#"{"name": "clearcheck", "stars": 10}"#);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"{stars: 10}\" should be JSON equivalent to \"{\\\"stars\\\": 10}\" but the value \"{stars: 10}\" is not a valid JSON")]
+    fn should_be_json_equivalent_with_an_invalid_value() {
+        let response = "{stars: 10}";
+        response.should_be_json_equivalent(r#"{"stars": 10}"#);
+    }
+
+    #[test]
+    fn should_not_be_json_equivalent_with_an_invalid_value() {
+        let response = "{stars: 10}";
+        response.should_not_be_json_equivalent(r#"{"stars": 11}"#);
+    }
+
+    #[test]
+    fn should_have_json_value_at_a_nested_object_path() {
+        let response = r#"{"data": {"owner": {"id": 42, "name": "sarthak"}}}"#;
+        response
+            .should_have_json_value_at("$.data.owner.id", "42")
+            .should_have_json_value_at("data.owner", r#"{"name": "sarthak", "id": 42}"#);
+    }
+
+    #[test]
+    fn should_have_json_value_at_an_array_path() {
+        let response = r#"{"items": [{"name": "rust"}, {"name": "clearcheck"}]}"#;
+        response.should_have_json_value_at("items.1.name", r#""clearcheck""#);
+    }
+
+    #[test]
+    #[should_panic(expected = "should have the JSON value 43 at \"$.data.id\" but was 42")]
+    fn should_have_json_value_at_but_the_value_differed() {
+        let response = r#"{"data": {"id": 42}}"#;
+        response.should_have_json_value_at("$.data.id", "43");
+    }
+
+    #[test]
+    #[should_panic(expected = "but the path was not found (missing segment \"3\")")]
+    fn should_have_json_value_at_but_the_array_index_was_missing() {
+        let response = r#"{"items": [{"name": "rust"}]}"#;
+        response.should_have_json_value_at("items.3.name", r#""rust""#);
+    }
+
+    #[test]
+    #[should_panic(expected = "but the path was not found (missing segment \"owner\")")]
+    fn should_have_json_value_at_but_the_key_was_missing() {
+        let response = r#"{"data": {"id": 42}}"#;
+        response.should_have_json_value_at("$.data.owner.id", "42");
+    }
+
+    #[test]
+    #[should_panic(expected = "should have the JSON value 42 at \"$.data.id\" but the value \"{data: 42}\" is not a valid JSON")]
+    fn should_have_json_value_at_but_the_value_was_not_a_valid_json() {
+        let response = "{data: 42}";
+        response.should_have_json_value_at("$.data.id", "42");
+    }
+
+    #[test]
+    fn should_not_have_json_value_at() {
+        let response = r#"{"data": {"id": 42}}"#;
+        response
+            .should_not_have_json_value_at("$.data.id", "43")
+            .should_not_have_json_value_at("$.data.name", "43");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_have_json_value_at_but_it_had() {
+        let response = r#"{"data": {"id": 42}}"#;
+        response.should_not_have_json_value_at("$.data.id", "42");
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod string_tests {
+    use crate::assertions::string::json::JsonAssertion;
+
+    #[test]
+    fn should_be_json_equivalent() {
+        let response = String::from(r#"{"stars": 10, "name": "clearcheck"}"#);
+        response.should_be_json_equivalent(r#"{"name":"clearcheck","stars":10}"#);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_json_equivalent_but_was_not() {
+        let response = String::from(r#"{"stars": 10}"#);
+        response.should_be_json_equivalent(r#"{"stars": 11}"#);
+    }
+}
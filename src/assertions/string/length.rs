@@ -2,8 +2,11 @@ use std::ops::{Range, RangeInclusive};
 
 use crate::matchers::{Should, ShouldNot};
 use crate::matchers::range::{have_length_in_exclusive_range, have_length_in_inclusive_range};
+#[cfg(feature = "unicode")]
+use crate::matchers::string::length::have_grapheme_count;
 use crate::matchers::string::length::{
-    have_atleast_same_length, have_atmost_same_length, have_same_length,
+    have_atleast_same_char_length, have_atleast_same_length, have_atmost_same_char_length,
+    have_atmost_same_length, have_same_char_length, have_same_length,
 };
 
 /// LengthAssertion enables assertions about the length of string (or str) values.
@@ -167,6 +170,209 @@ impl<T> LengthAssertion for T
     }
 }
 
+/// StringSizeAssertion enables collection-style size assertions on string (or str) values.
+///
+/// The size of a string is the number of its characters (`chars()`), so a multibyte character counts once.
+/// This differs from [LengthAssertion], which measures the length in bytes.
+///
+/// # Example
+/// ```
+/// use clearcheck::assertions::string::length::StringSizeAssertion;
+///
+/// let name = "héllo";
+/// name
+///     .should_have_size(5)
+///     .should_have_size_in_inclusive_range(1..=5);
+/// ```
+pub trait StringSizeAssertion {
+    /// - Asserts that the number of characters in the string is exactly the given size.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::length::StringSizeAssertion;
+    ///
+    /// let value = "héllo";
+    /// value.should_have_size(5);
+    /// ```
+    fn should_have_size(&self, size: usize) -> &Self;
+
+    /// - Asserts that the number of characters in the string is not the given size.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::length::StringSizeAssertion;
+    ///
+    /// let value = "héllo";
+    /// value.should_not_have_size(6);
+    /// ```
+    fn should_not_have_size(&self, size: usize) -> &Self;
+
+    /// - Asserts that the number of characters in the string is greater than or equal to the given size.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::length::StringSizeAssertion;
+    ///
+    /// let value = "héllo";
+    /// value.should_have_at_least_size(5);
+    /// ```
+    fn should_have_at_least_size(&self, size: usize) -> &Self;
+
+    /// - Asserts that the number of characters in the string is less than or equal to the given size.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::length::StringSizeAssertion;
+    ///
+    /// let value = "héllo";
+    /// value.should_have_at_most_size(5);
+    /// ```
+    fn should_have_at_most_size(&self, size: usize) -> &Self;
+
+    /// - Asserts that the number of characters in the string falls within the given inclusive range.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::length::StringSizeAssertion;
+    ///
+    /// let value = "héllo";
+    /// value.should_have_size_in_inclusive_range(3..=5);
+    /// ```
+    fn should_have_size_in_inclusive_range(&self, range: RangeInclusive<usize>) -> &Self;
+
+    /// - Asserts that the number of characters in the string does not fall within the given inclusive range.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::length::StringSizeAssertion;
+    ///
+    /// let value = "héllo";
+    /// value.should_not_have_size_in_inclusive_range(6..=8);
+    /// ```
+    fn should_not_have_size_in_inclusive_range(&self, range: RangeInclusive<usize>) -> &Self;
+
+    /// - Asserts that the number of characters in the string falls within the given exclusive range.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::length::StringSizeAssertion;
+    ///
+    /// let value = "héllo";
+    /// value.should_have_size_in_exclusive_range(3..6);
+    /// ```
+    fn should_have_size_in_exclusive_range(&self, range: Range<usize>) -> &Self;
+
+    /// - Asserts that the number of characters in the string does not fall within the given exclusive range.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::length::StringSizeAssertion;
+    ///
+    /// let value = "héllo";
+    /// value.should_not_have_size_in_exclusive_range(3..5);
+    /// ```
+    fn should_not_have_size_in_exclusive_range(&self, range: Range<usize>) -> &Self;
+}
+
+impl<T> StringSizeAssertion for T
+    where T: AsRef<str> {
+    fn should_have_size(&self, size: usize) -> &Self {
+        self.should(&have_same_char_length(size));
+        self
+    }
+
+    fn should_not_have_size(&self, size: usize) -> &Self {
+        self.should_not(&have_same_char_length(size));
+        self
+    }
+
+    fn should_have_at_least_size(&self, size: usize) -> &Self {
+        self.should(&have_atleast_same_char_length(size));
+        self
+    }
+
+    fn should_have_at_most_size(&self, size: usize) -> &Self {
+        self.should(&have_atmost_same_char_length(size));
+        self
+    }
+
+    fn should_have_size_in_inclusive_range(&self, range: RangeInclusive<usize>) -> &Self {
+        self.as_ref().chars().count().should(&have_length_in_inclusive_range(range));
+        self
+    }
+
+    fn should_not_have_size_in_inclusive_range(&self, range: RangeInclusive<usize>) -> &Self {
+        self.as_ref().chars().count()
+            .should_not(&have_length_in_inclusive_range(range));
+        self
+    }
+
+    fn should_have_size_in_exclusive_range(&self, range: Range<usize>) -> &Self {
+        self.as_ref().chars().count().should(&have_length_in_exclusive_range(range));
+        self
+    }
+
+    fn should_not_have_size_in_exclusive_range(&self, range: Range<usize>) -> &Self {
+        self.as_ref().chars().count()
+            .should_not(&have_length_in_exclusive_range(range));
+        self
+    }
+}
+
+/// GraphemeAssertion enables assertions about the number of user-perceived characters (extended grapheme clusters) in
+/// string (or str) values.
+///
+/// A character followed by combining marks, or an emoji with a skin-tone modifier counts once, whereas
+/// [StringSizeAssertion] counts every `char`.
+#[cfg(feature = "unicode")]
+pub trait GraphemeAssertion {
+    /// - Asserts that the number of grapheme clusters in the string is exactly the given count.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::length::GraphemeAssertion;
+    ///
+    /// let thumbs_up = "\u{1F44D}\u{1F3FD}";
+    /// thumbs_up.should_have_grapheme_count(1);
+    /// ```
+    fn should_have_grapheme_count(&self, count: usize) -> &Self;
+
+    /// - Asserts that the number of grapheme clusters in the string is not the given count.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::length::GraphemeAssertion;
+    ///
+    /// let thumbs_up = "\u{1F44D}\u{1F3FD}";
+    /// thumbs_up.should_not_have_grapheme_count(2);
+    /// ```
+    fn should_not_have_grapheme_count(&self, count: usize) -> &Self;
+}
+
+#[cfg(feature = "unicode")]
+impl<T> GraphemeAssertion for T
+    where T: AsRef<str> {
+    fn should_have_grapheme_count(&self, count: usize) -> &Self {
+        self.should(&have_grapheme_count(count));
+        self
+    }
+
+    fn should_not_have_grapheme_count(&self, count: usize) -> &Self {
+        self.should_not(&have_grapheme_count(count));
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::assertions::string::length::LengthAssertion;
@@ -384,3 +590,154 @@ mod string_tests {
         name.should_not_have_length_in_exclusive_range(3..9);
     }
 }
+
+#[cfg(test)]
+mod size_tests {
+    use crate::assertions::string::length::{LengthAssertion, StringSizeAssertion};
+
+    #[test]
+    fn should_have_size_matching_the_length_of_an_ascii_string() {
+        let name = "assert4j";
+        name.should_have_size(8).should_have_length(8);
+    }
+
+    #[test]
+    fn should_have_size_counting_characters_of_a_multibyte_string() {
+        let name = "héllo";
+        name.should_have_size(5).should_have_length(6);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"日本語\" length 3 should be 9")]
+    fn should_have_size_but_it_counted_characters_not_bytes() {
+        let name = "日本語";
+        name.should_have_length(9).should_have_size(9);
+    }
+
+    #[test]
+    fn should_not_have_size() {
+        let name = String::from("héllo");
+        name.should_not_have_size(6);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"héllo\" length 5 should not be 5")]
+    fn should_not_have_size_but_was() {
+        let name = String::from("héllo");
+        name.should_not_have_size(5);
+    }
+
+    #[test]
+    fn should_have_at_least_size() {
+        let name = "日本語";
+        name.should_have_at_least_size(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"日本語\" length 3 should be atleast 4")]
+    fn should_have_at_least_size_but_was_not() {
+        let name = "日本語";
+        name.should_have_at_least_size(4);
+    }
+
+    #[test]
+    fn should_have_at_most_size() {
+        let name = "日本語";
+        name.should_have_at_most_size(3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_at_most_size_but_was_not() {
+        let name = "日本語";
+        name.should_have_at_most_size(2);
+    }
+
+    #[test]
+    fn should_have_size_in_the_inclusive_range() {
+        let name = "héllo";
+        name.should_have_size_in_inclusive_range(1..=5)
+            .should_have_length_in_inclusive_range(1..=6);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"Length\" 5 should fall in the range 6..=8")]
+    fn should_have_size_in_the_inclusive_range_but_was_not() {
+        let name = "héllo";
+        name.should_have_size_in_inclusive_range(6..=8);
+    }
+
+    #[test]
+    fn should_not_have_size_in_the_inclusive_range() {
+        let name = "héllo";
+        name.should_not_have_size_in_inclusive_range(6..=8);
+    }
+
+    #[test]
+    fn should_have_size_in_the_exclusive_range() {
+        let name = "héllo";
+        name.should_have_size_in_exclusive_range(5..6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_size_in_the_exclusive_range_but_was_not() {
+        let name = "héllo";
+        name.should_have_size_in_exclusive_range(6..8);
+    }
+
+    #[test]
+    fn should_not_have_size_in_the_exclusive_range() {
+        let name = "héllo";
+        name.should_not_have_size_in_exclusive_range(1..5);
+    }
+}
+
+#[cfg(all(test, feature = "unicode"))]
+mod grapheme_tests {
+    use crate::assertions::string::length::{GraphemeAssertion, StringSizeAssertion};
+
+    #[test]
+    fn should_have_grapheme_count() {
+        let greeting = "hello";
+        greeting.should_have_grapheme_count(5);
+    }
+
+    #[test]
+    fn should_have_grapheme_count_given_an_emoji_with_a_skin_tone_modifier() {
+        let thumbs_up = "\u{1F44D}\u{1F3FD}";
+        thumbs_up.should_have_grapheme_count(1).should_have_size(2);
+    }
+
+    #[test]
+    fn should_have_grapheme_count_given_a_combining_accent() {
+        let word = String::from("cafe\u{301}");
+        word.should_have_grapheme_count(4).should_have_size(5);
+    }
+
+    #[test]
+    fn should_have_grapheme_count_given_a_family_emoji() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        family.should_have_grapheme_count(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "should have 2 graphemes but had 1 graphemes (2 chars)")]
+    fn should_have_grapheme_count_but_it_was_the_char_count() {
+        let thumbs_up = "\u{1F44D}\u{1F3FD}";
+        thumbs_up.should_have_grapheme_count(2);
+    }
+
+    #[test]
+    fn should_not_have_grapheme_count() {
+        let thumbs_up = "\u{1F44D}\u{1F3FD}";
+        thumbs_up.should_not_have_grapheme_count(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "should not have 1 graphemes")]
+    fn should_not_have_grapheme_count_but_it_did() {
+        let thumbs_up = "\u{1F44D}\u{1F3FD}";
+        thumbs_up.should_not_have_grapheme_count(1);
+    }
+}
@@ -0,0 +1,357 @@
+use std::ops::{Range, RangeInclusive};
+
+use crate::matchers::{Should, ShouldNot};
+use crate::matchers::range::{have_line_count_in_exclusive_range, have_line_count_in_inclusive_range};
+use crate::matchers::string::lines::{
+    have_atleast_line_count, have_atmost_line_count, have_line_count, have_no_leading_whitespace,
+    have_no_trailing_whitespace,
+};
+
+/// LineCountAssertion enables assertions about the number of lines in string (or str) values.
+///
+/// Lines are counted using [str::lines]: a trailing line ending does not start a new line, so "a\n" has one line
+/// and an empty string has no lines.
+///
+/// It offers a fluent interface for chaining multiple assertions.
+///
+/// # Example
+/// ```
+/// use clearcheck::assertions::string::lines::LineCountAssertion;
+///
+/// let content = "[package]\nname = \"clearcheck\"\n";
+/// content
+///     .should_have_line_count(2)
+///     .should_have_line_count_in_inclusive_range(1..=3);
+/// ```
+pub trait LineCountAssertion {
+    /// - Asserts that the string has exactly the given number of lines.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::lines::LineCountAssertion;
+    ///
+    /// let content = "clear\ncheck\n";
+    /// content.should_have_line_count(2);
+    /// ```
+    fn should_have_line_count(&self, count: usize) -> &Self;
+
+    /// - Asserts that the string does not have the given number of lines.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::lines::LineCountAssertion;
+    ///
+    /// let content = "clear\ncheck\n";
+    /// content.should_not_have_line_count(3);
+    /// ```
+    fn should_not_have_line_count(&self, count: usize) -> &Self;
+
+    /// - Asserts that the string has at least the given number of lines.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::lines::LineCountAssertion;
+    ///
+    /// let content = "clear\ncheck\n";
+    /// content.should_have_at_least_line_count(1);
+    /// ```
+    fn should_have_at_least_line_count(&self, count: usize) -> &Self;
+
+    /// - Asserts that the string has at most the given number of lines.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::lines::LineCountAssertion;
+    ///
+    /// let content = "clear\ncheck\n";
+    /// content.should_have_at_most_line_count(2);
+    /// ```
+    fn should_have_at_most_line_count(&self, count: usize) -> &Self;
+
+    /// - Asserts that the number of lines in the string falls within the given inclusive range.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::lines::LineCountAssertion;
+    ///
+    /// let content = "clear\ncheck\n";
+    /// content.should_have_line_count_in_inclusive_range(1..=2);
+    /// ```
+    fn should_have_line_count_in_inclusive_range(&self, range: RangeInclusive<usize>) -> &Self;
+
+    /// - Asserts that the number of lines in the string does not fall within the given inclusive range.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::lines::LineCountAssertion;
+    ///
+    /// let content = "clear\ncheck\n";
+    /// content.should_not_have_line_count_in_inclusive_range(3..=5);
+    /// ```
+    fn should_not_have_line_count_in_inclusive_range(&self, range: RangeInclusive<usize>) -> &Self;
+
+    /// - Asserts that the number of lines in the string falls within the given exclusive range.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::lines::LineCountAssertion;
+    ///
+    /// let content = "clear\ncheck\n";
+    /// content.should_have_line_count_in_exclusive_range(1..3);
+    /// ```
+    fn should_have_line_count_in_exclusive_range(&self, range: Range<usize>) -> &Self;
+
+    /// - Asserts that the number of lines in the string does not fall within the given exclusive range.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::lines::LineCountAssertion;
+    ///
+    /// let content = "clear\ncheck\n";
+    /// content.should_not_have_line_count_in_exclusive_range(3..5);
+    /// ```
+    fn should_not_have_line_count_in_exclusive_range(&self, range: Range<usize>) -> &Self;
+}
+
+impl<T> LineCountAssertion for T
+    where T: AsRef<str> {
+    fn should_have_line_count(&self, count: usize) -> &Self {
+        self.should(&have_line_count(count));
+        self
+    }
+
+    fn should_not_have_line_count(&self, count: usize) -> &Self {
+        self.should_not(&have_line_count(count));
+        self
+    }
+
+    fn should_have_at_least_line_count(&self, count: usize) -> &Self {
+        self.should(&have_atleast_line_count(count));
+        self
+    }
+
+    fn should_have_at_most_line_count(&self, count: usize) -> &Self {
+        self.should(&have_atmost_line_count(count));
+        self
+    }
+
+    fn should_have_line_count_in_inclusive_range(&self, range: RangeInclusive<usize>) -> &Self {
+        self.as_ref().lines().count().should(&have_line_count_in_inclusive_range(range));
+        self
+    }
+
+    fn should_not_have_line_count_in_inclusive_range(&self, range: RangeInclusive<usize>) -> &Self {
+        self.as_ref().lines().count()
+            .should_not(&have_line_count_in_inclusive_range(range));
+        self
+    }
+
+    fn should_have_line_count_in_exclusive_range(&self, range: Range<usize>) -> &Self {
+        self.as_ref().lines().count().should(&have_line_count_in_exclusive_range(range));
+        self
+    }
+
+    fn should_not_have_line_count_in_exclusive_range(&self, range: Range<usize>) -> &Self {
+        self.as_ref().lines().count()
+            .should_not(&have_line_count_in_exclusive_range(range));
+        self
+    }
+}
+
+/// LineWhitespaceAssertion enables assertions about the whitespace at the beginning and the end of each line in string (or str) values.
+///
+/// Lines are split using [str::lines], so the line endings are not treated as trailing whitespace. Line numbers in the
+/// failure messages start at 1.
+///
+/// # Example
+/// ```
+/// use clearcheck::assertions::string::lines::LineWhitespaceAssertion;
+///
+/// let content = "[package]\nname = \"clearcheck\"\n";
+/// content
+///     .should_have_no_trailing_whitespace()
+///     .should_have_no_leading_whitespace();
+/// ```
+pub trait LineWhitespaceAssertion {
+    /// - Asserts that no line of the string ends with whitespace.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting the numbers of the offending lines.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::lines::LineWhitespaceAssertion;
+    ///
+    /// let content = "fn main() {\n    println!();\n}\n";
+    /// content.should_have_no_trailing_whitespace();
+    /// ```
+    fn should_have_no_trailing_whitespace(&self) -> &Self;
+
+    /// - Asserts that no line of the string begins with whitespace.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting the numbers of the offending lines.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::lines::LineWhitespaceAssertion;
+    ///
+    /// let content = "name = \"clearcheck\"\nedition = \"2021\"\n";
+    /// content.should_have_no_leading_whitespace();
+    /// ```
+    fn should_have_no_leading_whitespace(&self) -> &Self;
+}
+
+impl<T> LineWhitespaceAssertion for T
+    where T: AsRef<str> {
+    fn should_have_no_trailing_whitespace(&self) -> &Self {
+        self.should(&have_no_trailing_whitespace());
+        self
+    }
+
+    fn should_have_no_leading_whitespace(&self) -> &Self {
+        self.should(&have_no_leading_whitespace());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::string::lines::LineCountAssertion;
+
+    #[test]
+    fn should_have_no_lines_for_an_empty_string() {
+        let content = "";
+        content.should_have_line_count(0);
+    }
+
+    #[test]
+    fn should_have_line_count_for_a_single_line() {
+        let content = "clearcheck";
+        content.should_have_line_count(1);
+    }
+
+    #[test]
+    fn should_have_line_count_ignoring_a_trailing_newline() {
+        let content = "clearcheck\n";
+        content.should_have_line_count(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"clear\\ncheck\\n\" should have 3 lines but had 2")]
+    fn should_have_line_count_but_was_not() {
+        let content = "clear\ncheck\n";
+        content.should_have_line_count(3);
+    }
+
+    #[test]
+    fn should_not_have_line_count() {
+        let content = "clear\ncheck\n";
+        content.should_not_have_line_count(3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_have_line_count_but_had() {
+        let content = "clear\ncheck\n";
+        content.should_not_have_line_count(2);
+    }
+
+    #[test]
+    fn should_have_at_least_line_count() {
+        let content = "clear\ncheck\n";
+        content.should_have_at_least_line_count(2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_at_most_line_count_but_had_more() {
+        let content = "clear\ncheck\nrust";
+        content.should_have_at_most_line_count(2);
+    }
+
+    #[test]
+    fn should_have_line_count_in_inclusive_range() {
+        let content = "clear\ncheck\n";
+        content.should_have_line_count_in_inclusive_range(1..=2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_line_count_in_inclusive_range_but_was_not() {
+        let content = "clear\ncheck\n";
+        content.should_have_line_count_in_inclusive_range(3..=5);
+    }
+
+    #[test]
+    fn should_not_have_line_count_in_exclusive_range() {
+        let content = "clear\ncheck\n";
+        content.should_not_have_line_count_in_exclusive_range(3..5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_line_count_in_exclusive_range_but_was_not() {
+        let content = "clear\ncheck\n";
+        content.should_have_line_count_in_exclusive_range(1..2);
+    }
+}
+
+#[cfg(test)]
+mod string_tests {
+    use crate::assertions::string::lines::LineCountAssertion;
+
+    #[test]
+    fn should_have_line_count() {
+        let content = String::from("clear\ncheck\n");
+        content.should_have_line_count(2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_line_count_but_was_not() {
+        let content = String::from("clear\ncheck\n");
+        content.should_have_line_count(3);
+    }
+}
+
+#[cfg(test)]
+mod whitespace_tests {
+    use crate::assertions::string::lines::LineWhitespaceAssertion;
+
+    #[test]
+    fn should_have_no_trailing_whitespace() {
+        let content = "fn main() {\r\n    println!();\r\n}\r\n";
+        content.should_have_no_trailing_whitespace();
+    }
+
+    #[test]
+    fn should_have_no_trailing_whitespace_given_an_empty_string() {
+        let content = String::new();
+        content.should_have_no_trailing_whitespace();
+    }
+
+    #[test]
+    #[should_panic(expected = "should have no trailing whitespace but it was found on lines [2, 3]")]
+    fn should_have_no_trailing_whitespace_but_lines_had_trailing_whitespace() {
+        let content = "fn main() {\n    println!(); \n}\t\n";
+        content.should_have_no_trailing_whitespace();
+    }
+
+    #[test]
+    fn should_have_no_leading_whitespace() {
+        let content = String::from("[package]\nname = \"clearcheck\" \n");
+        content.should_have_no_leading_whitespace();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"[package]\\n name = \\\"clearcheck\\\"\" should have no leading whitespace but it was found on lines [2]")]
+    fn should_have_no_leading_whitespace_but_a_line_had_leading_whitespace() {
+        let content = "[package]\n name = \"clearcheck\"";
+        content.should_have_no_leading_whitespace();
+    }
+}
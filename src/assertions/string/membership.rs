@@ -1,6 +1,8 @@
+use std::ops::RangeInclusive;
+
 use crate::matchers::{Should, ShouldNot};
-use crate::matchers::string::empty::be_empty;
-use crate::matchers::string::membership::{contain, contain_a_digit, contain_all_characters, contain_any_of_characters, contain_character, contain_ignoring_case, contain_only_digits, not_contain_digits};
+use crate::matchers::string::empty::{be_blank, be_empty};
+use crate::matchers::string::membership::{contain, contain_a_digit, contain_all_characters, contain_any_of_characters, contain_character, contain_control_characters, contain_ignoring_case, contain_only_digits, contain_text_between, contain_whitespace, have_char_count, have_char_count_in_inclusive_range, not_contain_control_characters, not_contain_digits, not_contain_whitespace};
 
 /// MembershipAssertion enables assertions about the presence or absence of characters, substrings, or digits within string (or str) values.
 ///
@@ -78,6 +80,42 @@ pub trait MembershipAssertion {
     /// ```
     fn should_not_contain_character(&self, ch: char) -> &Self;
 
+    /// - Asserts that the string contains the given character exactly the given number of times.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting the actual number of times the character occurred.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let fruit = "banana";
+    /// fruit.should_have_char_count('a', 3);
+    /// ```
+    fn should_have_char_count(&self, ch: char, count: usize) -> &Self;
+
+    /// - Asserts that the string does not contain the given character exactly the given number of times.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let fruit = "banana";
+    /// fruit.should_not_have_char_count('a', 2);
+    /// ```
+    fn should_not_have_char_count(&self, ch: char, count: usize) -> &Self;
+
+    /// - Asserts that the number of times the string contains the given character falls within the given inclusive range.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting the actual number of times the character occurred.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let fruit = "banana";
+    /// fruit.should_have_char_count_in_range('n', 1..=2);
+    /// ```
+    fn should_have_char_count_in_range(&self, ch: char, range: RangeInclusive<usize>) -> &Self;
+
     /// - Asserts that the string contains all the given characters.
     /// - Returns a reference to self for fluent chaining.
     /// - Panics if the assertion fails.
@@ -126,6 +164,54 @@ pub trait MembershipAssertion {
     /// ```
     fn should_not_contain_any_characters(&self, chars: Vec<char>) -> &Self;
 
+    /// - Asserts that the string contains a whitespace character (as defined by [char::is_whitespace]).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let phrase = "clear check";
+    /// phrase.should_contain_whitespace();
+    /// ```
+    fn should_contain_whitespace(&self) -> &Self;
+
+    /// - Asserts that the string does not contain any whitespace character (as defined by [char::is_whitespace]).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting the char index (not the byte index) of the first whitespace found.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let slug = "clear-check";
+    /// slug.should_not_contain_whitespace();
+    /// ```
+    fn should_not_contain_whitespace(&self) -> &Self;
+
+    /// - Asserts that the string contains a control character (as defined by [char::is_control]).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let alert = "clear\u{0007}check";
+    /// alert.should_contain_control_characters();
+    /// ```
+    fn should_contain_control_characters(&self) -> &Self;
+
+    /// - Asserts that the string does not contain any control character (as defined by [char::is_control]).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting the codepoint and the index of the first control character found.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let sanitized = "clear check";
+    /// sanitized.should_not_contain_control_characters();
+    /// ```
+    fn should_not_contain_control_characters(&self) -> &Self;
+
     /// - Asserts that the string contains the given substring.
     /// - Returns a reference to self for fluent chaining.
     /// - Panics if the assertion fails.
@@ -197,6 +283,54 @@ pub trait MembershipAssertion {
     /// email.should_not_be_empty();
     /// ```
     fn should_not_be_empty(&self) -> &Self;
+
+    /// - Asserts that the string is blank (empty or containing only whitespace).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let email = " \t\n";
+    /// email.should_be_blank();
+    /// ```
+    fn should_be_blank(&self) -> &Self;
+
+    /// - Asserts that the string is not blank (contains at least one non-whitespace character).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let email = " john@gmail.com ";
+    /// email.should_not_be_blank();
+    /// ```
+    fn should_not_be_blank(&self) -> &Self;
+
+    /// - Asserts that the text between the first occurrence of the start marker and the next occurrence of the end marker equals the expected text.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, telling whether the start marker was not found, the end marker was not found, or the text differed.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let html = "say <b>hello</b> world";
+    /// html.should_contain_text_between("<b>", "</b>", "hello");
+    /// ```
+    fn should_contain_text_between(&self, start: &'static str, end: &'static str, expected: &'static str) -> &Self;
+
+    /// - Asserts that the string does not contain the expected text between the first start marker and the next end marker.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let html = "say <b>hello</b> world";
+    /// html.should_not_contain_text_between("<b>", "</b>", "world");
+    /// ```
+    fn should_not_contain_text_between(&self, start: &'static str, end: &'static str, expected: &'static str) -> &Self;
 }
 
 impl<T> MembershipAssertion for T
@@ -226,6 +360,21 @@ impl<T> MembershipAssertion for T
         self
     }
 
+    fn should_have_char_count(&self, ch: char, count: usize) -> &Self {
+        self.should(&have_char_count(ch, count));
+        self
+    }
+
+    fn should_not_have_char_count(&self, ch: char, count: usize) -> &Self {
+        self.should_not(&have_char_count(ch, count));
+        self
+    }
+
+    fn should_have_char_count_in_range(&self, ch: char, range: RangeInclusive<usize>) -> &Self {
+        self.should(&have_char_count_in_inclusive_range(ch, range));
+        self
+    }
+
     fn should_contain_all_characters(&self, chars: Vec<char>) -> &Self {
         self.should(&contain_all_characters(chars));
         self
@@ -246,6 +395,26 @@ impl<T> MembershipAssertion for T
         self
     }
 
+    fn should_contain_whitespace(&self) -> &Self {
+        self.should(&contain_whitespace());
+        self
+    }
+
+    fn should_not_contain_whitespace(&self) -> &Self {
+        self.should(&not_contain_whitespace());
+        self
+    }
+
+    fn should_contain_control_characters(&self) -> &Self {
+        self.should(&contain_control_characters());
+        self
+    }
+
+    fn should_not_contain_control_characters(&self) -> &Self {
+        self.should(&not_contain_control_characters());
+        self
+    }
+
     fn should_contain(&self, substr: &'static str) -> &Self {
         self.should(&contain(substr));
         self
@@ -275,6 +444,26 @@ impl<T> MembershipAssertion for T
         self.should_not(&be_empty());
         self
     }
+
+    fn should_be_blank(&self) -> &Self {
+        self.should(&be_blank());
+        self
+    }
+
+    fn should_not_be_blank(&self) -> &Self {
+        self.should_not(&be_blank());
+        self
+    }
+
+    fn should_contain_text_between(&self, start: &'static str, end: &'static str, expected: &'static str) -> &Self {
+        self.should(&contain_text_between(start, end, expected));
+        self
+    }
+
+    fn should_not_contain_text_between(&self, start: &'static str, end: &'static str, expected: &'static str) -> &Self {
+        self.should_not(&contain_text_between(start, end, expected));
+        self
+    }
 }
 
 #[cfg(test)]
@@ -475,6 +664,116 @@ mod tests {
         let name = "";
         name.should_not_be_empty();
     }
+
+    #[test]
+    fn should_contain_whitespace() {
+        let phrase = "clear\tcheck";
+        phrase.should_contain_whitespace();
+    }
+
+    #[test]
+    fn should_contain_non_breaking_space_as_whitespace() {
+        let phrase = "clear\u{00A0}check";
+        phrase.should_contain_whitespace();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"clearcheck\" should contain a whitespace")]
+    fn should_contain_whitespace_but_it_did_not() {
+        let slug = "clearcheck";
+        slug.should_contain_whitespace();
+    }
+
+    #[test]
+    fn should_not_contain_whitespace() {
+        let slug = "clear-check";
+        slug.should_not_contain_whitespace();
+    }
+
+    #[test]
+    #[should_panic(expected = "should not contain a whitespace but found one at index 5")]
+    fn should_not_contain_whitespace_but_it_did() {
+        let slug = "clear\u{00A0}check";
+        slug.should_not_contain_whitespace();
+    }
+
+    #[test]
+    #[should_panic(expected = "should not contain a whitespace but found one at index 4")]
+    fn should_not_contain_whitespace_reporting_a_char_index() {
+        let slug = "café check";
+        slug.should_not_contain_whitespace();
+    }
+
+    #[test]
+    fn should_contain_control_characters() {
+        let alert = "clear\u{0007}check";
+        alert.should_contain_control_characters();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"clearcheck\" should contain a control character")]
+    fn should_contain_control_characters_but_it_did_not() {
+        let sanitized = "clearcheck";
+        sanitized.should_contain_control_characters();
+    }
+
+    #[test]
+    fn should_not_contain_control_characters() {
+        let sanitized = "clear check\u{00A0}✓";
+        sanitized.should_not_contain_control_characters();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"clear\\u{7}check\" should not contain a control character but found U+0007 at index 5")]
+    fn should_not_contain_control_characters_but_it_had_a_bell() {
+        let alert = "clear\u{0007}check";
+        alert.should_not_contain_control_characters();
+    }
+
+    #[test]
+    #[should_panic(expected = "should not contain a control character but found U+0009 at index 2")]
+    fn should_not_contain_control_characters_but_it_had_a_tab_after_multibyte_characters() {
+        let value = "✓✓\tcheck";
+        value.should_not_contain_control_characters();
+    }
+
+    #[test]
+    fn should_be_blank_given_an_empty_string() {
+        let name = "";
+        name.should_be_blank();
+    }
+
+    #[test]
+    fn should_be_blank_given_only_spaces() {
+        let name = "   ";
+        name.should_be_blank();
+    }
+
+    #[test]
+    fn should_be_blank_given_a_tab_and_a_newline() {
+        let name = "\t\n";
+        name.should_be_blank();
+    }
+
+    #[test]
+    #[should_panic(expected = "\" John \" should be blank (empty or only whitespace)")]
+    fn should_be_blank_but_was_not() {
+        let name = " John ";
+        name.should_be_blank();
+    }
+
+    #[test]
+    fn should_not_be_blank() {
+        let name = " John ";
+        name.should_not_be_blank();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"\\t\\n\" should not be blank (empty or only whitespace)")]
+    fn should_not_be_blank_but_was() {
+        let name = "\t\n";
+        name.should_not_be_blank();
+    }
 }
 
 #[cfg(test)]
@@ -675,4 +974,164 @@ mod string_tests {
         let name = String::from("");
         name.should_not_be_empty();
     }
+
+    #[test]
+    fn should_contain_whitespace() {
+        let phrase = String::from("clear check");
+        phrase.should_contain_whitespace();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_whitespace_but_it_did_not() {
+        let slug = String::from("clearcheck");
+        slug.should_contain_whitespace();
+    }
+
+    #[test]
+    fn should_not_contain_whitespace() {
+        let slug = String::from("clear-check");
+        slug.should_not_contain_whitespace();
+    }
+
+    #[test]
+    #[should_panic(expected = "should not contain a whitespace but found one at index 4")]
+    fn should_not_contain_whitespace_but_it_did() {
+        let slug = String::from("über\tcheck");
+        slug.should_not_contain_whitespace();
+    }
+
+    #[test]
+    fn should_be_blank() {
+        let name = String::from(" \t\n");
+        name.should_be_blank();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_blank_but_was() {
+        let name = String::from("   ");
+        name.should_not_be_blank();
+    }
+}
+
+#[cfg(test)]
+mod text_between_tests {
+    use crate::assertions::string::membership::MembershipAssertion;
+
+    #[test]
+    fn should_contain_text_between() {
+        let html = "say <b>hello</b> world";
+        html.should_contain_text_between("<b>", "</b>", "hello");
+    }
+
+    #[test]
+    fn should_contain_text_between_the_first_start_and_the_next_end_marker() {
+        let html = String::from("<b>hello</b> and <b>world</b>");
+        html.should_contain_text_between("<b>", "</b>", "hello");
+    }
+
+    #[test]
+    fn should_contain_empty_text_between() {
+        let html = "<b></b>";
+        html.should_contain_text_between("<b>", "</b>", "");
+    }
+
+    #[test]
+    #[should_panic(expected = "\"say hello\" should contain \"hello\" between \"<b>\" and \"</b>\" but the start marker \"<b>\" was not found")]
+    fn should_contain_text_between_but_the_start_marker_was_not_found() {
+        let html = "say hello";
+        html.should_contain_text_between("<b>", "</b>", "hello");
+    }
+
+    #[test]
+    #[should_panic(expected = "\"</b>say <b>hello\" should contain \"hello\" between \"<b>\" and \"</b>\" but the end marker \"</b>\" was not found after the start marker")]
+    fn should_contain_text_between_but_the_end_marker_was_not_found() {
+        let html = "</b>say <b>hello";
+        html.should_contain_text_between("<b>", "</b>", "hello");
+    }
+
+    #[test]
+    #[should_panic(expected = "\"say <b>world</b>\" should contain \"hello\" between \"<b>\" and \"</b>\" but found \"world\"")]
+    fn should_contain_text_between_but_the_text_differed() {
+        let html = "say <b>world</b>";
+        html.should_contain_text_between("<b>", "</b>", "hello");
+    }
+
+    #[test]
+    fn should_not_contain_text_between() {
+        let html = "say <b>world</b>";
+        html.should_not_contain_text_between("<b>", "</b>", "hello");
+    }
+
+    #[test]
+    #[should_panic(expected = "\"say <b>hello</b>\" should not contain \"hello\" between \"<b>\" and \"</b>\"")]
+    fn should_not_contain_text_between_but_it_did() {
+        let html = "say <b>hello</b>";
+        html.should_not_contain_text_between("<b>", "</b>", "hello");
+    }
+}
+
+#[cfg(test)]
+mod char_count_tests {
+    use crate::assertions::string::membership::MembershipAssertion;
+
+    #[test]
+    fn should_have_char_count() {
+        let fruit = "banana";
+        fruit.should_have_char_count('a', 3).should_have_char_count('b', 1);
+    }
+
+    #[test]
+    fn should_have_char_count_of_an_absent_character() {
+        let fruit = String::from("banana");
+        fruit.should_have_char_count('z', 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"banana\" should contain the character 'a' 2 times but contained it 3 times")]
+    fn should_have_char_count_but_it_did_not() {
+        let fruit = "banana";
+        fruit.should_have_char_count('a', 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"banana\" should contain the character 'z' 1 times but contained it 0 times")]
+    fn should_have_char_count_but_the_character_was_absent() {
+        let fruit = "banana";
+        fruit.should_have_char_count('z', 1);
+    }
+
+    #[test]
+    fn should_not_have_char_count() {
+        let fruit = "banana";
+        fruit.should_not_have_char_count('a', 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"banana\" should not contain the character 'n' 2 times")]
+    fn should_not_have_char_count_but_it_did() {
+        let fruit = "banana";
+        fruit.should_not_have_char_count('n', 2);
+    }
+
+    #[test]
+    fn should_have_char_count_in_range() {
+        let fruit = "banana";
+        fruit.should_have_char_count_in_range('n', 1..=2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"banana\" should contain the character 'a' a number of times in the range 0..=2 but contained it 3 times")]
+    fn should_have_char_count_in_range_but_it_did_not() {
+        let fruit = "banana";
+        fruit.should_have_char_count_in_range('a', 0..=2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"banana\" should contain the character 'z' a number of times in the range 1..=2 but contained it 0 times")]
+    fn should_have_char_count_in_range_but_the_character_was_absent() {
+        let fruit = "banana";
+        fruit.should_have_char_count_in_range('z', 1..=2);
+    }
 }
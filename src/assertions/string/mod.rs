@@ -1,8 +1,17 @@
 pub mod boundary;
 pub mod case;
 pub mod equal;
+#[cfg(feature = "json")]
+pub mod json;
 pub mod length;
+pub mod lines;
 pub mod membership;
+#[cfg(feature = "unicode")]
+pub mod normalization;
 pub mod numeric;
 #[cfg(feature = "regex")]
 pub mod regex;
+pub mod sort;
+pub mod structure;
+pub mod transform;
+pub mod words;
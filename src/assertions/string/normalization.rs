@@ -0,0 +1,142 @@
+use crate::matchers::{Should, ShouldNot};
+use crate::matchers::string::normalization::{be_nfc_normalized, be_nfd_normalized};
+
+/// NormalizationAssertion enables assertions about the Unicode normalization form of string (or str) values.
+///
+/// The string is normalized and compared with the original, so the assertions pass only when the string is already in
+/// the given normalization form.
+pub trait NormalizationAssertion {
+    /// - Asserts that the string is in the Unicode Normalization Form C (NFC), composed characters.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::normalization::NormalizationAssertion;
+    ///
+    /// let word = "caf\u{e9}";
+    /// word.should_be_nfc_normalized();
+    /// ```
+    fn should_be_nfc_normalized(&self) -> &Self;
+
+    /// - Asserts that the string is not in the Unicode Normalization Form C (NFC).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::normalization::NormalizationAssertion;
+    ///
+    /// let word = "cafe\u{301}";
+    /// word.should_not_be_nfc_normalized();
+    /// ```
+    fn should_not_be_nfc_normalized(&self) -> &Self;
+
+    /// - Asserts that the string is in the Unicode Normalization Form D (NFD), decomposed characters.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::normalization::NormalizationAssertion;
+    ///
+    /// let word = "cafe\u{301}";
+    /// word.should_be_nfd_normalized();
+    /// ```
+    fn should_be_nfd_normalized(&self) -> &Self;
+
+    /// - Asserts that the string is not in the Unicode Normalization Form D (NFD).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::normalization::NormalizationAssertion;
+    ///
+    /// let word = "caf\u{e9}";
+    /// word.should_not_be_nfd_normalized();
+    /// ```
+    fn should_not_be_nfd_normalized(&self) -> &Self;
+}
+
+impl<T> NormalizationAssertion for T
+    where T: AsRef<str> {
+    fn should_be_nfc_normalized(&self) -> &Self {
+        self.should(&be_nfc_normalized());
+        self
+    }
+
+    fn should_not_be_nfc_normalized(&self) -> &Self {
+        self.should_not(&be_nfc_normalized());
+        self
+    }
+
+    fn should_be_nfd_normalized(&self) -> &Self {
+        self.should(&be_nfd_normalized());
+        self
+    }
+
+    fn should_not_be_nfd_normalized(&self) -> &Self {
+        self.should_not(&be_nfd_normalized());
+        self
+    }
+}
+
+#[cfg(all(test, feature = "unicode"))]
+mod tests {
+    use crate::assertions::string::normalization::NormalizationAssertion;
+
+    #[test]
+    fn should_be_nfc_normalized() {
+        let word = "caf\u{e9}";
+        word.should_be_nfc_normalized();
+    }
+
+    #[test]
+    fn should_be_nfc_normalized_given_ascii() {
+        let word = String::from("clearcheck");
+        word.should_be_nfc_normalized().should_be_nfd_normalized();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"cafe\\u{301}\" should be NFC normalized but its NFC form was \"café\"")]
+    fn should_be_nfc_normalized_but_had_a_combining_accent() {
+        let word = "cafe\u{301}";
+        word.should_be_nfc_normalized();
+    }
+
+    #[test]
+    fn should_not_be_nfc_normalized() {
+        let word = "cafe\u{301}";
+        word.should_not_be_nfc_normalized();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"café\" should not be NFC normalized")]
+    fn should_not_be_nfc_normalized_but_was() {
+        let word = "caf\u{e9}";
+        word.should_not_be_nfc_normalized();
+    }
+
+    #[test]
+    fn should_be_nfd_normalized() {
+        let word = "cafe\u{301}";
+        word.should_be_nfd_normalized();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"café\" should be NFD normalized but its NFD form was \"cafe\\u{301}\"")]
+    fn should_be_nfd_normalized_but_had_a_precomposed_character() {
+        let word = "caf\u{e9}";
+        word.should_be_nfd_normalized();
+    }
+
+    #[test]
+    fn should_not_be_nfd_normalized() {
+        let word = "caf\u{e9}";
+        word.should_not_be_nfd_normalized();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"cafe\\u{301}\" should not be NFD normalized")]
+    fn should_not_be_nfd_normalized_but_was() {
+        let word = "cafe\u{301}";
+        word.should_not_be_nfd_normalized();
+    }
+}
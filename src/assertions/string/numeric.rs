@@ -1,7 +1,8 @@
+use std::fmt::{Debug, Display};
 use std::str::FromStr;
 
 use crate::matchers::{Should, ShouldNot};
-use crate::matchers::string::numeric::be_numeric;
+use crate::matchers::string::numeric::{be_digits_in_radix, be_numeric, be_parsable_as, have_decimal_places, parse_to};
 
 /// NumericAssertion enables assertions about whether a string (or str) is numeric.
 pub trait NumericAssertion {
@@ -44,6 +45,251 @@ impl<S> NumericAssertion for S
     }
 }
 
+/// ParseAssertion enables assertions about whether a string (or str) parses into a number.
+///
+/// On failure, the panic message includes the error reported by the parse.
+pub trait ParseAssertion {
+    /// - Asserts that the string is parsable as an i64.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::numeric::ParseAssertion;
+    ///
+    /// let value = "42";
+    /// value.should_be_parsable_as_i64();
+    /// ```
+    fn should_be_parsable_as_i64(&self) -> &Self;
+
+    /// - Asserts that the string is not parsable as an i64.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::numeric::ParseAssertion;
+    ///
+    /// let value = "4.2e1";
+    /// value.should_not_be_parsable_as_i64();
+    /// ```
+    fn should_not_be_parsable_as_i64(&self) -> &Self;
+
+    /// - Asserts that the string is parsable as an f64.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::numeric::ParseAssertion;
+    ///
+    /// let value = "4.2e1";
+    /// value.should_be_parsable_as_f64();
+    /// ```
+    fn should_be_parsable_as_f64(&self) -> &Self;
+
+    /// - Asserts that the string is not parsable as an f64.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::numeric::ParseAssertion;
+    ///
+    /// let value = "forty-two";
+    /// value.should_not_be_parsable_as_f64();
+    /// ```
+    fn should_not_be_parsable_as_f64(&self) -> &Self;
+
+    /// - Asserts that the string parses into the given expected value.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::numeric::ParseAssertion;
+    ///
+    /// let value = "4.2e1";
+    /// value.should_parse_to(42.0);
+    /// ```
+    fn should_parse_to<N>(&self, expected: N) -> &Self
+        where
+            N: FromStr + PartialEq + Debug,
+            N::Err: Display;
+
+    /// - Asserts that the string does not parse into the given expected value.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::numeric::ParseAssertion;
+    ///
+    /// let value = "42";
+    /// value.should_not_parse_to(43);
+    /// ```
+    fn should_not_parse_to<N>(&self, expected: N) -> &Self
+        where
+            N: FromStr + PartialEq + Debug,
+            N::Err: Display;
+}
+
+impl<S> ParseAssertion for S
+    where S: AsRef<str>
+{
+    fn should_be_parsable_as_i64(&self) -> &Self {
+        self.should(&be_parsable_as::<i64>());
+        self
+    }
+
+    fn should_not_be_parsable_as_i64(&self) -> &Self {
+        self.should_not(&be_parsable_as::<i64>());
+        self
+    }
+
+    fn should_be_parsable_as_f64(&self) -> &Self {
+        self.should(&be_parsable_as::<f64>());
+        self
+    }
+
+    fn should_not_be_parsable_as_f64(&self) -> &Self {
+        self.should_not(&be_parsable_as::<f64>());
+        self
+    }
+
+    fn should_parse_to<N>(&self, expected: N) -> &Self
+        where
+            N: FromStr + PartialEq + Debug,
+            N::Err: Display,
+    {
+        self.should(&parse_to(expected));
+        self
+    }
+
+    fn should_not_parse_to<N>(&self, expected: N) -> &Self
+        where
+            N: FromStr + PartialEq + Debug,
+            N::Err: Display,
+    {
+        self.should_not(&parse_to(expected));
+        self
+    }
+}
+
+/// RadixAssertion enables assertions about whether a string (or str) consists only of digits in a given radix.
+///
+/// Empty strings fail these assertions. On failure, the panic message names the radix and the first invalid character.
+pub trait RadixAssertion {
+    /// - Asserts that the string is not empty and contains only hexadecimal digits (0-9, a-f, A-F).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::numeric::RadixAssertion;
+    ///
+    /// let value = "1A3F";
+    /// value.should_only_contain_hex_digits();
+    /// ```
+    fn should_only_contain_hex_digits(&self) -> &Self;
+
+    /// - Asserts that the string is not empty and contains only binary digits (0 and 1).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::numeric::RadixAssertion;
+    ///
+    /// let value = "1011";
+    /// value.should_only_contain_binary_digits();
+    /// ```
+    fn should_only_contain_binary_digits(&self) -> &Self;
+
+    /// - Asserts that the string is not empty and contains only digits in the given radix.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails or if the radix is not in the range 2..=36.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::numeric::RadixAssertion;
+    ///
+    /// let value = "755";
+    /// value.should_be_valid_number_in_radix(8);
+    /// ```
+    fn should_be_valid_number_in_radix(&self, radix: u32) -> &Self;
+
+    /// - Asserts that the string is empty or contains a character that is not a digit in the given radix.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails or if the radix is not in the range 2..=36.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::numeric::RadixAssertion;
+    ///
+    /// let value = "789";
+    /// value.should_not_be_valid_number_in_radix(8);
+    /// ```
+    fn should_not_be_valid_number_in_radix(&self, radix: u32) -> &Self;
+}
+
+impl<S> RadixAssertion for S
+    where S: AsRef<str>
+{
+    fn should_only_contain_hex_digits(&self) -> &Self {
+        self.should(&be_digits_in_radix(16));
+        self
+    }
+
+    fn should_only_contain_binary_digits(&self) -> &Self {
+        self.should(&be_digits_in_radix(2));
+        self
+    }
+
+    fn should_be_valid_number_in_radix(&self, radix: u32) -> &Self {
+        self.should(&be_digits_in_radix(radix));
+        self
+    }
+
+    fn should_not_be_valid_number_in_radix(&self, radix: u32) -> &Self {
+        self.should_not(&be_digits_in_radix(radix));
+        self
+    }
+}
+
+/// DecimalPlacesAssertion enables assertions about the number of decimal places in a string (or str) holding a plain decimal number.
+///
+/// A number without a decimal point has 0 decimal places. Scientific notation is not a plain decimal number and fails these assertions.
+pub trait DecimalPlacesAssertion {
+    /// - Asserts that the string is a plain decimal number with exactly the given number of decimal places.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting the actual number of decimal places.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::numeric::DecimalPlacesAssertion;
+    ///
+    /// let value = "12.34";
+    /// value.should_have_decimal_places(2);
+    /// ```
+    fn should_have_decimal_places(&self, places: usize) -> &Self;
+
+    /// - Asserts that the string is not a plain decimal number with exactly the given number of decimal places.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::numeric::DecimalPlacesAssertion;
+    ///
+    /// let value = "12.340";
+    /// value.should_not_have_decimal_places(2);
+    /// ```
+    fn should_not_have_decimal_places(&self, places: usize) -> &Self;
+}
+
+impl<S> DecimalPlacesAssertion for S
+    where S: AsRef<str>
+{
+    fn should_have_decimal_places(&self, places: usize) -> &Self {
+        self.should(&have_decimal_places(places));
+        self
+    }
+
+    fn should_not_have_decimal_places(&self, places: usize) -> &Self {
+        self.should_not(&have_decimal_places(places));
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::assertions::string::numeric::NumericAssertion;
@@ -105,3 +351,252 @@ mod string_tests {
         value.should_not_be_numeric::<i32>();
     }
 }
+
+#[cfg(test)]
+mod parse_tests {
+    use crate::assertions::string::numeric::ParseAssertion;
+
+    #[test]
+    fn should_be_parsable_as_i64() {
+        let value = "42";
+        value.should_be_parsable_as_i64();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"4.2e1\" should be parsable as i64 but failed with invalid digit found in string")]
+    fn should_be_parsable_as_i64_but_was_not() {
+        let value = "4.2e1";
+        value.should_be_parsable_as_i64();
+    }
+
+    #[test]
+    fn should_not_be_parsable_as_i64() {
+        let value = "forty-two";
+        value.should_not_be_parsable_as_i64();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_parsable_as_i64_but_was() {
+        let value = "42";
+        value.should_not_be_parsable_as_i64();
+    }
+
+    #[test]
+    fn should_be_parsable_as_f64() {
+        let value = "4.2e1";
+        value.should_be_parsable_as_f64();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"forty-two\" should be parsable as f64 but failed with invalid float literal")]
+    fn should_be_parsable_as_f64_but_was_not() {
+        let value = "forty-two";
+        value.should_be_parsable_as_f64();
+    }
+
+    #[test]
+    fn should_not_be_parsable_as_f64() {
+        let value = String::from("forty-two");
+        value.should_not_be_parsable_as_f64();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_parsable_as_f64_but_was() {
+        let value = "42";
+        value.should_not_be_parsable_as_f64();
+    }
+
+    #[test]
+    fn should_parse_to_i64() {
+        let value = "42";
+        value.should_parse_to(42_i64);
+    }
+
+    #[test]
+    fn should_parse_to_f64() {
+        let value = String::from("4.2e1");
+        value.should_parse_to(42.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"42\" should parse to 43 but parsed to 42")]
+    fn should_parse_to_but_parsed_to_a_different_value() {
+        let value = "42";
+        value.should_parse_to(43);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"forty-two\" should parse to 42 but failed with invalid digit found in string")]
+    fn should_parse_to_but_failed_to_parse() {
+        let value = "forty-two";
+        value.should_parse_to(42);
+    }
+
+    #[test]
+    fn should_not_parse_to() {
+        let value = "42";
+        value.should_not_parse_to(43);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_parse_to_but_it_did() {
+        let value = "42";
+        value.should_not_parse_to(42);
+    }
+}
+
+#[cfg(test)]
+mod radix_tests {
+    use crate::assertions::string::numeric::RadixAssertion;
+
+    #[test]
+    fn should_only_contain_hex_digits() {
+        let value = "1A3F";
+        value.should_only_contain_hex_digits();
+    }
+
+    #[test]
+    fn should_only_contain_lowercase_hex_digits() {
+        let value = String::from("deadbeef");
+        value.should_only_contain_hex_digits();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"1A3G\" should only contain digits in radix 16 but found 'G' at index 3")]
+    fn should_only_contain_hex_digits_but_it_did_not() {
+        let value = "1A3G";
+        value.should_only_contain_hex_digits();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"\" should only contain digits in radix 16 but it was empty")]
+    fn should_only_contain_hex_digits_but_was_empty() {
+        let value = "";
+        value.should_only_contain_hex_digits();
+    }
+
+    #[test]
+    fn should_only_contain_binary_digits() {
+        let value = "1011";
+        value.should_only_contain_binary_digits();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"1021\" should only contain digits in radix 2 but found '2' at index 2")]
+    fn should_only_contain_binary_digits_but_it_did_not() {
+        let value = "1021";
+        value.should_only_contain_binary_digits();
+    }
+
+    #[test]
+    #[should_panic(expected = "found '-' at index 0")]
+    fn should_only_contain_binary_digits_but_it_had_a_sign() {
+        let value = "-101";
+        value.should_only_contain_binary_digits();
+    }
+
+    #[test]
+    fn should_be_valid_number_in_radix_8() {
+        let value = "755";
+        value.should_be_valid_number_in_radix(8);
+    }
+
+    #[test]
+    fn should_be_valid_number_in_radix_36() {
+        let value = "Zz09";
+        value.should_be_valid_number_in_radix(36);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"789\" should only contain digits in radix 8 but found '8' at index 1")]
+    fn should_be_valid_number_in_radix_8_but_was_not() {
+        let value = "789";
+        value.should_be_valid_number_in_radix(8);
+    }
+
+    #[test]
+    #[should_panic(expected = "radix should be in the range 2..=36 but was 37")]
+    fn should_be_valid_number_in_an_unsupported_radix() {
+        let value = "10";
+        value.should_be_valid_number_in_radix(37);
+    }
+
+    #[test]
+    fn should_not_be_valid_number_in_radix() {
+        let value = "789";
+        value.should_not_be_valid_number_in_radix(8);
+    }
+
+    #[test]
+    fn should_not_be_valid_number_in_radix_given_an_empty_string() {
+        let value = "";
+        value.should_not_be_valid_number_in_radix(10);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"1A3F\" should not only contain digits in radix 16")]
+    fn should_not_be_valid_number_in_radix_but_was() {
+        let value = "1A3F";
+        value.should_not_be_valid_number_in_radix(16);
+    }
+}
+
+#[cfg(test)]
+mod decimal_places_tests {
+    use crate::assertions::string::numeric::DecimalPlacesAssertion;
+
+    #[test]
+    fn should_have_decimal_places() {
+        let value = "12.34";
+        value.should_have_decimal_places(2);
+    }
+
+    #[test]
+    fn should_have_trailing_zeros_counted_as_decimal_places() {
+        let value = String::from("12.340");
+        value.should_have_decimal_places(3);
+    }
+
+    #[test]
+    fn should_have_zero_decimal_places_without_a_decimal_point() {
+        let value = "12";
+        value.should_have_decimal_places(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"12.340\" should have 2 decimal places but had 3")]
+    fn should_have_decimal_places_but_had_more() {
+        let value = "12.340";
+        value.should_have_decimal_places(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"12\" should have 2 decimal places but had 0")]
+    fn should_have_decimal_places_but_had_no_decimal_point() {
+        let value = "12";
+        value.should_have_decimal_places(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"1.5e3\" should have 1 decimal places but it was not a plain decimal number")]
+    fn should_have_decimal_places_but_was_in_scientific_notation() {
+        let value = "1.5e3";
+        value.should_have_decimal_places(1);
+    }
+
+    #[test]
+    fn should_not_have_decimal_places() {
+        let value = "12.340";
+        value.should_not_have_decimal_places(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"12.34\" should not have 2 decimal places")]
+    fn should_not_have_decimal_places_but_had() {
+        let value = "12.34";
+        value.should_not_have_decimal_places(2);
+    }
+}
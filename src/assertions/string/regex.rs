@@ -1,7 +1,7 @@
 use regex::Regex;
 
 use crate::matchers::{Should, ShouldNot};
-use crate::matchers::string::regex::match_with;
+use crate::matchers::string::regex::{be_valid_email, be_valid_url, capture, fully_match_with, match_with};
 
 /// RegularExpressionAssertion enables assertions about whether a string (or str) matches a regular expression.
 pub trait RegularExpressionAssertion {
@@ -32,6 +32,111 @@ pub trait RegularExpressionAssertion {
     /// phrase.should_not_match(regex);
     /// ```
     fn should_not_match(&self, regex: Regex) -> &Self;
+
+    /// - Asserts that the entire string matches the given pattern, as if it were anchored with `^` and `$`.
+    /// - Takes the pattern rather than a `Regex` so that it can be anchored; use inline flags like `(?i)` to change the options.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the pattern is not a valid regular expression or the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::regex::RegularExpressionAssertion;
+    ///
+    /// let date = "2024-01-02";
+    /// date.should_fully_match(r"(\d{4})-(\d{2})-(\d{2})");
+    /// ```
+    fn should_fully_match(&self, pattern: &str) -> &Self;
+
+    /// - Asserts that the entire string does not match the given pattern, though a part of it may.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the pattern is not a valid regular expression or the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::regex::RegularExpressionAssertion;
+    ///
+    /// let phrase = "Started clearcheck on 2024-01-02.";
+    /// phrase.should_not_fully_match(r"(\d{4})-(\d{2})-(\d{2})");
+    /// ```
+    fn should_not_fully_match(&self, pattern: &str) -> &Self;
+
+    /// - Asserts that the given capture group of the first match of the regular expression captures the expected value.
+    /// - Group 0 is the whole match, and the capture groups are numbered from 1.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use regex::Regex;
+    /// use clearcheck::assertions::string::regex::RegularExpressionAssertion;
+    ///
+    /// let regex = Regex::new(r"(\d{4})-").unwrap();
+    /// let date = "2024-01-10";
+    /// date.should_capture(regex, 1, "2024");
+    /// ```
+    fn should_capture(&self, regex: Regex, group_index: usize, expected: &str) -> &Self;
+
+    /// - Asserts that the given capture group of the first match of the regular expression does not capture the expected value.
+    /// - Passes if the regular expression does not match or the group is absent from the match.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use regex::Regex;
+    /// use clearcheck::assertions::string::regex::RegularExpressionAssertion;
+    ///
+    /// let regex = Regex::new(r"(\d{4})-").unwrap();
+    /// let date = "2024-01-10";
+    /// date.should_not_capture(regex, 1, "2023");
+    /// ```
+    fn should_not_capture(&self, regex: Regex, group_index: usize, expected: &str) -> &Self;
+
+    /// - Asserts that the string is a valid email address.
+    /// - The validation is pragmatic rather than RFC-exhaustive; quoted local parts and IP address literals are not supported.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::regex::RegularExpressionAssertion;
+    ///
+    /// let email = "clearcheck@example.com";
+    /// email.should_be_valid_email();
+    /// ```
+    fn should_be_valid_email(&self) -> &Self;
+
+    /// - Asserts that the string is not a valid email address.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::regex::RegularExpressionAssertion;
+    ///
+    /// let email = "clearcheck.example.com";
+    /// email.should_not_be_valid_email();
+    /// ```
+    fn should_not_be_valid_email(&self) -> &Self;
+
+    /// - Asserts that the string is a valid URL with an http, https or ftp scheme.
+    /// - The validation is pragmatic rather than RFC-exhaustive; user information and IPv6 hosts are not supported.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::regex::RegularExpressionAssertion;
+    ///
+    /// let url = "https://github.com/SarthakMakhija/clearcheck";
+    /// url.should_be_valid_url();
+    /// ```
+    fn should_be_valid_url(&self) -> &Self;
+
+    /// - Asserts that the string is not a valid URL with an http, https or ftp scheme.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::regex::RegularExpressionAssertion;
+    ///
+    /// let url = "github.com/SarthakMakhija/clearcheck";
+    /// url.should_not_be_valid_url();
+    /// ```
+    fn should_not_be_valid_url(&self) -> &Self;
 }
 
 impl<T> RegularExpressionAssertion for T
@@ -45,6 +150,46 @@ impl<T> RegularExpressionAssertion for T
         self.should_not(&match_with(regex));
         self
     }
+
+    fn should_fully_match(&self, pattern: &str) -> &Self {
+        self.should(&fully_match_with(pattern));
+        self
+    }
+
+    fn should_not_fully_match(&self, pattern: &str) -> &Self {
+        self.should_not(&fully_match_with(pattern));
+        self
+    }
+
+    fn should_capture(&self, regex: Regex, group_index: usize, expected: &str) -> &Self {
+        self.should(&capture(regex, group_index, expected));
+        self
+    }
+
+    fn should_not_capture(&self, regex: Regex, group_index: usize, expected: &str) -> &Self {
+        self.should_not(&capture(regex, group_index, expected));
+        self
+    }
+
+    fn should_be_valid_email(&self) -> &Self {
+        self.should(&be_valid_email());
+        self
+    }
+
+    fn should_not_be_valid_email(&self) -> &Self {
+        self.should_not(&be_valid_email());
+        self
+    }
+
+    fn should_be_valid_url(&self) -> &Self {
+        self.should(&be_valid_url());
+        self
+    }
+
+    fn should_not_be_valid_url(&self) -> &Self {
+        self.should_not(&be_valid_url());
+        self
+    }
 }
 
 #[cfg(all(test, feature = "regex"))]
@@ -120,3 +265,204 @@ mod string_tests {
         str.should_not_match(regex);
     }
 }
+
+#[cfg(all(test, feature = "regex"))]
+mod full_match_tests {
+    use regex::Regex;
+
+    use crate::assertions::string::regex::RegularExpressionAssertion;
+
+    #[test]
+    fn should_fully_match_word_characters() {
+        let value = "abc123";
+        value.should_fully_match(r"\w+");
+    }
+
+    #[test]
+    #[should_panic(expected = "\"abc123\" should fully match the regular expression Regex(\"\\\\d+\") but it matched only partially")]
+    fn should_fully_match_digits_but_it_matched_only_partially() {
+        let value = "abc123";
+        value.should_match(Regex::new(r"\d+").unwrap())
+            .should_fully_match(r"\d+");
+    }
+
+    #[test]
+    #[should_panic(expected = "\"abcdef\" should fully match the regular expression Regex(\"\\\\d+\") but it did not match at all")]
+    fn should_fully_match_digits_but_it_did_not_match_at_all() {
+        let value = String::from("abcdef");
+        value.should_fully_match(r"\d+");
+    }
+
+    #[test]
+    fn should_fully_match_an_already_anchored_regular_expression() {
+        let value = "2024-01-02";
+        value.should_fully_match(r"^\d{4}-\d{2}-\d{2}$");
+    }
+
+    #[test]
+    fn should_fully_match_the_longer_alternative() {
+        let value = "ab";
+        value.should_fully_match(r"a|ab");
+    }
+
+    #[test]
+    fn should_fully_match_a_lazy_quantifier() {
+        let value = "abc";
+        value.should_fully_match(r"\w+?");
+    }
+
+    #[test]
+    fn should_fully_match_a_case_insensitive_pattern() {
+        let value = "ABC";
+        value.should_fully_match(r"(?i)[a-z]+");
+    }
+
+    #[test]
+    #[should_panic(expected = "\"ABC\" should fully match the regular expression Regex(\"[a-z]+\") but it did not match at all")]
+    fn should_fully_match_a_case_sensitive_pattern_but_it_did_not() {
+        let value = "ABC";
+        value.should_fully_match(r"[a-z]+");
+    }
+
+    #[test]
+    fn should_not_fully_match() {
+        let value = String::from("abc123");
+        value.should_not_fully_match(r"\d+");
+    }
+
+    #[test]
+    #[should_panic(expected = "\"abc123\" should not fully match the regular expression")]
+    fn should_not_fully_match_but_it_did() {
+        let value = "abc123";
+        value.should_not_fully_match(r"[a-z0-9]+");
+    }
+}
+
+#[cfg(all(test, feature = "regex"))]
+mod capture_tests {
+    use regex::Regex;
+
+    use crate::assertions::string::regex::RegularExpressionAssertion;
+
+    #[test]
+    fn should_capture_the_year() {
+        let date = "2024-01-10";
+        date.should_capture(Regex::new(r"(\d{4})-").unwrap(), 1, "2024");
+    }
+
+    #[test]
+    fn should_capture_the_whole_match_as_group_0() {
+        let date = String::from("released on 2024-01-10");
+        date.should_capture(Regex::new(r"\d{4}-\d{2}").unwrap(), 0, "2024-01");
+    }
+
+    #[test]
+    #[should_panic(expected = "\"released on 10th January\" should capture \"2024\" in group 1 of the regular expression Regex(\"(\\\\d{4})-\") but the regular expression did not match")]
+    fn should_capture_but_the_regular_expression_did_not_match() {
+        let date = "released on 10th January";
+        date.should_capture(Regex::new(r"(\d{4})-").unwrap(), 1, "2024");
+    }
+
+    #[test]
+    #[should_panic(expected = "but group 2 was absent from the match")]
+    fn should_capture_but_the_optional_group_did_not_participate() {
+        let version = "v1";
+        version.should_capture(Regex::new(r"v(\d+)(?:\.(\d+))?").unwrap(), 2, "0");
+    }
+
+    #[test]
+    #[should_panic(expected = "but group 3 was absent from the match")]
+    fn should_capture_but_the_group_does_not_exist() {
+        let date = "2024-01-10";
+        date.should_capture(Regex::new(r"(\d{4})-(\d{2})").unwrap(), 3, "10");
+    }
+
+    #[test]
+    #[should_panic(expected = "\"2024-01-10\" should capture \"2023\" in group 1 of the regular expression Regex(\"(\\\\d{4})-\") but group 1 captured \"2024\"")]
+    fn should_capture_but_the_group_captured_a_different_value() {
+        let date = "2024-01-10";
+        date.should_capture(Regex::new(r"(\d{4})-").unwrap(), 1, "2023");
+    }
+
+    #[test]
+    fn should_not_capture() {
+        let date = "2024-01-10";
+        date.should_not_capture(Regex::new(r"(\d{4})-").unwrap(), 1, "2023");
+    }
+
+    #[test]
+    fn should_not_capture_given_no_match() {
+        let date = "10th January";
+        date.should_not_capture(Regex::new(r"(\d{4})-").unwrap(), 1, "2024");
+    }
+
+    #[test]
+    #[should_panic(expected = "\"2024-01-10\" should not capture \"2024\" in group 1 of the regular expression")]
+    fn should_not_capture_but_it_did() {
+        let date = "2024-01-10";
+        date.should_not_capture(Regex::new(r"(\d{4})-").unwrap(), 1, "2024");
+    }
+}
+
+#[cfg(all(test, feature = "regex"))]
+mod format_tests {
+    use crate::assertions::string::regex::RegularExpressionAssertion;
+
+    #[test]
+    fn should_be_valid_emails() {
+        "clearcheck@example.com".should_be_valid_email();
+        "clear.check+rust@mail.example.co.in".should_be_valid_email();
+        String::from("o'reilly_42@example-domain.org").should_be_valid_email();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"clearcheck.example.com\" should be a valid email address")]
+    fn should_be_a_valid_email_but_the_at_sign_was_missing() {
+        "clearcheck.example.com".should_be_valid_email();
+    }
+
+    #[test]
+    fn should_not_be_valid_emails() {
+        "clear..check@example.com".should_not_be_valid_email();
+        ".clearcheck@example.com".should_not_be_valid_email();
+        "clearcheck@localhost".should_not_be_valid_email();
+        "clearcheck@-example.com".should_not_be_valid_email();
+        "clear check@example.com".should_not_be_valid_email();
+        "clearcheck@example.com\n".should_not_be_valid_email();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"clearcheck@example.com\" should not be a valid email address")]
+    fn should_not_be_a_valid_email_but_it_was() {
+        "clearcheck@example.com".should_not_be_valid_email();
+    }
+
+    #[test]
+    fn should_be_valid_urls() {
+        "https://github.com/SarthakMakhija/clearcheck".should_be_valid_url();
+        "http://localhost:8080/health".should_be_valid_url();
+        "HTTPS://docs.rs/regex/latest/regex/?search=Regex#examples".should_be_valid_url();
+        String::from("ftp://192.168.1.10/files").should_be_valid_url();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"github.com/SarthakMakhija\" should be a valid URL")]
+    fn should_be_a_valid_url_but_the_scheme_was_missing() {
+        "github.com/SarthakMakhija".should_be_valid_url();
+    }
+
+    #[test]
+    fn should_not_be_valid_urls() {
+        "mailto:clearcheck@example.com".should_not_be_valid_url();
+        "https://".should_not_be_valid_url();
+        "https://exa mple.com".should_not_be_valid_url();
+        "https://example.com:port".should_not_be_valid_url();
+        "https://example.com/a path".should_not_be_valid_url();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"https://example.com\" should not be a valid URL")]
+    fn should_not_be_a_valid_url_but_it_was() {
+        "https://example.com".should_not_be_valid_url();
+    }
+}
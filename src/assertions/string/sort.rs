@@ -0,0 +1,159 @@
+use crate::matchers::{Should, ShouldNot};
+use crate::matchers::string::sort::{have_sorted_characters, have_strictly_sorted_characters};
+
+/// CharacterSortAssertion enables assertions about the order of the characters of string (or str) values.
+///
+/// Characters are compared by their Unicode scalar values, so uppercase letters sort before lowercase letters.
+///
+/// It offers a fluent interface for chaining multiple assertions.
+///
+/// # Example
+/// ```
+/// use clearcheck::assertions::string::sort::CharacterSortAssertion;
+///
+/// let token = "aabcd";
+/// token
+///     .should_have_sorted_characters()
+///     .should_not_have_strictly_sorted_characters();
+/// ```
+pub trait CharacterSortAssertion {
+    /// - Asserts that the characters of the string are in non-decreasing order.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting the first out-of-order pair of characters.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::sort::CharacterSortAssertion;
+    ///
+    /// let token = "abcd";
+    /// token.should_have_sorted_characters();
+    /// ```
+    fn should_have_sorted_characters(&self) -> &Self;
+
+    /// - Asserts that the characters of the string are not in non-decreasing order.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::sort::CharacterSortAssertion;
+    ///
+    /// let token = "abdc";
+    /// token.should_not_have_sorted_characters();
+    /// ```
+    fn should_not_have_sorted_characters(&self) -> &Self;
+
+    /// - Asserts that the characters of the string are in strictly increasing order, without repeated characters.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting the first out-of-order pair of characters.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::sort::CharacterSortAssertion;
+    ///
+    /// let token = "abcd";
+    /// token.should_have_strictly_sorted_characters();
+    /// ```
+    fn should_have_strictly_sorted_characters(&self) -> &Self;
+
+    /// - Asserts that the characters of the string are not in strictly increasing order.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::sort::CharacterSortAssertion;
+    ///
+    /// let token = "aabcd";
+    /// token.should_not_have_strictly_sorted_characters();
+    /// ```
+    fn should_not_have_strictly_sorted_characters(&self) -> &Self;
+}
+
+impl<T> CharacterSortAssertion for T
+    where T: AsRef<str> {
+    fn should_have_sorted_characters(&self) -> &Self {
+        self.should(&have_sorted_characters());
+        self
+    }
+
+    fn should_not_have_sorted_characters(&self) -> &Self {
+        self.should_not(&have_sorted_characters());
+        self
+    }
+
+    fn should_have_strictly_sorted_characters(&self) -> &Self {
+        self.should(&have_strictly_sorted_characters());
+        self
+    }
+
+    fn should_not_have_strictly_sorted_characters(&self) -> &Self {
+        self.should_not(&have_strictly_sorted_characters());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::string::sort::CharacterSortAssertion;
+
+    #[test]
+    fn should_have_sorted_characters() {
+        let token = "abcd";
+        token.should_have_sorted_characters();
+    }
+
+    #[test]
+    fn should_have_sorted_characters_given_repeated_characters() {
+        let token = String::from("aabbc");
+        token.should_have_sorted_characters();
+    }
+
+    #[test]
+    fn should_have_sorted_characters_given_an_empty_string() {
+        let token = "";
+        token.should_have_sorted_characters();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"abdc\" should have sorted characters but 'd' at index 2 was followed by 'c'")]
+    fn should_have_sorted_characters_but_did_not() {
+        let token = "abdc";
+        token.should_have_sorted_characters();
+    }
+
+    #[test]
+    fn should_not_have_sorted_characters() {
+        let token = "abdc";
+        token.should_not_have_sorted_characters();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_have_sorted_characters_but_did() {
+        let token = "abcd";
+        token.should_not_have_sorted_characters();
+    }
+
+    #[test]
+    fn should_have_strictly_sorted_characters() {
+        let token = "abcd";
+        token.should_have_strictly_sorted_characters();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"aabcd\" should have strictly sorted characters but 'a' at index 0 was followed by 'a'")]
+    fn should_have_strictly_sorted_characters_but_had_a_repeated_character() {
+        let token = "aabcd";
+        token.should_have_strictly_sorted_characters();
+    }
+
+    #[test]
+    fn should_not_have_strictly_sorted_characters() {
+        let token = "aabcd";
+        token.should_not_have_strictly_sorted_characters();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_have_strictly_sorted_characters_but_did() {
+        let token = "abcd";
+        token.should_not_have_strictly_sorted_characters();
+    }
+}
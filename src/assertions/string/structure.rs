@@ -0,0 +1,141 @@
+use crate::matchers::{Should, ShouldNot};
+use crate::matchers::string::structure::{have_balanced_delimiters, have_balanced_delimiters_of};
+
+/// StructureAssertion enables assertions about the structure of string (or str) values, such as the balance of their delimiters.
+///
+/// It offers a fluent interface for chaining multiple assertions.
+///
+/// # Example
+/// ```
+/// use clearcheck::assertions::string::structure::StructureAssertion;
+///
+/// let expression = "(1 + [2 * {3 - 4}])";
+/// expression
+///     .should_have_balanced_delimiters()
+///     .should_have_balanced_delimiters_of(vec![('(', ')')]);
+/// ```
+pub trait StructureAssertion {
+    /// - Asserts that the parentheses `()`, brackets `[]` and braces `{}` of the string are balanced and properly nested.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting the index and the kind of the first unbalanced delimiter.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::structure::StructureAssertion;
+    ///
+    /// let expression = "{[()]}";
+    /// expression.should_have_balanced_delimiters();
+    /// ```
+    fn should_have_balanced_delimiters(&self) -> &Self;
+
+    /// - Asserts that the parentheses `()`, brackets `[]` and braces `{}` of the string are not balanced.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::structure::StructureAssertion;
+    ///
+    /// let expression = "{[(])}";
+    /// expression.should_not_have_balanced_delimiters();
+    /// ```
+    fn should_not_have_balanced_delimiters(&self) -> &Self;
+
+    /// - Asserts that the given (opening, closing) delimiter pairs of the string are balanced and properly nested.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, reporting the index and the kind of the first unbalanced delimiter.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::structure::StructureAssertion;
+    ///
+    /// let type_name = "HashMap<String, Vec<u8>>";
+    /// type_name.should_have_balanced_delimiters_of(vec![('<', '>')]);
+    /// ```
+    fn should_have_balanced_delimiters_of(&self, pairs: Vec<(char, char)>) -> &Self;
+}
+
+impl<T> StructureAssertion for T
+    where T: AsRef<str> {
+    fn should_have_balanced_delimiters(&self) -> &Self {
+        self.should(&have_balanced_delimiters());
+        self
+    }
+
+    fn should_not_have_balanced_delimiters(&self) -> &Self {
+        self.should_not(&have_balanced_delimiters());
+        self
+    }
+
+    fn should_have_balanced_delimiters_of(&self, pairs: Vec<(char, char)>) -> &Self {
+        self.should(&have_balanced_delimiters_of(pairs));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::string::structure::StructureAssertion;
+
+    #[test]
+    fn should_have_balanced_delimiters() {
+        let expression = "fn main() { let values = vec![(1, 2)]; }";
+        expression.should_have_balanced_delimiters();
+    }
+
+    #[test]
+    fn should_have_balanced_delimiters_given_no_delimiters() {
+        let expression = String::from("clearcheck");
+        expression.should_have_balanced_delimiters();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"(a))\" should have balanced delimiters but the closing ')' at index 3 had no matching opening delimiter")]
+    fn should_have_balanced_delimiters_but_a_closing_delimiter_was_unexpected() {
+        let expression = "(a))";
+        expression.should_have_balanced_delimiters();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"{[}]\" should have balanced delimiters but the closing '}' at index 2 did not match the opening '[' at index 1")]
+    fn should_have_balanced_delimiters_but_a_closing_delimiter_was_mismatched() {
+        let expression = "{[}]";
+        expression.should_have_balanced_delimiters();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"{(a)\" should have balanced delimiters but the opening '{' at index 0 was never closed")]
+    fn should_have_balanced_delimiters_but_an_opening_delimiter_was_never_closed() {
+        let expression = "{(a)";
+        expression.should_have_balanced_delimiters();
+    }
+
+    #[test]
+    fn should_not_have_balanced_delimiters() {
+        let expression = "[1, 2";
+        expression.should_not_have_balanced_delimiters();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"[1, 2]\" should not have balanced delimiters")]
+    fn should_not_have_balanced_delimiters_but_it_had() {
+        let expression = "[1, 2]";
+        expression.should_not_have_balanced_delimiters();
+    }
+
+    #[test]
+    fn should_have_balanced_delimiters_of_the_given_pairs() {
+        let template = "<div><span></span></div>";
+        template.should_have_balanced_delimiters_of(vec![('<', '>')]);
+    }
+
+    #[test]
+    fn should_have_balanced_symmetric_delimiters() {
+        let quoted = "'a' + '(b)'";
+        quoted.should_have_balanced_delimiters_of(vec![('\'', '\''), ('(', ')')]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"'a' + 'b\" should have balanced delimiters but the opening '\\'' at index 6 was never closed")]
+    fn should_have_balanced_symmetric_delimiters_but_one_was_never_closed() {
+        let quoted = "'a' + 'b";
+        quoted.should_have_balanced_delimiters_of(vec![('\'', '\'')]);
+    }
+}
@@ -0,0 +1,207 @@
+use crate::matchers::{Should, ShouldNot};
+use crate::matchers::string::transform::{be_anagram_of, be_palindrome, be_palindrome_ignoring_non_alphanumerics};
+
+/// TransformAssertion enables assertions about whether a string (or str) is a palindrome or an anagram of another string.
+///
+/// It offers a fluent interface for chaining multiple assertions.
+///
+/// # Example
+/// ```
+/// use clearcheck::assertions::string::transform::TransformAssertion;
+///
+/// let word = "listen";
+/// word
+///     .should_be_anagram_of("silent")
+///     .should_not_be_palindrome();
+/// ```
+pub trait TransformAssertion {
+    /// - Asserts that the string reads the same backward as forward, ignoring case differences.
+    /// - Whitespace and punctuation are significant, use [TransformAssertion::should_be_palindrome_ignoring_non_alphanumerics] to ignore them.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::transform::TransformAssertion;
+    ///
+    /// let word = "Racecar";
+    /// word.should_be_palindrome();
+    /// ```
+    fn should_be_palindrome(&self) -> &Self;
+
+    /// - Asserts that the string does not read the same backward as forward, ignoring case differences.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::transform::TransformAssertion;
+    ///
+    /// let word = "clearcheck";
+    /// word.should_not_be_palindrome();
+    /// ```
+    fn should_not_be_palindrome(&self) -> &Self;
+
+    /// - Asserts that the string reads the same backward as forward, ignoring case differences and all the non-alphanumeric characters (spaces, punctuation etc.).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::transform::TransformAssertion;
+    ///
+    /// let phrase = "A man, a plan, a canal: Panama";
+    /// phrase.should_be_palindrome_ignoring_non_alphanumerics();
+    /// ```
+    fn should_be_palindrome_ignoring_non_alphanumerics(&self) -> &Self;
+
+    /// - Asserts that the string is an anagram of the given string (case-sensitive, every character counts).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::transform::TransformAssertion;
+    ///
+    /// let word = "listen";
+    /// word.should_be_anagram_of("silent");
+    /// ```
+    fn should_be_anagram_of(&self, other: &str) -> &Self;
+
+    /// - Asserts that the string is not an anagram of the given string (case-sensitive, every character counts).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::transform::TransformAssertion;
+    ///
+    /// let word = "listen";
+    /// word.should_not_be_anagram_of("silence");
+    /// ```
+    fn should_not_be_anagram_of(&self, other: &str) -> &Self;
+}
+
+impl<T> TransformAssertion for T
+    where T: AsRef<str> {
+    fn should_be_palindrome(&self) -> &Self {
+        self.should(&be_palindrome());
+        self
+    }
+
+    fn should_not_be_palindrome(&self) -> &Self {
+        self.should_not(&be_palindrome());
+        self
+    }
+
+    fn should_be_palindrome_ignoring_non_alphanumerics(&self) -> &Self {
+        self.should(&be_palindrome_ignoring_non_alphanumerics());
+        self
+    }
+
+    fn should_be_anagram_of(&self, other: &str) -> &Self {
+        self.should(&be_anagram_of(other));
+        self
+    }
+
+    fn should_not_be_anagram_of(&self, other: &str) -> &Self {
+        self.should_not(&be_anagram_of(other));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::string::transform::TransformAssertion;
+
+    #[test]
+    fn should_be_palindrome() {
+        let word = "Level";
+        word.should_be_palindrome();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_palindrome_but_was_not() {
+        let word = "clearcheck";
+        word.should_be_palindrome();
+    }
+
+    #[test]
+    fn should_not_be_palindrome() {
+        let word = "clearcheck";
+        word.should_not_be_palindrome();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_palindrome_but_was() {
+        let word = "Level";
+        word.should_not_be_palindrome();
+    }
+
+    #[test]
+    fn should_be_palindrome_ignoring_non_alphanumerics() {
+        let phrase = "A man a plan a canal Panama";
+        phrase.should_be_palindrome_ignoring_non_alphanumerics();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_palindrome_ignoring_non_alphanumerics_but_was_not() {
+        let phrase = "A man a plan a canal Suez";
+        phrase.should_be_palindrome_ignoring_non_alphanumerics();
+    }
+
+    #[test]
+    fn should_be_anagram_of() {
+        let word = "listen";
+        word.should_be_anagram_of("silent");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_anagram_of_but_was_not() {
+        let word = "listen";
+        word.should_be_anagram_of("listed");
+    }
+
+    #[test]
+    fn should_not_be_anagram_of() {
+        let word = "listen";
+        word.should_not_be_anagram_of("listed");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_anagram_of_but_was() {
+        let word = "listen";
+        word.should_not_be_anagram_of("silent");
+    }
+}
+
+#[cfg(test)]
+mod string_tests {
+    use crate::assertions::string::transform::TransformAssertion;
+
+    #[test]
+    fn should_be_palindrome_ignoring_non_alphanumerics() {
+        let phrase = String::from("A man a plan a canal Panama");
+        phrase.should_be_palindrome_ignoring_non_alphanumerics();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_palindrome_but_was_not() {
+        let phrase = String::from("A man a plan a canal Panama");
+        phrase.should_be_palindrome();
+    }
+
+    #[test]
+    fn should_be_anagram_of() {
+        let word = String::from("listen");
+        word.should_be_anagram_of("silent");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_anagram_of_but_was_not() {
+        let word = String::from("Listen");
+        word.should_be_anagram_of("silent");
+    }
+}
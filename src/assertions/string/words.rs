@@ -0,0 +1,174 @@
+use crate::matchers::{Should, ShouldNot};
+use crate::matchers::string::words::{contain_word, have_word_count};
+
+/// WordAssertion enables assertions about the whitespace separated words of string (or str) values.
+///
+/// Words are counted and compared using [str::split_whitespace], so a word only matches a whole token and never a
+/// substring of a longer token.
+///
+/// It offers a fluent interface for chaining multiple assertions.
+///
+/// # Example
+/// ```
+/// use clearcheck::assertions::string::words::WordAssertion;
+///
+/// let sentence = "the cat sat on the mat";
+/// sentence
+///     .should_have_word_count(6)
+///     .should_contain_word("cat")
+///     .should_not_contain_word("at");
+/// ```
+pub trait WordAssertion {
+    /// - Asserts that the string has exactly the given number of whitespace separated words.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::words::WordAssertion;
+    ///
+    /// let sentence = "the cat sat";
+    /// sentence.should_have_word_count(3);
+    /// ```
+    fn should_have_word_count(&self, count: usize) -> &Self;
+
+    /// - Asserts that the string does not have the given number of whitespace separated words.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::words::WordAssertion;
+    ///
+    /// let sentence = "the cat sat";
+    /// sentence.should_not_have_word_count(2);
+    /// ```
+    fn should_not_have_word_count(&self, count: usize) -> &Self;
+
+    /// - Asserts that the string contains the given word as a whole whitespace separated token.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::words::WordAssertion;
+    ///
+    /// let sentence = "the cat sat";
+    /// sentence.should_contain_word("cat");
+    /// ```
+    fn should_contain_word(&self, word: &'static str) -> &Self;
+
+    /// - Asserts that the string does not contain the given word as a whole whitespace separated token.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::words::WordAssertion;
+    ///
+    /// let sentence = "category";
+    /// sentence.should_not_contain_word("cat");
+    /// ```
+    fn should_not_contain_word(&self, word: &'static str) -> &Self;
+}
+
+impl<T> WordAssertion for T
+    where T: AsRef<str> {
+    fn should_have_word_count(&self, count: usize) -> &Self {
+        self.should(&have_word_count(count));
+        self
+    }
+
+    fn should_not_have_word_count(&self, count: usize) -> &Self {
+        self.should_not(&have_word_count(count));
+        self
+    }
+
+    fn should_contain_word(&self, word: &'static str) -> &Self {
+        self.should(&contain_word(word));
+        self
+    }
+
+    fn should_not_contain_word(&self, word: &'static str) -> &Self {
+        self.should_not(&contain_word(word));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::string::words::WordAssertion;
+
+    #[test]
+    fn should_have_word_count() {
+        let sentence = "the  cat\tsat\n";
+        sentence.should_have_word_count(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"the cat sat\" should have 2 words but had 3")]
+    fn should_have_word_count_but_was_not() {
+        let sentence = "the cat sat";
+        sentence.should_have_word_count(2);
+    }
+
+    #[test]
+    fn should_not_have_word_count() {
+        let sentence = "the cat sat";
+        sentence.should_not_have_word_count(2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_have_word_count_but_had() {
+        let sentence = "the cat sat";
+        sentence.should_not_have_word_count(3);
+    }
+
+    #[test]
+    fn should_contain_word() {
+        let sentence = "the cat sat";
+        sentence.should_contain_word("cat");
+    }
+
+    #[test]
+    #[should_panic(expected = "\"category\" should contain the word \"cat\" but it only appeared as a part of another word")]
+    fn should_contain_word_but_it_was_only_a_substring() {
+        let sentence = "category";
+        sentence.should_contain_word("cat");
+    }
+
+    #[test]
+    #[should_panic(expected = "\"the dog sat\" should contain the word \"cat\"")]
+    fn should_contain_word_but_it_did_not() {
+        let sentence = "the dog sat";
+        sentence.should_contain_word("cat");
+    }
+
+    #[test]
+    fn should_not_contain_word() {
+        let sentence = "category";
+        sentence.should_not_contain_word("cat");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_contain_word_but_it_did() {
+        let sentence = "the cat sat";
+        sentence.should_not_contain_word("cat");
+    }
+}
+
+#[cfg(test)]
+mod string_tests {
+    use crate::assertions::string::words::WordAssertion;
+
+    #[test]
+    fn should_contain_word() {
+        let sentence = String::from("the cat sat");
+        sentence.should_contain_word("cat");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_word_count_but_was_not() {
+        let sentence = String::from("the cat sat");
+        sentence.should_have_word_count(4);
+    }
+}
@@ -7,7 +7,6 @@
 //! use clearcheck::assertions::string::length::LengthAssertion;
 //! use clearcheck::assertions::string::membership::MembershipAssertion;
 //! use clearcheck::assertions::string::numeric::NumericAssertion;
-//! use clearcheck::assertions::string::regex::RegularExpressionAssertion;
 //!
 //! let pass_phrase = "P@@sw0rd1 zebra alpha";
 //! pass_phrase.should_not_be_empty()
@@ -30,8 +29,11 @@
 //! clearcheck crate supports the following features:
 //! - date enables [assertions on date](assertions::date::DateAssertion)
 //! - file enables [assertions on filepath](assertions::file::FileAssertion)
+//! - json enables [JSON assertions on string](assertions::string::json)
 //! - num enables [assertions on float](assertions::float::FloatAssertion) and [assertions on integer](assertions::int::IntAssertion)
 //! - regex enables [regular expression assertions on string](assertions::string::regex)
+//! - stats enables [assertion counting](matchers::assertion_count)
+//! - unicode enables [Unicode normalization assertions on string](assertions::string::normalization) and [grapheme count assertions on string](assertions::string::length::GraphemeAssertion)
 //!
 //! # Assertions vs Matchers
 //!
@@ -103,11 +105,8 @@
 //! }
 //!
 //! //3. That's it. Use the password assertion.
-//! #[test]
-//! fn should_be_a_valid_password() {
-//!     let password = "P@@sw0rd9082";
-//!     password.should_be_a_valid_password();
-//! }
+//! let password = "P@@sw0rd9082";
+//! password.should_be_a_valid_password();
 //! ```
 
 pub mod assertions;
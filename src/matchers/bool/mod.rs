@@ -1,3 +1,5 @@
+use std::ops::RangeInclusive;
+
 use crate::matchers::{Matcher, MatcherResult};
 
 /// TrueFalseMatcher provides a way to assert whether boolean values evaluate to true or false.
@@ -40,6 +42,142 @@ pub fn be_false() -> TrueFalseMatcher {
     TrueFalseMatcher::False
 }
 
+/// BoolCollectionMatcher offers a flexible way to assert whether all (or any) of the booleans in a collection are true
+/// or false.
+///
+/// An empty collection is all true as well as all false, but it does not have any true value.
+///
+/// clearcheck implements BoolCollectionMatcher for collection types including vector, arrays and reference to slices.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::bool::all_be_true;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = all_be_true();
+/// let flags = vec![true, true, true];
+///
+/// assert!(matcher.test(&flags).passed());
+/// ```
+pub enum BoolCollectionMatcher {
+    AllTrue,
+    AllFalse,
+    AnyTrue,
+    TrueCount(usize),
+    TrueCountInRange(RangeInclusive<usize>),
+}
+
+impl BoolCollectionMatcher {
+    fn test(&self, collection: &[bool]) -> MatcherResult {
+        match self {
+            BoolCollectionMatcher::AllTrue => {
+                let offending = collection.iter().position(|value| !value);
+                MatcherResult::formatted(
+                    offending.is_none(),
+                    format!(
+                        "{:?} should be all true but the value at index {:?} was false",
+                        collection,
+                        offending.unwrap_or_default()
+                    ),
+                    format!("{:?} should not be all true", collection),
+                )
+            }
+            BoolCollectionMatcher::AllFalse => {
+                let offending = collection.iter().position(|value| *value);
+                MatcherResult::formatted(
+                    offending.is_none(),
+                    format!(
+                        "{:?} should be all false but the value at index {:?} was true",
+                        collection,
+                        offending.unwrap_or_default()
+                    ),
+                    format!("{:?} should not be all false", collection),
+                )
+            }
+            BoolCollectionMatcher::AnyTrue => {
+                let first_true = collection.iter().position(|value| *value);
+                MatcherResult::formatted(
+                    first_true.is_some(),
+                    format!("{:?} should have any true value but none was true", collection),
+                    format!(
+                        "{:?} should not have any true value but the value at index {:?} was true",
+                        collection,
+                        first_true.unwrap_or_default()
+                    ),
+                )
+            }
+            BoolCollectionMatcher::TrueCount(count) => {
+                let true_count = collection.iter().filter(|value| **value).count();
+                MatcherResult::formatted(
+                    true_count == *count,
+                    format!(
+                        "{:?} should have {:?} true values but had {:?}",
+                        collection, count, true_count
+                    ),
+                    format!("{:?} should not have {:?} true values", collection, count),
+                )
+            }
+            BoolCollectionMatcher::TrueCountInRange(range) => {
+                let true_count = collection.iter().filter(|value| **value).count();
+                MatcherResult::formatted(
+                    range.contains(&true_count),
+                    format!(
+                        "{:?} should have true values in the range {:?} but had {:?}",
+                        collection, range, true_count
+                    ),
+                    format!(
+                        "{:?} should not have true values in the range {:?} but had {:?}",
+                        collection, range, true_count
+                    ),
+                )
+            }
+        }
+    }
+}
+
+impl Matcher<Vec<bool>> for BoolCollectionMatcher {
+    fn test(&self, collection: &Vec<bool>) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+impl<const N: usize> Matcher<[bool; N]> for BoolCollectionMatcher {
+    fn test(&self, collection: &[bool; N]) -> MatcherResult {
+        self.test(collection as &[bool])
+    }
+}
+
+impl Matcher<&[bool]> for BoolCollectionMatcher {
+    fn test(&self, collection: &&[bool]) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+/// Creates a BoolCollectionMatcher that asserts whether all the booleans in a collection are true.
+pub fn all_be_true() -> BoolCollectionMatcher {
+    BoolCollectionMatcher::AllTrue
+}
+
+/// Creates a BoolCollectionMatcher that asserts whether all the booleans in a collection are false.
+pub fn all_be_false() -> BoolCollectionMatcher {
+    BoolCollectionMatcher::AllFalse
+}
+
+/// Creates a BoolCollectionMatcher that asserts whether any of the booleans in a collection is true.
+pub fn any_be_true() -> BoolCollectionMatcher {
+    BoolCollectionMatcher::AnyTrue
+}
+
+/// Creates a BoolCollectionMatcher that asserts whether exactly the given number of booleans in a collection are true.
+pub fn have_true_count(count: usize) -> BoolCollectionMatcher {
+    BoolCollectionMatcher::TrueCount(count)
+}
+
+/// Creates a BoolCollectionMatcher that asserts whether the number of true booleans in a collection falls within the given inclusive range.
+pub fn have_true_count_in_inclusive_range(range: RangeInclusive<usize>) -> BoolCollectionMatcher {
+    BoolCollectionMatcher::TrueCountInRange(range)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::assertions::bool::TrueFalseAssertion;
@@ -58,3 +196,54 @@ mod tests {
         matcher.test(&false).passed.should_be_true();
     }
 }
+
+#[cfg(test)]
+mod collection_tests {
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::matchers::bool::{
+        all_be_false, all_be_true, any_be_true, have_true_count, have_true_count_in_inclusive_range,
+    };
+
+    #[test]
+    fn should_all_be_true() {
+        let matcher = all_be_true();
+        matcher.test(&[true, true]).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_all_be_true() {
+        let matcher = all_be_true();
+        matcher.test(&[true, false]).passed.should_be_false();
+    }
+
+    #[test]
+    fn should_all_be_false() {
+        let matcher = all_be_false();
+        let flags: &[bool] = &[false, false];
+        matcher.test(flags).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_any_true_value() {
+        let matcher = any_be_true();
+        matcher.test(&[false, true]).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_true_count() {
+        let matcher = have_true_count(2);
+        matcher.test(&[true, false, true]).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_true_count_in_inclusive_range() {
+        let matcher = have_true_count_in_inclusive_range(1..=2);
+        matcher.test(&[true, false, false]).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_have_any_true_value_given_an_empty_collection() {
+        let matcher = any_be_true();
+        matcher.test(&[]).passed.should_be_false();
+    }
+}
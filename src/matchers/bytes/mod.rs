@@ -0,0 +1,439 @@
+use std::str::Utf8Error;
+
+use crate::matchers::{Matcher, MatcherResult};
+
+/// Utf8Matcher offers a flexible way to assert whether a sequence of bytes is valid UTF-8, optionally decoding to the
+/// expected string.
+///
+/// On invalid UTF-8, the failure message includes the byte offset at which the invalid sequence starts.
+///
+/// clearcheck implements Utf8Matcher for `Vec<u8>`, `[u8; N]` and `&[u8]`.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::bytes::be_valid_utf8;
+/// use clearcheck::matchers::Matcher;
+///
+/// let bytes = "clearcheck".as_bytes();
+/// let matcher = be_valid_utf8();
+///
+/// assert!(matcher.test(&bytes).passed());
+/// ```
+pub enum Utf8Matcher {
+    Valid,
+    DecodeTo(String),
+}
+
+impl Utf8Matcher {
+    fn test(&self, bytes: &[u8]) -> MatcherResult {
+        let decoded = std::str::from_utf8(bytes);
+        match self {
+            Utf8Matcher::Valid => MatcherResult::formatted(
+                decoded.is_ok(),
+                format!(
+                    "{:?} should be valid UTF-8 but {}",
+                    bytes,
+                    decoded.err().map(describe_error).unwrap_or_default()
+                ),
+                format!("{:?} should not be valid UTF-8", bytes),
+            ),
+            Utf8Matcher::DecodeTo(expected) => MatcherResult::formatted(
+                decoded.is_ok_and(|decoded| decoded == expected),
+                match decoded {
+                    Ok(decoded) => format!(
+                        "{:?} should decode to {:?} but decoded to {:?}",
+                        bytes, expected, decoded
+                    ),
+                    Err(err) => format!(
+                        "{:?} should decode to {:?} but {}",
+                        bytes, expected, describe_error(err)
+                    ),
+                },
+                format!("{:?} should not decode to {:?}", bytes, expected),
+            ),
+        }
+    }
+}
+
+fn describe_error(err: Utf8Error) -> String {
+    match err.error_len() {
+        Some(_) => format!("found an invalid byte sequence at offset {}", err.valid_up_to()),
+        None => format!("found an incomplete byte sequence at offset {}", err.valid_up_to()),
+    }
+}
+
+impl Matcher<Vec<u8>> for Utf8Matcher {
+    fn test(&self, collection: &Vec<u8>) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+impl<const N: usize> Matcher<[u8; N]> for Utf8Matcher {
+    fn test(&self, collection: &[u8; N]) -> MatcherResult {
+        self.test(collection as &[u8])
+    }
+}
+
+impl Matcher<&[u8]> for Utf8Matcher {
+    fn test(&self, collection: &&[u8]) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+/// Creates a Utf8Matcher that asserts whether a sequence of bytes is valid UTF-8.
+pub fn be_valid_utf8() -> Utf8Matcher {
+    Utf8Matcher::Valid
+}
+
+/// Creates a Utf8Matcher that asserts whether a sequence of bytes is valid UTF-8 and decodes to the given string.
+pub fn decode_to(expected: &str) -> Utf8Matcher {
+    Utf8Matcher::DecodeTo(expected.to_string())
+}
+
+/// EncodingMatcher offers a flexible way to assert whether a sequence of bytes equals the bytes represented by a hex or
+/// a base64 encoded string.
+///
+/// The expected string is decoded when the matcher is created, and the failure message shows both the expected and the
+/// actual bytes in the same encoding.
+///
+/// clearcheck implements EncodingMatcher for `Vec<u8>`, `[u8; N]` and `&[u8]`.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::bytes::equal_hex;
+/// use clearcheck::matchers::Matcher;
+///
+/// let bytes = [0xDE, 0xAD, 0xBE, 0xEF];
+/// let matcher = equal_hex("deadbeef");
+///
+/// assert!(matcher.test(&bytes).passed());
+/// ```
+pub struct EncodingMatcher {
+    encoding: Encoding,
+    expected: Vec<u8>,
+}
+
+#[derive(Clone, Copy)]
+enum Encoding {
+    Hex,
+    Base64,
+}
+
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+impl Encoding {
+    fn name(&self) -> &'static str {
+        match self {
+            Encoding::Hex => "hex",
+            Encoding::Base64 => "base64",
+        }
+    }
+
+    fn encode(&self, bytes: &[u8]) -> String {
+        match self {
+            Encoding::Hex => bytes.iter().map(|byte| format!("{:02x}", byte)).collect(),
+            Encoding::Base64 => bytes
+                .chunks(3)
+                .flat_map(|chunk| {
+                    let group = chunk
+                        .iter()
+                        .enumerate()
+                        .fold(0u32, |group, (index, byte)| group | ((*byte as u32) << (16 - 8 * index)));
+                    (0..4).map(move |index| {
+                        if index <= chunk.len() {
+                            BASE64_ALPHABET[((group >> (18 - 6 * index)) & 0x3F) as usize] as char
+                        } else {
+                            '='
+                        }
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    fn decode(&self, encoded: &str) -> Result<Vec<u8>, String> {
+        match self {
+            Encoding::Hex => {
+                if !encoded.len().is_multiple_of(2) {
+                    return Err("it has an odd number of digits".to_string());
+                }
+                encoded
+                    .as_bytes()
+                    .chunks(2)
+                    .enumerate()
+                    .map(|(index, pair)| {
+                        let high = hex_value(pair[0]).ok_or_else(|| invalid_character(encoded, 2 * index))?;
+                        let low = hex_value(pair[1]).ok_or_else(|| invalid_character(encoded, 2 * index + 1))?;
+                        Ok((high << 4) | low)
+                    })
+                    .collect()
+            }
+            Encoding::Base64 => {
+                let unpadded = encoded.trim_end_matches('=');
+                if encoded.len() - unpadded.len() > 2 || (encoded.len() != unpadded.len() && !encoded.len().is_multiple_of(4)) {
+                    return Err("it has invalid padding".to_string());
+                }
+                if unpadded.len() % 4 == 1 {
+                    return Err("it has an invalid length".to_string());
+                }
+                let mut values = Vec::with_capacity(unpadded.len());
+                for (index, character) in unpadded.bytes().enumerate() {
+                    let value = BASE64_ALPHABET
+                        .iter()
+                        .position(|candidate| *candidate == character)
+                        .ok_or_else(|| invalid_character(encoded, index))?;
+                    values.push(value as u32);
+                }
+                Ok(values
+                    .chunks(4)
+                    .flat_map(|chunk| {
+                        let group = chunk
+                            .iter()
+                            .enumerate()
+                            .fold(0u32, |group, (index, value)| group | (value << (18 - 6 * index)));
+                        (0..chunk.len() - 1).map(move |index| (group >> (16 - 8 * index)) as u8)
+                    })
+                    .collect())
+            }
+        }
+    }
+}
+
+fn hex_value(digit: u8) -> Option<u8> {
+    (digit as char).to_digit(16).map(|value| value as u8)
+}
+
+fn invalid_character(encoded: &str, index: usize) -> String {
+    format!(
+        "it has an invalid character {:?} at index {}",
+        encoded.as_bytes()[index] as char,
+        index
+    )
+}
+
+impl EncodingMatcher {
+    fn new(encoding: Encoding, encoded: &str) -> Self {
+        match encoding.decode(encoded) {
+            Ok(expected) => EncodingMatcher { encoding, expected },
+            Err(reason) => panic!("{:?} is not a valid {} string, {}", encoded, encoding.name(), reason),
+        }
+    }
+
+    fn test(&self, bytes: &[u8]) -> MatcherResult {
+        let name = self.encoding.name();
+        let expected = self.encoding.encode(&self.expected);
+        MatcherResult::formatted(
+            bytes == self.expected.as_slice(),
+            format!(
+                "Bytes should equal {} {:?} but were {} {:?}",
+                name, expected, name, self.encoding.encode(bytes)
+            ),
+            format!("Bytes should not equal {} {:?}", name, expected),
+        )
+    }
+}
+
+impl Matcher<Vec<u8>> for EncodingMatcher {
+    fn test(&self, collection: &Vec<u8>) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+impl<const N: usize> Matcher<[u8; N]> for EncodingMatcher {
+    fn test(&self, collection: &[u8; N]) -> MatcherResult {
+        self.test(collection as &[u8])
+    }
+}
+
+impl Matcher<&[u8]> for EncodingMatcher {
+    fn test(&self, collection: &&[u8]) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+/// Creates an EncodingMatcher that asserts whether a sequence of bytes equals the bytes represented by the given hex string.
+///
+/// Panics if the hex string is not valid.
+pub fn equal_hex(hex: &str) -> EncodingMatcher {
+    EncodingMatcher::new(Encoding::Hex, hex)
+}
+
+/// Creates an EncodingMatcher that asserts whether a sequence of bytes equals the bytes represented by the given base64 (standard alphabet) string.
+///
+/// Panics if the base64 string is not valid.
+pub fn equal_base64(base64: &str) -> EncodingMatcher {
+    EncodingMatcher::new(Encoding::Base64, base64)
+}
+
+/// SubsliceMatcher offers a flexible way to assert whether a sequence of bytes contains the given bytes as a contiguous
+/// subsequence, similar to a substring search.
+///
+/// An empty needle is contained in every sequence of bytes. The failure message shows the bytes and the needle in hex,
+/// truncating the bytes after the first 32.
+///
+/// clearcheck implements SubsliceMatcher for `Vec<u8>`, `[u8; N]` and `&[u8]`.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::bytes::contain_bytes;
+/// use clearcheck::matchers::Matcher;
+///
+/// let bytes = [0xCA, 0xFE, 0xBA, 0xBE];
+/// let matcher = contain_bytes(&[0xFE, 0xBA]);
+///
+/// assert!(matcher.test(&bytes).passed());
+/// ```
+pub struct SubsliceMatcher {
+    needle: Vec<u8>,
+}
+
+const MAX_HEX_BYTES_IN_MESSAGE: usize = 32;
+
+impl SubsliceMatcher {
+    fn test(&self, bytes: &[u8]) -> MatcherResult {
+        let contains = self.needle.is_empty()
+            || bytes
+                .windows(self.needle.len())
+                .any(|window| window == self.needle.as_slice());
+
+        let haystack = if bytes.len() > MAX_HEX_BYTES_IN_MESSAGE {
+            format!("{}...", Encoding::Hex.encode(&bytes[..MAX_HEX_BYTES_IN_MESSAGE]))
+        } else {
+            Encoding::Hex.encode(bytes)
+        };
+        let needle = Encoding::Hex.encode(&self.needle);
+
+        MatcherResult::formatted(
+            contains,
+            format!("Bytes (hex) {:?} should contain {:?}", haystack, needle),
+            format!("Bytes (hex) {:?} should not contain {:?}", haystack, needle),
+        )
+    }
+}
+
+impl Matcher<Vec<u8>> for SubsliceMatcher {
+    fn test(&self, collection: &Vec<u8>) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+impl<const N: usize> Matcher<[u8; N]> for SubsliceMatcher {
+    fn test(&self, collection: &[u8; N]) -> MatcherResult {
+        self.test(collection as &[u8])
+    }
+}
+
+impl Matcher<&[u8]> for SubsliceMatcher {
+    fn test(&self, collection: &&[u8]) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+/// Creates a SubsliceMatcher that asserts whether a sequence of bytes contains the given bytes as a contiguous subsequence.
+pub fn contain_bytes(needle: &[u8]) -> SubsliceMatcher {
+    SubsliceMatcher {
+        needle: needle.to_vec(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::matchers::bytes::{be_valid_utf8, decode_to};
+
+    #[test]
+    fn should_be_valid_utf8() {
+        let bytes = "héllo".as_bytes();
+        let matcher = be_valid_utf8();
+        matcher.test(bytes).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_valid_utf8_but_was_not() {
+        let bytes = vec![104, 0xFF, 108];
+        let matcher = be_valid_utf8();
+        matcher.test(&bytes).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_decode_to() {
+        let bytes = [104, 105];
+        let matcher = decode_to("hi");
+        matcher.test(&bytes).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_decode_to_but_did_not() {
+        let bytes = [104, 105];
+        let matcher = decode_to("ho");
+        matcher.test(&bytes).passed.should_be_true();
+    }
+}
+
+#[cfg(test)]
+mod encoding_tests {
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::matchers::bytes::{equal_base64, equal_hex, Encoding};
+
+    #[test]
+    fn should_equal_hex() {
+        let bytes = [0xDE, 0xAD, 0xBE, 0xEF];
+        let matcher = equal_hex("DEADbeef");
+        matcher.test(&bytes).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_equal_hex_but_did_not() {
+        let bytes = [0xDE, 0xAD, 0xBE, 0xEF];
+        let matcher = equal_hex("deadbeaf");
+        matcher.test(&bytes).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_equal_base64() {
+        let bytes = "foobar".as_bytes();
+        let matcher = equal_base64("Zm9vYmFy");
+        matcher.test(bytes).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_equal_base64_but_did_not() {
+        let bytes = "foobar".as_bytes();
+        let matcher = equal_base64("Zm9vYmFz");
+        matcher.test(bytes).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_round_trip_base64_test_vectors() {
+        let vectors = [("", ""), ("f", "Zg=="), ("fo", "Zm8="), ("foo", "Zm9v"), ("foob", "Zm9vYg=="), ("fooba", "Zm9vYmE="), ("foobar", "Zm9vYmFy")];
+        for (plain, encoded) in vectors {
+            (Encoding::Base64.encode(plain.as_bytes()) == encoded).should_be_true();
+            (Encoding::Base64.decode(encoded).unwrap() == plain.as_bytes()).should_be_true();
+        }
+    }
+}
+
+#[cfg(test)]
+mod subslice_tests {
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::matchers::bytes::contain_bytes;
+
+    #[test]
+    fn should_contain_bytes() {
+        let bytes = [0xCA, 0xFE, 0xBA, 0xBE];
+        let matcher = contain_bytes(&[0xBA, 0xBE]);
+        matcher.test(&bytes).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_bytes_but_did_not() {
+        let bytes = vec![0xCA, 0xFE, 0xBA, 0xBE];
+        let matcher = contain_bytes(&[0xFE, 0xBE]);
+        matcher.test(&bytes).passed.should_be_true();
+    }
+}
@@ -0,0 +1,340 @@
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::ops::{Add, RangeInclusive};
+
+use crate::matchers::{Matcher, MatcherResult};
+
+/// Summable is implemented for the primitive numeric types whose elements can be summed, averaged and compared by [AggregateMatcher].
+///
+/// Integers (up to 64 bits) are summed as i128 to avoid overflow. Floats are summed in their own type, so the sum is the one
+/// native floating point arithmetic produces (`0.1f32 + 0.2f32` equals `0.3f32`) and is compared exactly.
+pub trait Summable: Copy + Debug + PartialOrd {
+    /// The type in which the elements are summed.
+    type Sum: Copy + Debug + PartialOrd + Default + Add<Output=Self::Sum>;
+
+    /// Converts the element into the type in which the elements are summed.
+    fn widen(self) -> Self::Sum;
+
+    /// Converts the sum into f64, to compute the average.
+    fn to_f64(sum: Self::Sum) -> f64;
+}
+
+macro_rules! summable_integer {
+    ($($t:ty),*) => {
+        $(impl Summable for $t {
+            type Sum = i128;
+
+            fn widen(self) -> i128 {
+                self as i128
+            }
+
+            fn to_f64(sum: i128) -> f64 {
+                sum as f64
+            }
+        })*
+    };
+}
+
+macro_rules! summable_float {
+    ($($t:ty),*) => {
+        $(impl Summable for $t {
+            type Sum = $t;
+
+            fn widen(self) -> $t {
+                self
+            }
+
+            fn to_f64(sum: $t) -> f64 {
+                sum as f64
+            }
+        })*
+    };
+}
+
+summable_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+summable_float!(f32, f64);
+
+/// AggregateMatcher offers a flexible way to assert the sum or the average of the numeric elements in a collection.
+///
+/// The sum of an empty collection is zero, while an empty collection has no average, median, minimum or maximum and fails those assertions.
+///
+/// The median, the minimum and the maximum only require the elements to be `PartialOrd`, so they work with floats. A collection
+/// containing NaN cannot be ordered and fails these assertions. The median of an even number of elements is the average of the two
+/// middle elements.
+///
+/// clearcheck implements AggregateMatcher for collection types including vector, arrays and reference to slices.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::collection::aggregate::have_sum;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = have_sum(100);
+/// let collection = vec![40, 35, 25];
+///
+/// assert!(matcher.test(&collection).passed());
+/// ```
+pub enum AggregateMatcher<T: Summable> {
+    Sum(T),
+    SumInInclusiveRange(RangeInclusive<T>),
+    Average(f64, f64),
+    MinValue(T),
+    MaxValue(T),
+    Median(f64, f64),
+}
+
+impl<T: Summable> AggregateMatcher<T> {
+    fn test(&self, collection: &[T]) -> MatcherResult {
+        let sum = collection
+            .iter()
+            .fold(T::Sum::default(), |sum, element| sum + element.widen());
+
+        match self {
+            AggregateMatcher::Sum(expected) => MatcherResult::formatted(
+                sum == expected.widen(),
+                format!(
+                    "{:?} should have sum {:?} but had {:?}",
+                    collection, expected, sum
+                ),
+                format!("{:?} should not have sum {:?}", collection, expected),
+            ),
+            AggregateMatcher::SumInInclusiveRange(range) => MatcherResult::formatted(
+                sum >= range.start().widen() && sum <= range.end().widen(),
+                format!(
+                    "{:?} should have sum in range {:?} but had {:?}",
+                    collection, range, sum
+                ),
+                format!(
+                    "{:?} should not have sum in range {:?} but had {:?}",
+                    collection, range, sum
+                ),
+            ),
+            AggregateMatcher::Average(expected, tolerance) => {
+                let average = (!collection.is_empty())
+                    .then(|| T::to_f64(sum) / collection.len() as f64);
+                let reason = match average {
+                    Some(average) => format!("had {:?}", average),
+                    None => String::from("it was empty"),
+                };
+                MatcherResult::formatted(
+                    average.is_some_and(|average| (average - expected).abs() <= *tolerance),
+                    format!(
+                        "{:?} should have average {:?} within {:?} but {}",
+                        collection, expected, tolerance, reason
+                    ),
+                    format!(
+                        "{:?} should not have average {:?} within {:?} but {}",
+                        collection, expected, tolerance, reason
+                    ),
+                )
+            }
+            AggregateMatcher::MinValue(expected) => {
+                Self::test_extremum(collection, "min", expected, |element, extremum| element < extremum)
+            }
+            AggregateMatcher::MaxValue(expected) => {
+                Self::test_extremum(collection, "max", expected, |element, extremum| element > extremum)
+            }
+            AggregateMatcher::Median(expected, tolerance) => {
+                let median = median(collection);
+                let reason = match median {
+                    _ if has_nan(collection) => String::from("it contained NaN"),
+                    Some(median) => format!("had {:?}", median),
+                    None => String::from("it was empty"),
+                };
+                MatcherResult::formatted(
+                    !has_nan(collection) && median.is_some_and(|median| (median - expected).abs() <= *tolerance),
+                    format!(
+                        "{:?} should have median {:?} within {:?} but {}",
+                        collection, expected, tolerance, reason
+                    ),
+                    format!(
+                        "{:?} should not have median {:?} within {:?} but {}",
+                        collection, expected, tolerance, reason
+                    ),
+                )
+            }
+        }
+    }
+
+    fn test_extremum<F>(collection: &[T], kind: &'static str, expected: &T, replaces: F) -> MatcherResult
+        where F: Fn(&T, &T) -> bool
+    {
+        let has_nan = has_nan(collection);
+        let extremum = collection.iter().fold(None, |extremum: Option<&T>, element| {
+            match extremum {
+                Some(extremum) if !replaces(element, extremum) => Some(extremum),
+                _ => Some(element),
+            }
+        });
+
+        let reason = match extremum {
+            _ if has_nan => String::from("it contained NaN"),
+            Some(extremum) => format!("had {:?}", extremum),
+            None => String::from("it was empty"),
+        };
+        MatcherResult::formatted(
+            !has_nan && extremum == Some(expected),
+            format!(
+                "{:?} should have {} value {:?} but {}",
+                collection, kind, expected, reason
+            ),
+            format!(
+                "{:?} should not have {} value {:?} but {}",
+                collection, kind, expected, reason
+            ),
+        )
+    }
+}
+
+impl<T: Summable> Matcher<Vec<T>> for AggregateMatcher<T> {
+    fn test(&self, collection: &Vec<T>) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+impl<T: Summable, const N: usize> Matcher<[T; N]> for AggregateMatcher<T> {
+    fn test(&self, collection: &[T; N]) -> MatcherResult {
+        self.test(collection as &[T])
+    }
+}
+
+impl<T: Summable> Matcher<&[T]> for AggregateMatcher<T> {
+    fn test(&self, collection: &&[T]) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+fn has_nan<T: PartialOrd>(collection: &[T]) -> bool {
+    collection
+        .iter()
+        .any(|element| element.partial_cmp(element).is_none())
+}
+
+fn median<T: Summable>(collection: &[T]) -> Option<f64> {
+    let mut sorted = collection.to_vec();
+    sorted.sort_by(|one, other| one.partial_cmp(other).unwrap_or(Ordering::Equal));
+
+    let middle = sorted.len() / 2;
+    match sorted.len() {
+        0 => None,
+        length if length % 2 == 1 => Some(T::to_f64(sorted[middle].widen())),
+        _ => Some(T::to_f64(sorted[middle - 1].widen() + sorted[middle].widen()) / 2.0),
+    }
+}
+
+/// Creates an AggregateMatcher that asserts whether the sum of the elements in a collection equals the given sum.
+pub fn have_sum<T: Summable>(sum: T) -> AggregateMatcher<T> {
+    AggregateMatcher::Sum(sum)
+}
+
+/// Creates an AggregateMatcher that asserts whether the sum of the elements in a collection falls within the given inclusive range.
+pub fn have_sum_in_inclusive_range<T: Summable>(range: RangeInclusive<T>) -> AggregateMatcher<T> {
+    AggregateMatcher::SumInInclusiveRange(range)
+}
+
+/// Creates an AggregateMatcher that asserts whether the average of the elements in a non-empty collection is within the tolerance of the given average.
+pub fn have_average<T: Summable>(average: f64, tolerance: f64) -> AggregateMatcher<T> {
+    AggregateMatcher::Average(average, tolerance)
+}
+
+/// Creates an AggregateMatcher that asserts whether the minimum of the elements in a non-empty collection without NaN equals the given value.
+pub fn have_min_value<T: Summable>(value: T) -> AggregateMatcher<T> {
+    AggregateMatcher::MinValue(value)
+}
+
+/// Creates an AggregateMatcher that asserts whether the maximum of the elements in a non-empty collection without NaN equals the given value.
+pub fn have_max_value<T: Summable>(value: T) -> AggregateMatcher<T> {
+    AggregateMatcher::MaxValue(value)
+}
+
+/// Creates an AggregateMatcher that asserts whether the median of the elements in a non-empty collection without NaN is within the tolerance of the given median.
+pub fn have_median<T: Summable>(median: f64, tolerance: f64) -> AggregateMatcher<T> {
+    AggregateMatcher::Median(median, tolerance)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::matchers::collection::aggregate::{have_average, have_max_value, have_median, have_min_value, have_sum, have_sum_in_inclusive_range};
+
+    #[test]
+    fn should_have_sum() {
+        let matcher = have_sum(100);
+        let collection = vec![40, 35, 25];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_sum_without_overflowing() {
+        let matcher = have_sum_in_inclusive_range(0..=u8::MAX);
+        let collection = [200_u8, 100, 0];
+        matcher.test(&collection).passed.should_be_false();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_sum_but_it_did_not() {
+        let matcher = have_sum(10);
+        let collection = vec![1, 2, 3];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_average() {
+        let matcher = have_average(2.5, 0.0);
+        let collection = vec![1, 2, 3, 4];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_average_but_it_was_empty() {
+        let matcher = have_average(0.0, 0.5);
+        let collection: Vec<f64> = vec![];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_min_value() {
+        let matcher = have_min_value(-1.5);
+        let collection = vec![2.0, -1.5, 0.25];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_max_value() {
+        let matcher = have_max_value(7);
+        let collection = [3, 7, 5];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_max_value_but_it_contained_nan() {
+        let matcher = have_max_value(2.0);
+        let collection = vec![f64::NAN, 2.0];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_median_of_odd_length() {
+        let matcher = have_median(5.0, 0.0);
+        let collection = vec![9, 1, 5];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_median_of_even_length() {
+        let matcher = have_median(4.5, 0.0);
+        let collection = [9.0, 1.0, 5.0, 4.0];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_median_but_it_was_empty() {
+        let matcher = have_median(0.0, 0.0);
+        let collection: Vec<i32> = vec![];
+        matcher.test(&collection).passed.should_be_true();
+    }
+}
@@ -0,0 +1,97 @@
+use crate::matchers::{Matcher, MatcherResult};
+
+/// CapacityMatcher offers a flexible way to assert the capacity of growable collections.
+///
+/// clearcheck implements CapacityMatcher for vector and String.
+///
+/// The capacity of a collection is implementation-defined: the allocator may reserve more than requested,
+/// which makes [have_atleast_capacity] the primary useful form.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::collection::capacity::have_atleast_capacity;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = have_atleast_capacity(10);
+/// let collection: Vec<i32> = Vec::with_capacity(10);
+///
+/// assert!(matcher.test(&collection).passed());
+/// ```
+pub enum CapacityMatcher {
+    Same(usize),
+    Atleast(usize),
+}
+
+impl<T> Matcher<Vec<T>> for CapacityMatcher {
+    fn test(&self, collection: &Vec<T>) -> MatcherResult {
+        self.test_capacity(collection.capacity())
+    }
+}
+
+impl Matcher<String> for CapacityMatcher {
+    fn test(&self, value: &String) -> MatcherResult {
+        self.test_capacity(value.capacity())
+    }
+}
+
+impl CapacityMatcher {
+    fn test_capacity(&self, input_capacity: usize) -> MatcherResult {
+        match self {
+            CapacityMatcher::Same(capacity) => MatcherResult::formatted(
+                input_capacity == *capacity,
+                format!("Capacity {:?} should be {:?}", input_capacity, capacity),
+                format!("Capacity {:?} should not be {:?}", input_capacity, capacity),
+            ),
+            CapacityMatcher::Atleast(capacity) => MatcherResult::formatted(
+                input_capacity >= *capacity,
+                format!("Capacity {:?} should be atleast {:?}", input_capacity, capacity),
+                format!("Capacity {:?} should not be atleast {:?}", input_capacity, capacity),
+            ),
+        }
+    }
+}
+
+/// Creates a CapacityMatcher that asserts whether the capacity of a collection is same as the given capacity.
+pub fn have_same_capacity(capacity: usize) -> CapacityMatcher {
+    CapacityMatcher::Same(capacity)
+}
+
+/// Creates a CapacityMatcher that asserts whether the capacity of a collection is greater than or equal to the given capacity.
+pub fn have_atleast_capacity(capacity: usize) -> CapacityMatcher {
+    CapacityMatcher::Atleast(capacity)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::matchers::collection::capacity::{have_atleast_capacity, have_same_capacity};
+    use crate::matchers::Matcher;
+
+    #[test]
+    fn should_have_atleast_capacity() {
+        let matcher = have_atleast_capacity(16);
+        let collection: Vec<i32> = Vec::with_capacity(16);
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_atleast_capacity_but_was_not() {
+        let matcher = have_atleast_capacity(16);
+        let collection: Vec<i32> = Vec::new();
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_same_capacity() {
+        let matcher = have_same_capacity(0);
+        let collection: Vec<i32> = Vec::new();
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_atleast_capacity_for_string() {
+        let matcher = have_atleast_capacity(32);
+        matcher.test(&String::with_capacity(32)).passed.should_be_true();
+    }
+}
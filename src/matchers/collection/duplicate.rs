@@ -1,4 +1,7 @@
+use std::collections::HashSet;
 use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::RangeInclusive;
 
 use crate::matchers::{Matcher, MatcherResult};
 
@@ -58,10 +61,353 @@ pub fn contain_duplicates() -> DuplicateContentMatcher {
     DuplicateContentMatcher
 }
 
+/// ConsecutiveDuplicateMatcher offers a flexible way to assert whether a collection contains adjacent equal elements.
+///
+/// Unlike [DuplicateContentMatcher], only adjacent pairs are compared, so `[1, 2, 1]` contains duplicates but no consecutive duplicates.
+///
+/// clearcheck implements ConsecutiveDuplicateMatcher for collection types including vector, arrays and reference to slices.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::collection::duplicate::contain_consecutive_duplicates;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = contain_consecutive_duplicates();
+/// let collection = vec!["junit", "junit", "clearcheck"];
+///
+/// assert!(matcher.test(&collection).passed());
+/// ```
+pub struct ConsecutiveDuplicateMatcher;
+
+impl ConsecutiveDuplicateMatcher {
+    fn test<T: Eq + Debug>(&self, collection: &[T]) -> MatcherResult {
+        let first_pair = collection
+            .windows(2)
+            .position(|pair| pair[0] == pair[1]);
+
+        let inverted_failure_message = match first_pair {
+            Some(index) => format!(
+                "{:?} should not contain consecutive duplicates but {:?} at index {} was repeated at index {}",
+                collection, collection[index], index, index + 1
+            ),
+            None => format!("{:?} should not contain consecutive duplicates", collection),
+        };
+        MatcherResult::formatted(
+            first_pair.is_some(),
+            format!("{:?} should contain consecutive duplicates", collection),
+            inverted_failure_message,
+        )
+    }
+}
+
+impl<T: Eq + Debug> Matcher<Vec<T>> for ConsecutiveDuplicateMatcher {
+    fn test(&self, collection: &Vec<T>) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+impl<T: Eq + Debug, const N: usize> Matcher<[T; N]> for ConsecutiveDuplicateMatcher {
+    fn test(&self, collection: &[T; N]) -> MatcherResult {
+        self.test(collection as &[T])
+    }
+}
+
+impl<T: Eq + Debug> Matcher<&[T]> for ConsecutiveDuplicateMatcher {
+    fn test(&self, collection: &&[T]) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+/// Creates a ConsecutiveDuplicateMatcher that asserts whether the underlying collection contains adjacent equal elements.
+pub fn contain_consecutive_duplicates() -> ConsecutiveDuplicateMatcher {
+    ConsecutiveDuplicateMatcher
+}
+
+/// UniformityMatcher offers a flexible way to assert whether all the elements of a collection are equal to each other,
+/// or all of them are distinct.
+///
+/// An empty or a single element collection has all its elements equal as well as distinct.
+///
+/// clearcheck implements UniformityMatcher for collection types including vector, arrays and reference to slices.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::collection::duplicate::all_be_equal;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = all_be_equal();
+/// let collection = vec!["junit", "junit", "junit"];
+///
+/// assert!(matcher.test(&collection).passed());
+/// ```
+pub enum UniformityMatcher {
+    AllEqual,
+    AllDistinct,
+}
+
+impl UniformityMatcher {
+    fn test<T: Eq + Debug>(&self, collection: &[T]) -> MatcherResult {
+        match self {
+            UniformityMatcher::AllEqual => {
+                let differing = collection
+                    .iter()
+                    .enumerate()
+                    .find(|(_, element)| *element != &collection[0]);
+                let failure_message = match differing {
+                    Some((index, element)) => format!(
+                        "{:?} should have all elements equal but {:?} at index {} differed from {:?}",
+                        collection, element, index, collection[0]
+                    ),
+                    None => format!("{:?} should have all elements equal", collection),
+                };
+                MatcherResult::formatted(
+                    differing.is_none(),
+                    failure_message,
+                    format!("{:?} should not have all elements equal", collection),
+                )
+            }
+            UniformityMatcher::AllDistinct => {
+                let duplicate = (0..collection.len()).find_map(|index| {
+                    collection[index + 1..]
+                        .iter()
+                        .position(|other| other == &collection[index])
+                        .map(|offset| (index, index + 1 + offset))
+                });
+                let failure_message = match duplicate {
+                    Some((first, second)) => format!(
+                        "{:?} should have all elements distinct but {:?} at index {} was repeated at index {}",
+                        collection, collection[first], first, second
+                    ),
+                    None => format!("{:?} should have all elements distinct", collection),
+                };
+                MatcherResult::formatted(
+                    duplicate.is_none(),
+                    failure_message,
+                    format!("{:?} should not have all elements distinct", collection),
+                )
+            }
+        }
+    }
+}
+
+impl<T: Eq + Debug> Matcher<Vec<T>> for UniformityMatcher {
+    fn test(&self, collection: &Vec<T>) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+impl<T: Eq + Debug, const N: usize> Matcher<[T; N]> for UniformityMatcher {
+    fn test(&self, collection: &[T; N]) -> MatcherResult {
+        self.test(collection as &[T])
+    }
+}
+
+impl<T: Eq + Debug> Matcher<&[T]> for UniformityMatcher {
+    fn test(&self, collection: &&[T]) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+/// Creates a UniformityMatcher that asserts whether all the elements of the underlying collection are equal to each other.
+pub fn all_be_equal() -> UniformityMatcher {
+    UniformityMatcher::AllEqual
+}
+
+/// Creates a UniformityMatcher that asserts whether all the elements of the underlying collection are distinct.
+pub fn all_be_distinct() -> UniformityMatcher {
+    UniformityMatcher::AllDistinct
+}
+
+/// OccurrenceMatcher offers a flexible way to assert how many times a specific element occurs in a collection.
+///
+/// clearcheck implements OccurrenceMatcher for collection types including vector, arrays and reference to slices.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::collection::duplicate::contain_duplicate_of;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = contain_duplicate_of("junit");
+/// let collection = vec!["junit", "testify", "junit"];
+///
+/// assert!(matcher.test(&collection).passed());
+/// ```
+pub enum OccurrenceMatcher<T: Eq> {
+    Duplicate(T),
+    Exactly(T, usize),
+}
+
+impl<T: Eq + Debug> OccurrenceMatcher<T> {
+    fn test(&self, collection: &[T]) -> MatcherResult {
+        match self {
+            OccurrenceMatcher::Duplicate(element) => {
+                let occurrences = count_of(collection, element);
+                MatcherResult::formatted(
+                    occurrences >= 2,
+                    format!(
+                        "{:?} should contain {:?} at least twice but it occurred {} time(s)",
+                        collection, element, occurrences
+                    ),
+                    format!(
+                        "{:?} should not contain {:?} more than once but it occurred {} time(s)",
+                        collection, element, occurrences
+                    ),
+                )
+            }
+            OccurrenceMatcher::Exactly(element, times) => {
+                let occurrences = count_of(collection, element);
+                MatcherResult::formatted(
+                    occurrences == *times,
+                    format!(
+                        "{:?} should contain {:?} exactly {} time(s) but it occurred {} time(s)",
+                        collection, element, times, occurrences
+                    ),
+                    format!(
+                        "{:?} should not contain {:?} exactly {} time(s)",
+                        collection, element, times
+                    ),
+                )
+            }
+        }
+    }
+}
+
+fn count_of<T: Eq>(collection: &[T], element: &T) -> usize {
+    collection.iter().filter(|source| *source == element).count()
+}
+
+impl<T: Eq + Debug> Matcher<Vec<T>> for OccurrenceMatcher<T> {
+    fn test(&self, collection: &Vec<T>) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+impl<T: Eq + Debug, const N: usize> Matcher<[T; N]> for OccurrenceMatcher<T> {
+    fn test(&self, collection: &[T; N]) -> MatcherResult {
+        self.test(collection as &[T])
+    }
+}
+
+impl<T: Eq + Debug> Matcher<&[T]> for OccurrenceMatcher<T> {
+    fn test(&self, collection: &&[T]) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+/// Creates an OccurrenceMatcher that asserts whether the given element occurs at least twice in the underlying collection.
+pub fn contain_duplicate_of<T: Eq>(element: T) -> OccurrenceMatcher<T> {
+    OccurrenceMatcher::Duplicate(element)
+}
+
+/// Creates an OccurrenceMatcher that asserts whether the given element occurs exactly the given number of times in the underlying collection.
+pub fn contain_exactly_n_of<T: Eq>(element: T, times: usize) -> OccurrenceMatcher<T> {
+    OccurrenceMatcher::Exactly(element, times)
+}
+
+/// DistinctCountMatcher offers a flexible way to assert the number of distinct elements in a collection.
+///
+/// clearcheck implements DistinctCountMatcher for collection types including vector, arrays and reference to slices.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::collection::duplicate::have_distinct_count;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = have_distinct_count(2);
+/// let collection = vec!["junit", "clearcheck", "junit"];
+///
+/// assert!(matcher.test(&collection).passed());
+/// ```
+pub enum DistinctCountMatcher {
+    Same(usize),
+    InclusiveRange(RangeInclusive<usize>),
+}
+
+impl DistinctCountMatcher {
+    fn test<T: Eq + Hash + Debug>(&self, collection: &[T]) -> MatcherResult {
+        let mut seen = HashSet::new();
+        let distinct = collection
+            .iter()
+            .filter(|element| seen.insert(*element))
+            .collect::<Vec<_>>();
+
+        match self {
+            DistinctCountMatcher::Same(count) => MatcherResult::formatted(
+                distinct.len() == *count,
+                format!(
+                    "{:?} should have {:?} distinct elements but had {:?} {:?}",
+                    collection, count, distinct.len(), distinct
+                ),
+                format!(
+                    "{:?} should not have {:?} distinct elements",
+                    collection, count
+                ),
+            ),
+            DistinctCountMatcher::InclusiveRange(range) => MatcherResult::formatted(
+                range.contains(&distinct.len()),
+                format!(
+                    "{:?} should have distinct elements count in the range {:?} but had {:?} {:?}",
+                    collection, range, distinct.len(), distinct
+                ),
+                format!(
+                    "{:?} should not have distinct elements count in the range {:?} but had {:?} {:?}",
+                    collection, range, distinct.len(), distinct
+                ),
+            ),
+        }
+    }
+}
+
+impl<T: Eq + Hash + Debug> Matcher<Vec<T>> for DistinctCountMatcher {
+    fn test(&self, collection: &Vec<T>) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+impl<T: Eq + Hash + Debug, const N: usize> Matcher<[T; N]> for DistinctCountMatcher {
+    fn test(&self, collection: &[T; N]) -> MatcherResult {
+        self.test(collection as &[T])
+    }
+}
+
+impl<T: Eq + Hash + Debug> Matcher<&[T]> for DistinctCountMatcher {
+    fn test(&self, collection: &&[T]) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+/// Creates a DistinctCountMatcher that asserts whether the number of distinct elements in the underlying collection is same as the given count.
+pub fn have_distinct_count(count: usize) -> DistinctCountMatcher {
+    DistinctCountMatcher::Same(count)
+}
+
+/// Creates a DistinctCountMatcher that asserts whether the number of distinct elements in the underlying collection falls within the given inclusive range.
+pub fn have_distinct_count_in_inclusive_range(range: RangeInclusive<usize>) -> DistinctCountMatcher {
+    DistinctCountMatcher::InclusiveRange(range)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::assertions::bool::TrueFalseAssertion;
-    use crate::matchers::collection::duplicate::contain_duplicates;
+    use crate::matchers::collection::duplicate::{
+        all_be_distinct, all_be_equal, contain_consecutive_duplicates, contain_duplicate_of, contain_duplicates,
+        contain_exactly_n_of, have_distinct_count, have_distinct_count_in_inclusive_range,
+    };
+
+    #[test]
+    fn should_contain_consecutive_duplicates() {
+        let matcher = contain_consecutive_duplicates();
+        let collection = vec![1, 1, 2];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_consecutive_duplicates_but_the_duplicates_were_apart() {
+        let matcher = contain_consecutive_duplicates();
+        let collection = vec![1, 2, 1];
+        matcher.test(&collection).passed.should_be_true();
+    }
 
     #[test]
     fn should_contains_duplicates() {
@@ -77,4 +423,101 @@ mod tests {
         let collection = vec!["junit", "assert4j", ""];
         matcher.test(&collection).passed.should_be_true();
     }
+
+    #[test]
+    fn should_have_distinct_count() {
+        let matcher = have_distinct_count(2);
+        let collection = vec!["junit", "assert4j", "junit"];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_distinct_count_but_it_did_not() {
+        let matcher = have_distinct_count(3);
+        let collection = vec!["junit", "assert4j", "junit"];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_distinct_count_in_inclusive_range() {
+        let matcher = have_distinct_count_in_inclusive_range(1..=2);
+        let collection = ["junit", "junit", "junit"];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_distinct_count_in_inclusive_range_but_it_did_not() {
+        let matcher = have_distinct_count_in_inclusive_range(3..=4);
+        let collection = ["junit", "assert4j", "junit"];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_all_be_equal() {
+        let matcher = all_be_equal();
+        let collection = vec!["junit", "junit", "junit"];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_all_be_equal_for_an_empty_collection() {
+        let matcher = all_be_equal();
+        let collection: Vec<&str> = vec![];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_all_be_equal_but_was_not() {
+        let matcher = all_be_equal();
+        let collection = vec!["junit", "junit", "assert4j"];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_all_be_distinct() {
+        let matcher = all_be_distinct();
+        let collection = ["junit", "assert4j", "testify"];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_all_be_distinct_but_was_not() {
+        let matcher = all_be_distinct();
+        let collection = ["junit", "assert4j", "junit"];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_contain_duplicate_of() {
+        let matcher = contain_duplicate_of("junit");
+        let collection = vec!["junit", "testify", "junit"];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_duplicate_of_but_it_occurred_once() {
+        let matcher = contain_duplicate_of("junit");
+        let collection = vec!["junit", "testify"];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_contain_exactly_n_of() {
+        let matcher = contain_exactly_n_of(1, 3);
+        let collection = [1, 2, 1, 1];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_exactly_n_of_but_it_did_not() {
+        let matcher = contain_exactly_n_of(1, 2);
+        let collection = [1, 2, 1, 1];
+        matcher.test(&collection).passed.should_be_true();
+    }
 }
@@ -3,6 +3,7 @@ use crate::matchers::{Matcher, MatcherResult};
 /// CollectionEmptyMatcher offers a flexible way to assert whether a collection is empty.
 ///
 /// clearcheck implements CollectionEmptyMatcher for collection types including vector, arrays and reference to slices.
+/// When a collection that should be empty is not, the failure message reports its length.
 ///
 /// # Example
 ///```
@@ -40,15 +41,15 @@ impl<T> Matcher<&[T]> for CollectionEmptyMatcher {
 impl CollectionEmptyMatcher {
     pub fn test_length<T>(&self, collection: &[T]) -> MatcherResult {
         match self {
-            CollectionEmptyMatcher::Empty => MatcherResult::new(
+            CollectionEmptyMatcher::Empty => MatcherResult::formatted(
                 collection.is_empty(),
-                "Collection should be empty",
-                "Collection should not be empty",
+                format!("Collection should be empty but had length {}", collection.len()),
+                String::from("Collection should not be empty"),
             ),
-            CollectionEmptyMatcher::NotEmpty => MatcherResult::new(
+            CollectionEmptyMatcher::NotEmpty => MatcherResult::formatted(
                 !collection.is_empty(),
-                "Collection should not be empty",
-                "Collection should be empty",
+                String::from("Collection should not be empty"),
+                format!("Collection should be empty but had length {}", collection.len()),
             ),
         }
     }
@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+use crate::matchers::{Matcher, MatcherResult};
+
+/// GroupMatcher offers a flexible way to assert the sizes of the groups formed by grouping the elements of a collection by a key function.
+///
+/// Every key produced by the key function must be present in the expected sizes, and every expected key must have a group of the expected size.
+/// On failure, the message lists the actual and the expected size for every key that differs, sorted for readability.
+///
+/// clearcheck implements GroupMatcher for collection types including vector, arrays and reference to slices.
+///
+/// # Example
+///```
+/// use std::collections::HashMap;
+/// use clearcheck::matchers::collection::group::group_by;
+/// use clearcheck::matchers::Matcher;
+///
+/// let collection = vec!["junit", "jest", "clearcheck"];
+/// let matcher = group_by(
+///     |element: &&str| element.chars().next(),
+///     HashMap::from([(Some('j'), 2), (Some('c'), 1)]),
+/// );
+///
+/// assert!(matcher.test(&collection).passed());
+/// ```
+pub struct GroupMatcher<F, T, K>
+    where F: Fn(&T) -> K,
+          K: Hash + Eq
+{
+    key_fn: F,
+    expected: HashMap<K, usize>,
+    _inner: PhantomData<T>,
+}
+
+impl<F, T, K> GroupMatcher<F, T, K>
+    where F: Fn(&T) -> K,
+          T: Debug,
+          K: Hash + Eq + Debug
+{
+    fn test(&self, collection: &[T]) -> MatcherResult {
+        let mut actual: HashMap<K, usize> = HashMap::new();
+        collection
+            .iter()
+            .for_each(|element| *actual.entry((self.key_fn)(element)).or_default() += 1);
+
+        let mut differences = actual
+            .iter()
+            .filter(|(key, size)| self.expected.get(key) != Some(size))
+            .map(|(key, size)| (key, *size, self.expected.get(key).copied().unwrap_or(0)))
+            .chain(
+                self.expected
+                    .iter()
+                    .filter(|(key, size)| **size != 0 && !actual.contains_key(key))
+                    .map(|(key, size)| (key, 0, *size)),
+            )
+            .map(|(key, actual, expected)| format!("{:?} (actual {}, expected {})", key, actual, expected))
+            .collect::<Vec<_>>();
+        differences.sort();
+
+        MatcherResult::formatted(
+            differences.is_empty(),
+            format!(
+                "{:?} should group into the expected sizes but differed for {}",
+                collection,
+                differences.join(", ")
+            ),
+            format!("{:?} should not group into the expected sizes", collection),
+        )
+    }
+}
+
+impl<F, T, K> Matcher<Vec<T>> for GroupMatcher<F, T, K>
+    where F: Fn(&T) -> K,
+          T: Debug,
+          K: Hash + Eq + Debug
+{
+    fn test(&self, collection: &Vec<T>) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+impl<F, T, K, const N: usize> Matcher<[T; N]> for GroupMatcher<F, T, K>
+    where F: Fn(&T) -> K,
+          T: Debug,
+          K: Hash + Eq + Debug
+{
+    fn test(&self, collection: &[T; N]) -> MatcherResult {
+        self.test(collection as &[T])
+    }
+}
+
+impl<F, T, K> Matcher<&[T]> for GroupMatcher<F, T, K>
+    where F: Fn(&T) -> K,
+          T: Debug,
+          K: Hash + Eq + Debug
+{
+    fn test(&self, collection: &&[T]) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+/// Creates a GroupMatcher that asserts whether grouping the elements of a collection by the key function yields groups of the expected sizes.
+pub fn group_by<F, T, K>(key_fn: F, expected: HashMap<K, usize>) -> GroupMatcher<F, T, K>
+    where F: Fn(&T) -> K,
+          K: Hash + Eq
+{
+    GroupMatcher {
+        key_fn,
+        expected,
+        _inner: PhantomData,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::matchers::collection::group::group_by;
+
+    #[test]
+    fn should_group_by() {
+        let matcher = group_by(
+            |element: &i32| element % 2 == 0,
+            HashMap::from([(true, 2), (false, 1)]),
+        );
+        let collection = vec![1, 2, 4];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_group_by_but_a_group_size_differed() {
+        let matcher = group_by(
+            |element: &i32| element % 2 == 0,
+            HashMap::from([(true, 1), (false, 2)]),
+        );
+        let collection = vec![1, 2, 4];
+        matcher.test(&collection).passed.should_be_true();
+    }
+}
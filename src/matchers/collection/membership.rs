@@ -1,4 +1,6 @@
+use std::collections::HashSet;
 use std::fmt::Debug;
+use std::hash::Hash;
 
 use crate::matchers::{Matcher, MatcherResult};
 
@@ -6,6 +8,10 @@ use crate::matchers::{Matcher, MatcherResult};
 ///
 /// Works with any data type that implements the Eq and Debug trait.
 ///
+/// On failure, `contain_all` reports every expected element that was missing from the collection. As the elements are
+/// only required to implement Eq (not Hash), the missing elements are found by a linear scan of the collection for
+/// each expected element.
+///
 /// clearcheck implements MembershipMatcher for collection types including vector, arrays and reference to slices.
 ///
 /// # Example
@@ -24,6 +30,10 @@ pub enum MembershipMatcher<T: Eq> {
     Contain(T),
     ContainAll(Vec<T>),
     ContainAny(Vec<T>),
+    ContainSequence(Vec<T>),
+    AllEqualTo(T),
+    ContainExactlyOnce(T),
+    PermutationOf(Vec<T>),
 }
 
 impl<T: Eq + Debug> MembershipMatcher<T> {
@@ -54,6 +64,73 @@ impl<T: Eq + Debug> MembershipMatcher<T> {
                 format!("{:?} should contain any of {:?}", collection, target),
                 format!("{:?} should not contain any of {:?}", collection, target),
             ),
+            MembershipMatcher::ContainSequence(sequence) => MatcherResult::formatted(
+                sequence.is_empty() || collection.windows(sequence.len()).any(|window| window == sequence.as_slice()),
+                format!("{:?} should contain the sequence {:?} contiguously but it was not found", collection, sequence),
+                format!("{:?} should not contain the sequence {:?} contiguously", collection, sequence),
+            ),
+            MembershipMatcher::AllEqualTo(element) => {
+                let mismatch = collection
+                    .iter()
+                    .enumerate()
+                    .find(|(_, source)| *source != element);
+
+                let failure_message = match mismatch {
+                    Some((index, source)) => format!(
+                        "{:?} should have all elements equal to {:?} but the element at index {:?} was {:?}",
+                        collection, element, index, source
+                    ),
+                    None => format!("{:?} should have all elements equal to {:?}", collection, element),
+                };
+                MatcherResult::formatted(
+                    mismatch.is_none(),
+                    failure_message,
+                    format!("{:?} should not have all elements equal to {:?}", collection, element),
+                )
+            }
+            MembershipMatcher::ContainExactlyOnce(element) => {
+                let occurrences = collection.iter().filter(|source| *source == element).count();
+                let failure_message = if occurrences == 0 {
+                    format!("{:?} should contain {:?} exactly once but it was not found", collection, element)
+                } else {
+                    format!(
+                        "{:?} should contain {:?} exactly once but it was found {:?} times",
+                        collection, element, occurrences
+                    )
+                };
+                MatcherResult::formatted(
+                    occurrences == 1,
+                    failure_message,
+                    format!("{:?} should not contain {:?} exactly once", collection, element),
+                )
+            }
+            MembershipMatcher::PermutationOf(other) => {
+                let mut distinct: Vec<&T> = Vec::new();
+                collection.iter().chain(other.iter()).for_each(|element| {
+                    if !distinct.contains(&element) {
+                        distinct.push(element)
+                    }
+                });
+                let differences = distinct
+                    .iter()
+                    .filter_map(|element| {
+                        let found = collection.iter().filter(|source| source == element).count();
+                        let expected = other.iter().filter(|source| source == element).count();
+                        (found != expected).then(|| {
+                            format!("{:?} (found {}, expected {})", element, found, expected)
+                        })
+                    })
+                    .collect::<Vec<_>>();
+
+                MatcherResult::formatted(
+                    differences.is_empty(),
+                    format!(
+                        "{:?} should be a permutation of {:?} but it was not: {}",
+                        collection, other, differences.join(", ")
+                    ),
+                    format!("{:?} should not be a permutation of {:?}", collection, other),
+                )
+            }
         }
     }
 }
@@ -109,10 +186,212 @@ pub fn contain_any<T>(elements: Vec<T>) -> MembershipMatcher<T>
     MembershipMatcher::ContainAny(elements)
 }
 
+/// Creates a MembershipMatcher that asserts whether a collection contains the given elements as a contiguous run, in the same order.
+/// An empty sequence is always contained.
+pub fn contain_sequence<T>(sequence: Vec<T>) -> MembershipMatcher<T>
+    where
+        T: Eq + Debug,
+{
+    MembershipMatcher::ContainSequence(sequence)
+}
+
+/// Creates a MembershipMatcher that asserts whether every element of a collection equals the given element.
+/// Empty collections trivially pass.
+pub fn all_be_equal_to<T>(element: T) -> MembershipMatcher<T>
+    where
+        T: Eq + Debug,
+{
+    MembershipMatcher::AllEqualTo(element)
+}
+
+/// Creates a MembershipMatcher that asserts whether a collection contains the given element exactly once.
+pub fn contain_exactly_once<T>(element: T) -> MembershipMatcher<T>
+    where
+        T: Eq + Debug,
+{
+    MembershipMatcher::ContainExactlyOnce(element)
+}
+
+/// Creates a MembershipMatcher that asserts whether a collection is a permutation of the given elements,
+/// that is, it contains the same elements with the same number of occurrences, in any order.
+pub fn be_permutation_of<T>(other: Vec<T>) -> MembershipMatcher<T>
+    where
+        T: Eq + Debug,
+{
+    MembershipMatcher::PermutationOf(other)
+}
+
+/// IgnoreCaseOrderedMembershipMatcher offers a flexible way to assert that a collection of strings contains the given
+/// elements in the same relative order, ignoring case differences.
+///
+/// The elements need not be contiguous: other elements may appear between them. Elements are compared by their
+/// lowercased forms.
+///
+/// clearcheck implements IgnoreCaseOrderedMembershipMatcher for collection types including vector, arrays and
+/// reference to slices, where the elements implement `AsRef<str>`.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::collection::membership::contain_all_in_order_ignoring_case;
+/// use clearcheck::matchers::Matcher;
+///
+/// let log = vec!["STARTING server", "listening", "Ready", "shutdown"];
+/// let matcher = contain_all_in_order_ignoring_case(vec!["starting server", "READY"]);
+///
+/// assert!(matcher.test(&log).passed());
+/// ```
+pub struct IgnoreCaseOrderedMembershipMatcher<T: AsRef<str>> {
+    elements: Vec<T>,
+}
+
+impl<T: AsRef<str> + Debug> IgnoreCaseOrderedMembershipMatcher<T> {
+    fn test<S: AsRef<str> + Debug>(&self, collection: &[S]) -> MatcherResult {
+        let mut candidates = collection.iter().map(|source| source.as_ref().to_lowercase());
+        let matched = self
+            .elements
+            .iter()
+            .take_while(|element| {
+                let element = element.as_ref().to_lowercase();
+                candidates.any(|candidate| candidate == element)
+            })
+            .count();
+
+        let missing = self
+            .elements
+            .get(matched)
+            .map(|element| format!("{:?}", element))
+            .unwrap_or_default();
+
+        MatcherResult::formatted(
+            matched == self.elements.len(),
+            format!(
+                "{:?} should contain {:?} in order ignoring case but only {} of {} elements matched in order and {} was not found",
+                collection,
+                self.elements,
+                matched,
+                self.elements.len(),
+                missing
+            ),
+            format!(
+                "{:?} should not contain {:?} in order ignoring case",
+                collection, self.elements
+            ),
+        )
+    }
+}
+
+impl<S, T> Matcher<Vec<S>> for IgnoreCaseOrderedMembershipMatcher<T>
+    where
+        S: AsRef<str> + Debug,
+        T: AsRef<str> + Debug,
+{
+    fn test(&self, collection: &Vec<S>) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+impl<S, T, const N: usize> Matcher<[S; N]> for IgnoreCaseOrderedMembershipMatcher<T>
+    where
+        S: AsRef<str> + Debug,
+        T: AsRef<str> + Debug,
+{
+    fn test(&self, collection: &[S; N]) -> MatcherResult {
+        self.test(collection as &[S])
+    }
+}
+
+impl<S, T> Matcher<&[S]> for IgnoreCaseOrderedMembershipMatcher<T>
+    where
+        S: AsRef<str> + Debug,
+        T: AsRef<str> + Debug,
+{
+    fn test(&self, collection: &&[S]) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+/// Creates an IgnoreCaseOrderedMembershipMatcher that asserts whether a collection of strings contains all the given
+/// elements in the same relative order, ignoring case differences.
+pub fn contain_all_in_order_ignoring_case<T>(elements: Vec<T>) -> IgnoreCaseOrderedMembershipMatcher<T>
+    where
+        T: AsRef<str> + Debug,
+{
+    IgnoreCaseOrderedMembershipMatcher { elements }
+}
+
+/// DisjointMatcher offers a flexible way to assert that a collection shares no elements with the given elements.
+///
+/// The given elements are collected into a set once and the collection is scanned against it, so the elements need
+/// to implement Hash. On failure, each shared element is reported once, in the order it appears in the collection.
+/// Empty collections are trivially disjoint.
+///
+/// clearcheck implements DisjointMatcher for collection types including vector, arrays and reference to slices.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::collection::membership::be_disjoint_from;
+/// use clearcheck::matchers::Matcher;
+///
+/// let collection = vec!["junit", "assert4j"];
+/// let matcher = be_disjoint_from(vec!["testify", "xunit"]);
+///
+/// assert!(matcher.test(&collection).passed());
+/// ```
+pub struct DisjointMatcher<T: Eq + Hash> {
+    other: Vec<T>,
+}
+
+impl<T: Eq + Hash + Debug> DisjointMatcher<T> {
+    fn test(&self, collection: &[T]) -> MatcherResult {
+        let other = self.other.iter().collect::<HashSet<_>>();
+        let mut seen = HashSet::new();
+        let shared = collection
+            .iter()
+            .filter(|element| other.contains(element) && seen.insert(*element))
+            .collect::<Vec<_>>();
+
+        MatcherResult::formatted(
+            shared.is_empty(),
+            format!(
+                "{:?} should be disjoint from {:?} but shared {:?}",
+                collection, self.other, shared
+            ),
+            format!("{:?} should not be disjoint from {:?}", collection, self.other),
+        )
+    }
+}
+
+impl<T: Eq + Hash + Debug> Matcher<Vec<T>> for DisjointMatcher<T> {
+    fn test(&self, collection: &Vec<T>) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+impl<T: Eq + Hash + Debug, const N: usize> Matcher<[T; N]> for DisjointMatcher<T> {
+    fn test(&self, collection: &[T; N]) -> MatcherResult {
+        self.test(collection as &[T])
+    }
+}
+
+impl<T: Eq + Hash + Debug> Matcher<&[T]> for DisjointMatcher<T> {
+    fn test(&self, collection: &&[T]) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+/// Creates a DisjointMatcher that asserts whether a collection shares no elements with the given elements.
+/// Empty collections are trivially disjoint.
+pub fn be_disjoint_from<T>(other: Vec<T>) -> DisjointMatcher<T>
+    where
+        T: Eq + Hash + Debug,
+{
+    DisjointMatcher { other }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::assertions::bool::TrueFalseAssertion;
-    use crate::matchers::collection::membership::{contain, contain_all, contain_any};
+    use crate::matchers::collection::membership::{all_be_equal_to, be_disjoint_from, be_permutation_of, contain, contain_all, contain_all_in_order_ignoring_case, contain_any, contain_exactly_once, contain_sequence};
 
     #[test]
     fn should_contain() {
@@ -162,4 +441,103 @@ mod tests {
         let matcher = contain_any(to_be_contained);
         matcher.test(&collection).passed.should_be_true();
     }
+
+    #[test]
+    fn should_contain_sequence() {
+        let collection = vec![1, 2, 3, 4];
+        let matcher = contain_sequence(vec![2, 3]);
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_sequence_but_it_was_not_contiguous() {
+        let collection = vec![1, 2, 3, 4];
+        let matcher = contain_sequence(vec![2, 4]);
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_contain_an_empty_sequence() {
+        let collection = vec![1, 2, 3, 4];
+        let matcher = contain_sequence(vec![]);
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_sequence_but_it_was_longer_than_the_collection() {
+        let collection = vec![1, 2];
+        let matcher = contain_sequence(vec![1, 2, 3]);
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_disjoint_from() {
+        let collection = vec![1, 2, 3];
+        let matcher = be_disjoint_from(vec![4, 5]);
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_disjoint_from_but_it_was_not() {
+        let collection = vec![1, 2, 3];
+        let matcher = be_disjoint_from(vec![3, 4]);
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_all_be_equal_to() {
+        let matcher = all_be_equal_to(7);
+        matcher.test(&[7, 7, 7]).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_all_be_equal_to_but_an_element_was_not() {
+        let matcher = all_be_equal_to(7);
+        matcher.test(&[7, 8, 7]).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_contain_exactly_once() {
+        let matcher = contain_exactly_once("junit");
+        matcher.test(&["junit", "testify"]).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_exactly_once_but_it_was_duplicated() {
+        let matcher = contain_exactly_once("junit");
+        matcher.test(&["junit", "testify", "junit"]).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_permutation_of() {
+        let matcher = be_permutation_of(vec![3, 1, 2, 1]);
+        matcher.test(&[1, 1, 2, 3]).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_permutation_of_but_the_counts_differed() {
+        let matcher = be_permutation_of(vec![1, 2, 2]);
+        matcher.test(&[1, 1, 2]).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_contain_all_in_order_ignoring_case() {
+        let collection = vec!["STARTING server", "listening", "Ready", "shutdown"];
+        let matcher = contain_all_in_order_ignoring_case(vec!["starting server", "READY"]);
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_all_in_order_ignoring_case_but_the_order_was_different() {
+        let collection = vec!["STARTING server", "listening", "Ready", "shutdown"];
+        let matcher = contain_all_in_order_ignoring_case(vec!["ready", "listening"]);
+        matcher.test(&collection).passed.should_be_true();
+    }
 }
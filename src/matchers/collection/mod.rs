@@ -1,7 +1,10 @@
+pub mod aggregate;
 pub mod bound;
+pub mod capacity;
 pub mod duplicate;
 pub mod empty;
 pub mod equal;
+pub mod group;
 pub mod increasing_decreasing;
 pub mod length;
 pub mod membership;
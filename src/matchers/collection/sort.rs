@@ -2,7 +2,9 @@ use std::fmt::Debug;
 
 use crate::matchers::{Matcher, MatcherResult};
 
-/// SortMatcher offers a flexible way to assert whether a collection is sorted in ascending or descending order.
+/// SortMatcher offers a flexible way to assert whether a collection is sorted in ascending or descending order,
+/// or is monotonic (sorted in either of the two directions), or is approximately sorted ascending with at most the given
+/// number of inversions.
 ///
 /// clearcheck implements SortMatcher for collection types including vector, arrays and reference to slices.
 ///
@@ -19,6 +21,8 @@ use crate::matchers::{Matcher, MatcherResult};
 pub enum SortMatcher {
     Ascending,
     Descending,
+    Monotonic,
+    AtMostInversions(usize),
 }
 
 impl SortMatcher {
@@ -34,10 +38,53 @@ impl SortMatcher {
                 format!("{:?} should be sorted descending", collection),
                 format!("{:?} should not be sorted descending", collection),
             ),
+            SortMatcher::Monotonic => {
+                let broken_at = first_non_monotonic_index(collection);
+                MatcherResult::formatted(
+                    broken_at.is_none(),
+                    format!(
+                        "{:?} should be monotonic (sorted ascending or descending) but the order broke in both directions at index {}",
+                        collection,
+                        broken_at.unwrap_or_default()
+                    ),
+                    format!("{:?} should not be monotonic (sorted ascending or descending)", collection),
+                )
+            }
+            SortMatcher::AtMostInversions(allowed) => {
+                let inversions = count_inversions(collection);
+                MatcherResult::formatted(
+                    inversions <= *allowed,
+                    format!(
+                        "{:?} should have at most {} inversions but had {}",
+                        collection, allowed, inversions
+                    ),
+                    format!(
+                        "{:?} should not have at most {} inversions but had {}",
+                        collection, allowed, inversions
+                    ),
+                )
+            }
         }
     }
 }
 
+fn first_non_monotonic_index<T: PartialOrd>(collection: &[T]) -> Option<usize> {
+    let ascending_break = collection.windows(2).position(|pair| pair[0] > pair[1]);
+    let descending_break = collection.windows(2).position(|pair| pair[0] < pair[1]);
+    match (ascending_break, descending_break) {
+        (Some(ascending), Some(descending)) => Some(ascending.max(descending) + 1),
+        _ => None,
+    }
+}
+
+fn count_inversions<T: PartialOrd>(collection: &[T]) -> usize {
+    collection
+        .iter()
+        .enumerate()
+        .map(|(index, element)| collection[index + 1..].iter().filter(|other| element > *other).count())
+        .sum()
+}
+
 impl<T: PartialOrd + Debug> Matcher<Vec<T>> for SortMatcher {
     fn test(&self, collection: &Vec<T>) -> MatcherResult {
         self.test(collection)
@@ -66,10 +113,232 @@ pub fn be_sorted_descending() -> SortMatcher {
     SortMatcher::Descending
 }
 
+/// Creates a SortMatcher that asserts whether the elements in a collection are either entirely non-decreasing or entirely non-increasing.
+pub fn be_monotonic() -> SortMatcher {
+    SortMatcher::Monotonic
+}
+
+/// Creates a SortMatcher that asserts whether a collection has at most the given number of inversions, where an inversion is any pair of elements (not necessarily adjacent) that is out of ascending order.
+pub fn have_at_most_inversions(inversions: usize) -> SortMatcher {
+    SortMatcher::AtMostInversions(inversions)
+}
+
+/// SortByKeyMatcher offers a flexible way to assert whether a collection is sorted in ascending or descending order
+/// of a key extracted from each element.
+///
+/// Keys are extracted lazily while walking the adjacent pairs, stopping at the first pair out of order.
+///
+/// clearcheck implements SortByKeyMatcher for collection types including vector, arrays and reference to slices.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::collection::sort::be_sorted_ascending_by_key;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = be_sorted_ascending_by_key(|name: &&str| name.len());
+/// let collection = vec!["junit", "clearcheck"];
+///
+/// assert!(matcher.test(&collection).passed());
+/// ```
+pub enum SortByKeyMatcher<F> {
+    Ascending(F),
+    Descending(F),
+}
+
+impl<F> SortByKeyMatcher<F> {
+    fn test<T, K>(&self, collection: &[T]) -> MatcherResult
+    where
+        T: Debug,
+        K: Ord + Debug,
+        F: Fn(&T) -> K,
+    {
+        match self {
+            SortByKeyMatcher::Ascending(key_fn) => {
+                let offending = first_out_of_order(collection, key_fn, |previous, next| previous > next);
+                MatcherResult::formatted(
+                    offending.is_none(),
+                    format!(
+                        "{:?} should be sorted ascending by key{}",
+                        collection,
+                        describe(offending, "greater")
+                    ),
+                    format!("{:?} should not be sorted ascending by key", collection),
+                )
+            }
+            SortByKeyMatcher::Descending(key_fn) => {
+                let offending = first_out_of_order(collection, key_fn, |previous, next| previous < next);
+                MatcherResult::formatted(
+                    offending.is_none(),
+                    format!(
+                        "{:?} should be sorted descending by key{}",
+                        collection,
+                        describe(offending, "less")
+                    ),
+                    format!("{:?} should not be sorted descending by key", collection),
+                )
+            }
+        }
+    }
+}
+
+fn first_out_of_order<T, K, F, P>(collection: &[T], key_fn: &F, out_of_order: P) -> Option<(usize, K, K)>
+where
+    F: Fn(&T) -> K,
+    P: Fn(&K, &K) -> bool,
+{
+    collection
+        .windows(2)
+        .enumerate()
+        .map(|(index, pair)| (index, key_fn(&pair[0]), key_fn(&pair[1])))
+        .find(|(_, previous, next)| out_of_order(previous, next))
+}
+
+fn describe<K: Debug>(offending: Option<(usize, K, K)>, comparison: &str) -> String {
+    match offending {
+        Some((index, previous, next)) => format!(
+            " but the key {:?} at index {} was {} than the key {:?} at index {}",
+            previous,
+            index,
+            comparison,
+            next,
+            index + 1
+        ),
+        None => String::new(),
+    }
+}
+
+impl<T, K, F> Matcher<Vec<T>> for SortByKeyMatcher<F>
+where
+    T: Debug,
+    K: Ord + Debug,
+    F: Fn(&T) -> K,
+{
+    fn test(&self, collection: &Vec<T>) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+impl<T, K, F, const N: usize> Matcher<[T; N]> for SortByKeyMatcher<F>
+where
+    T: Debug,
+    K: Ord + Debug,
+    F: Fn(&T) -> K,
+{
+    fn test(&self, collection: &[T; N]) -> MatcherResult {
+        self.test(collection as &[T])
+    }
+}
+
+impl<T, K, F> Matcher<&[T]> for SortByKeyMatcher<F>
+where
+    T: Debug,
+    K: Ord + Debug,
+    F: Fn(&T) -> K,
+{
+    fn test(&self, collection: &&[T]) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+/// Creates a SortByKeyMatcher that asserts whether the elements in a collection are sorted in ascending order of the key extracted by the given function.
+pub fn be_sorted_ascending_by_key<T, K, F>(key_fn: F) -> SortByKeyMatcher<F>
+where
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+    SortByKeyMatcher::Ascending(key_fn)
+}
+
+/// Creates a SortByKeyMatcher that asserts whether the elements in a collection are sorted in descending order of the key extracted by the given function.
+pub fn be_sorted_descending_by_key<T, K, F>(key_fn: F) -> SortByKeyMatcher<F>
+where
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+    SortByKeyMatcher::Descending(key_fn)
+}
+
+/// IgnoreCaseSortMatcher offers a flexible way to assert whether a collection of strings is sorted in ascending order,
+/// ignoring case differences.
+///
+/// Adjacent elements are compared by their lowercased forms, so "apple" may come before "Banana".
+///
+/// clearcheck implements IgnoreCaseSortMatcher for collection types including vector, arrays and reference to slices,
+/// where the elements implement `AsRef<str>`.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::collection::sort::be_sorted_ascending_ignoring_case;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = be_sorted_ascending_ignoring_case();
+/// let collection = vec!["apple", "Banana", "cherry"];
+///
+/// assert!(matcher.test(&collection).passed());
+/// ```
+pub struct IgnoreCaseSortMatcher;
+
+impl IgnoreCaseSortMatcher {
+    fn test<T: AsRef<str> + Debug>(&self, collection: &[T]) -> MatcherResult {
+        let offending = collection
+            .windows(2)
+            .position(|pair| pair[0].as_ref().to_lowercase() > pair[1].as_ref().to_lowercase());
+
+        let reason = offending
+            .map(|index| {
+                format!(
+                    " but {:?} at index {} should not come before {:?} at index {}",
+                    collection[index],
+                    index,
+                    collection[index + 1],
+                    index + 1
+                )
+            })
+            .unwrap_or_default();
+
+        MatcherResult::formatted(
+            offending.is_none(),
+            format!("{:?} should be sorted ascending ignoring case{}", collection, reason),
+            format!("{:?} should not be sorted ascending ignoring case", collection),
+        )
+    }
+}
+
+impl<T: AsRef<str> + Debug> Matcher<Vec<T>> for IgnoreCaseSortMatcher {
+    fn test(&self, collection: &Vec<T>) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+impl<T: AsRef<str> + Debug, const N: usize> Matcher<[T; N]> for IgnoreCaseSortMatcher {
+    fn test(&self, collection: &[T; N]) -> MatcherResult {
+        self.test(collection as &[T])
+    }
+}
+
+impl<T: AsRef<str> + Debug> Matcher<&[T]> for IgnoreCaseSortMatcher {
+    fn test(&self, collection: &&[T]) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+/// Creates an IgnoreCaseSortMatcher that asserts whether the strings in a collection are sorted in ascending order, ignoring case.
+pub fn be_sorted_ascending_ignoring_case() -> IgnoreCaseSortMatcher {
+    IgnoreCaseSortMatcher
+}
+
 #[cfg(test)]
 mod tests {
     use crate::assertions::bool::TrueFalseAssertion;
-    use crate::matchers::collection::sort::{be_sorted_ascending, be_sorted_descending};
+    use crate::matchers::collection::sort::{
+        be_monotonic, be_sorted_ascending, be_sorted_ascending_by_key, be_sorted_ascending_ignoring_case,
+        be_sorted_descending, be_sorted_descending_by_key, have_at_most_inversions,
+    };
+
+    #[derive(Debug)]
+    struct Book {
+        pages: usize,
+    }
 
     #[test]
     fn should_be_sorted_ascending() {
@@ -100,4 +369,85 @@ mod tests {
         let collection = vec!["assert4j", "junit"];
         matcher.test(&collection).passed.should_be_true();
     }
+
+    #[test]
+    fn should_be_sorted_ascending_by_key() {
+        let matcher = be_sorted_ascending_by_key(|book: &Book| book.pages);
+        let collection = vec![Book { pages: 100 }, Book { pages: 100 }, Book { pages: 250 }];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_sorted_ascending_by_key_but_was_not() {
+        let matcher = be_sorted_ascending_by_key(|book: &Book| book.pages);
+        let collection = vec![Book { pages: 250 }, Book { pages: 100 }];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_sorted_descending_by_key() {
+        let matcher = be_sorted_descending_by_key(|book: &Book| book.pages);
+        let collection = [Book { pages: 250 }, Book { pages: 100 }];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_sorted_ascending_by_key_for_an_empty_collection() {
+        let matcher = be_sorted_ascending_by_key(|book: &Book| book.pages);
+        let collection: Vec<Book> = vec![];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_monotonic_given_a_descending_collection() {
+        let matcher = be_monotonic();
+        let collection = vec![9, 7, 7, 2];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_monotonic_given_an_empty_collection() {
+        let matcher = be_monotonic();
+        let collection: Vec<i32> = vec![];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_monotonic_but_was_not() {
+        let matcher = be_monotonic();
+        let collection = vec![1, 3, 2];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_at_most_inversions() {
+        let matcher = have_at_most_inversions(1);
+        let collection = vec![1, 3, 2, 4];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_at_most_inversions_but_had_more() {
+        let matcher = have_at_most_inversions(2);
+        let collection = vec![3, 2, 1];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_sorted_ascending_ignoring_case() {
+        let matcher = be_sorted_ascending_ignoring_case();
+        let collection = vec!["apple", "Banana", "cherry"];
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_sorted_ascending_ignoring_case_but_was_only_sorted_case_sensitively() {
+        let matcher = be_sorted_ascending_ignoring_case();
+        let collection = vec!["Banana", "apple"];
+        matcher.test(&collection).passed.should_be_true();
+    }
 }
@@ -1,10 +1,13 @@
 use std::fmt::Debug;
+use std::marker::PhantomData;
 
 use crate::matchers::{Matcher, MatcherResult};
 
 enum Kind {
     And,
     Or,
+    Xor,
+    AtLeast(usize),
 }
 
 /// MatcherBehavior encapsulates a matcher and an inversion flag, governing how it's applied in assertions.
@@ -98,6 +101,18 @@ impl<T: Debug> MatchersBuilder<T> {
         self
     }
 
+    /// Pushes the instances of all the given matchers to the MatchersBuilder, preserving their order.
+    pub fn push_all(mut self, matchers: Vec<Box<dyn Matcher<T>>>) -> Self {
+        self.matchers_behaviors.extend(matchers.into_iter().map(MatcherBehavior::new));
+        self
+    }
+
+    /// Pushes the instances of all the given matchers inverted to the MatchersBuilder, preserving their order.
+    pub fn push_all_inverted(mut self, matchers: Vec<Box<dyn Matcher<T>>>) -> Self {
+        self.matchers_behaviors.extend(matchers.into_iter().map(MatcherBehavior::inverted));
+        self
+    }
+
     /// Combines all the matchers using AND operator.
     /// All the matchers must pass for Matchers to pass.
     pub fn combine_as_and(self) -> Matchers<T> {
@@ -109,14 +124,58 @@ impl<T: Debug> MatchersBuilder<T> {
     pub fn combine_as_or(self) -> Matchers<T> {
         Matchers::or(self.matchers_behaviors)
     }
+
+    /// Combines all the matchers using XOR operator.
+    /// Exactly one of the matchers must pass for Matchers to pass.
+    pub fn combine_as_xor(self) -> Matchers<T> {
+        Matchers::xor(self.matchers_behaviors)
+    }
+
+    /// Combines all the matchers such that at least k of them must pass for Matchers to pass.
+    /// If k is greater than the number of matchers, Matchers never passes and the failure message reports the shortfall.
+    ///
+    /// Panics if k is zero, as Matchers would pass for any value.
+    pub fn combine_as_at_least(self, k: usize) -> Matchers<T> {
+        assert!(k > 0, "k should be greater than zero");
+        Matchers::at_least(self.matchers_behaviors, k)
+    }
+
+    /// Combines all the matchers using AND operator, stopping at the first failing matcher.
+    /// All the matchers must pass for Matchers to pass.
+    /// The matchers after the first failing one are not run, so the failure message reports "a matcher failed:" followed by the first failure,
+    /// instead of counting the failures.
+    pub fn combine_as_and_short_circuit(self) -> Matchers<T> {
+        Matchers::and(self.matchers_behaviors).short_circuit()
+    }
+
+    /// Combines all the matchers using OR operator, stopping at the first passing matcher.
+    /// Any of the matchers must pass for Matchers to pass.
+    /// The matchers after the first passing one are not run, which is useful when the matchers are expensive.
+    pub fn combine_as_or_short_circuit(self) -> Matchers<T> {
+        Matchers::or(self.matchers_behaviors).short_circuit()
+    }
 }
 
 /// Matchers provides a way to combine various matchers using AND or OR operators.
 /// If an instance of Matchers is created using AND operator, all the underlying matchers MUST pass for Matchers to pass.
 /// If an instance of Matchers is created using OR operator, any of the underlying matchers MUST pass for Matchers to pass.
+/// If an instance of Matchers is created using XOR operator, exactly one of the underlying matchers MUST pass for Matchers to pass.
+/// If an instance of Matchers is created using at least k, k or more of the underlying matchers MUST pass for Matchers to pass.
+/// By default, all the underlying matchers are run to collect complete diagnostics; a short-circuiting Matchers stops
+/// at the first matcher that decides the outcome.
+///
+/// A failing AND prefixes the failure messages with "N of M matchers failed:" and a failing OR prefixes them with
+/// "all M matchers failed:". A short-circuiting AND only runs up to the first failure, so its message carries that
+/// failure without a count.
+///
+/// When Matchers is used with `should_not`, the inverted failure message explains that the composite unexpectedly passed
+/// and lists the inverted failure messages of the matchers that passed: "all M matchers passed but at least one should fail:"
+/// for AND and "N of M matchers passed but none should pass:" for OR. A short-circuiting OR only runs up to the first
+/// passing matcher, so its inverted message carries that matcher without a count.
 pub struct Matchers<T> {
     matcher_behaviors: Vec<MatcherBehavior<T>>,
     kind: Kind,
+    short_circuit: bool,
 }
 
 impl<T: Debug> Matchers<T> {
@@ -124,6 +183,7 @@ impl<T: Debug> Matchers<T> {
         Matchers {
             matcher_behaviors: matchers,
             kind: Kind::And,
+            short_circuit: false,
         }
     }
 
@@ -131,46 +191,256 @@ impl<T: Debug> Matchers<T> {
         Matchers {
             matcher_behaviors: matchers,
             kind: Kind::Or,
+            short_circuit: false,
+        }
+    }
+
+    fn xor(matchers: Vec<MatcherBehavior<T>>) -> Self {
+        Matchers {
+            matcher_behaviors: matchers,
+            kind: Kind::Xor,
+            short_circuit: false,
+        }
+    }
+
+    fn at_least(matchers: Vec<MatcherBehavior<T>>, k: usize) -> Self {
+        Matchers {
+            matcher_behaviors: matchers,
+            kind: Kind::AtLeast(k),
+            short_circuit: false,
+        }
+    }
+
+    fn short_circuit(mut self) -> Self {
+        self.short_circuit = true;
+        self
+    }
+
+    fn run_matchers(&self, value: &T) -> Vec<MatcherResult> {
+        let mut results = Vec::with_capacity(self.matcher_behaviors.len());
+        for matcher_behavior in &self.matcher_behaviors {
+            let result = matcher_behavior.run_matcher(value);
+            let decided = match self.kind {
+                Kind::And => !result.passed,
+                Kind::Or => result.passed,
+                Kind::Xor | Kind::AtLeast(_) => false,
+            };
+            results.push(result);
+            if self.short_circuit && decided {
+                break;
+            }
         }
+        results
     }
 }
 
 /// Matchers implement the [`crate::matchers::Matcher`] trait.
 impl<T: Debug> Matcher<T> for Matchers<T> {
     fn test(&self, value: &T) -> MatcherResult {
-        let results = self
-            .matcher_behaviors
-            .iter()
-            .map(|matcher_behavior| matcher_behavior.run_matcher(value))
-            .collect::<Vec<_>>();
+        let results = self.run_matchers(value);
 
         match self.kind {
-            Kind::And => MatcherResult::formatted(
-                results.iter().all(|result| result.passed),
-                messages(
+            Kind::And => {
+                let failures = messages(
                     &results,
                     |result| !result.passed,
                     |result| result.failure_message.clone(),
-                ),
-                messages(
-                    &results,
-                    |result| result.passed,
-                    |result| result.inverted_failure_message.clone(),
-                ),
-            ),
-            Kind::Or => MatcherResult::formatted(
-                results.iter().any(|result| result.passed),
-                messages(&results, |_| true, |result| result.failure_message.clone()),
-                messages(
-                    &results,
-                    |_| true,
-                    |result| result.inverted_failure_message.clone(),
-                ),
-            ),
+                );
+                let failure_message = if self.short_circuit {
+                    with_details(String::from("a matcher failed:"), failures)
+                } else {
+                    let failed = results.iter().filter(|result| !result.passed).count();
+                    with_details(
+                        format!("{} of {} matchers failed:", failed, results.len()),
+                        failures,
+                    )
+                };
+                MatcherResult::formatted(
+                    results.iter().all(|result| result.passed),
+                    failure_message,
+                    with_details(
+                        format!("all {} matchers passed but at least one should fail:", results.len()),
+                        messages(
+                            &results,
+                            |result| result.passed,
+                            |result| result.inverted_failure_message.clone(),
+                        ),
+                    ),
+                )
+            }
+            Kind::Or => {
+                let passed = results.iter().filter(|result| result.passed).count();
+                let summary = if self.short_circuit {
+                    String::from("a matcher passed but none should pass:")
+                } else {
+                    format!("{} of {} matchers passed but none should pass:", passed, results.len())
+                };
+                MatcherResult::formatted(
+                    passed > 0,
+                    with_details(
+                        format!("all {} matchers failed:", results.len()),
+                        messages(&results, |_| true, |result| result.failure_message.clone()),
+                    ),
+                    with_details(
+                        summary,
+                        messages(
+                            &results,
+                            |result| result.passed,
+                            |result| result.inverted_failure_message.clone(),
+                        ),
+                    ),
+                )
+            }
+            Kind::Xor => {
+                let passed = results.iter().filter(|result| result.passed).count();
+                let details = if passed == 0 {
+                    messages(&results, |_| true, |result| result.failure_message.clone())
+                } else {
+                    messages(
+                        &results,
+                        |result| result.passed,
+                        |result| result.inverted_failure_message.clone(),
+                    )
+                };
+                MatcherResult::formatted(
+                    passed == 1,
+                    with_details(
+                        format!("{} of {} matchers passed but exactly one should pass", passed, results.len()),
+                        details.clone(),
+                    ),
+                    with_details(
+                        format!("exactly one of {} matchers passed but it should not", results.len()),
+                        details,
+                    ),
+                )
+            }
+            Kind::AtLeast(required) => {
+                let passed = results.iter().filter(|result| result.passed).count();
+                MatcherResult::formatted(
+                    passed >= required,
+                    with_details(
+                        format!(
+                            "{} of {} matchers passed but at least {} should pass",
+                            passed,
+                            results.len(),
+                            required
+                        ),
+                        messages(
+                            &results,
+                            |result| !result.passed,
+                            |result| result.failure_message.clone(),
+                        ),
+                    ),
+                    with_details(
+                        format!(
+                            "{} of {} matchers passed but fewer than {} should pass",
+                            passed,
+                            results.len(),
+                            required
+                        ),
+                        messages(
+                            &results,
+                            |result| result.passed,
+                            |result| result.inverted_failure_message.clone(),
+                        ),
+                    ),
+                )
+            }
         }
     }
 }
 
+/// DescribedMatcher wraps a matcher and prepends a label to both its failure and inverted failure messages.
+///
+/// It is useful to identify which value failed when an assertion runs inside a loop.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::{BoxWrap, Matcher};
+/// use clearcheck::matchers::compose::described_as;
+/// use clearcheck::matchers::string::boundary::begin_with;
+///
+/// for (index, name) in ["clearcheck", "clearcase"].iter().enumerate() {
+///     let matcher = described_as(&format!("iteration {}", index), begin_with("clear").boxed());
+///     assert!(matcher.test(name).passed());
+/// }
+/// ```
+pub struct DescribedMatcher<T> {
+    label: String,
+    matcher: Box<dyn Matcher<T>>,
+}
+
+/// Creates a DescribedMatcher that prepends the given label to the messages of the given matcher.
+pub fn described_as<T>(label: &str, matcher: Box<dyn Matcher<T>>) -> DescribedMatcher<T> {
+    DescribedMatcher {
+        label: label.to_string(),
+        matcher,
+    }
+}
+
+impl<T> Matcher<T> for DescribedMatcher<T> {
+    fn test(&self, value: &T) -> MatcherResult {
+        let matcher_result = self.matcher.test(value);
+        MatcherResult::formatted(
+            matcher_result.passed,
+            format!("{}: {}", self.label, matcher_result.failure_message),
+            format!("{}: {}", self.label, matcher_result.inverted_failure_message),
+        )
+    }
+}
+
+/// MappedMatcher projects the value under test with a function and runs the wrapped matcher against the projection.
+///
+/// It allows reusing a matcher written for one type against another type, for example running a string matcher
+/// against a field of a struct. The messages of the wrapped matcher are preserved as they are.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::{BoxWrap, Matcher};
+/// use clearcheck::matchers::compose::map_matcher;
+/// use clearcheck::matchers::string::boundary::begin_with;
+///
+/// struct Library {
+///     name: String,
+/// }
+///
+/// let matcher = map_matcher(|library: &Library| library.name.clone(), begin_with("clear").boxed());
+/// assert!(matcher.test(&Library { name: String::from("clearcheck") }).passed());
+/// ```
+pub struct MappedMatcher<A, B, F>
+    where F: Fn(&A) -> B
+{
+    mapper: F,
+    matcher: Box<dyn Matcher<B>>,
+    _source: PhantomData<fn(&A)>,
+}
+
+/// Creates a MappedMatcher that applies the given function to the value under test and runs the given matcher against the result.
+pub fn map_matcher<A, B, F>(mapper: F, matcher: Box<dyn Matcher<B>>) -> MappedMatcher<A, B, F>
+    where F: Fn(&A) -> B
+{
+    MappedMatcher {
+        mapper,
+        matcher,
+        _source: PhantomData,
+    }
+}
+
+impl<A, B, F> Matcher<A> for MappedMatcher<A, B, F>
+    where F: Fn(&A) -> B
+{
+    fn test(&self, value: &A) -> MatcherResult {
+        self.matcher.test(&(self.mapper)(value))
+    }
+}
+
+fn with_details(summary: String, details: String) -> String {
+    if details.is_empty() {
+        return summary;
+    }
+    format!("{}\n{}", summary, details)
+}
+
 fn messages<P, M>(results: &[MatcherResult], predicate: P, mapper: M) -> String
     where
         P: FnMut(&&MatcherResult) -> bool,
@@ -187,6 +457,7 @@ fn messages<P, M>(results: &[MatcherResult], predicate: P, mapper: M) -> String
 #[cfg(test)]
 mod string_matchers {
     use crate::assertions::bool::TrueFalseAssertion;
+    use crate::assertions::equal::EqualityAssertion;
     use crate::matchers::{BoxWrap, Matcher};
     use crate::matchers::compose::MatchersBuilder;
     use crate::matchers::string::boundary::{begin_with, end_with};
@@ -241,6 +512,31 @@ mod string_matchers {
         matchers.test(&term).passed.should_be_false();
     }
 
+    #[test]
+    fn should_report_the_number_of_failed_matchers_for_and() {
+        let matchers = MatchersBuilder::start_building(begin_with("go").boxed())
+            .push(end_with("test").boxed())
+            .push(be_empty().boxed())
+            .combine_as_and();
+
+        let result = matchers.test(&"goselect");
+        result.failure_message.should_equal(
+            "2 of 3 matchers failed:\n\"goselect\" should end with \"test\"\nValue should be empty"
+        );
+    }
+
+    #[test]
+    fn should_report_that_all_the_matchers_failed_for_or() {
+        let matchers = MatchersBuilder::start_building(begin_with("go").boxed())
+            .push(end_with("select").boxed())
+            .combine_as_or();
+
+        let result = matchers.test(&"testify");
+        result.failure_message.should_equal(
+            "all 2 matchers failed:\n\"testify\" should begin with \"go\"\n\"testify\" should end with \"select\""
+        );
+    }
+
     #[test]
     fn should_run_inverted_matchers_successfully() {
         let begin_with = begin_with("go").boxed();
@@ -254,6 +550,269 @@ mod string_matchers {
     }
 }
 
+#[cfg(test)]
+mod inverted_tests {
+    use crate::matchers::{BoxWrap, ShouldNot};
+    use crate::matchers::compose::MatchersBuilder;
+    use crate::matchers::string::boundary::{begin_with, end_with};
+    use crate::matchers::string::empty::be_empty;
+
+    #[test]
+    fn should_not_pass_and() {
+        let matchers = MatchersBuilder::start_building(begin_with("go").boxed())
+            .push(end_with("test").boxed())
+            .combine_as_and();
+
+        "goselect".should_not(&matchers);
+    }
+
+    #[test]
+    #[should_panic(expected = "all 2 matchers passed but at least one should fail:\n\"goselect\" should not begin with \"go\"\n\"goselect\" should not end with \"select\"")]
+    fn should_not_pass_and_but_all_the_matchers_passed() {
+        let matchers = MatchersBuilder::start_building(begin_with("go").boxed())
+            .push(end_with("select").boxed())
+            .combine_as_and();
+
+        "goselect".should_not(&matchers);
+    }
+
+    #[test]
+    fn should_not_pass_or() {
+        let matchers = MatchersBuilder::start_building(begin_with("test").boxed())
+            .push(be_empty().boxed())
+            .combine_as_or();
+
+        "goselect".should_not(&matchers);
+    }
+
+    #[test]
+    #[should_panic(expected = "1 of 3 matchers passed but none should pass:\n\"goselect\" should not end with \"select\"")]
+    fn should_not_pass_or_but_a_matcher_passed() {
+        let matchers = MatchersBuilder::start_building(begin_with("test").boxed())
+            .push(end_with("select").boxed())
+            .push(be_empty().boxed())
+            .combine_as_or();
+
+        "goselect".should_not(&matchers);
+    }
+
+    #[test]
+    #[should_panic(expected = "a matcher passed but none should pass:\n\"goselect\" should not begin with \"go\"")]
+    fn should_not_pass_short_circuiting_or_but_a_matcher_passed() {
+        let matchers = MatchersBuilder::start_building(begin_with("go").boxed())
+            .push(end_with("select").boxed())
+            .combine_as_or_short_circuit();
+
+        "goselect".should_not(&matchers);
+    }
+
+    #[test]
+    #[should_panic(expected = "all 2 matchers passed but at least one should fail:\nValue should be empty\n\"goselect\" should begin with \"test\"")]
+    fn should_not_pass_and_with_inverted_matchers_but_all_the_matchers_passed() {
+        let matchers = MatchersBuilder::start_building_with_inverted(be_empty().boxed())
+            .push_inverted(begin_with("test").boxed())
+            .combine_as_and();
+
+        "goselect".should_not(&matchers);
+    }
+}
+
+#[cfg(test)]
+mod xor_tests {
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::assertions::equal::EqualityAssertion;
+    use crate::matchers::{BoxWrap, Matcher};
+    use crate::matchers::compose::MatchersBuilder;
+    use crate::matchers::string::boundary::{begin_with, end_with};
+
+    #[test]
+    fn should_pass_given_exactly_one_passing_matcher() {
+        let matchers = MatchersBuilder::start_building(begin_with("go").boxed())
+            .push(end_with("test").boxed())
+            .combine_as_xor();
+
+        matchers.test(&"goselect").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_fail_given_no_passing_matcher() {
+        let matchers = MatchersBuilder::start_building(begin_with("go").boxed())
+            .push(end_with("test").boxed())
+            .combine_as_xor();
+
+        let result = matchers.test(&"clearcheck");
+        result.passed.should_be_false();
+        result.failure_message.should_equal(
+            &"0 of 2 matchers passed but exactly one should pass\n\"clearcheck\" should begin with \"go\"\n\"clearcheck\" should end with \"test\"".to_string()
+        );
+    }
+
+    #[test]
+    fn should_fail_given_two_passing_matchers() {
+        let matchers = MatchersBuilder::start_building(begin_with("go").boxed())
+            .push(end_with("select").boxed())
+            .combine_as_xor();
+
+        let result = matchers.test(&"goselect");
+        result.passed.should_be_false();
+        result.failure_message.should_equal(
+            &"2 of 2 matchers passed but exactly one should pass\n\"goselect\" should not begin with \"go\"\n\"goselect\" should not end with \"select\"".to_string()
+        );
+    }
+
+    #[test]
+    fn should_pass_given_exactly_one_passing_inverted_matcher() {
+        let matchers = MatchersBuilder::start_building(begin_with("go").boxed())
+            .push_inverted(end_with("select").boxed())
+            .combine_as_xor();
+
+        matchers.test(&"goselect").passed.should_be_true();
+    }
+}
+
+#[cfg(test)]
+mod at_least_tests {
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::assertions::equal::EqualityAssertion;
+    use crate::matchers::{BoxWrap, Matcher};
+    use crate::matchers::compose::MatchersBuilder;
+    use crate::matchers::string::boundary::{begin_with, end_with};
+    use crate::matchers::string::empty::be_empty;
+    use crate::matchers::string::length::have_atleast_same_length;
+
+    #[test]
+    fn should_pass_given_at_least_k_passing_matchers() {
+        let matchers = MatchersBuilder::start_building(begin_with("go").boxed())
+            .push(end_with("select").boxed())
+            .push(have_atleast_same_length(20).boxed())
+            .push(be_empty().boxed())
+            .combine_as_at_least(2);
+
+        matchers.test(&"goselect").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_fail_given_fewer_than_k_passing_matchers() {
+        let matchers = MatchersBuilder::start_building(begin_with("go").boxed())
+            .push(end_with("test").boxed())
+            .push(be_empty().boxed())
+            .combine_as_at_least(2);
+
+        let result = matchers.test(&"goselect");
+        result.passed.should_be_false();
+        result.failure_message.should_equal(
+            &"1 of 3 matchers passed but at least 2 should pass\n\"goselect\" should end with \"test\"\nValue should be empty".to_string()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "k should be greater than zero")]
+    fn should_not_create_at_least_matchers_given_k_is_zero() {
+        let matchers = MatchersBuilder::start_building(begin_with("test").boxed())
+            .combine_as_at_least(0);
+
+        matchers.test(&"goselect");
+    }
+
+    #[test]
+    fn should_fail_given_k_is_greater_than_the_number_of_matchers() {
+        let matchers = MatchersBuilder::start_building(begin_with("go").boxed())
+            .push(end_with("select").boxed())
+            .combine_as_at_least(3);
+
+        let result = matchers.test(&"goselect");
+        result.passed.should_be_false();
+        result.failure_message.should_equal(&"2 of 2 matchers passed but at least 3 should pass".to_string());
+    }
+}
+
+#[cfg(test)]
+mod short_circuit_tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::assertions::equal::EqualityAssertion;
+    use crate::matchers::{BoxWrap, Matcher, MatcherResult};
+    use crate::matchers::compose::MatchersBuilder;
+    use crate::matchers::string::boundary::{begin_with, end_with};
+
+    struct CountingMatcher {
+        passed: bool,
+        invocations: Rc<Cell<usize>>,
+    }
+
+    impl Matcher<&str> for CountingMatcher {
+        fn test(&self, _: &&str) -> MatcherResult {
+            self.invocations.set(self.invocations.get() + 1);
+            MatcherResult::new(self.passed, "counting matcher failed", "counting matcher passed")
+        }
+    }
+
+    fn counting_matcher(passed: bool, invocations: &Rc<Cell<usize>>) -> CountingMatcher {
+        CountingMatcher { passed, invocations: invocations.clone() }
+    }
+
+    #[test]
+    fn should_stop_at_the_first_passing_matcher_for_or() {
+        let invocations = Rc::new(Cell::new(0));
+        let matchers = MatchersBuilder::start_building(begin_with("go").boxed())
+            .push(counting_matcher(true, &invocations).boxed())
+            .combine_as_or_short_circuit();
+
+        matchers.test(&"goselect").passed.should_be_true();
+        invocations.get().should_equal(&0);
+    }
+
+    #[test]
+    fn should_run_matchers_until_one_passes_for_or() {
+        let invocations = Rc::new(Cell::new(0));
+        let matchers = MatchersBuilder::start_building(begin_with("test").boxed())
+            .push(counting_matcher(true, &invocations).boxed())
+            .push(counting_matcher(true, &invocations).boxed())
+            .combine_as_or_short_circuit();
+
+        matchers.test(&"goselect").passed.should_be_true();
+        invocations.get().should_equal(&1);
+    }
+
+    #[test]
+    fn should_stop_at_the_first_failing_matcher_for_and() {
+        let invocations = Rc::new(Cell::new(0));
+        let matchers = MatchersBuilder::start_building(end_with("test").boxed())
+            .push(counting_matcher(true, &invocations).boxed())
+            .combine_as_and_short_circuit();
+
+        let result = matchers.test(&"goselect");
+        result.passed.should_be_false();
+        result.failure_message.should_equal("a matcher failed:\n\"goselect\" should end with \"test\"");
+        invocations.get().should_equal(&0);
+    }
+
+    #[test]
+    fn should_report_only_the_first_failure_for_and() {
+        let matchers = MatchersBuilder::start_building(begin_with("go").boxed())
+            .push(end_with("test").boxed())
+            .push(end_with("go").boxed())
+            .combine_as_and_short_circuit();
+
+        let result = matchers.test(&"goselect");
+        result.passed.should_be_false();
+        result.failure_message.should_equal("a matcher failed:\n\"goselect\" should end with \"test\"");
+    }
+
+    #[test]
+    fn should_run_all_matchers_without_short_circuit() {
+        let invocations = Rc::new(Cell::new(0));
+        let matchers = MatchersBuilder::start_building(begin_with("go").boxed())
+            .push(counting_matcher(false, &invocations).boxed())
+            .push(counting_matcher(false, &invocations).boxed())
+            .combine_as_or();
+
+        matchers.test(&"goselect").passed.should_be_true();
+        invocations.get().should_equal(&2);
+    }
+}
 
 #[cfg(test)]
 mod slice_matchers {
@@ -490,4 +1049,158 @@ mod custom_collection_matchers_tests {
         ];
         brands.should_be_intel_laptop_brands();
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod described_tests {
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::matchers::{BoxWrap, Matcher, Should, ShouldNot};
+    use crate::matchers::compose::described_as;
+    use crate::matchers::string::boundary::begin_with;
+
+    #[test]
+    fn should_preserve_the_passed_result() {
+        let matcher = described_as("iteration 3", begin_with("clear").boxed());
+        matcher.test(&"clearcheck").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_preserve_the_failed_result() {
+        let matcher = described_as("iteration 3", begin_with("junit").boxed());
+        matcher.test(&"clearcheck").passed.should_be_false();
+    }
+
+    #[test]
+    #[should_panic(expected = "iteration 3: \"clearcheck\" should begin with \"junit\"")]
+    fn should_prefix_the_failure_message() {
+        "clearcheck".should(&described_as("iteration 3", begin_with("junit").boxed()));
+    }
+
+    #[test]
+    #[should_panic(expected = "iteration 3: \"clearcheck\" should not begin with \"clear\"")]
+    fn should_prefix_the_inverted_failure_message() {
+        "clearcheck".should_not(&described_as("iteration 3", begin_with("clear").boxed()));
+    }
+}
+
+#[cfg(test)]
+mod mapped_tests {
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::matchers::{BoxWrap, Matcher, Should, ShouldNot};
+    use crate::matchers::compose::{map_matcher, MatchersBuilder};
+    use crate::matchers::string::boundary::begin_with;
+    use crate::matchers::string::empty::be_empty;
+
+    #[derive(Debug)]
+    struct Library {
+        name: String,
+        stars: u32,
+    }
+
+    #[test]
+    fn should_run_the_matcher_against_the_projected_field() {
+        let library = Library { name: String::new(), stars: 10 };
+        let matcher = map_matcher(|library: &Library| library.name.clone(), be_empty().boxed());
+        matcher.test(&library).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_fail_the_matcher_against_the_projected_field() {
+        let library = Library { name: String::from("clearcheck"), stars: 10 };
+        let matcher = map_matcher(|library: &Library| library.name.clone(), be_empty().boxed());
+        matcher.test(&library).passed.should_be_false();
+    }
+
+    #[test]
+    #[should_panic(expected = "Value should be empty")]
+    fn should_preserve_the_failure_message() {
+        let library = Library { name: String::from("clearcheck"), stars: 10 };
+        library.should(&map_matcher(|library: &Library| library.name.clone(), be_empty().boxed()));
+    }
+
+    #[test]
+    #[should_panic(expected = "\"clearcheck\" should not begin with \"clear\"")]
+    fn should_preserve_the_inverted_failure_message() {
+        let library = Library { name: String::from("clearcheck"), stars: 10 };
+        library.should_not(&map_matcher(|library: &Library| library.name.clone(), begin_with("clear").boxed()));
+    }
+
+    #[test]
+    fn should_compose_mapped_matchers() {
+        let library = Library { name: String::from("clearcheck"), stars: 10 };
+        let matchers = MatchersBuilder::start_building(
+            map_matcher(|library: &Library| library.name.clone(), begin_with("clear").boxed()).boxed(),
+        )
+        .push_inverted(map_matcher(|library: &Library| library.name.clone(), be_empty().boxed()).boxed())
+        .combine_as_and();
+
+        matchers.test(&library).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_run_a_string_matcher_against_a_projected_number() {
+        let library = Library { name: String::from("clearcheck"), stars: 10 };
+        let matcher = map_matcher(|library: &Library| library.stars.to_string(), begin_with("1").boxed());
+        matcher.test(&library).passed.should_be_true();
+    }
+}
+
+#[cfg(test)]
+mod push_all_tests {
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::assertions::equal::EqualityAssertion;
+    use crate::matchers::{BoxWrap, Matcher};
+    use crate::matchers::compose::MatchersBuilder;
+    use crate::matchers::string::boundary::{begin_with, end_with};
+    use crate::matchers::string::empty::be_empty;
+    use crate::matchers::string::length::have_atleast_same_length;
+
+    #[test]
+    fn should_run_all_the_pushed_matchers_successfully() {
+        let matchers = MatchersBuilder::start_building(begin_with("go").boxed())
+            .push_all(vec![end_with("select").boxed(), have_atleast_same_length(4).boxed()])
+            .combine_as_and();
+
+        matchers.test(&"goselect").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_run_all_the_pushed_inverted_matchers_successfully() {
+        let matchers = MatchersBuilder::start_building(begin_with("go").boxed())
+            .push_all_inverted(vec![end_with("test").boxed(), be_empty().boxed()])
+            .combine_as_and();
+
+        matchers.test(&"goselect").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_equivalent_to_sequential_pushes() {
+        let pushed_all = MatchersBuilder::start_building(begin_with("go").boxed())
+            .push_all(vec![end_with("select").boxed(), have_atleast_same_length(10).boxed()])
+            .push_all_inverted(vec![be_empty().boxed(), end_with("fy").boxed()])
+            .combine_as_and();
+
+        let pushed = MatchersBuilder::start_building(begin_with("go").boxed())
+            .push(end_with("select").boxed())
+            .push(have_atleast_same_length(10).boxed())
+            .push_inverted(be_empty().boxed())
+            .push_inverted(end_with("fy").boxed())
+            .combine_as_and();
+
+        let pushed_all_result = pushed_all.test(&"testify");
+        let pushed_result = pushed.test(&"testify");
+
+        pushed_all_result.passed.should_equal(&pushed_result.passed);
+        pushed_all_result.failure_message.should_equal(&pushed_result.failure_message);
+    }
+
+    #[test]
+    fn should_keep_the_builder_unchanged_given_no_matchers() {
+        let matchers = MatchersBuilder::start_building(begin_with("go").boxed())
+            .push_all(vec![])
+            .push_all_inverted(vec![])
+            .combine_as_and();
+
+        matchers.test(&"goselect").passed.should_be_true();
+    }
+}
@@ -1,4 +1,6 @@
-use chrono::{Datelike, NaiveDate};
+use std::ops::RangeInclusive;
+
+use chrono::{Datelike, NaiveDate, Weekday};
 
 use crate::matchers::{Matcher, MatcherResult};
 
@@ -20,6 +22,13 @@ pub enum DateMatcher {
     SameMonth(u32),
     SameDay(u32),
     LeapYear,
+    Before(NaiveDate),
+    After(NaiveDate),
+    Between(RangeInclusive<NaiveDate>),
+    OnWeekday(Weekday),
+    Weekend,
+    Weekday,
+    AgeAtLeast(u32, NaiveDate),
 }
 
 impl Matcher<NaiveDate> for DateMatcher {
@@ -45,10 +54,70 @@ impl Matcher<NaiveDate> for DateMatcher {
                 format!("{:?} should be a leap year", value),
                 format!("{:?} should not be a leap year", value),
             ),
+            DateMatcher::Before(other) => MatcherResult::formatted(
+                value < other,
+                format!("date {:?} should be before {:?}", value, other),
+                format!("date {:?} should not be before {:?}", value, other),
+            ),
+            DateMatcher::After(other) => MatcherResult::formatted(
+                value > other,
+                format!("date {:?} should be after {:?}", value, other),
+                format!("date {:?} should not be after {:?}", value, other),
+            ),
+            DateMatcher::Between(range) => MatcherResult::formatted(
+                range.contains(value),
+                format!(
+                    "date {:?} should be between {:?} and {:?} (inclusive)",
+                    value, range.start(), range.end()
+                ),
+                format!(
+                    "date {:?} should not be between {:?} and {:?} (inclusive)",
+                    value, range.start(), range.end()
+                ),
+            ),
+            DateMatcher::OnWeekday(weekday) => MatcherResult::formatted(
+                value.weekday() == *weekday,
+                format!("date {:?} should be on {:?} but was on {:?}", value, weekday, value.weekday()),
+                format!("date {:?} should not be on {:?}", value, weekday),
+            ),
+            DateMatcher::Weekend => MatcherResult::formatted(
+                is_weekend(value),
+                format!("date {:?} should be on a weekend but was on {:?}", value, value.weekday()),
+                format!("date {:?} should not be on a weekend but was on {:?}", value, value.weekday()),
+            ),
+            DateMatcher::Weekday => MatcherResult::formatted(
+                !is_weekend(value),
+                format!("date {:?} should be on a weekday but was on {:?}", value, value.weekday()),
+                format!("date {:?} should not be on a weekday but was on {:?}", value, value.weekday()),
+            ),
+            DateMatcher::AgeAtLeast(years, as_of) => MatcherResult::formatted(
+                i64::from(age(value, as_of)) >= i64::from(*years),
+                format!(
+                    "date {:?} should be an age of at least {} years as of {:?} but the age was {}",
+                    value, years, as_of, age(value, as_of)
+                ),
+                format!(
+                    "date {:?} should not be an age of at least {} years as of {:?} but the age was {}",
+                    value, years, as_of, age(value, as_of)
+                ),
+            ),
         }
     }
 }
 
+fn is_weekend(date: &NaiveDate) -> bool {
+    matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+fn age(birthdate: &NaiveDate, as_of: &NaiveDate) -> i32 {
+    let years = as_of.year() - birthdate.year();
+    if (as_of.month(), as_of.day()) < (birthdate.month(), birthdate.day()) {
+        years - 1
+    } else {
+        years
+    }
+}
+
 /// Creates a DateMatcher that asserts whether a date has the same year as the given year.
 pub fn have_same_year(year: i32) -> DateMatcher {
     DateMatcher::SameYear(year)
@@ -69,12 +138,50 @@ pub fn be_a_leap_year() -> DateMatcher {
     DateMatcher::LeapYear
 }
 
+/// Creates a DateMatcher that asserts whether a date is strictly before the given date.
+pub fn be_before(other: NaiveDate) -> DateMatcher {
+    DateMatcher::Before(other)
+}
+
+/// Creates a DateMatcher that asserts whether a date is strictly after the given date.
+pub fn be_after(other: NaiveDate) -> DateMatcher {
+    DateMatcher::After(other)
+}
+
+/// Creates a DateMatcher that asserts whether a date falls within the given inclusive range of dates.
+pub fn be_between(range: RangeInclusive<NaiveDate>) -> DateMatcher {
+    DateMatcher::Between(range)
+}
+
+/// Creates a DateMatcher that asserts whether a date falls on the given day of the week.
+pub fn be_on_weekday(weekday: Weekday) -> DateMatcher {
+    DateMatcher::OnWeekday(weekday)
+}
+
+/// Creates a DateMatcher that asserts whether a date falls on a Saturday or a Sunday.
+pub fn be_weekend() -> DateMatcher {
+    DateMatcher::Weekend
+}
+
+/// Creates a DateMatcher that asserts whether a date falls on a day from Monday to Friday.
+pub fn be_weekday() -> DateMatcher {
+    DateMatcher::Weekday
+}
+
+/// Creates a DateMatcher that asserts whether the age of a birthdate, in full years as of the given date, is at least the given number of years.
+pub fn be_age_at_least(years: u32, as_of: NaiveDate) -> DateMatcher {
+    DateMatcher::AgeAtLeast(years, as_of)
+}
+
 #[cfg(all(test, feature = "date"))]
 mod tests {
     use crate::assertions::bool::TrueFalseAssertion;
-    use crate::matchers::date::{be_a_leap_year, have_same_day, have_same_month, have_same_year};
+    use crate::matchers::date::{
+        be_a_leap_year, be_after, be_age_at_least, be_before, be_between, be_on_weekday,
+        be_weekday, be_weekend, have_same_day, have_same_month, have_same_year,
+    };
     use crate::matchers::Matcher;
-    use chrono::NaiveDate;
+    use chrono::{NaiveDate, Weekday};
 
     #[test]
     fn should_have_same_year() {
@@ -103,4 +210,55 @@ mod tests {
         let matcher = be_a_leap_year();
         matcher.test(&date).passed.should_be_true();
     }
+
+    #[test]
+    fn should_be_before() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+        let matcher = be_before(NaiveDate::from_ymd_opt(2024, 1, 11).unwrap());
+        matcher.test(&date).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_after() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+        let matcher = be_after(NaiveDate::from_ymd_opt(2024, 1, 9).unwrap());
+        matcher.test(&date).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_between() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+        let matcher = be_between(
+            NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()..=NaiveDate::from_ymd_opt(2024, 1, 31).unwrap(),
+        );
+        matcher.test(&date).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_on_weekday() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 6).unwrap();
+        let matcher = be_on_weekday(Weekday::Sat);
+        matcher.test(&date).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_weekend() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 7).unwrap();
+        let matcher = be_weekend();
+        matcher.test(&date).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_weekday() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 8).unwrap();
+        let matcher = be_weekday();
+        matcher.test(&date).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_age_at_least() {
+        let birthdate = NaiveDate::from_ymd_opt(2006, 3, 15).unwrap();
+        let matcher = be_age_at_least(18, NaiveDate::from_ymd_opt(2024, 3, 15).unwrap());
+        matcher.test(&birthdate).passed.should_be_true();
+    }
 }
@@ -4,9 +4,9 @@ use crate::matchers::{Matcher, MatcherResult};
 
 /// EqualityMatcher offers a flexible way to assert the equality between two values of the same type.
 ///
-/// Works with any data type that implements the Eq trait.
+/// Works with any data type that implements the PartialEq trait.
 ///
-/// clearcheck implements EqualityMatcher for any T: Eq + Debug.
+/// clearcheck implements EqualityMatcher for any T: PartialEq + Debug.
 ///
 /// # Example
 ///```
@@ -29,10 +29,96 @@ use crate::matchers::{Matcher, MatcherResult};
 ///
 /// assert!(matcher.test(&books).passed());
 /// ```
-pub struct EqualityMatcher<T: Eq> {
+pub struct EqualityMatcher<T: PartialEq> {
     pub other: T,
 }
 
+/// AnyEqualityMatcher offers a flexible way to assert that a value equals at least one of the given values.
+///
+/// clearcheck implements AnyEqualityMatcher for any T: PartialEq + Debug.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::equal::be_equal_to_any;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = be_equal_to_any(vec![1, 2, 3]);
+///
+/// assert!(matcher.test(&2).passed());
+/// ```
+pub struct AnyEqualityMatcher<T: PartialEq> {
+    pub others: Vec<T>,
+}
+
+/// ComparatorEqualityMatcher offers a way to assert the equality between two values using a custom comparator.
+///
+/// It works with types that do not implement PartialEq, or whose domain-specific equality differs from PartialEq.
+/// The description of the comparator is included in the failure messages.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::equal::be_equal_by;
+/// use clearcheck::matchers::Matcher;
+///
+/// let expected = 10.001;
+/// let matcher = be_equal_by(&expected, "within a tolerance of 0.01", |a: &f64, b: &f64| (a - b).abs() <= 0.01);
+///
+/// assert!(matcher.test(&10.0).passed());
+/// ```
+pub struct ComparatorEqualityMatcher<'a, T, F>
+    where F: Fn(&T, &T) -> bool
+{
+    other: &'a T,
+    description: String,
+    comparator: F,
+}
+
+/// VariantMatcher offers a way to assert that a value, typically an enum, is of a given variant.
+///
+/// The variant is decided by a predicate, usually written with [matches!], so the payload of the variant is not
+/// compared and the type does not need to implement PartialEq. The variant name is only used in the failure messages.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::equal::be_variant;
+/// use clearcheck::matchers::Matcher;
+///
+/// #[derive(Debug)]
+/// enum Shape {
+///     Circle(f64),
+///     Square(f64),
+/// }
+///
+/// let matcher = be_variant(|shape: &Shape| matches!(shape, Shape::Circle(_)), "Shape::Circle");
+/// assert!(matcher.test(&Shape::Circle(2.5)).passed());
+/// ```
+pub struct VariantMatcher<F> {
+    predicate: F,
+    variant_name: String,
+}
+
+/// DefaultMatcher offers a flexible way to assert whether a value equals the default value of its type.
+///
+/// clearcheck implements DefaultMatcher for any T: Default + PartialEq + Debug.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::equal::be_default;
+/// use clearcheck::matchers::Matcher;
+///
+/// #[derive(Debug, Default, PartialEq)]
+/// struct Config {
+///     retries: u32,
+///     verbose: bool,
+/// }
+///
+/// let config = Config::default();
+/// let matcher = be_default();
+///
+/// assert!(matcher.test(&config).passed());
+/// ```
+pub struct DefaultMatcher;
+
 /// IgnoreCaseEqualityMatcher offers a flexible way to assert the equality between two values of same type, ignoring case differences.
 ///
 /// clearcheck implements IgnoreCaseEqualityMatcher for the following:
@@ -57,16 +143,47 @@ pub struct IgnoreCaseEqualityMatcher<T: Eq> {
 }
 
 /// Creates an EqualityMatcher that asserts whether a value equals the given value.
-pub fn be_equal<T: Eq>(other: T) -> EqualityMatcher<T> {
+pub fn be_equal<T: PartialEq>(other: T) -> EqualityMatcher<T> {
     EqualityMatcher { other }
 }
 
+/// Creates an AnyEqualityMatcher that asserts whether a value equals any of the given values.
+pub fn be_equal_to_any<T: PartialEq>(others: Vec<T>) -> AnyEqualityMatcher<T> {
+    AnyEqualityMatcher { others }
+}
+
+/// Creates a ComparatorEqualityMatcher that asserts whether a value equals the given value, as decided by the comparator.
+pub fn be_equal_by<'a, T, F>(other: &'a T, description: &str, comparator: F) -> ComparatorEqualityMatcher<'a, T, F>
+    where F: Fn(&T, &T) -> bool
+{
+    ComparatorEqualityMatcher {
+        other,
+        description: description.to_string(),
+        comparator,
+    }
+}
+
+/// Creates a VariantMatcher that asserts whether a value is of the variant named variant_name, as decided by the predicate.
+pub fn be_variant<T, F>(predicate: F, variant_name: &str) -> VariantMatcher<F>
+    where F: Fn(&T) -> bool
+{
+    VariantMatcher {
+        predicate,
+        variant_name: variant_name.to_string(),
+    }
+}
+
+/// Creates a DefaultMatcher that asserts whether a value equals the default value of its type.
+pub fn be_default() -> DefaultMatcher {
+    DefaultMatcher
+}
+
 /// Creates an IgnoreCaseEqualityMatcher that asserts whether a value equals the given value, ignoring case differences.
 pub fn be_equal_ignoring_case<T: Eq>(other: T) -> IgnoreCaseEqualityMatcher<T> {
     IgnoreCaseEqualityMatcher { other }
 }
 
-impl<T: Eq + Debug> Matcher<T> for EqualityMatcher<T> {
+impl<T: PartialEq + Debug> Matcher<T> for EqualityMatcher<T> {
     fn test(&self, value: &T) -> MatcherResult {
         MatcherResult::formatted(
             value == &self.other,
@@ -76,10 +193,59 @@ impl<T: Eq + Debug> Matcher<T> for EqualityMatcher<T> {
     }
 }
 
+impl<T: PartialEq + Debug> Matcher<T> for AnyEqualityMatcher<T> {
+    fn test(&self, value: &T) -> MatcherResult {
+        MatcherResult::formatted(
+            self.others.contains(value),
+            format!("{:?} should equal any of {:?}", value, self.others),
+            format!("{:?} should not equal any of {:?}", value, self.others),
+        )
+    }
+}
+
+impl<T, F> Matcher<T> for ComparatorEqualityMatcher<'_, T, F>
+    where
+        T: Debug,
+        F: Fn(&T, &T) -> bool,
+{
+    fn test(&self, value: &T) -> MatcherResult {
+        MatcherResult::formatted(
+            (self.comparator)(value, self.other),
+            format!("{:?} should equal {:?} ({})", value, self.other, self.description),
+            format!("{:?} should not equal {:?} ({})", value, self.other, self.description),
+        )
+    }
+}
+
+impl<T, F> Matcher<T> for VariantMatcher<F>
+    where
+        T: Debug,
+        F: Fn(&T) -> bool,
+{
+    fn test(&self, value: &T) -> MatcherResult {
+        MatcherResult::formatted(
+            (self.predicate)(value),
+            format!("{:?} should be the variant {}", value, self.variant_name),
+            format!("{:?} should not be the variant {}", value, self.variant_name),
+        )
+    }
+}
+
+impl<T: Default + PartialEq + Debug> Matcher<T> for DefaultMatcher {
+    fn test(&self, value: &T) -> MatcherResult {
+        let default = T::default();
+        MatcherResult::formatted(
+            value == &default,
+            format!("{:?} should be the default value {:?}", value, default),
+            format!("{:?} should not be the default value {:?}", value, default),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::assertions::bool::TrueFalseAssertion;
-    use crate::matchers::equal::be_equal;
+    use crate::matchers::equal::{be_default, be_equal, be_equal_by, be_equal_to_any, be_variant};
     use crate::matchers::Matcher;
 
     #[derive(Debug, Eq, PartialEq)]
@@ -126,4 +292,58 @@ mod tests {
         let matcher = be_equal(target);
         matcher.test(&books).passed.should_be_true();
     }
+
+    #[test]
+    fn should_be_equal_to_any() {
+        let matcher = be_equal_to_any(vec!["junit", "clearcheck"]);
+        matcher.test(&"clearcheck").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_equal_to_any_but_was_not() {
+        let matcher = be_equal_to_any(vec!["junit", "clearcheck"]);
+        matcher.test(&"testify").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_equal_by_the_comparator() {
+        let expected = 10.001;
+        let matcher = be_equal_by(&expected, "within a tolerance of 0.01", |a: &f64, b: &f64| (a - b).abs() <= 0.01);
+        matcher.test(&10.0).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_equal_by_the_comparator_but_was_not() {
+        let expected = 10.5;
+        let matcher = be_equal_by(&expected, "within a tolerance of 0.01", |a: &f64, b: &f64| (a - b).abs() <= 0.01);
+        matcher.test(&10.0).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_variant() {
+        let matcher = be_variant(|value: &Option<i32>| matches!(value, Some(1..)), "Some positive");
+        matcher.test(&Some(10)).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_variant_but_was_not() {
+        let matcher = be_variant(|value: &Option<i32>| matches!(value, Some(1..)), "Some positive");
+        matcher.test(&None).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_default() {
+        let matcher = be_default();
+        matcher.test(&0).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_default_but_was_not() {
+        let matcher = be_default();
+        matcher.test(&String::from("clearcheck")).passed.should_be_true();
+    }
 }
@@ -2,8 +2,14 @@ use std::collections::HashSet;
 use std::ffi::OsStr;
 use std::fmt::Debug;
 use std::fs;
-use std::path::Path;
-
+use std::io::ErrorKind;
+use std::ops::RangeInclusive;
+#[cfg(unix)]
+use std::os::unix::fs::FileTypeExt;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use glob::Pattern;
 use walkdir::WalkDir;
 
 use crate::matchers::{Matcher, MatcherResult};
@@ -14,8 +20,13 @@ pub enum FileTypeMatcher {
     Directory,
     SymbolicLink,
     ZeroSized,
+    EmptyDirectory,
     Readonly,
     Writable,
+    #[cfg(unix)]
+    NamedPipe,
+    #[cfg(unix)]
+    Socket,
 }
 
 /// FilePathMatcher offers a flexible way to make assertions about various properties related to file paths.
@@ -23,6 +34,113 @@ pub enum FilePathMatcher {
     Absolute,
     Relative,
     Extension(&'static str),
+    ExtensionIgnoringCase(&'static str),
+}
+
+/// FileSizeMatcher offers a flexible way to make assertions about the size (in bytes) of a file.
+///
+/// The size is read from the file metadata; a path whose metadata can not be read (for example, a missing file)
+/// never matches.
+/// # Example
+///```
+/// use std::fs::File;
+/// use std::io::Write;
+/// use tempdir::TempDir;
+/// use clearcheck::matchers::file::have_file_size;
+/// use clearcheck::matchers::Matcher;
+///
+/// let temporary_directory = TempDir::new(".").unwrap();
+/// let file_path = temporary_directory.path().join("clearcheck.txt");
+///
+/// let mut file = File::create(file_path.clone()).unwrap();
+/// write!(file, "clearcheck").unwrap();
+///
+/// let matcher = have_file_size(10);
+/// assert!(matcher.test(&file_path).passed());
+/// ```
+pub enum FileSizeMatcher {
+    Exact(u64),
+    AtLeast(u64),
+    AtMost(u64),
+    InclusiveRange(RangeInclusive<u64>),
+}
+
+/// ModificationTimeMatcher offers a flexible way to compare the modification time of a file with that of another file.
+///
+/// Failing to read the modification time of either file is reported separately from an ordering failure.
+/// # Example
+///```
+/// use std::fs::File;
+/// use std::time::{Duration, SystemTime};
+/// use tempdir::TempDir;
+/// use clearcheck::matchers::file::be_modified_after;
+/// use clearcheck::matchers::Matcher;
+///
+/// let temporary_directory = TempDir::new(".").unwrap();
+/// let source_path = temporary_directory.path().join("main.rs");
+/// let artifact_path = temporary_directory.path().join("main.o");
+///
+/// let source = File::create(source_path.clone()).unwrap();
+/// source.set_modified(SystemTime::now() - Duration::from_secs(10)).unwrap();
+/// let _ = File::create(artifact_path.clone()).unwrap();
+///
+/// let matcher = be_modified_after(&source_path);
+/// assert!(matcher.test(&artifact_path).passed());
+/// ```
+pub enum ModificationTimeMatcher {
+    After(PathBuf),
+    Before(PathBuf),
+}
+
+/// FileContentMatcher offers a flexible way to make assertions about the textual content of a file.
+///
+/// The file is read to a string; a file that can not be read, or whose content is not valid UTF-8, never matches and
+/// its failure message names the path and the reason.
+/// # Example
+///```
+/// use std::fs::File;
+/// use std::io::Write;
+/// use tempdir::TempDir;
+/// use clearcheck::matchers::file::contain_line;
+/// use clearcheck::matchers::Matcher;
+///
+/// let temporary_directory = TempDir::new(".").unwrap();
+/// let file_path = temporary_directory.path().join("config.properties");
+///
+/// let mut file = File::create(file_path.clone()).unwrap();
+/// writeln!(file, "enabled=true").unwrap();
+///
+/// let matcher = contain_line("enabled=true");
+/// assert!(matcher.test(&file_path).passed());
+/// ```
+pub enum FileContentMatcher {
+    Line(&'static str),
+    Text(&'static str),
+}
+
+/// SameFileMatcher offers a flexible way to assert whether two paths refer to the same file, after resolving `.`, `..`
+/// and symbolic links with [`fs::canonicalize`].
+///
+/// Both paths must exist; a path that can not be canonicalized never matches and its failure message names the path
+/// and the reason.
+/// # Example
+///```
+/// use std::fs::{self, File};
+/// use tempdir::TempDir;
+/// use clearcheck::matchers::file::be_same_file_as;
+/// use clearcheck::matchers::Matcher;
+///
+/// let temporary_directory = TempDir::new(".").unwrap();
+/// fs::create_dir(temporary_directory.path().join("dir")).unwrap();
+/// let _ = File::create(temporary_directory.path().join("clearcheck.txt")).unwrap();
+///
+/// let path = temporary_directory.path().join("dir/../clearcheck.txt");
+/// let matcher = be_same_file_as(&temporary_directory.path().join("clearcheck.txt"));
+///
+/// assert!(matcher.test(&path).passed());
+/// ```
+pub struct SameFileMatcher {
+    other: PathBuf,
 }
 
 /// TreeMatcher offers a flexible way to make assertions about presence or absence of files or directories within a tree structure.
@@ -47,6 +165,9 @@ pub enum TreeMatcher {
     Contain(&'static str),
     ContainAll(Vec<&'static str>),
     ContainAny(Vec<&'static str>),
+    ContainMatching(&'static str),
+    ContainWithinDepth(&'static str, usize),
+    ContainFollowingSymlinks(&'static str),
 }
 
 impl<T: AsRef<Path> + Debug> Matcher<T> for FileTypeMatcher {
@@ -73,6 +194,27 @@ impl<T: AsRef<Path> + Debug> Matcher<T> for FileTypeMatcher {
                 format!("{:?} should be zero sized", value),
                 format!("{:?} should not be zero sized", value),
             ),
+            FileTypeMatcher::EmptyDirectory => {
+                let (empty, failure_message) = match fs::read_dir(value) {
+                    Ok(mut entries) => match entries.next() {
+                        None => (true, format!("{:?} should be an empty directory", value)),
+                        Some(_) => (false, format!("{:?} should be an empty directory but it had entries", value)),
+                    },
+                    Err(_) if !value.as_ref().is_dir() => (
+                        false,
+                        format!("{:?} should be an empty directory but it was not a directory", value),
+                    ),
+                    Err(err) => (
+                        false,
+                        format!("{:?} should be an empty directory but it could not be read: {}", value, err),
+                    ),
+                };
+                MatcherResult::formatted(
+                    empty,
+                    failure_message,
+                    format!("{:?} should not be an empty directory", value),
+                )
+            }
             FileTypeMatcher::Readonly => MatcherResult::formatted(
                 metadata.is_ok_and(|metadata| metadata.permissions().readonly()),
                 format!("{:?} should be readonly", value),
@@ -83,10 +225,177 @@ impl<T: AsRef<Path> + Debug> Matcher<T> for FileTypeMatcher {
                 format!("{:?} should be writable", value),
                 format!("{:?} should not be writable", value),
             ),
+            #[cfg(unix)]
+            FileTypeMatcher::NamedPipe => MatcherResult::formatted(
+                metadata.is_ok_and(|metadata| metadata.file_type().is_fifo()),
+                format!("{:?} should be a named pipe", value),
+                format!("{:?} should not be a named pipe", value),
+            ),
+            #[cfg(unix)]
+            FileTypeMatcher::Socket => MatcherResult::formatted(
+                metadata.is_ok_and(|metadata| metadata.file_type().is_socket()),
+                format!("{:?} should be a socket", value),
+                format!("{:?} should not be a socket", value),
+            ),
         }
     }
 }
 
+impl FileSizeMatcher {
+    fn matches(&self, size: u64) -> bool {
+        match self {
+            FileSizeMatcher::Exact(expected) => size == *expected,
+            FileSizeMatcher::AtLeast(minimum) => size >= *minimum,
+            FileSizeMatcher::AtMost(maximum) => size <= *maximum,
+            FileSizeMatcher::InclusiveRange(range) => range.contains(&size),
+        }
+    }
+
+    fn description(&self) -> String {
+        match self {
+            FileSizeMatcher::Exact(expected) => format!("have a size of {} bytes", expected),
+            FileSizeMatcher::AtLeast(minimum) => format!("have a size of at least {} bytes", minimum),
+            FileSizeMatcher::AtMost(maximum) => format!("have a size of at most {} bytes", maximum),
+            FileSizeMatcher::InclusiveRange(range) => format!("have a size in the range {:?} bytes", range),
+        }
+    }
+}
+
+impl<T: AsRef<Path> + Debug> Matcher<T> for FileSizeMatcher {
+    fn test(&self, value: &T) -> MatcherResult {
+        let (passed, failure_message) = match fs::metadata(value) {
+            Ok(metadata) => (
+                self.matches(metadata.len()),
+                format!("{:?} should {} but was {} bytes", value, self.description(), metadata.len()),
+            ),
+            Err(err) => (
+                false,
+                format!("{:?} should {} but its size could not be read: {}", value, self.description(), err),
+            ),
+        };
+        MatcherResult::formatted(
+            passed,
+            failure_message,
+            format!("{:?} should not {}", value, self.description()),
+        )
+    }
+}
+
+impl<T: AsRef<Path> + Debug> Matcher<T> for ModificationTimeMatcher {
+    fn test(&self, value: &T) -> MatcherResult {
+        let (other, relation) = match self {
+            ModificationTimeMatcher::After(other) => (other, "after"),
+            ModificationTimeMatcher::Before(other) => (other, "before"),
+        };
+        let modified = |path: &Path| fs::metadata(path).and_then(|metadata| metadata.modified());
+
+        let (passed, failure_message) = match (modified(value.as_ref()), modified(other)) {
+            (Ok(modified), Ok(other_modified)) => (
+                match self {
+                    ModificationTimeMatcher::After(_) => modified > other_modified,
+                    ModificationTimeMatcher::Before(_) => modified < other_modified,
+                },
+                format!(
+                    "{:?} should be modified {} {:?} but it was modified {}",
+                    value, relation, other, describe_relative_time(modified, other_modified)
+                ),
+            ),
+            (Err(err), _) => (
+                false,
+                format!(
+                    "{:?} should be modified {} {:?} but the modification time of {:?} could not be read: {}",
+                    value, relation, other, value, err
+                ),
+            ),
+            (_, Err(err)) => (
+                false,
+                format!(
+                    "{:?} should be modified {} {:?} but the modification time of {:?} could not be read: {}",
+                    value, relation, other, other, err
+                ),
+            ),
+        };
+        MatcherResult::formatted(
+            passed,
+            failure_message,
+            format!("{:?} should not be modified {} {:?}", value, relation, other),
+        )
+    }
+}
+
+fn describe_relative_time(modified: SystemTime, other_modified: SystemTime) -> String {
+    match modified.duration_since(other_modified) {
+        Ok(duration) if duration.is_zero() => "at the same time".to_string(),
+        Ok(duration) => format!("{:?} after it", duration),
+        Err(err) => format!("{:?} before it", err.duration()),
+    }
+}
+
+impl<T: AsRef<Path> + Debug> Matcher<T> for FileContentMatcher {
+    fn test(&self, value: &T) -> MatcherResult {
+        let description = match self {
+            FileContentMatcher::Line(line) => format!("contain the line {:?}", line),
+            FileContentMatcher::Text(text) => format!("contain the text {:?}", text),
+        };
+        let (passed, failure_message) = match fs::read_to_string(value) {
+            Ok(content) => (
+                match self {
+                    FileContentMatcher::Line(line) => content.lines().any(|source| source == *line),
+                    FileContentMatcher::Text(text) => content.contains(text),
+                },
+                format!("{:?} should {}", value, description),
+            ),
+            Err(err) if err.kind() == ErrorKind::InvalidData => (
+                false,
+                format!("{:?} should {} but its content was not valid UTF-8", value, description),
+            ),
+            Err(err) => (
+                false,
+                format!("{:?} should {} but it could not be read: {}", value, description, err),
+            ),
+        };
+        MatcherResult::formatted(
+            passed,
+            failure_message,
+            format!("{:?} should not {}", value, description),
+        )
+    }
+}
+
+impl<T: AsRef<Path> + Debug> Matcher<T> for SameFileMatcher {
+    fn test(&self, value: &T) -> MatcherResult {
+        let other = &self.other;
+        let (passed, failure_message) = match (fs::canonicalize(value), fs::canonicalize(other)) {
+            (Ok(canonical), Ok(other_canonical)) => (
+                canonical == other_canonical,
+                format!(
+                    "{:?} should be the same file as {:?} but they resolved to {:?} and {:?}",
+                    value, other, canonical, other_canonical
+                ),
+            ),
+            (Err(err), _) => (
+                false,
+                format!(
+                    "{:?} should be the same file as {:?} but {:?} could not be resolved: {}",
+                    value, other, value, err
+                ),
+            ),
+            (_, Err(err)) => (
+                false,
+                format!(
+                    "{:?} should be the same file as {:?} but {:?} could not be resolved: {}",
+                    value, other, other, err
+                ),
+            ),
+        };
+        MatcherResult::formatted(
+            passed,
+            failure_message,
+            format!("{:?} should not be the same file as {:?}", value, other),
+        )
+    }
+}
+
 impl<T: AsRef<Path> + Debug> Matcher<T> for FilePathMatcher {
     fn test(&self, value: &T) -> MatcherResult {
         match self {
@@ -109,6 +418,15 @@ impl<T: AsRef<Path> + Debug> Matcher<T> for FilePathMatcher {
                 format!("{:?} should have extension {:?}", value, extension),
                 format!("{:?} should not be have extension {:?}", value, extension),
             ),
+            FilePathMatcher::ExtensionIgnoringCase(extension) => MatcherResult::formatted(
+                value
+                    .as_ref()
+                    .extension()
+                    .filter(|source| source.to_string_lossy().to_lowercase() == extension.to_lowercase())
+                    .is_some(),
+                format!("{:?} should have extension {:?} ignoring case", value, extension),
+                format!("{:?} should not have extension {:?} ignoring case", value, extension),
+            ),
         }
     }
 }
@@ -168,6 +486,56 @@ impl<T: AsRef<Path> + Debug> Matcher<T> for TreeMatcher {
                     ),
                 )
             }
+            TreeMatcher::ContainMatching(pattern) => {
+                let glob = Pattern::new(pattern)
+                    .unwrap_or_else(|err| panic!("invalid glob pattern {:?}: {}", pattern, err));
+
+                let matched = WalkDir::new(value)
+                    .into_iter()
+                    .flatten()
+                    .any(|directory_entry| glob.matches(&directory_entry.file_name().to_string_lossy()));
+
+                MatcherResult::formatted(
+                    matched,
+                    format!("{:?} should contain a file name matching {:?}", value, pattern),
+                    format!("{:?} should not contain a file name matching {:?}", value, pattern),
+                )
+            }
+            TreeMatcher::ContainWithinDepth(name, max_depth) => MatcherResult::formatted(
+                WalkDir::new(value)
+                    .max_depth(*max_depth)
+                    .into_iter()
+                    .flatten()
+                    .any(|directory_entry| &directory_entry.file_name() == name),
+                format!(
+                    "{:?} should contain a file name {:?} within depth {:?}",
+                    value, name, max_depth
+                ),
+                format!(
+                    "{:?} should not contain a file name {:?} within depth {:?}",
+                    value, name, max_depth
+                ),
+            ),
+            TreeMatcher::ContainFollowingSymlinks(name) => {
+                let mut contains = false;
+                for entry in WalkDir::new(value).follow_links(true) {
+                    match entry {
+                        Ok(directory_entry) if &directory_entry.file_name() == name => {
+                            contains = true;
+                            break;
+                        }
+                        Err(err) if err.loop_ancestor().is_some() => {
+                            panic!("file system loop detected while walking {:?}: {}", value, err)
+                        }
+                        _ => {}
+                    }
+                }
+                MatcherResult::formatted(
+                    contains,
+                    format!("{:?} should contain a file name {:?} following symlinks", value, name),
+                    format!("{:?} should not contain a file name {:?} following symlinks", value, name),
+                )
+            }
         }
     }
 }
@@ -192,6 +560,12 @@ pub fn be_zero_sized() -> FileTypeMatcher {
     FileTypeMatcher::ZeroSized
 }
 
+/// Creates a FileTypeMatcher that asserts whether the path corresponds to a directory without any entries.
+/// A path that is not a directory (or can not be read) never matches.
+pub fn be_an_empty_directory() -> FileTypeMatcher {
+    FileTypeMatcher::EmptyDirectory
+}
+
 /// Creates a FileTypeMatcher that asserts whether the path corresponds to a readonly file.
 pub fn be_readonly() -> FileTypeMatcher {
     FileTypeMatcher::Readonly
@@ -202,6 +576,63 @@ pub fn be_writable() -> FileTypeMatcher {
     FileTypeMatcher::Writable
 }
 
+/// Creates a FileTypeMatcher that asserts whether the path corresponds to a named pipe (FIFO).
+#[cfg(unix)]
+pub fn be_a_named_pipe() -> FileTypeMatcher {
+    FileTypeMatcher::NamedPipe
+}
+
+/// Creates a FileTypeMatcher that asserts whether the path corresponds to a unix domain socket.
+#[cfg(unix)]
+pub fn be_a_socket() -> FileTypeMatcher {
+    FileTypeMatcher::Socket
+}
+
+/// Creates a FileSizeMatcher that asserts whether the file has exactly the given size in bytes.
+pub fn have_file_size(bytes: u64) -> FileSizeMatcher {
+    FileSizeMatcher::Exact(bytes)
+}
+
+/// Creates a FileSizeMatcher that asserts whether the file has a size greater than or equal to the given bytes.
+pub fn have_file_size_at_least(bytes: u64) -> FileSizeMatcher {
+    FileSizeMatcher::AtLeast(bytes)
+}
+
+/// Creates a FileSizeMatcher that asserts whether the file has a size less than or equal to the given bytes.
+pub fn have_file_size_at_most(bytes: u64) -> FileSizeMatcher {
+    FileSizeMatcher::AtMost(bytes)
+}
+
+/// Creates a FileSizeMatcher that asserts whether the file has a size (in bytes) within the given inclusive range.
+pub fn have_file_size_in_inclusive_range(range: RangeInclusive<u64>) -> FileSizeMatcher {
+    FileSizeMatcher::InclusiveRange(range)
+}
+
+/// Creates a ModificationTimeMatcher that asserts whether the file was modified strictly after the given file.
+pub fn be_modified_after(other: &Path) -> ModificationTimeMatcher {
+    ModificationTimeMatcher::After(other.to_path_buf())
+}
+
+/// Creates a ModificationTimeMatcher that asserts whether the file was modified strictly before the given file.
+pub fn be_modified_before(other: &Path) -> ModificationTimeMatcher {
+    ModificationTimeMatcher::Before(other.to_path_buf())
+}
+
+/// Creates a FileContentMatcher that asserts whether the file contains the given line, compared without its line terminator.
+pub fn contain_line(line: &'static str) -> FileContentMatcher {
+    FileContentMatcher::Line(line)
+}
+
+/// Creates a FileContentMatcher that asserts whether the file contains the given text anywhere in its content.
+pub fn contain_text(text: &'static str) -> FileContentMatcher {
+    FileContentMatcher::Text(text)
+}
+
+/// Creates a SameFileMatcher that asserts whether the path refers to the same file as the given path, once both are canonicalized.
+pub fn be_same_file_as(other: &Path) -> SameFileMatcher {
+    SameFileMatcher { other: other.to_path_buf() }
+}
+
 /// Creates a FilePathMatcher that asserts whether the path is absolute.
 pub fn be_absolute() -> FilePathMatcher {
     FilePathMatcher::Absolute
@@ -217,6 +648,11 @@ pub fn have_extension(extension: &'static str) -> FilePathMatcher {
     FilePathMatcher::Extension(extension)
 }
 
+/// Creates a FilePathMatcher that asserts whether the path has the given extension, ignoring case differences.
+pub fn have_extension_ignoring_case(extension: &'static str) -> FilePathMatcher {
+    FilePathMatcher::ExtensionIgnoringCase(extension)
+}
+
 /// Creates a TreeMatcher that asserts whether the path contains the given file name.
 pub fn contain_file_name(name: &'static str) -> TreeMatcher {
     TreeMatcher::Contain(name)
@@ -232,6 +668,24 @@ pub fn contain_any_file_names(names: Vec<&'static str>) -> TreeMatcher {
     TreeMatcher::ContainAny(names)
 }
 
+/// Creates a TreeMatcher that asserts whether the path contains a file name matching the given glob pattern (for example: `*.rs`).
+/// Panics if the pattern is not a valid glob.
+pub fn contain_file_name_matching(pattern: &'static str) -> TreeMatcher {
+    TreeMatcher::ContainMatching(pattern)
+}
+
+/// Creates a TreeMatcher that asserts whether the path contains the given file name, descending at most max_depth levels.
+/// A max_depth of 1 only considers the direct children of the path.
+pub fn contain_file_name_at_depth(name: &'static str, max_depth: usize) -> TreeMatcher {
+    TreeMatcher::ContainWithinDepth(name, max_depth)
+}
+
+/// Creates a TreeMatcher that asserts whether the path contains the given file name, following symbolic links while walking the tree.
+/// Panics if a symbolic link introduces a file system loop.
+pub fn contain_file_name_following_symlinks(name: &'static str) -> TreeMatcher {
+    TreeMatcher::ContainFollowingSymlinks(name)
+}
+
 #[cfg(all(test, feature = "file"))]
 mod file_type_tests {
     use std::fs::File;
@@ -241,8 +695,10 @@ mod file_type_tests {
 
     use crate::assertions::bool::TrueFalseAssertion;
     use crate::matchers::file::{
-        be_a_directory, be_a_file, be_readonly, be_writable, be_zero_sized,
+        be_a_directory, be_a_file, be_an_empty_directory, be_readonly, be_writable, be_zero_sized,
     };
+    #[cfg(unix)]
+    use crate::matchers::file::{be_a_named_pipe, be_a_socket};
     use crate::matchers::Matcher;
 
     #[test]
@@ -290,6 +746,23 @@ mod file_type_tests {
         matcher.test(&path).passed.should_be_true();
     }
 
+    #[test]
+    fn should_be_an_empty_directory() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let matcher = be_an_empty_directory();
+        matcher.test(&temporary_directory.path()).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_an_empty_directory_but_it_had_a_file() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let _ = File::create(temporary_directory.path().join("temporary.txt")).unwrap();
+
+        let matcher = be_an_empty_directory();
+        matcher.test(&temporary_directory.path()).passed.should_be_true();
+    }
+
     #[test]
     fn should_be_readonly() {
         let temporary_directory = TempDir::new(".").unwrap();
@@ -349,6 +822,247 @@ mod file_type_tests {
         let matcher = be_writable();
         matcher.test(&path).passed.should_be_true();
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn should_be_a_named_pipe() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let pipe_path = temporary_directory.path().join("junit.fifo");
+        let status = std::process::Command::new("mkfifo").arg(&pipe_path).status().unwrap();
+        status.success().should_be_true();
+
+        let matcher = be_a_named_pipe();
+        matcher.test(&pipe_path).passed.should_be_true();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    #[should_panic]
+    fn should_be_a_named_pipe_but_was_not() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = temporary_directory.path().join("junit.txt");
+        let _ = File::create(&file_path).unwrap();
+
+        let matcher = be_a_named_pipe();
+        matcher.test(&file_path).passed.should_be_true();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn should_be_a_socket() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let socket_path = temporary_directory.path().join("junit.sock");
+        let _listener = std::os::unix::net::UnixListener::bind(&socket_path).unwrap();
+
+        let matcher = be_a_socket();
+        matcher.test(&socket_path).passed.should_be_true();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    #[should_panic]
+    fn should_be_a_socket_but_was_not() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = temporary_directory.path().join("junit.txt");
+        let _ = File::create(&file_path).unwrap();
+
+        let matcher = be_a_socket();
+        matcher.test(&file_path).passed.should_be_true();
+    }
+}
+
+#[cfg(all(test, feature = "file"))]
+mod file_size_tests {
+    use std::fs::File;
+    use std::io::Write;
+
+    use tempdir::TempDir;
+
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::matchers::file::{
+        have_file_size, have_file_size_at_least, have_file_size_at_most, have_file_size_in_inclusive_range,
+    };
+    use crate::matchers::Matcher;
+
+    #[test]
+    fn should_have_file_size() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = temporary_directory.path().join("temporary.txt");
+        let mut file = File::create(file_path.clone()).unwrap();
+        write!(file, "clearcheck").unwrap();
+
+        let matcher = have_file_size(10);
+        matcher.test(&file_path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_file_size_at_least() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = temporary_directory.path().join("temporary.txt");
+        let mut file = File::create(file_path.clone()).unwrap();
+        write!(file, "clearcheck").unwrap();
+
+        let matcher = have_file_size_at_least(10);
+        matcher.test(&file_path).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_file_size_at_most_but_was_larger() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = temporary_directory.path().join("temporary.txt");
+        let mut file = File::create(file_path.clone()).unwrap();
+        write!(file, "clearcheck").unwrap();
+
+        let matcher = have_file_size_at_most(9);
+        matcher.test(&file_path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_file_size_in_inclusive_range() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = temporary_directory.path().join("temporary.txt");
+        let mut file = File::create(file_path.clone()).unwrap();
+        write!(file, "clearcheck").unwrap();
+
+        let matcher = have_file_size_in_inclusive_range(5..=10);
+        matcher.test(&file_path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_have_file_size_given_a_missing_file() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = temporary_directory.path().join("missing.txt");
+
+        let matcher = have_file_size(0);
+        matcher.test(&file_path).passed.should_be_false();
+    }
+}
+
+#[cfg(all(test, feature = "file"))]
+mod modification_time_tests {
+    use std::fs::File;
+    use std::time::{Duration, SystemTime};
+
+    use tempdir::TempDir;
+
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::matchers::file::{be_modified_after, be_modified_before};
+    use crate::matchers::Matcher;
+
+    #[test]
+    fn should_be_modified_after() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let older_path = temporary_directory.path().join("older.txt");
+        let newer_path = temporary_directory.path().join("newer.txt");
+
+        let older = File::create(older_path.clone()).unwrap();
+        older.set_modified(SystemTime::now() - Duration::from_secs(10)).unwrap();
+        let _ = File::create(newer_path.clone()).unwrap();
+
+        let matcher = be_modified_after(&older_path);
+        matcher.test(&newer_path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_modified_before_given_a_missing_file() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = temporary_directory.path().join("temporary.txt");
+        let _ = File::create(file_path.clone()).unwrap();
+
+        let matcher = be_modified_before(&temporary_directory.path().join("missing.txt"));
+        matcher.test(&file_path).passed.should_be_false();
+    }
+}
+
+#[cfg(all(test, feature = "file"))]
+mod file_content_tests {
+    use std::fs::File;
+    use std::io::Write;
+
+    use tempdir::TempDir;
+
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::matchers::file::{contain_line, contain_text};
+    use crate::matchers::Matcher;
+
+    #[test]
+    fn should_contain_line() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = temporary_directory.path().join("config.properties");
+        let mut file = File::create(file_path.clone()).unwrap();
+        write!(file, "name=clearcheck\r\nenabled=true\r\n").unwrap();
+
+        let matcher = contain_line("enabled=true");
+        matcher.test(&file_path).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_line_but_it_was_only_a_part_of_a_line() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = temporary_directory.path().join("config.properties");
+        let mut file = File::create(file_path.clone()).unwrap();
+        writeln!(file, "enabled=true # default").unwrap();
+
+        let matcher = contain_line("enabled=true");
+        matcher.test(&file_path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_contain_text() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = temporary_directory.path().join("config.properties");
+        let mut file = File::create(file_path.clone()).unwrap();
+        writeln!(file, "enabled=true # default").unwrap();
+
+        let matcher = contain_text("true #");
+        matcher.test(&file_path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_contain_text_given_a_non_utf8_file() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let file_path = temporary_directory.path().join("binary.bin");
+        let mut file = File::create(file_path.clone()).unwrap();
+        file.write_all(&[0xff, 0xfe, 0x00]).unwrap();
+
+        let matcher = contain_text("");
+        matcher.test(&file_path).passed.should_be_false();
+    }
+}
+
+#[cfg(all(test, feature = "file"))]
+mod same_file_tests {
+    use std::fs;
+    use std::fs::File;
+
+    use tempdir::TempDir;
+
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::matchers::file::be_same_file_as;
+    use crate::matchers::Matcher;
+
+    #[test]
+    fn should_be_same_file_as() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        fs::create_dir(temporary_directory.path().join("dir")).unwrap();
+        let _ = File::create(temporary_directory.path().join("file.txt")).unwrap();
+
+        let path = temporary_directory.path().join("./dir/../file.txt");
+        let matcher = be_same_file_as(&temporary_directory.path().join("./file.txt"));
+        matcher.test(&path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_same_file_as_given_a_missing_file() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let _ = File::create(temporary_directory.path().join("file.txt")).unwrap();
+
+        let path = temporary_directory.path().join("file.txt");
+        let matcher = be_same_file_as(&temporary_directory.path().join("missing.txt"));
+        matcher.test(&path).passed.should_be_false();
+    }
 }
 
 #[cfg(all(test, feature = "file"))]
@@ -356,7 +1070,7 @@ mod file_path_tests {
     use std::path::Path;
 
     use crate::assertions::bool::TrueFalseAssertion;
-    use crate::matchers::file::{be_absolute, be_relative, have_extension};
+    use crate::matchers::file::{be_absolute, be_relative, have_extension, have_extension_ignoring_case};
     use crate::matchers::Matcher;
 
     #[test]
@@ -379,17 +1093,42 @@ mod file_path_tests {
         let matcher = have_extension("txt");
         matcher.test(&path).passed.should_be_true();
     }
+
+    #[test]
+    #[should_panic]
+    fn should_have_extension_but_case_was_different() {
+        let path = Path::new("/etc/sample.TXT");
+        let matcher = have_extension("txt");
+        matcher.test(&path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_extension_ignoring_case() {
+        let path = Path::new("/etc/sample.TXT");
+        let matcher = have_extension_ignoring_case("txt");
+        matcher.test(&path).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_extension_ignoring_case_but_was_not() {
+        let path = Path::new("/etc/sample.TXT");
+        let matcher = have_extension_ignoring_case("zip");
+        matcher.test(&path).passed.should_be_true();
+    }
 }
 
 #[cfg(all(test, feature = "file"))]
 mod walk_tree_tests {
+    use std::fs;
     use std::fs::File;
 
     use tempdir::TempDir;
 
     use crate::assertions::bool::TrueFalseAssertion;
     use crate::matchers::file::{
-        contain_all_file_names, contain_any_file_names, contain_file_name,
+        contain_all_file_names, contain_any_file_names, contain_file_name, contain_file_name_at_depth,
+        contain_file_name_following_symlinks, contain_file_name_matching,
     };
     use crate::matchers::Matcher;
 
@@ -443,4 +1182,123 @@ mod walk_tree_tests {
         let matcher = contain_any_file_names(vec!["assert.txt", "assert.txt"]);
         matcher.test(&directory_path).passed.should_be_true();
     }
+
+    #[test]
+    fn should_contain_a_file_matching_glob() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let _ = File::create(temporary_directory.path().join("a.rs")).unwrap();
+        let _ = File::create(temporary_directory.path().join("b.txt")).unwrap();
+
+        let directory_path = temporary_directory.path();
+        let matcher = contain_file_name_matching("*.rs");
+        matcher.test(&directory_path).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_a_file_matching_glob_but_it_did_not() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let _ = File::create(temporary_directory.path().join("b.txt")).unwrap();
+
+        let directory_path = temporary_directory.path();
+        let matcher = contain_file_name_matching("*.rs");
+        matcher.test(&directory_path).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid glob pattern")]
+    fn should_panic_for_an_invalid_glob() {
+        let temporary_directory = TempDir::new(".").unwrap();
+
+        let directory_path = temporary_directory.path();
+        let matcher = contain_file_name_matching("[*.rs");
+        let _ = matcher.test(&directory_path);
+    }
+
+    #[test]
+    fn should_contain_a_direct_child_at_depth_1() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let _ = File::create(temporary_directory.path().join("junit.txt")).unwrap();
+
+        let directory_path = temporary_directory.path();
+        let matcher = contain_file_name_at_depth("junit.txt", 1);
+        matcher.test(&directory_path).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_a_nested_file_at_depth_1_but_it_did_not() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let nested_directory = temporary_directory.path().join("nested");
+        fs::create_dir(&nested_directory).unwrap();
+        let _ = File::create(nested_directory.join("junit.txt")).unwrap();
+
+        let directory_path = temporary_directory.path();
+        let matcher = contain_file_name_at_depth("junit.txt", 1);
+        matcher.test(&directory_path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_contain_a_nested_file_at_depth_2() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let nested_directory = temporary_directory.path().join("nested");
+        fs::create_dir(&nested_directory).unwrap();
+        let _ = File::create(nested_directory.join("junit.txt")).unwrap();
+
+        let directory_path = temporary_directory.path();
+        let matcher = contain_file_name_at_depth("junit.txt", 2);
+        matcher.test(&directory_path).passed.should_be_true();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn should_contain_a_file_name_through_a_symlinked_directory() {
+        let target_directory = TempDir::new(".").unwrap();
+        let _ = File::create(target_directory.path().join("junit.txt")).unwrap();
+
+        let temporary_directory = TempDir::new(".").unwrap();
+        std::os::unix::fs::symlink(
+            target_directory.path().canonicalize().unwrap(),
+            temporary_directory.path().join("linked"),
+        )
+        .unwrap();
+
+        let directory_path = temporary_directory.path();
+        let matcher = contain_file_name_following_symlinks("junit.txt");
+        matcher.test(&directory_path).passed.should_be_true();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn should_not_contain_a_file_name_through_a_symlinked_directory_without_following_symlinks() {
+        let target_directory = TempDir::new(".").unwrap();
+        let _ = File::create(target_directory.path().join("junit.txt")).unwrap();
+
+        let temporary_directory = TempDir::new(".").unwrap();
+        std::os::unix::fs::symlink(
+            target_directory.path().canonicalize().unwrap(),
+            temporary_directory.path().join("linked"),
+        )
+        .unwrap();
+
+        let directory_path = temporary_directory.path();
+        let matcher = contain_file_name("junit.txt");
+        matcher.test(&directory_path).passed.should_be_false();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    #[should_panic(expected = "file system loop detected")]
+    fn should_surface_a_symlink_loop_while_following_symlinks() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        std::os::unix::fs::symlink(
+            temporary_directory.path().canonicalize().unwrap(),
+            temporary_directory.path().join("cycle"),
+        )
+        .unwrap();
+
+        let directory_path = temporary_directory.path();
+        let matcher = contain_file_name_following_symlinks("junit.txt");
+        matcher.test(&directory_path);
+    }
 }
@@ -21,6 +21,14 @@ pub enum FloatMatcher {
     Zero,
     Positive,
     Negative,
+    NonNegative,
+    NonPositive,
+    PositiveZero,
+    NegativeZero,
+    Finite,
+    Infinite,
+    IntegerValued,
+    Fractional,
 }
 
 impl<T: Float + Debug + Default + PartialEq> Matcher<T> for FloatMatcher {
@@ -46,10 +54,64 @@ impl<T: Float + Debug + Default + PartialEq> Matcher<T> for FloatMatcher {
                 format!("{:?} should be negative", value),
                 format!("{:?} should not be negative", value),
             ),
+            FloatMatcher::NonNegative => MatcherResult::formatted(
+                *value >= T::zero(),
+                format!("{:?} should be non-negative (zero or positive)", value),
+                format!("{:?} should not be non-negative (zero or positive)", value),
+            ),
+            FloatMatcher::NonPositive => MatcherResult::formatted(
+                *value <= T::zero(),
+                format!("{:?} should be non-positive (zero or negative)", value),
+                format!("{:?} should not be non-positive (zero or negative)", value),
+            ),
+            FloatMatcher::PositiveZero => MatcherResult::formatted(
+                value.is_zero() && value.is_sign_positive(),
+                format!("{:?} should be positive zero but was {}", value, describe(value)),
+                format!("{:?} should not be positive zero", value),
+            ),
+            FloatMatcher::NegativeZero => MatcherResult::formatted(
+                value.is_zero() && value.is_sign_negative(),
+                format!("{:?} should be negative zero but was {}", value, describe(value)),
+                format!("{:?} should not be negative zero", value),
+            ),
+            FloatMatcher::Finite => MatcherResult::formatted(
+                value.is_finite(),
+                format!("{:?} should be finite but was {}", value, describe(value)),
+                format!("{:?} should not be finite", value),
+            ),
+            FloatMatcher::Infinite => MatcherResult::formatted(
+                value.is_infinite(),
+                format!("{:?} should be infinite but was {}", value, describe(value)),
+                format!("{:?} should not be infinite", value),
+            ),
+            FloatMatcher::IntegerValued => MatcherResult::formatted(
+                value.is_finite() && value.fract().is_zero(),
+                format!("{:?} should be integer valued but had the fractional part {:?}", value, value.fract()),
+                format!("{:?} should not be integer valued", value),
+            ),
+            FloatMatcher::Fractional => MatcherResult::formatted(
+                value.is_finite() && !value.fract().is_zero(),
+                format!("{:?} should have a fractional part but had the fractional part {:?}", value, value.fract()),
+                format!("{:?} should not have a fractional part but had the fractional part {:?}", value, value.fract()),
+            ),
         }
     }
 }
 
+fn describe<T: Float>(value: &T) -> &'static str {
+    if value.is_nan() {
+        "NaN"
+    } else if value.is_infinite() {
+        "infinite"
+    } else if value.is_zero() && value.is_sign_negative() {
+        "negative zero"
+    } else if value.is_zero() {
+        "positive zero"
+    } else {
+        "finite"
+    }
+}
+
 /// Creates a FloatMatcher that asserts whether a floating value is NaN (not a number).
 pub fn be_nan() -> FloatMatcher {
     FloatMatcher::NaN
@@ -70,10 +132,267 @@ pub fn be_negative() -> FloatMatcher {
     FloatMatcher::Negative
 }
 
+/// Creates a FloatMatcher that asserts whether a floating value is greater than or equal to zero; -0.0 is non-negative and NaN is not.
+pub fn be_non_negative() -> FloatMatcher {
+    FloatMatcher::NonNegative
+}
+
+/// Creates a FloatMatcher that asserts whether a floating value is less than or equal to zero; +0.0 is non-positive and NaN is not.
+pub fn be_non_positive() -> FloatMatcher {
+    FloatMatcher::NonPositive
+}
+
+/// Creates a FloatMatcher that asserts whether a floating value is positive zero (+0.0).
+pub fn be_positive_zero() -> FloatMatcher {
+    FloatMatcher::PositiveZero
+}
+
+/// Creates a FloatMatcher that asserts whether a floating value is negative zero (-0.0).
+pub fn be_negative_zero() -> FloatMatcher {
+    FloatMatcher::NegativeZero
+}
+
+/// Creates a FloatMatcher that asserts whether a floating value is finite (neither infinite nor NaN).
+pub fn be_finite() -> FloatMatcher {
+    FloatMatcher::Finite
+}
+
+/// Creates a FloatMatcher that asserts whether a floating value is positive or negative infinity.
+pub fn be_infinite() -> FloatMatcher {
+    FloatMatcher::Infinite
+}
+
+/// Creates a FloatMatcher that asserts whether a floating value is finite and has no fractional part (like 4.0).
+pub fn be_integer_valued() -> FloatMatcher {
+    FloatMatcher::IntegerValued
+}
+
+/// Creates a FloatMatcher that asserts whether a floating value is finite and has a non-zero fractional part (like 4.5).
+pub fn have_fractional_part() -> FloatMatcher {
+    FloatMatcher::Fractional
+}
+
+/// SignMatcher offers a flexible way to assert whether a floating value has the same sign as, or the opposite sign from,
+/// another value.
+///
+/// Both positive zero (0.0) and negative zero (-0.0) are treated as zero, which has the same sign only as zero,
+/// and the opposite sign from no value. NaN has no sign, so any comparison involving NaN fails.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::float::have_same_sign_as;
+/// use clearcheck::matchers::Matcher;
+///
+/// let value = 10.5;
+/// let matcher = have_same_sign_as(0.25);
+///
+/// assert!(matcher.test(&value).passed());
+/// ```
+pub enum SignMatcher<T> {
+    Same(T),
+    Opposite(T),
+}
+
+impl<T: Float + Debug> Matcher<T> for SignMatcher<T> {
+    fn test(&self, value: &T) -> MatcherResult {
+        match self {
+            SignMatcher::Same(other) => {
+                let (sign, other_sign) = (sign_of(value), sign_of(other));
+                MatcherResult::formatted(
+                    sign.is_some() && sign == other_sign,
+                    format!(
+                        "{:?} should have the same sign as {:?} but {:?} is {} and {:?} is {}",
+                        value, other, value, describe_sign(sign), other, describe_sign(other_sign)
+                    ),
+                    format!(
+                        "{:?} should not have the same sign as {:?} but both are {}",
+                        value, other, describe_sign(sign)
+                    ),
+                )
+            }
+            SignMatcher::Opposite(other) => {
+                let (sign, other_sign) = (sign_of(value), sign_of(other));
+                MatcherResult::formatted(
+                    matches!(
+                        (sign, other_sign),
+                        (Some(Sign::Positive), Some(Sign::Negative)) | (Some(Sign::Negative), Some(Sign::Positive))
+                    ),
+                    format!(
+                        "{:?} should have the opposite sign from {:?} but {:?} is {} and {:?} is {}",
+                        value, other, value, describe_sign(sign), other, describe_sign(other_sign)
+                    ),
+                    format!(
+                        "{:?} should not have the opposite sign from {:?} but {:?} is {} and {:?} is {}",
+                        value, other, value, describe_sign(sign), other, describe_sign(other_sign)
+                    ),
+                )
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum Sign {
+    Positive,
+    Negative,
+    Zero,
+}
+
+fn sign_of<T: Float>(value: &T) -> Option<Sign> {
+    if value.is_nan() {
+        None
+    } else if value.is_zero() {
+        Some(Sign::Zero)
+    } else if value.is_sign_positive() {
+        Some(Sign::Positive)
+    } else {
+        Some(Sign::Negative)
+    }
+}
+
+fn describe_sign(sign: Option<Sign>) -> &'static str {
+    match sign {
+        Some(Sign::Positive) => "positive",
+        Some(Sign::Negative) => "negative",
+        Some(Sign::Zero) => "zero",
+        None => "NaN",
+    }
+}
+
+/// Creates a SignMatcher that asserts whether a floating value has the same sign as the given value.
+pub fn have_same_sign_as<T>(other: T) -> SignMatcher<T> {
+    SignMatcher::Same(other)
+}
+
+/// Creates a SignMatcher that asserts whether a floating value has the opposite sign from the given value.
+pub fn have_opposite_sign_from<T>(other: T) -> SignMatcher<T> {
+    SignMatcher::Opposite(other)
+}
+
+/// RoundingMatcher offers a flexible way to assert whether a floating value rounds to the expected value at the given
+/// number of decimal places.
+///
+/// Rounding follows [`Float::round`], which rounds half-way cases away from zero (2.5 rounds to 3.0 and -2.5 rounds
+/// to -3.0), rather than half-to-even. Only the value is rounded: it is scaled by 10^places, rounded and scaled back,
+/// and the result must equal the expected value, so an expected value with more decimal places than `places` never
+/// matches. Note that a decimal literal like 1.005 is stored slightly below its written value and therefore rounds
+/// to 1.0 at 2 places.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::float::round_to;
+/// use clearcheck::matchers::Matcher;
+///
+/// let value: f64 = 12.34567;
+/// let matcher = round_to(12.35, 2);
+///
+/// assert!(matcher.test(&value).passed());
+/// ```
+pub struct RoundingMatcher<T: Float> {
+    expected: T,
+    places: u32,
+}
+
+impl<T: Float + Debug> Matcher<T> for RoundingMatcher<T> {
+    fn test(&self, value: &T) -> MatcherResult {
+        let scale = scale_for::<T>(self.places).unwrap();
+        let scaled = *value * scale;
+        let rounded = if scaled.is_finite() {
+            scaled.round() / scale
+        } else {
+            *value
+        };
+
+        MatcherResult::formatted(
+            rounded == self.expected,
+            format!(
+                "{:?} should round to {:?} at {} decimal places but rounded to {:?}",
+                value, self.expected, self.places, rounded
+            ),
+            format!(
+                "{:?} should not round to {:?} at {} decimal places",
+                value, self.expected, self.places
+            ),
+        )
+    }
+}
+
+/// Creates a RoundingMatcher that asserts whether a floating value rounds to the expected value at the given number of decimal places.
+///
+/// Panics if 10^places is not representable in the floating type.
+pub fn round_to<T: Float>(expected: T, places: u32) -> RoundingMatcher<T> {
+    assert!(
+        scale_for::<T>(places).is_some(),
+        "{} decimal places exceed the range of the floating type",
+        places
+    );
+    RoundingMatcher { expected, places }
+}
+
+fn scale_for<T: Float>(places: u32) -> Option<T> {
+    i32::try_from(places)
+        .ok()
+        .map(|places| T::from(10).unwrap().powi(places))
+        .filter(|scale| scale.is_finite())
+}
+
+/// CloseToAnyMatcher offers a flexible way to assert that a floating value is within a tolerance of any of the candidate values.
+///
+/// The tolerance is inclusive. NaN is never close to any candidate, and a matcher without candidates never passes.
+/// On failure, the message lists the candidates along with the distance to the closest one.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::float::be_close_to_any;
+/// use clearcheck::matchers::Matcher;
+///
+/// let value: f64 = 2.004;
+/// let matcher = be_close_to_any(vec![1.0, 2.0], 0.01);
+///
+/// assert!(matcher.test(&value).passed());
+/// ```
+pub struct CloseToAnyMatcher<T: Float> {
+    candidates: Vec<T>,
+    tolerance: T,
+}
+
+impl<T: Float + Debug> Matcher<T> for CloseToAnyMatcher<T> {
+    fn test(&self, value: &T) -> MatcherResult {
+        let closest_distance = self
+            .candidates
+            .iter()
+            .map(|candidate| (*value - *candidate).abs())
+            .fold(None, |closest: Option<T>, distance| {
+                Some(closest.map_or(distance, |closest| closest.min(distance)))
+            });
+
+        let reason = match closest_distance {
+            Some(distance) => format!("the closest distance was {:?}", distance),
+            None => String::from("there were no candidates"),
+        };
+        MatcherResult::formatted(
+            closest_distance.is_some_and(|distance| distance <= self.tolerance),
+            format!(
+                "{:?} should be within {:?} of any of {:?} but {}",
+                value, self.tolerance, self.candidates, reason
+            ),
+            format!(
+                "{:?} should not be within {:?} of any of {:?} but {}",
+                value, self.tolerance, self.candidates, reason
+            ),
+        )
+    }
+}
+
+/// Creates a CloseToAnyMatcher that asserts whether a floating value is within the tolerance of any of the candidate values.
+pub fn be_close_to_any<T: Float>(candidates: Vec<T>, tolerance: T) -> CloseToAnyMatcher<T> {
+    CloseToAnyMatcher { candidates, tolerance }
+}
+
 #[cfg(all(test, feature = "num"))]
 mod tests {
     use crate::assertions::bool::TrueFalseAssertion;
-    use crate::matchers::float::{be_nan, be_negative, be_positive, be_zero};
+    use crate::matchers::float::{be_close_to_any, be_finite, be_infinite, be_integer_valued, be_nan, be_negative, be_negative_zero, be_non_negative, be_non_positive, be_positive, be_positive_zero, be_zero, have_fractional_part, have_opposite_sign_from, have_same_sign_as, round_to};
     use crate::matchers::Matcher;
     use num::Float;
 
@@ -136,4 +455,180 @@ mod tests {
         let matcher = be_negative();
         matcher.test(&value).passed.should_be_true();
     }
+
+    #[test]
+    fn should_be_non_negative_given_negative_zero() {
+        let value: f64 = -0.0;
+        let matcher = be_non_negative();
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_non_negative_given_nan() {
+        let value: f64 = Float::nan();
+        let matcher = be_non_negative();
+        matcher.test(&value).passed.should_be_false();
+    }
+
+    #[test]
+    fn should_be_non_positive_given_positive_zero() {
+        let value: f64 = 0.0;
+        let matcher = be_non_positive();
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_non_positive_given_nan() {
+        let value: f64 = Float::nan();
+        let matcher = be_non_positive();
+        matcher.test(&value).passed.should_be_false();
+    }
+
+    #[test]
+    fn should_be_positive_zero() {
+        let value: f64 = 0.0;
+        let matcher = be_positive_zero();
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_positive_zero_but_was_negative_zero() {
+        let value: f64 = -0.0;
+        let matcher = be_positive_zero();
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_negative_zero() {
+        let value: f64 = -0.0;
+        let matcher = be_negative_zero();
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_negative_zero_but_was_positive_zero() {
+        let value: f64 = 0.0;
+        let matcher = be_negative_zero();
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_finite() {
+        let value: f64 = 1.5;
+        let matcher = be_finite();
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_finite_but_was_nan() {
+        let value: f64 = f64::NAN;
+        let matcher = be_finite();
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_infinite() {
+        let value: f64 = f64::NEG_INFINITY;
+        let matcher = be_infinite();
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_infinite_but_was_not() {
+        let value: f64 = f64::NAN;
+        let matcher = be_infinite();
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_same_sign_as() {
+        let value: f64 = -0.0;
+        let matcher = have_same_sign_as(0.0);
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_same_sign_as_but_was_nan() {
+        let value: f64 = f64::NAN;
+        let matcher = have_same_sign_as(f64::NAN);
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_opposite_sign_from() {
+        let value: f64 = -1.5;
+        let matcher = have_opposite_sign_from(f64::INFINITY);
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_opposite_sign_from_but_was_zero() {
+        let value: f64 = -0.0;
+        let matcher = have_opposite_sign_from(1.5);
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_integer_valued() {
+        let value: f64 = 4.0;
+        let matcher = be_integer_valued();
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_integer_valued_given_infinity() {
+        let value: f64 = Float::infinity();
+        let matcher = be_integer_valued();
+        matcher.test(&value).passed.should_be_false();
+    }
+
+    #[test]
+    fn should_have_fractional_part() {
+        let value: f64 = 4.5;
+        let matcher = have_fractional_part();
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_have_fractional_part_given_nan() {
+        let value: f64 = Float::nan();
+        let matcher = have_fractional_part();
+        matcher.test(&value).passed.should_be_false();
+    }
+
+    #[test]
+    fn should_round_to() {
+        let value: f64 = 12.34567;
+        let matcher = round_to(12.35, 2);
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_round_to_but_it_did_not() {
+        let value: f64 = 12.34567;
+        let matcher = round_to(12.34, 2);
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_close_to_any() {
+        let value: f64 = 2.004;
+        let matcher = be_close_to_any(vec![1.0, 2.0], 0.01);
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_close_to_any_but_it_was_not() {
+        let value: f64 = 2.5;
+        let matcher = be_close_to_any(vec![1.0, 2.0], 0.01);
+        matcher.test(&value).passed.should_be_true();
+    }
 }
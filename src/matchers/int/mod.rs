@@ -1,6 +1,7 @@
-use std::fmt::Debug;
+use std::fmt::{Binary, Debug};
+use std::ops::RangeInclusive;
 
-use num::Integer;
+use num::{Integer, PrimInt};
 
 use crate::matchers::{Matcher, MatcherResult};
 
@@ -19,9 +20,12 @@ use crate::matchers::{Matcher, MatcherResult};
 pub enum IntMatcher {
     Positive,
     Negative,
+    NonNegative,
+    NonPositive,
     Even,
     Odd,
     Zero,
+    Prime,
 }
 
 impl<T: Integer + Debug + PartialEq + Default> Matcher<T> for IntMatcher {
@@ -37,6 +41,16 @@ impl<T: Integer + Debug + PartialEq + Default> Matcher<T> for IntMatcher {
                 format!("{:?} should be negative", value),
                 format!("{:?} should not be negative", value),
             ),
+            IntMatcher::NonNegative => MatcherResult::formatted(
+                *value >= T::default(),
+                format!("{:?} should be non-negative (zero or positive)", value),
+                format!("{:?} should not be non-negative (zero or positive)", value),
+            ),
+            IntMatcher::NonPositive => MatcherResult::formatted(
+                *value <= T::default(),
+                format!("{:?} should be non-positive (zero or negative)", value),
+                format!("{:?} should not be non-positive (zero or negative)", value),
+            ),
             IntMatcher::Even => MatcherResult::formatted(
                 value.is_even(),
                 format!("{:?} should be even", value),
@@ -52,8 +66,33 @@ impl<T: Integer + Debug + PartialEq + Default> Matcher<T> for IntMatcher {
                 format!("{:?} should be zero", value),
                 format!("{:?} should not be zero", value),
             ),
+            IntMatcher::Prime => MatcherResult::formatted(
+                is_prime(value),
+                format!("{:?} should be prime", value),
+                format!("{:?} should not be prime but it is a prime", value),
+            ),
+        }
+    }
+}
+
+/// Checks primality using trial division by 2 and the odd numbers up to the square root of the value.
+/// Zero, one and negative values are not prime.
+fn is_prime<T: Integer>(value: &T) -> bool {
+    let two = || T::one() + T::one();
+    if *value < two() {
+        return false;
+    }
+    if value.is_multiple_of(&two()) {
+        return *value == two();
+    }
+    let mut divisor = two() + T::one();
+    while divisor <= value.div_floor(&divisor) {
+        if value.is_multiple_of(&divisor) {
+            return false;
         }
+        divisor = divisor + two();
     }
+    true
 }
 
 /// Creates an IntMatcher that asserts whether an integer value is positive.
@@ -66,6 +105,16 @@ pub fn be_negative() -> IntMatcher {
     IntMatcher::Negative
 }
 
+/// Creates an IntMatcher that asserts whether an integer value is non-negative (zero or positive).
+pub fn be_non_negative() -> IntMatcher {
+    IntMatcher::NonNegative
+}
+
+/// Creates an IntMatcher that asserts whether an integer value is non-positive (zero or negative).
+pub fn be_non_positive() -> IntMatcher {
+    IntMatcher::NonPositive
+}
+
 /// Creates an IntMatcher that asserts whether an integer value is even.
 pub fn be_even() -> IntMatcher {
     IntMatcher::Even
@@ -81,10 +130,334 @@ pub fn be_zero() -> IntMatcher {
     IntMatcher::Zero
 }
 
+/// Creates an IntMatcher that asserts whether an integer value is prime.
+pub fn be_prime() -> IntMatcher {
+    IntMatcher::Prime
+}
+
+/// StepMatcher offers a flexible way to assert whether an integer value is aligned to a step,
+/// either as a multiple of the step or as a value reachable from the start of a range in steps.
+///
+/// A zero step is rejected with a panic when the matcher is created.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::int::be_in_range_with_step;
+/// use clearcheck::matchers::Matcher;
+///
+/// let value = 35;
+/// let matcher = be_in_range_with_step(0..=100, 5);
+///
+/// assert!(matcher.test(&value).passed());
+/// ```
+pub enum StepMatcher<T: Integer> {
+    MultipleOf(T),
+    InRangeWithStep(RangeInclusive<T>, T),
+}
+
+impl<T: Integer + Debug> Matcher<T> for StepMatcher<T> {
+    fn test(&self, value: &T) -> MatcherResult {
+        match self {
+            StepMatcher::MultipleOf(step) => MatcherResult::formatted(
+                floor_remainder(value, step) == T::zero(),
+                format!("{:?} should be a multiple of {:?}", value, step),
+                format!("{:?} should not be a multiple of {:?}", value, step),
+            ),
+            StepMatcher::InRangeWithStep(range, step) => {
+                let in_range = range.contains(value);
+                let aligned = floor_remainder(value, step) == floor_remainder(range.start(), step);
+                let reason = if !in_range {
+                    "it was outside the range"
+                } else {
+                    "it was not aligned to the step"
+                };
+                MatcherResult::formatted(
+                    in_range && aligned,
+                    format!(
+                        "{:?} should be in the range {:?} with step {:?} but {}",
+                        value, range, step, reason
+                    ),
+                    format!(
+                        "{:?} should not be in the range {:?} with step {:?}",
+                        value, range, step
+                    ),
+                )
+            }
+        }
+    }
+}
+
+// Every integer is a multiple of -1, and mod_floor would overflow for T::MIN with a step of -1.
+fn floor_remainder<T: Integer>(value: &T, step: &T) -> T {
+    if *step < T::zero() && *step == T::zero() - T::one() {
+        return T::zero();
+    }
+    value.mod_floor(step)
+}
+
+/// Creates a StepMatcher that asserts whether an integer value is a multiple of the given step.
+///
+/// Panics if the step is zero.
+pub fn be_multiple_of<T: Integer>(step: T) -> StepMatcher<T> {
+    assert!(step != T::zero(), "step should not be zero");
+    StepMatcher::MultipleOf(step)
+}
+
+/// Creates a StepMatcher that asserts whether an integer value is within the given inclusive range
+/// and is reachable from the start of the range in increments of the given step.
+///
+/// Panics if the step is zero.
+pub fn be_in_range_with_step<T: Integer>(range: RangeInclusive<T>, step: T) -> StepMatcher<T> {
+    assert!(step != T::zero(), "step should not be zero");
+    StepMatcher::InRangeWithStep(range, step)
+}
+
+/// BitMatcher offers a flexible way to assert whether specific bits of a primitive integer value are set or cleared.
+///
+/// The failure messages print the values in binary.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::int::have_bits_set;
+/// use clearcheck::matchers::Matcher;
+///
+/// let flags = 0b1011;
+/// let matcher = have_bits_set(0b0011);
+///
+/// assert!(matcher.test(&flags).passed());
+/// ```
+pub enum BitMatcher<T: PrimInt> {
+    BitSet(u32),
+    BitsSet(T),
+    NoBitsSet(T),
+}
+
+impl<T: PrimInt + Binary> Matcher<T> for BitMatcher<T> {
+    fn test(&self, value: &T) -> MatcherResult {
+        match self {
+            BitMatcher::BitSet(index) => MatcherResult::formatted(
+                *value & (T::one() << *index as usize) != T::zero(),
+                format!("{:#b} should have bit {} set", value, index),
+                format!("{:#b} should not have bit {} set", value, index),
+            ),
+            BitMatcher::BitsSet(mask) => MatcherResult::formatted(
+                *value & *mask == *mask,
+                format!(
+                    "{:#b} should have all the bits of the mask {:#b} set but {:#b} were not set",
+                    value, mask, *mask & !*value
+                ),
+                format!(
+                    "{:#b} should not have all the bits of the mask {:#b} set",
+                    value, mask
+                ),
+            ),
+            BitMatcher::NoBitsSet(mask) => MatcherResult::formatted(
+                *value & *mask == T::zero(),
+                format!(
+                    "{:#b} should have none of the bits of the mask {:#b} set but {:#b} were set",
+                    value, mask, *value & *mask
+                ),
+                format!(
+                    "{:#b} should have some of the bits of the mask {:#b} set",
+                    value, mask
+                ),
+            ),
+        }
+    }
+}
+
+/// Creates a BitMatcher that asserts whether the bit at the given index (0 being the least significant bit) is set.
+///
+/// Panics if the index is not within the bit width of the integer type.
+pub fn have_bit_set<T: PrimInt>(index: u32) -> BitMatcher<T> {
+    let bit_width = T::zero().count_zeros();
+    assert!(
+        index < bit_width,
+        "bit index {} is out of range for a {}-bit integer",
+        index,
+        bit_width
+    );
+    BitMatcher::BitSet(index)
+}
+
+/// Creates a BitMatcher that asserts whether all the bits of the given mask are set.
+pub fn have_bits_set<T: PrimInt>(mask: T) -> BitMatcher<T> {
+    BitMatcher::BitsSet(mask)
+}
+
+/// Creates a BitMatcher that asserts whether none of the bits of the given mask are set.
+pub fn have_no_bits_set<T: PrimInt>(mask: T) -> BitMatcher<T> {
+    BitMatcher::NoBitsSet(mask)
+}
+
+/// SignMatcher offers a flexible way to assert whether an integer value has the same sign as, or the opposite sign from,
+/// another value.
+///
+/// Zero has its own sign: it has the same sign only as zero, and the opposite sign from no value.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::int::have_same_sign_as;
+/// use clearcheck::matchers::Matcher;
+///
+/// let value = 10;
+/// let matcher = have_same_sign_as(3);
+///
+/// assert!(matcher.test(&value).passed());
+/// ```
+pub enum SignMatcher<T> {
+    Same(T),
+    Opposite(T),
+}
+
+impl<T: Integer + Debug> Matcher<T> for SignMatcher<T> {
+    fn test(&self, value: &T) -> MatcherResult {
+        match self {
+            SignMatcher::Same(other) => {
+                let (sign, other_sign) = (sign_of(value), sign_of(other));
+                MatcherResult::formatted(
+                    sign == other_sign,
+                    format!(
+                        "{:?} should have the same sign as {:?} but {:?} is {} and {:?} is {}",
+                        value, other, value, describe_sign(sign), other, describe_sign(other_sign)
+                    ),
+                    format!(
+                        "{:?} should not have the same sign as {:?} but both are {}",
+                        value, other, describe_sign(sign)
+                    ),
+                )
+            }
+            SignMatcher::Opposite(other) => {
+                let (sign, other_sign) = (sign_of(value), sign_of(other));
+                MatcherResult::formatted(
+                    matches!(
+                        (sign, other_sign),
+                        (Sign::Positive, Sign::Negative) | (Sign::Negative, Sign::Positive)
+                    ),
+                    format!(
+                        "{:?} should have the opposite sign from {:?} but {:?} is {} and {:?} is {}",
+                        value, other, value, describe_sign(sign), other, describe_sign(other_sign)
+                    ),
+                    format!(
+                        "{:?} should not have the opposite sign from {:?} but {:?} is {} and {:?} is {}",
+                        value, other, value, describe_sign(sign), other, describe_sign(other_sign)
+                    ),
+                )
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum Sign {
+    Positive,
+    Negative,
+    Zero,
+}
+
+fn sign_of<T: Integer>(value: &T) -> Sign {
+    if *value > T::zero() {
+        Sign::Positive
+    } else if *value < T::zero() {
+        Sign::Negative
+    } else {
+        Sign::Zero
+    }
+}
+
+fn describe_sign(sign: Sign) -> &'static str {
+    match sign {
+        Sign::Positive => "positive",
+        Sign::Negative => "negative",
+        Sign::Zero => "zero",
+    }
+}
+
+/// Creates a SignMatcher that asserts whether an integer value has the same sign as the given value.
+pub fn have_same_sign_as<T>(other: T) -> SignMatcher<T> {
+    SignMatcher::Same(other)
+}
+
+/// Creates a SignMatcher that asserts whether an integer value has the opposite sign from the given value.
+pub fn have_opposite_sign_from<T>(other: T) -> SignMatcher<T> {
+    SignMatcher::Opposite(other)
+}
+
+/// CloseToMatcher offers a flexible way to assert whether a primitive integer value is within an absolute delta of
+/// the expected value.
+///
+/// The absolute difference is computed without overflowing, even for values near the minimum and the maximum of the
+/// integer type.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::int::be_close_to;
+/// use clearcheck::matchers::Matcher;
+///
+/// let value = 102;
+/// let matcher = be_close_to(100, 5);
+///
+/// assert!(matcher.test(&value).passed());
+/// ```
+pub struct CloseToMatcher<T: PrimInt> {
+    expected: T,
+    delta: T,
+}
+
+impl<T: PrimInt + Debug> Matcher<T> for CloseToMatcher<T> {
+    fn test(&self, value: &T) -> MatcherResult {
+        let difference = absolute_difference(*value, self.expected);
+        MatcherResult::formatted(
+            difference.is_ok_and(|difference| difference <= self.delta),
+            format!(
+                "{:?} should be close to {:?} within a delta of {:?} but the difference was {}",
+                value, self.expected, self.delta, describe_difference(difference)
+            ),
+            format!(
+                "{:?} should not be close to {:?} within a delta of {:?} but the difference was {}",
+                value, self.expected, self.delta, describe_difference(difference)
+            ),
+        )
+    }
+}
+
+/// Returns the absolute difference if it fits in T, otherwise the absolute difference widened to u128.
+/// The difference only overflows T for signed types, all of which fit in i128.
+fn absolute_difference<T: PrimInt>(value: T, other: T) -> Result<T, u128> {
+    let (larger, smaller) = if value >= other { (value, other) } else { (other, value) };
+    larger.checked_sub(&smaller).ok_or_else(|| {
+        larger
+            .to_i128()
+            .zip(smaller.to_i128())
+            .map(|(larger, smaller)| larger.abs_diff(smaller))
+            .unwrap_or(u128::MAX)
+    })
+}
+
+fn describe_difference<T: Debug>(difference: Result<T, u128>) -> String {
+    match difference {
+        Ok(difference) => format!("{:?}", difference),
+        Err(difference) => difference.to_string(),
+    }
+}
+
+/// Creates a CloseToMatcher that asserts whether a primitive integer value is within the given absolute delta of the
+/// expected value.
+///
+/// Panics if the delta is negative.
+pub fn be_close_to<T: PrimInt>(expected: T, delta: T) -> CloseToMatcher<T> {
+    assert!(delta >= T::zero(), "delta should not be negative");
+    CloseToMatcher { expected, delta }
+}
+
 #[cfg(all(test, feature = "num"))]
 mod tests {
     use crate::assertions::bool::TrueFalseAssertion;
-    use crate::matchers::int::{be_even, be_negative, be_odd, be_positive, be_zero};
+    use crate::matchers::int::{
+        be_close_to, be_even, be_in_range_with_step, be_multiple_of, be_negative, be_non_negative,
+        be_non_positive, be_odd, be_positive, be_prime, be_zero, have_bit_set, have_bits_set, have_no_bits_set,
+        have_opposite_sign_from, have_same_sign_as,
+    };
     use crate::matchers::Matcher;
 
     #[test]
@@ -117,6 +490,36 @@ mod tests {
         matcher.test(&value).passed.should_be_true();
     }
 
+    #[test]
+    fn should_be_non_negative() {
+        let value = 0;
+        let matcher = be_non_negative();
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_non_negative_but_was_not() {
+        let value = -1;
+        let matcher = be_non_negative();
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_non_positive() {
+        let value = 0;
+        let matcher = be_non_positive();
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_non_positive_but_was_not() {
+        let value = 1;
+        let matcher = be_non_positive();
+        matcher.test(&value).passed.should_be_true();
+    }
+
     #[test]
     fn should_be_even() {
         let value = -10;
@@ -161,4 +564,171 @@ mod tests {
         let matcher = be_zero();
         matcher.test(&value).passed.should_be_true();
     }
+
+    #[test]
+    fn should_be_multiple_of() {
+        let value = -15;
+        let matcher = be_multiple_of(5);
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_multiple_of_but_was_not() {
+        let value = 16;
+        let matcher = be_multiple_of(5);
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic(expected = "step should not be zero")]
+    fn should_not_create_a_multiple_of_matcher_with_zero_step() {
+        be_multiple_of(0);
+    }
+
+    #[test]
+    fn should_be_multiple_of_minus_one_given_the_minimum_value() {
+        let matcher = be_multiple_of(-1);
+        matcher.test(&i32::MIN).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_in_range_with_minus_one_step_given_the_minimum_value() {
+        let matcher = be_in_range_with_step(i64::MIN..=0, -1);
+        matcher.test(&i64::MIN).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_in_range_with_step() {
+        let value = 13;
+        let matcher = be_in_range_with_step(3..=23, 5);
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_in_range_with_step_but_was_outside_the_range() {
+        let value = 28;
+        let matcher = be_in_range_with_step(3..=23, 5);
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_prime() {
+        let value = 97;
+        let matcher = be_prime();
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_prime_but_was_not() {
+        let value = 91;
+        let matcher = be_prime();
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_bit_set() {
+        let value: u8 = 0b0100;
+        let matcher = have_bit_set(2);
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_bit_set_but_it_was_cleared() {
+        let value: u8 = 0b0100;
+        let matcher = have_bit_set(1);
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic(expected = "bit index 8 is out of range for a 8-bit integer")]
+    fn should_not_create_a_bit_matcher_with_an_index_beyond_the_bit_width() {
+        let _ = have_bit_set::<u8>(8);
+    }
+
+    #[test]
+    fn should_have_bits_set() {
+        let value = 0b1011;
+        let matcher = have_bits_set(0b0011);
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_bits_set_but_they_were_not() {
+        let value = 0b1011;
+        let matcher = have_bits_set(0b0111);
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_no_bits_set() {
+        let value = 0b1011;
+        let matcher = have_no_bits_set(0b0100);
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_no_bits_set_but_they_were() {
+        let value = 0b1011;
+        let matcher = have_no_bits_set(0b0110);
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_same_sign_as() {
+        let value = -10;
+        let matcher = have_same_sign_as(-3);
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_same_sign_as_but_did_not() {
+        let value = -10;
+        let matcher = have_same_sign_as(0);
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_opposite_sign_from() {
+        let value = -10;
+        let matcher = have_opposite_sign_from(3);
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_opposite_sign_from_but_did_not() {
+        let value = 0;
+        let matcher = have_opposite_sign_from(3);
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_close_to() {
+        let value = 98;
+        let matcher = be_close_to(100, 2);
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_close_to_but_was_not() {
+        let value = 97;
+        let matcher = be_close_to(100, 2);
+        matcher.test(&value).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_close_to_but_the_difference_overflowed() {
+        let value = i32::MIN;
+        let matcher = be_close_to(i32::MAX, i32::MAX);
+        matcher.test(&value).passed.should_be_true();
+    }
 }
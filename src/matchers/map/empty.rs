@@ -1,9 +1,9 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::hash::Hash;
 
 use crate::matchers::{Matcher, MatcherResult};
 
-/// MapEmptyMatcher offers a flexible way to assert whether a HashMap is empty.
+/// MapEmptyMatcher offers a flexible way to assert whether a HashMap (or a BTreeMap) is empty.
 ///
 /// # Example
 ///```
@@ -21,16 +21,16 @@ pub enum MapEmptyMatcher {
     NotEmpty,
 }
 
-impl<K: Hash + Eq, V> Matcher<HashMap<K, V>> for MapEmptyMatcher {
-    fn test(&self, collection: &HashMap<K, V>) -> MatcherResult {
+impl MapEmptyMatcher {
+    fn test_emptiness(&self, empty: bool) -> MatcherResult {
         match self {
             MapEmptyMatcher::Empty => MatcherResult::new(
-                collection.is_empty(),
+                empty,
                 "Map should be empty",
                 "Map should not be empty",
             ),
             MapEmptyMatcher::NotEmpty => MatcherResult::new(
-                !collection.is_empty(),
+                !empty,
                 "Map should not be empty",
                 "Map should be empty",
             ),
@@ -38,6 +38,18 @@ impl<K: Hash + Eq, V> Matcher<HashMap<K, V>> for MapEmptyMatcher {
     }
 }
 
+impl<K: Hash + Eq, V> Matcher<HashMap<K, V>> for MapEmptyMatcher {
+    fn test(&self, collection: &HashMap<K, V>) -> MatcherResult {
+        self.test_emptiness(collection.is_empty())
+    }
+}
+
+impl<K: Ord, V> Matcher<BTreeMap<K, V>> for MapEmptyMatcher {
+    fn test(&self, collection: &BTreeMap<K, V>) -> MatcherResult {
+        self.test_emptiness(collection.is_empty())
+    }
+}
+
 /// Creates a MapEmptyMatcher that asserts whether a HashMap is empty.
 pub fn be_empty() -> MapEmptyMatcher {
     MapEmptyMatcher::Empty
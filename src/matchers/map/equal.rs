@@ -0,0 +1,186 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::matchers::{Matcher, MatcherResult};
+
+/// MapEqualityMatcher offers a flexible way to assert that a HashMap (or a BTreeMap) has exactly the same entries as the other map.
+///
+/// On failure, the message lists the missing keys, the extra keys and the keys with different values separately.
+///
+/// # Example
+///```
+/// use std::collections::HashMap;
+/// use clearcheck::matchers::map::equal::equal_map;
+/// use clearcheck::matchers::Matcher;
+///
+/// let mut key_value = HashMap::new();
+/// key_value.insert("rust", "clearcheck");
+///
+/// let mut other = HashMap::new();
+/// other.insert("rust", "clearcheck");
+///
+/// let matcher = equal_map(&other);
+/// assert!(matcher.test(&key_value).passed());
+/// ```
+pub struct MapEqualityMatcher<'a, M> {
+    other: &'a M,
+}
+
+impl<K, V> Matcher<HashMap<K, V>> for MapEqualityMatcher<'_, HashMap<K, V>>
+    where
+        K: Hash + Eq + Debug,
+        V: PartialEq + Debug,
+{
+    fn test(&self, collection: &HashMap<K, V>) -> MatcherResult {
+        let difference = MapDifference::between(
+            collection.iter(),
+            |key| collection.get(key),
+            self.other.iter(),
+            |key| self.other.get(key),
+        );
+        difference.into_result(collection, self.other)
+    }
+}
+
+impl<K, V> Matcher<BTreeMap<K, V>> for MapEqualityMatcher<'_, BTreeMap<K, V>>
+    where
+        K: Ord + Debug,
+        V: PartialEq + Debug,
+{
+    fn test(&self, collection: &BTreeMap<K, V>) -> MatcherResult {
+        let difference = MapDifference::between(
+            collection.iter(),
+            |key| collection.get(key),
+            self.other.iter(),
+            |key| self.other.get(key),
+        );
+        difference.into_result(collection, self.other)
+    }
+}
+
+struct MapDifference<'a, K> {
+    missing: Vec<&'a K>,
+    extra: Vec<&'a K>,
+    different_values: Vec<&'a K>,
+}
+
+impl<'a, K: Debug> MapDifference<'a, K> {
+    fn between<V: PartialEq + 'a>(
+        actual: impl Iterator<Item=(&'a K, &'a V)>,
+        actual_lookup: impl Fn(&K) -> Option<&'a V>,
+        expected: impl Iterator<Item=(&'a K, &'a V)>,
+        expected_lookup: impl Fn(&K) -> Option<&'a V>,
+    ) -> Self {
+        let mut extra = Vec::new();
+        let mut different_values = Vec::new();
+        for (key, value) in actual {
+            match expected_lookup(key) {
+                None => extra.push(key),
+                Some(expected_value) if expected_value != value => different_values.push(key),
+                Some(_) => {}
+            }
+        }
+        let missing = expected
+            .filter(|(key, _)| actual_lookup(key).is_none())
+            .map(|(key, _)| key)
+            .collect();
+
+        MapDifference {
+            missing,
+            extra,
+            different_values,
+        }
+    }
+
+    fn into_result<M: Debug>(self, collection: &M, other: &M) -> MatcherResult {
+        let mut reasons = Vec::new();
+        if !self.missing.is_empty() {
+            reasons.push(format!("missing keys {:?}", self.missing));
+        }
+        if !self.extra.is_empty() {
+            reasons.push(format!("extra keys {:?}", self.extra));
+        }
+        if !self.different_values.is_empty() {
+            reasons.push(format!("different values for keys {:?}", self.different_values));
+        }
+
+        MatcherResult::formatted(
+            reasons.is_empty(),
+            format!(
+                "{:?} should equal {:?} but had {}",
+                collection,
+                other,
+                reasons.join(", ")
+            ),
+            format!("{:?} should not equal {:?}", collection, other),
+        )
+    }
+}
+
+/// Creates a MapEqualityMatcher that asserts whether a map has exactly the same entries as the other map.
+pub fn equal_map<M>(other: &M) -> MapEqualityMatcher<'_, M> {
+    MapEqualityMatcher { other }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashMap};
+
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::matchers::map::equal::equal_map;
+    use crate::matchers::Matcher;
+
+    #[test]
+    fn should_equal_map() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "clearcheck");
+        key_value.insert("java", "junit");
+
+        let mut other = HashMap::new();
+        other.insert("java", "junit");
+        other.insert("rust", "clearcheck");
+
+        let matcher = equal_map(&other);
+        matcher.test(&key_value).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_equal_map_but_a_value_was_different() {
+        let mut key_value = HashMap::new();
+        key_value.insert("rust", "clearcheck");
+
+        let mut other = HashMap::new();
+        other.insert("rust", "assert");
+
+        let matcher = equal_map(&other);
+        matcher.test(&key_value).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_equal_btree_map() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert("rust", "clearcheck");
+
+        let mut other = BTreeMap::new();
+        other.insert("rust", "clearcheck");
+
+        let matcher = equal_map(&other);
+        matcher.test(&key_value).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_equal_btree_map_but_a_key_was_missing() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert("rust", "clearcheck");
+
+        let mut other = BTreeMap::new();
+        other.insert("rust", "clearcheck");
+        other.insert("java", "junit");
+
+        let matcher = equal_map(&other);
+        matcher.test(&key_value).passed.should_be_true();
+    }
+}
@@ -1,9 +1,9 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::hash::Hash;
 
 use crate::matchers::{Matcher, MatcherResult};
 
-/// MapLengthMatcher offers a flexible way to assert various length properties of HashMap.
+/// MapLengthMatcher offers a flexible way to assert various length properties of HashMap (or BTreeMap).
 ///
 /// # Example
 ///```
@@ -31,6 +31,12 @@ impl<K: Hash + Eq, V> Matcher<HashMap<K, V>> for MapLengthMatcher {
     }
 }
 
+impl<K: Ord, V> Matcher<BTreeMap<K, V>> for MapLengthMatcher {
+    fn test(&self, collection: &BTreeMap<K, V>) -> MatcherResult {
+        self.test_length(collection.len())
+    }
+}
+
 impl MapLengthMatcher {
     fn test_length(&self, input_length: usize) -> MatcherResult {
         let message_prefix = "Map";
@@ -158,3 +164,32 @@ mod tests {
         matcher.test(&key_value).passed.should_be_true();
     }
 }
+
+#[cfg(test)]
+mod btree_tests {
+    use std::collections::BTreeMap;
+
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::matchers::map::length::{have_atleast_same_length, have_same_length};
+    use crate::matchers::Matcher;
+
+    #[test]
+    fn should_have_same_length() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert(1, 10);
+        key_value.insert(2, 20);
+
+        let matcher = have_same_length(2);
+        matcher.test(&key_value).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_atleast_same_length_but_was_not() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert(1, 10);
+
+        let matcher = have_atleast_same_length(2);
+        matcher.test(&key_value).passed.should_be_true();
+    }
+}
@@ -1,10 +1,10 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Debug;
 use std::hash::Hash;
 
 use crate::matchers::{Matcher, MatcherResult};
 
-/// KeyMembershipMatcher offers a flexible way to assert the presence or absence of specific keys within a HashMap.
+/// KeyMembershipMatcher offers a flexible way to assert the presence or absence of specific keys within a HashMap (or a BTreeMap).
 ///
 /// Works with any data type that implements the Eq and Debug trait.
 ///
@@ -29,7 +29,7 @@ pub enum KeyMembershipMatcher<T: Eq> {
     AnyOfKeys(Vec<T>),
 }
 
-/// ValueMembershipMatcher offers a flexible way to assert the presence or absence of specific values within a HashMap.
+/// ValueMembershipMatcher offers a flexible way to assert the presence or absence of specific values within a HashMap (or a BTreeMap).
 ///
 /// Works with any data type that implements the Eq and Debug trait.
 ///
@@ -78,109 +78,132 @@ pub enum KeyValueMembershipMatcher<K: Hash + Eq, V: Eq> {
     AnyOfKeyValues(HashMap<K, V>),
 }
 
-impl<K, V> Matcher<HashMap<K, V>> for KeyMembershipMatcher<K>
+impl<K> KeyMembershipMatcher<K>
     where
-        K: Hash + Eq + Debug,
+        K: Eq + Debug,
 {
-    fn test(&self, collection: &HashMap<K, V>) -> MatcherResult {
+    fn test_keys<I, F>(&self, keys: I, contains_key: F) -> MatcherResult
+        where
+            I: Debug,
+            F: Fn(&K) -> bool,
+    {
         match self {
             KeyMembershipMatcher::Key(key) => MatcherResult::formatted(
-                collection.contains_key(key),
+                contains_key(key),
                 format!(
                     "Keys {:?} in the map should contain {:?}",
-                    collection.keys(),
+                    keys,
                     key
                 ),
                 format!(
                     "Keys {:?} in the map should not contain {:?}",
-                    collection.keys(),
+                    keys,
                     key
                 ),
             ),
-            KeyMembershipMatcher::AllKeys(keys) => {
-                let missing = keys
+            KeyMembershipMatcher::AllKeys(expected) => {
+                let missing = expected
                     .iter()
-                    .filter(|key| !collection.contains_key(key))
+                    .filter(|key| !contains_key(key))
                     .collect::<Vec<_>>();
 
                 MatcherResult::formatted(
                     missing.is_empty(),
                     format!(
                         "Keys {:?} in the map should contain all {:?} but was missing {:?}",
-                        collection.keys(),
                         keys,
+                        expected,
                         missing
                     ),
                     format!(
                         "Keys {:?} in the map should not contain {:?}",
-                        collection.keys(),
-                        keys
+                        keys,
+                        expected
                     ),
                 )
             }
-            KeyMembershipMatcher::AnyOfKeys(keys) => MatcherResult::formatted(
-                keys.iter().any(|key| collection.contains_key(key)),
+            KeyMembershipMatcher::AnyOfKeys(expected) => MatcherResult::formatted(
+                expected.iter().any(&contains_key),
                 format!(
                     "Keys {:?} in the map should contain any of the keys {:?}",
-                    collection.keys(),
-                    keys
+                    keys,
+                    expected
                 ),
                 format!(
                     "Keys {:?} in the map should not contain any of the keys {:?}",
-                    collection.keys(),
-                    keys
+                    keys,
+                    expected
                 ),
             ),
         }
     }
 }
 
-impl<V> ValueMembershipMatcher<V>
+impl<K, V> Matcher<HashMap<K, V>> for KeyMembershipMatcher<K>
     where
-        V: Eq + Debug,
+        K: Hash + Eq + Debug,
 {
-    fn contains_value<K: Hash + Eq>(collection: &HashMap<K, V>, value: &V) -> bool {
-        collection.values().any(|source| source == value)
+    fn test(&self, collection: &HashMap<K, V>) -> MatcherResult {
+        self.test_keys(collection.keys(), |key| collection.contains_key(key))
     }
 }
 
-impl<K, V> Matcher<HashMap<K, V>> for ValueMembershipMatcher<V>
+impl<K, V> Matcher<BTreeMap<K, V>> for KeyMembershipMatcher<K>
+    where
+        K: Ord + Debug,
+{
+    fn test(&self, collection: &BTreeMap<K, V>) -> MatcherResult {
+        self.test_keys(collection.keys(), |key| collection.contains_key(key))
+    }
+}
+
+impl<V> ValueMembershipMatcher<V>
     where
-        K: Hash + Eq,
         V: Eq + Debug,
 {
-    fn test(&self, collection: &HashMap<K, V>) -> MatcherResult {
+    fn contains_value<'a>(mut values: impl Iterator<Item = &'a V>, value: &V) -> bool
+        where
+            V: 'a,
+    {
+        values.any(|source| source == value)
+    }
+
+    fn test_values<'a, I>(&self, source: I) -> MatcherResult
+        where
+            I: Iterator<Item = &'a V> + Clone + Debug,
+            V: 'a,
+    {
         match self {
             ValueMembershipMatcher::Value(value) => MatcherResult::formatted(
-                Self::contains_value(collection, value),
+                Self::contains_value(source.clone(), value),
                 format!(
                     "Values {:?} in the map should contain {:?}",
-                    collection.values(),
+                    source,
                     value
                 ),
                 format!(
                     "Values {:?} in the map should not contain {:?}",
-                    collection.values(),
+                    source,
                     value
                 ),
             ),
             ValueMembershipMatcher::AllValues(values) => {
                 let missing = values
                     .iter()
-                    .filter(|value| !Self::contains_value(collection, value))
+                    .filter(|value| !Self::contains_value(source.clone(), value))
                     .collect::<Vec<_>>();
 
                 MatcherResult::formatted(
                     missing.is_empty(),
                     format!(
                         "Values {:?} in the map should contain all {:?} but was missing {:?}",
-                        collection.values(),
+                        source,
                         values,
                         missing
                     ),
                     format!(
                         "Values {:?} in the map should not contain {:?}",
-                        collection.values(),
+                        source,
                         values
                     ),
                 )
@@ -188,15 +211,15 @@ impl<K, V> Matcher<HashMap<K, V>> for ValueMembershipMatcher<V>
             ValueMembershipMatcher::AnyOfValues(values) => MatcherResult::formatted(
                 values
                     .iter()
-                    .any(|value| Self::contains_value(collection, value)),
+                    .any(|value| Self::contains_value(source.clone(), value)),
                 format!(
                     "Values {:?} in the map should contain any of the values {:?}",
-                    collection.values(),
+                    source,
                     values
                 ),
                 format!(
                     "Values {:?} in the map should not contain any of the values {:?}",
-                    collection.values(),
+                    source,
                     values
                 ),
             ),
@@ -204,6 +227,26 @@ impl<K, V> Matcher<HashMap<K, V>> for ValueMembershipMatcher<V>
     }
 }
 
+impl<K, V> Matcher<HashMap<K, V>> for ValueMembershipMatcher<V>
+    where
+        K: Hash + Eq,
+        V: Eq + Debug,
+{
+    fn test(&self, collection: &HashMap<K, V>) -> MatcherResult {
+        self.test_values(collection.values())
+    }
+}
+
+impl<K, V> Matcher<BTreeMap<K, V>> for ValueMembershipMatcher<V>
+    where
+        K: Ord,
+        V: Eq + Debug,
+{
+    fn test(&self, collection: &BTreeMap<K, V>) -> MatcherResult {
+        self.test_values(collection.values())
+    }
+}
+
 impl<K, V> KeyValueMembershipMatcher<K, V>
     where
         K: Hash + Eq + Debug,
@@ -223,75 +266,172 @@ impl<K, V> Matcher<HashMap<K, V>> for KeyValueMembershipMatcher<K, V>
         V: Eq + Debug,
 {
     fn test(&self, collection: &HashMap<K, V>) -> MatcherResult {
-        return match self {
-            KeyValueMembershipMatcher::KeyValue(key, value) => MatcherResult::formatted(
+        match self {
+            KeyValueMembershipMatcher::KeyValue(key, value) => key_value_result(
                 Self::contains_key_value(collection, key, value),
-                format!(
-                    "Map {:?} should contain key {:?} and value {:?}",
-                    collection, key, value
-                ),
-                format!(
-                    "Map {:?} should not contain key {:?} and value {:?}",
-                    collection, key, value
-                ),
+                collection,
+                key,
+                value,
             ),
-            KeyValueMembershipMatcher::AllKeyValues(key_values) => {
-                let missing = key_values
+            KeyValueMembershipMatcher::AllKeyValues(key_values) => all_key_values_result(
+                collection,
+                key_values,
+                key_values
                     .iter()
                     .filter(|key_value| {
                         !Self::contains_key_value(collection, key_value.0, key_value.1)
                     })
-                    .collect::<Vec<_>>();
+                    .collect::<Vec<_>>(),
+            ),
+            KeyValueMembershipMatcher::AnyOfKeyValues(key_values) => any_of_key_values_result(
+                key_values.iter().any(|key_value| {
+                    Self::contains_key_value(collection, key_value.0, key_value.1)
+                }),
+                collection,
+                key_values,
+            ),
+        }
+    }
+}
 
-                MatcherResult::formatted(
-                    missing.is_empty(),
-                    format!(
-                        "Map {:?} should contain all of key/value pairs {:?} but was missing {:?}",
-                        collection, key_values, missing
-                    ),
-                    format!(
-                        "Map {:?} should not contain all of key/value pairs {:?}",
-                        collection, key_values
-                    ),
-                )
-            }
-            KeyValueMembershipMatcher::AnyOfKeyValues(key_values) => MatcherResult::formatted(
+/// OrderedKeyValueMembershipMatcher offers a flexible way to assert the presence or absence of specific key/value pair(s) within a BTreeMap.
+///
+/// Unlike KeyValueMembershipMatcher, the keys only need to implement Ord, not Hash.
+///
+/// # Example
+///```
+/// use std::collections::BTreeMap;
+/// use clearcheck::matchers::map::membership::contain_all_ordered_key_values;
+/// use clearcheck::matchers::Matcher;
+///
+/// let mut collection = BTreeMap::new();
+/// collection.insert("rust", "clearcheck");
+///
+/// let mut should_contain = BTreeMap::new();
+/// should_contain.insert("rust", "clearcheck");
+///
+/// let matcher = contain_all_ordered_key_values(should_contain);
+///
+/// assert!(matcher.test(&collection).passed());
+/// ```
+pub enum OrderedKeyValueMembershipMatcher<K: Ord, V: Eq> {
+    KeyValue(K, V),
+    AllKeyValues(BTreeMap<K, V>),
+    AnyOfKeyValues(BTreeMap<K, V>),
+}
+
+impl<K, V> OrderedKeyValueMembershipMatcher<K, V>
+    where
+        K: Ord + Debug,
+        V: Eq + Debug,
+{
+    fn contains_key_value(collection: &BTreeMap<K, V>, key: &K, value: &V) -> bool {
+        collection
+            .get(key)
+            .filter(|source_value| *source_value == value)
+            .is_some()
+    }
+}
+
+impl<K, V> Matcher<BTreeMap<K, V>> for OrderedKeyValueMembershipMatcher<K, V>
+    where
+        K: Ord + Debug,
+        V: Eq + Debug,
+{
+    fn test(&self, collection: &BTreeMap<K, V>) -> MatcherResult {
+        match self {
+            OrderedKeyValueMembershipMatcher::KeyValue(key, value) => key_value_result(
+                Self::contains_key_value(collection, key, value),
+                collection,
+                key,
+                value,
+            ),
+            OrderedKeyValueMembershipMatcher::AllKeyValues(key_values) => all_key_values_result(
+                collection,
+                key_values,
+                key_values
+                    .iter()
+                    .filter(|key_value| {
+                        !Self::contains_key_value(collection, key_value.0, key_value.1)
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+            OrderedKeyValueMembershipMatcher::AnyOfKeyValues(key_values) => any_of_key_values_result(
                 key_values.iter().any(|key_value| {
                     Self::contains_key_value(collection, key_value.0, key_value.1)
                 }),
-                format!(
-                    "Map {:?} should contain any of key/value pairs {:?}",
-                    collection, key_values
-                ),
-                format!(
-                    "Map {:?} should not contain any of key/value pairs {:?}",
-                    collection, key_values
-                ),
+                collection,
+                key_values,
             ),
-        };
+        }
     }
 }
 
-/// Creates a KeyMembershipMatcher that asserts whether a HashMap contains the given key.
+fn key_value_result<M: Debug, K: Debug, V: Debug>(contains: bool, collection: &M, key: &K, value: &V) -> MatcherResult {
+    MatcherResult::formatted(
+        contains,
+        format!(
+            "Map {:?} should contain key {:?} and value {:?}",
+            collection, key, value
+        ),
+        format!(
+            "Map {:?} should not contain key {:?} and value {:?}",
+            collection, key, value
+        ),
+    )
+}
+
+fn all_key_values_result<M: Debug, E: Debug, P: Debug>(collection: &M, key_values: &E, missing: Vec<P>) -> MatcherResult {
+    MatcherResult::formatted(
+        missing.is_empty(),
+        format!(
+            "Map {:?} should contain all of key/value pairs {:?} but was missing {:?}",
+            collection, key_values, missing
+        ),
+        format!(
+            "Map {:?} should not contain all of key/value pairs {:?}",
+            collection, key_values
+        ),
+    )
+}
+
+fn any_of_key_values_result<M: Debug, E: Debug>(contains: bool, collection: &M, key_values: &E) -> MatcherResult {
+    MatcherResult::formatted(
+        contains,
+        format!(
+            "Map {:?} should contain any of key/value pairs {:?}",
+            collection, key_values
+        ),
+        format!(
+            "Map {:?} should not contain any of key/value pairs {:?}",
+            collection, key_values
+        ),
+    )
+}
+
+/// Creates a KeyMembershipMatcher that asserts whether a HashMap (or a BTreeMap) contains the given key.
 pub fn contain_key<Q>(key: Q) -> KeyMembershipMatcher<Q>
     where
-        Q: Hash + Eq + Debug,
+        Q: Eq + Debug,
 {
     KeyMembershipMatcher::Key(key)
 }
 
-/// Creates a KeyMembershipMatcher that asserts whether a HashMap contains all the given keys.
+/// Creates a KeyMembershipMatcher that asserts whether a HashMap (or a BTreeMap) contains all the given keys.
 pub fn contain_all_keys<Q>(keys: Vec<Q>) -> KeyMembershipMatcher<Q>
     where
-        Q: Hash + Eq + Debug,
+        Q: Eq + Debug,
 {
     KeyMembershipMatcher::AllKeys(keys)
 }
 
-/// Creates a KeyMembershipMatcher that asserts whether a HashMap contains any of the given keys.
+/// Creates a KeyMembershipMatcher that asserts whether a HashMap (or a BTreeMap) contains any of the given keys.
+///
+/// The matcher fails if the given keys are empty, as no key can match; its inversion (`should_not`) therefore passes
+/// trivially for empty keys.
 pub fn contain_any_of_keys<Q>(keys: Vec<Q>) -> KeyMembershipMatcher<Q>
     where
-        Q: Hash + Eq + Debug,
+        Q: Eq + Debug,
 {
     KeyMembershipMatcher::AnyOfKeys(keys)
 }
@@ -349,6 +489,35 @@ pub fn contain_any_of_key_values<K, V>(
     KeyValueMembershipMatcher::AnyOfKeyValues(key_values)
 }
 
+/// Creates an OrderedKeyValueMembershipMatcher that asserts whether a BTreeMap contains the given key/value pair.
+pub fn contain_ordered_key_value<K, V>(key: K, value: V) -> OrderedKeyValueMembershipMatcher<K, V>
+    where
+        K: Ord + Debug,
+        V: Eq + Debug,
+{
+    OrderedKeyValueMembershipMatcher::KeyValue(key, value)
+}
+
+/// Creates an OrderedKeyValueMembershipMatcher that asserts whether a BTreeMap contains all the given key/value pairs.
+pub fn contain_all_ordered_key_values<K, V>(key_values: BTreeMap<K, V>) -> OrderedKeyValueMembershipMatcher<K, V>
+    where
+        K: Ord + Debug,
+        V: Eq + Debug,
+{
+    OrderedKeyValueMembershipMatcher::AllKeyValues(key_values)
+}
+
+/// Creates an OrderedKeyValueMembershipMatcher that asserts whether a BTreeMap contains any of the given key/value pairs.
+pub fn contain_any_of_ordered_key_values<K, V>(
+    key_values: BTreeMap<K, V>,
+) -> OrderedKeyValueMembershipMatcher<K, V>
+    where
+        K: Ord + Debug,
+        V: Eq + Debug,
+{
+    OrderedKeyValueMembershipMatcher::AnyOfKeyValues(key_values)
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
@@ -425,6 +594,15 @@ mod tests {
         matcher.test(&collection).passed.should_be_true();
     }
 
+    #[test]
+    fn should_not_contain_any_of_keys_given_the_keys_were_empty() {
+        let mut collection = HashMap::new();
+        collection.insert("rust", "assert");
+
+        let matcher = contain_any_of_keys(Vec::<&str>::new());
+        matcher.test(&collection).passed.should_be_false();
+    }
+
     #[test]
     fn should_contain_a_value() {
         let mut collection = HashMap::new();
@@ -558,3 +736,109 @@ mod tests {
         matcher.test(&collection).passed.should_be_true();
     }
 }
+
+#[cfg(test)]
+mod ordered_tests {
+    use std::collections::BTreeMap;
+
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::matchers::map::membership::{
+        contain_all_ordered_key_values, contain_any_of_ordered_key_values, contain_key, contain_ordered_key_value,
+    };
+    use crate::matchers::Matcher;
+
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct Id(u32);
+
+    #[test]
+    fn should_contain_a_key_which_is_not_hashable() {
+        let mut collection = BTreeMap::new();
+        collection.insert(Id(1), "rust");
+
+        let matcher = contain_key(Id(1));
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_a_key_which_is_not_hashable_but_it_did_not() {
+        let mut collection = BTreeMap::new();
+        collection.insert(Id(1), "rust");
+
+        let matcher = contain_key(Id(2));
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_contain_a_key_value() {
+        let mut collection = BTreeMap::new();
+        collection.insert(Id(1), "rust");
+
+        let matcher = contain_ordered_key_value(Id(1), "rust");
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_a_key_value_but_it_did_not() {
+        let mut collection = BTreeMap::new();
+        collection.insert(Id(1), "rust");
+
+        let matcher = contain_ordered_key_value(Id(1), "java");
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_contain_all_key_values() {
+        let mut collection = BTreeMap::new();
+        collection.insert(Id(1), "rust");
+        collection.insert(Id(2), "java");
+
+        let mut should_contain = BTreeMap::new();
+        should_contain.insert(Id(1), "rust");
+        should_contain.insert(Id(2), "java");
+
+        let matcher = contain_all_ordered_key_values(should_contain);
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_all_key_values_but_it_did_not() {
+        let mut collection = BTreeMap::new();
+        collection.insert(Id(1), "rust");
+
+        let mut should_contain = BTreeMap::new();
+        should_contain.insert(Id(1), "rust");
+        should_contain.insert(Id(2), "java");
+
+        let matcher = contain_all_ordered_key_values(should_contain);
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_contain_any_of_key_values() {
+        let mut collection = BTreeMap::new();
+        collection.insert(Id(1), "rust");
+
+        let mut should_contain = BTreeMap::new();
+        should_contain.insert(Id(1), "rust");
+        should_contain.insert(Id(2), "java");
+
+        let matcher = contain_any_of_ordered_key_values(should_contain);
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_any_of_key_values_but_it_did_not() {
+        let mut collection = BTreeMap::new();
+        collection.insert(Id(1), "rust");
+
+        let mut should_contain = BTreeMap::new();
+        should_contain.insert(Id(1), "java");
+
+        let matcher = contain_any_of_ordered_key_values(should_contain);
+        matcher.test(&collection).passed.should_be_true();
+    }
+}
@@ -1,3 +1,6 @@
 pub mod empty;
+pub mod equal;
 pub mod length;
 pub mod membership;
+pub mod order;
+pub mod predicate;
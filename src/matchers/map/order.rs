@@ -0,0 +1,84 @@
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+
+use crate::matchers::{Matcher, MatcherResult};
+
+/// KeyOrderMatcher offers a flexible way to assert that the keys of a BTreeMap iterate in the given sequence.
+///
+/// # Example
+///```
+/// use std::collections::BTreeMap;
+/// use clearcheck::matchers::map::order::have_keys_in_order;
+/// use clearcheck::matchers::Matcher;
+///
+/// let mut key_value = BTreeMap::new();
+/// key_value.insert("java", 8);
+/// key_value.insert("rust", 10);
+///
+/// let matcher = have_keys_in_order(vec!["java", "rust"]);
+/// assert!(matcher.test(&key_value).passed());
+/// ```
+pub struct KeyOrderMatcher<T: Eq> {
+    keys: Vec<T>,
+}
+
+impl<K, V> Matcher<BTreeMap<K, V>> for KeyOrderMatcher<K>
+    where
+        K: Ord + Debug,
+{
+    fn test(&self, collection: &BTreeMap<K, V>) -> MatcherResult {
+        let actual = collection.keys().collect::<Vec<_>>();
+        let expected = self.keys.iter().collect::<Vec<_>>();
+
+        MatcherResult::formatted(
+            actual == expected,
+            format!(
+                "Keys in the map should be in the order {:?} but were in the order {:?}",
+                expected, actual
+            ),
+            format!(
+                "Keys in the map should not be in the order {:?}",
+                expected
+            ),
+        )
+    }
+}
+
+/// Creates a KeyOrderMatcher that asserts whether the keys of a BTreeMap iterate in the given sequence.
+pub fn have_keys_in_order<Q>(keys: Vec<Q>) -> KeyOrderMatcher<Q>
+    where
+        Q: Ord + Debug,
+{
+    KeyOrderMatcher { keys }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::matchers::map::order::have_keys_in_order;
+    use crate::matchers::Matcher;
+
+    #[test]
+    fn should_have_keys_in_order() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert(3, "three");
+        key_value.insert(1, "one");
+        key_value.insert(2, "two");
+
+        let matcher = have_keys_in_order(vec![1, 2, 3]);
+        matcher.test(&key_value).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_keys_in_order_but_it_did_not() {
+        let mut key_value = BTreeMap::new();
+        key_value.insert(3, "three");
+        key_value.insert(1, "one");
+
+        let matcher = have_keys_in_order(vec![3, 1]);
+        matcher.test(&key_value).passed.should_be_true();
+    }
+}
@@ -0,0 +1,414 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+use crate::matchers::{Matcher, MatcherResult};
+
+/// KeyPredicateMatcher offers a flexible way to assert whether all the keys in a HashMap satisfy the given predicate.
+///
+/// # Example
+///```
+/// use std::collections::HashMap;
+/// use clearcheck::matchers::map::predicate::satisfy_for_all_keys;
+/// use clearcheck::matchers::Matcher;
+///
+/// let mut scores = HashMap::new();
+/// scores.insert("rust", 10);
+/// scores.insert("java", 8);
+///
+/// let matcher = satisfy_for_all_keys(|key: &&str| key.len() == 4);
+/// assert!(matcher.test(&scores).passed());
+/// ```
+pub struct KeyPredicateMatcher<F, K>
+    where F: Fn(&K) -> bool
+{
+    predicate: F,
+    _key: PhantomData<K>,
+}
+
+/// ValuePredicateMatcher offers a flexible way to assert whether all the values in a HashMap satisfy the given predicate.
+///
+/// # Example
+///```
+/// use std::collections::HashMap;
+/// use clearcheck::matchers::map::predicate::satisfy_for_all_values;
+/// use clearcheck::matchers::Matcher;
+///
+/// let mut scores = HashMap::new();
+/// scores.insert("rust", 10);
+/// scores.insert("java", 8);
+///
+/// let matcher = satisfy_for_all_values(|score: &i32| *score >= 0);
+/// assert!(matcher.test(&scores).passed());
+/// ```
+pub struct ValuePredicateMatcher<F, V>
+    where F: Fn(&V) -> bool
+{
+    predicate: F,
+    _value: PhantomData<V>,
+}
+
+/// AnyValuePredicateMatcher offers a flexible way to assert whether any value in a HashMap (or a BTreeMap) satisfies the given predicate, regardless of its key.
+///
+/// # Example
+///```
+/// use std::collections::HashMap;
+/// use clearcheck::matchers::map::predicate::satisfy_for_any_value;
+/// use clearcheck::matchers::Matcher;
+///
+/// let mut scores = HashMap::new();
+/// scores.insert("rust", 10);
+/// scores.insert("java", 8);
+///
+/// let matcher = satisfy_for_any_value(|score: &i32| *score > 9);
+/// assert!(matcher.test(&scores).passed());
+/// ```
+pub struct AnyValuePredicateMatcher<F, V>
+    where F: Fn(&V) -> bool
+{
+    predicate: F,
+    _value: PhantomData<V>,
+}
+
+/// EntryPredicateMatcher offers a flexible way to assert whether any entry, or all the entries, in a HashMap (or a BTreeMap)
+/// satisfy the given predicate over the key and the value.
+///
+/// # Example
+///```
+/// use std::collections::HashMap;
+/// use clearcheck::matchers::map::predicate::satisfy_for_any_entry;
+/// use clearcheck::matchers::Matcher;
+///
+/// let mut scores = HashMap::new();
+/// scores.insert("rust", 10);
+/// scores.insert("java", 8);
+///
+/// let matcher = satisfy_for_any_entry(|key: &&str, score: &i32| key.starts_with('r') && *score > 9);
+/// assert!(matcher.test(&scores).passed());
+/// ```
+pub struct EntryPredicateMatcher<F, K, V>
+    where F: Fn(&K, &V) -> bool
+{
+    predicate: F,
+    all: bool,
+    _entry: PhantomData<(K, V)>,
+}
+
+impl<F, K, V> Matcher<HashMap<K, V>> for KeyPredicateMatcher<F, K>
+    where
+        F: Fn(&K) -> bool,
+        K: Hash + Eq + Debug,
+        V: Debug,
+{
+    fn test(&self, collection: &HashMap<K, V>) -> MatcherResult {
+        let offending = collection.iter().find(|(key, _)| !(self.predicate)(key));
+        MatcherResult::formatted(
+            offending.is_none(),
+            format!(
+                "All the keys in the map {:?} should satisfy the given predicate but {} did not",
+                collection, describe(offending)
+            ),
+            format!(
+                "All the keys in the map {:?} should not satisfy the given predicate",
+                collection
+            ),
+        )
+    }
+}
+
+impl<F, K, V> Matcher<HashMap<K, V>> for ValuePredicateMatcher<F, V>
+    where
+        F: Fn(&V) -> bool,
+        K: Hash + Eq + Debug,
+        V: Debug,
+{
+    fn test(&self, collection: &HashMap<K, V>) -> MatcherResult {
+        let offending = collection.iter().find(|(_, value)| !(self.predicate)(value));
+        MatcherResult::formatted(
+            offending.is_none(),
+            format!(
+                "All the values in the map {:?} should satisfy the given predicate but {} did not",
+                collection, describe(offending)
+            ),
+            format!(
+                "All the values in the map {:?} should not satisfy the given predicate",
+                collection
+            ),
+        )
+    }
+}
+
+impl<F, K, V> Matcher<HashMap<K, V>> for AnyValuePredicateMatcher<F, V>
+    where
+        F: Fn(&V) -> bool,
+        K: Hash + Eq + Debug,
+        V: Debug,
+{
+    fn test(&self, collection: &HashMap<K, V>) -> MatcherResult {
+        self.test_entries(collection, collection.iter())
+    }
+}
+
+impl<F, K, V> Matcher<BTreeMap<K, V>> for AnyValuePredicateMatcher<F, V>
+    where
+        F: Fn(&V) -> bool,
+        K: Ord + Debug,
+        V: Debug,
+{
+    fn test(&self, collection: &BTreeMap<K, V>) -> MatcherResult {
+        self.test_entries(collection, collection.iter())
+    }
+}
+
+impl<F, V> AnyValuePredicateMatcher<F, V>
+    where
+        F: Fn(&V) -> bool,
+        V: Debug,
+{
+    fn test_entries<'a, M, K>(&self, collection: &M, mut entries: impl Iterator<Item = (&'a K, &'a V)>) -> MatcherResult
+        where
+            M: Debug,
+            K: Debug + 'a,
+            V: 'a,
+    {
+        let matching = entries.find(|(_, value)| (self.predicate)(value));
+        MatcherResult::formatted(
+            matching.is_some(),
+            format!(
+                "The map {:?} should contain a value satisfying the given predicate but no value did",
+                collection
+            ),
+            format!(
+                "The map {:?} should not contain a value satisfying the given predicate but {} did",
+                collection, describe(matching)
+            ),
+        )
+    }
+}
+
+impl<F, K, V> Matcher<HashMap<K, V>> for EntryPredicateMatcher<F, K, V>
+    where
+        F: Fn(&K, &V) -> bool,
+        K: Hash + Eq + Debug,
+        V: Debug,
+{
+    fn test(&self, collection: &HashMap<K, V>) -> MatcherResult {
+        self.test_entries(collection, collection.iter())
+    }
+}
+
+impl<F, K, V> Matcher<BTreeMap<K, V>> for EntryPredicateMatcher<F, K, V>
+    where
+        F: Fn(&K, &V) -> bool,
+        K: Ord + Debug,
+        V: Debug,
+{
+    fn test(&self, collection: &BTreeMap<K, V>) -> MatcherResult {
+        self.test_entries(collection, collection.iter())
+    }
+}
+
+impl<F, K, V> EntryPredicateMatcher<F, K, V>
+    where
+        F: Fn(&K, &V) -> bool,
+        K: Debug,
+        V: Debug,
+{
+    fn test_entries<'a, M>(&self, collection: &M, mut entries: impl Iterator<Item = (&'a K, &'a V)>) -> MatcherResult
+        where
+            M: Debug,
+            K: 'a,
+            V: 'a,
+    {
+        if self.all {
+            let offending = entries.find(|(key, value)| !(self.predicate)(key, value));
+            return MatcherResult::formatted(
+                offending.is_none(),
+                format!(
+                    "All the entries in the map {:?} should satisfy the given predicate but {} did not",
+                    collection, describe(offending)
+                ),
+                format!(
+                    "All the entries in the map {:?} should not satisfy the given predicate",
+                    collection
+                ),
+            );
+        }
+        let matching = entries.find(|(key, value)| (self.predicate)(key, value));
+        MatcherResult::formatted(
+            matching.is_some(),
+            format!(
+                "The map {:?} should contain an entry satisfying the given predicate but no entry did",
+                collection
+            ),
+            format!(
+                "The map {:?} should not contain an entry satisfying the given predicate but {} did",
+                collection, describe(matching)
+            ),
+        )
+    }
+}
+
+fn describe<K: Debug, V: Debug>(entry: Option<(&K, &V)>) -> String {
+    entry
+        .map(|(key, value)| format!("the entry with key {:?} and value {:?}", key, value))
+        .unwrap_or_default()
+}
+
+/// Creates a KeyPredicateMatcher that asserts whether all the keys in a HashMap satisfy the given predicate.
+pub fn satisfy_for_all_keys<F, K>(predicate: F) -> KeyPredicateMatcher<F, K>
+    where
+        F: Fn(&K) -> bool,
+{
+    KeyPredicateMatcher { predicate, _key: PhantomData }
+}
+
+/// Creates a ValuePredicateMatcher that asserts whether all the values in a HashMap satisfy the given predicate.
+pub fn satisfy_for_all_values<F, V>(predicate: F) -> ValuePredicateMatcher<F, V>
+    where
+        F: Fn(&V) -> bool,
+{
+    ValuePredicateMatcher { predicate, _value: PhantomData }
+}
+
+/// Creates an AnyValuePredicateMatcher that asserts whether any value in a HashMap satisfies the given predicate.
+pub fn satisfy_for_any_value<F, V>(predicate: F) -> AnyValuePredicateMatcher<F, V>
+    where
+        F: Fn(&V) -> bool,
+{
+    AnyValuePredicateMatcher { predicate, _value: PhantomData }
+}
+
+/// Creates an EntryPredicateMatcher that asserts whether any entry in a HashMap (or a BTreeMap) satisfies the given predicate.
+pub fn satisfy_for_any_entry<F, K, V>(predicate: F) -> EntryPredicateMatcher<F, K, V>
+    where
+        F: Fn(&K, &V) -> bool,
+{
+    EntryPredicateMatcher { predicate, all: false, _entry: PhantomData }
+}
+
+/// Creates an EntryPredicateMatcher that asserts whether all the entries in a HashMap (or a BTreeMap) satisfy the given predicate.
+pub fn satisfy_for_all_entries<F, K, V>(predicate: F) -> EntryPredicateMatcher<F, K, V>
+    where
+        F: Fn(&K, &V) -> bool,
+{
+    EntryPredicateMatcher { predicate, all: true, _entry: PhantomData }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::matchers::Matcher;
+    use crate::matchers::map::predicate::{
+        satisfy_for_all_entries, satisfy_for_all_keys, satisfy_for_all_values,
+        satisfy_for_any_entry, satisfy_for_any_value,
+    };
+
+    #[test]
+    fn should_satisfy_for_all_keys() {
+        let mut scores = HashMap::new();
+        scores.insert("rust", 10);
+        scores.insert("java", 8);
+
+        let matcher = satisfy_for_all_keys(|key: &&str| key.len() == 4);
+        matcher.test(&scores).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_satisfy_for_all_keys_but_it_did_not() {
+        let mut scores = HashMap::new();
+        scores.insert("rust", 10);
+        scores.insert("golang", 8);
+
+        let matcher = satisfy_for_all_keys(|key: &&str| key.len() == 4);
+        matcher.test(&scores).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_satisfy_for_all_values() {
+        let mut scores = HashMap::new();
+        scores.insert("rust", 10);
+        scores.insert("java", 8);
+
+        let matcher = satisfy_for_all_values(|score: &i32| *score >= 0);
+        matcher.test(&scores).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_satisfy_for_all_values_but_it_did_not() {
+        let mut scores = HashMap::new();
+        scores.insert("rust", 10);
+        scores.insert("java", -8);
+
+        let matcher = satisfy_for_all_values(|score: &i32| *score >= 0);
+        matcher.test(&scores).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_satisfy_for_any_value() {
+        let mut scores = HashMap::new();
+        scores.insert("rust", 10);
+        scores.insert("java", -8);
+
+        let matcher = satisfy_for_any_value(|score: &i32| *score < 0);
+        matcher.test(&scores).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_satisfy_for_any_value_but_it_did_not() {
+        let mut scores = HashMap::new();
+        scores.insert("rust", 10);
+        scores.insert("java", 8);
+
+        let matcher = satisfy_for_any_value(|score: &i32| *score < 0);
+        matcher.test(&scores).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_satisfy_for_any_entry() {
+        let mut scores = HashMap::new();
+        scores.insert("rust", 10);
+        scores.insert("java", 8);
+
+        let matcher = satisfy_for_any_entry(|key: &&str, score: &i32| key.starts_with('j') && *score > 5);
+        matcher.test(&scores).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_satisfy_for_any_entry_but_it_did_not() {
+        let mut scores = HashMap::new();
+        scores.insert("rust", 10);
+        scores.insert("java", 8);
+
+        let matcher = satisfy_for_any_entry(|key: &&str, score: &i32| key.starts_with('j') && *score > 9);
+        matcher.test(&scores).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_satisfy_for_all_entries() {
+        let mut scores = HashMap::new();
+        scores.insert("rust", 10);
+        scores.insert("java", 8);
+
+        let matcher = satisfy_for_all_entries(|key: &&str, score: &i32| key.len() == 4 && *score > 5);
+        matcher.test(&scores).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_satisfy_for_all_entries_but_it_did_not() {
+        let mut scores = HashMap::new();
+        scores.insert("rust", 10);
+        scores.insert("java", 2);
+
+        let matcher = satisfy_for_all_entries(|key: &&str, score: &i32| key.len() == 4 && *score > 5);
+        matcher.test(&scores).passed.should_be_true();
+    }
+}
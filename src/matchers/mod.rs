@@ -15,6 +15,7 @@
 //! ```
 
 pub mod bool;
+pub mod bytes;
 pub mod char;
 pub mod collection;
 pub mod compose;
@@ -34,6 +35,10 @@ pub mod range;
 pub mod result;
 pub mod string;
 
+use std::cell::RefCell;
+#[cfg(feature = "stats")]
+use std::sync::atomic::{AtomicUsize, Ordering};
+
 /// Should provides a convenient way to express positive assertions within tests, indicating that a value should meet a certain condition.
 pub trait Should<T> {
     /// - Takes a matcher as input and performs an assertion against the value itself.
@@ -52,9 +57,7 @@ pub trait ShouldNot<T> {
 impl<T> Should<T> for T {
     fn should(&self, matcher: &dyn Matcher<T>) {
         let matcher_result = matcher.test(self);
-        if !matcher_result.passed {
-            panic!("assertion failed: {}", matcher_result.failure_message);
-        }
+        conclude(matcher_result.passed, &matcher_result.failure_message);
     }
 }
 
@@ -62,12 +65,100 @@ impl<T> ShouldNot<T> for T {
     fn should_not(&self, matcher: &dyn Matcher<T>) {
         let matcher_result = matcher.test(self);
         let passed = !matcher_result.passed;
-        if !passed {
-            panic!(
-                "assertion failed: {}",
-                matcher_result.inverted_failure_message
-            );
+        conclude(passed, &matcher_result.inverted_failure_message);
+    }
+}
+
+thread_local! {
+    static COLLECTOR: RefCell<Option<Vec<CollectedResult>>> = const { RefCell::new(None) };
+}
+
+/// CollectedResult is the outcome of an assertion recorded by the collector installed with [install_collector].
+///
+/// The message is the failure message of the assertion, it is recorded for the passed assertions as well so that a
+/// test harness can report what was asserted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CollectedResult {
+    pub passed: bool,
+    pub message: String,
+}
+
+/// Installs a collector on the current thread.
+///
+/// While a collector is installed, the assertions made on the current thread record their results in the collector
+/// instead of panicking. The results can be drained with [drain_results], for example to serialize them into a CI
+/// report. Installing a collector again discards the results recorded so far.
+///
+/// # Example
+/// ```
+/// use clearcheck::assertions::string::membership::MembershipAssertion;
+/// use clearcheck::matchers::{drain_results, install_collector};
+///
+/// install_collector();
+/// "clearcheck".should_contain("assert");
+///
+/// let results = drain_results();
+/// assert!(!results[0].passed);
+/// assert!(results[0].message.contains("should contain"));
+/// ```
+pub fn install_collector() {
+    COLLECTOR.with(|collector| *collector.borrow_mut() = Some(Vec::new()));
+}
+
+/// Returns the results recorded by the collector installed on the current thread, in the order the assertions were made,
+/// and uninstalls the collector, so the assertions panic on failure again.
+///
+/// Returns an empty vector if no collector is installed.
+pub fn drain_results() -> Vec<CollectedResult> {
+    COLLECTOR.with(|collector| collector.borrow_mut().take().unwrap_or_default())
+}
+
+#[cfg(feature = "stats")]
+static ASSERTION_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// Returns the number of assertions made across all the threads since the start of the process or since the last call
+/// to [reset_assertion_count].
+///
+/// Every call to `should` or `should_not` is counted, irrespective of whether the assertion passed, failed or was
+/// recorded by a collector.
+///
+/// # Example
+/// ```
+/// use clearcheck::assertions::equal::EqualityAssertion;
+/// use clearcheck::matchers::{assertion_count, reset_assertion_count};
+///
+/// reset_assertion_count();
+/// 10.should_equal(&10).should_not_equal(&20);
+///
+/// assert_eq!(2, assertion_count());
+/// ```
+#[cfg(feature = "stats")]
+pub fn assertion_count() -> usize {
+    ASSERTION_COUNT.load(Ordering::Relaxed)
+}
+
+/// Resets the number of assertions returned by [assertion_count] to zero.
+#[cfg(feature = "stats")]
+pub fn reset_assertion_count() {
+    ASSERTION_COUNT.store(0, Ordering::Relaxed);
+}
+
+fn conclude(passed: bool, message: &str) {
+    #[cfg(feature = "stats")]
+    ASSERTION_COUNT.fetch_add(1, Ordering::Relaxed);
+
+    let collected = COLLECTOR.with(|collector| match collector.borrow_mut().as_mut() {
+        Some(results) => {
+            results.push(CollectedResult {
+                passed,
+                message: message.to_string(),
+            });
+            true
         }
+        None => false,
+    });
+    if !collected && !passed {
+        panic!("assertion failed: {}", message);
     }
 }
 
@@ -130,3 +221,85 @@ impl MatcherResult {
         self.passed
     }
 }
+
+#[cfg(test)]
+mod collector_tests {
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::assertions::collection::size::SizeAssertion;
+    use crate::assertions::equal::EqualityAssertion;
+    use crate::assertions::string::membership::MembershipAssertion;
+    use crate::matchers::{drain_results, install_collector, CollectedResult};
+
+    #[test]
+    fn should_collect_failed_assertions_instead_of_panicking() {
+        install_collector();
+        "clearcheck".should_contain("assert");
+        vec![1, 2, 3].should_have_size(2);
+
+        let results = drain_results();
+        results.should_have_size(2);
+        results[0].passed.should_be_false();
+        results[0].message.should_contain("should contain");
+        results[1].passed.should_be_false();
+        results[1].message.should_contain("length 3 should be 2");
+    }
+
+    #[test]
+    fn should_collect_passed_assertions() {
+        install_collector();
+        "clearcheck".should_contain("check");
+
+        let results = drain_results();
+        results.should_equal(&vec![CollectedResult {
+            passed: true,
+            message: "\"clearcheck\" should contain the substring \"check\"".to_string(),
+        }]);
+    }
+
+    #[test]
+    fn should_collect_inverted_failure_message_of_negative_assertions() {
+        install_collector();
+        10.should_not_equal(&10);
+
+        let results = drain_results();
+        results[0].passed.should_be_false();
+        results[0].message.should_contain("should not equal");
+    }
+
+    #[test]
+    fn should_collect_results_in_order() {
+        install_collector();
+        10.should_equal(&10);
+        10.should_equal(&11);
+        10.should_not_equal(&11);
+
+        let passed = drain_results().iter().map(|result| result.passed).collect::<Vec<_>>();
+        passed.should_equal(&vec![true, false, true]);
+    }
+
+    #[test]
+    fn should_return_no_results_without_a_collector() {
+        10.should_equal(&10);
+        drain_results().is_empty().should_be_true();
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed")]
+    fn should_panic_after_the_results_are_drained() {
+        install_collector();
+        drain_results();
+        10.should_equal(&11);
+    }
+
+    #[test]
+    fn should_not_collect_assertions_made_on_another_thread() {
+        install_collector();
+        let outcome = std::thread::spawn(|| {
+            10.should_equal(&11);
+        }).join();
+
+        let results = drain_results();
+        outcome.is_err().should_be_true();
+        results.is_empty().should_be_true();
+    }
+}
@@ -74,6 +74,16 @@ pub fn have_length_in_exclusive_range(range: Range<usize>) -> RangeMatcher<usize
     RangeMatcher::HalfOpen("Length", range)
 }
 
+/// Creates a RangeMatcher that asserts whether the line count (of string) falls within the given inclusive range.
+pub fn have_line_count_in_inclusive_range(range: RangeInclusive<usize>) -> RangeMatcher<usize> {
+    RangeMatcher::Closed("Line count", range)
+}
+
+/// Creates a RangeMatcher that asserts whether the line count (of string) falls within the given exclusive range.
+pub fn have_line_count_in_exclusive_range(range: Range<usize>) -> RangeMatcher<usize> {
+    RangeMatcher::HalfOpen("Line count", range)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::assertions::bool::TrueFalseAssertion;
@@ -13,6 +13,9 @@ use crate::matchers::{Matcher, MatcherResult};
 pub enum BoundaryMatcher {
     Begin(&'static str),
     End(&'static str),
+    BeginIgnoringCase(&'static str),
+    EndIgnoringCase(&'static str),
+    BeginAndEnd(&'static str),
 }
 
 impl<T> Matcher<T> for BoundaryMatcher
@@ -30,6 +33,30 @@ impl<T> Matcher<T> for BoundaryMatcher
                 format!("{:?} should end with {:?}", value.as_ref(), suffix),
                 format!("{:?} should not end with {:?}", value.as_ref(), suffix),
             ),
+            BoundaryMatcher::BeginIgnoringCase(prefix) => MatcherResult::formatted(
+                value.as_ref().to_lowercase().starts_with(&prefix.to_lowercase()),
+                format!("{:?} should begin with {:?} ignoring case", value.as_ref(), prefix),
+                format!("{:?} should not begin with {:?} ignoring case", value.as_ref(), prefix),
+            ),
+            BoundaryMatcher::EndIgnoringCase(suffix) => MatcherResult::formatted(
+                value.as_ref().to_lowercase().ends_with(&suffix.to_lowercase()),
+                format!("{:?} should end with {:?} ignoring case", value.as_ref(), suffix),
+                format!("{:?} should not end with {:?} ignoring case", value.as_ref(), suffix),
+            ),
+            BoundaryMatcher::BeginAndEnd(affix) => {
+                let begins = begin_with(affix).test(value).passed;
+                let ends = end_with(affix).test(value).passed;
+                let reason = match (begins, ends) {
+                    (false, false) => "it neither began nor ended with it",
+                    (false, true) => "it did not begin with it",
+                    _ => "it did not end with it",
+                };
+                MatcherResult::formatted(
+                    begins && ends,
+                    format!("{:?} should begin and end with {:?} but {}", value.as_ref(), affix, reason),
+                    format!("{:?} should not begin and end with {:?}", value.as_ref(), affix),
+                )
+            }
         }
     }
 }
@@ -44,11 +71,29 @@ pub fn end_with(suffix: &'static str) -> BoundaryMatcher {
     BoundaryMatcher::End(suffix)
 }
 
+/// Creates a BoundaryMatcher that asserts whether a string value both begins and ends with the given affix.
+/// The beginning and the ending may overlap, so a string equal to the affix passes, while a string shorter than the affix never does.
+pub fn start_and_end_with(affix: &'static str) -> BoundaryMatcher {
+    BoundaryMatcher::BeginAndEnd(affix)
+}
+
+/// Creates a BoundaryMatcher that asserts whether a string value begins with the given prefix, ignoring case differences.
+pub fn begin_with_ignoring_case(prefix: &'static str) -> BoundaryMatcher {
+    BoundaryMatcher::BeginIgnoringCase(prefix)
+}
+
+/// Creates a BoundaryMatcher that asserts whether a string value ends with the given suffix, ignoring case differences.
+pub fn end_with_ignoring_case(suffix: &'static str) -> BoundaryMatcher {
+    BoundaryMatcher::EndIgnoringCase(suffix)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::assertions::bool::TrueFalseAssertion;
     use crate::matchers::Matcher;
-    use crate::matchers::string::boundary::{begin_with, end_with};
+    use crate::matchers::string::boundary::{
+        begin_with, begin_with_ignoring_case, end_with, end_with_ignoring_case, start_and_end_with,
+    };
 
     #[test]
     fn should_begin_with() {
@@ -75,4 +120,43 @@ mod tests {
         let matcher = end_with("go");
         matcher.test(&"select").passed.should_be_true();
     }
+
+    #[test]
+    fn should_begin_with_ignoring_case() {
+        let matcher = begin_with_ignoring_case("http://");
+        matcher.test(&"HTTP://x").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_begin_with_ignoring_case_but_the_prefix_was_longer() {
+        let matcher = begin_with_ignoring_case("http://");
+        matcher.test(&"HTTP").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_end_with_ignoring_case() {
+        let matcher = end_with_ignoring_case(".RS");
+        matcher.test(&"main.rs").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_end_with_ignoring_case_but_did_not() {
+        let matcher = end_with_ignoring_case(".rs");
+        matcher.test(&"main.go").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_start_and_end_with() {
+        let matcher = start_and_end_with("\"");
+        matcher.test(&"\"clearcheck\"").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_start_and_end_with_but_did_not_end_with() {
+        let matcher = start_and_end_with("\"");
+        matcher.test(&"\"clearcheck").passed.should_be_true();
+    }
 }
@@ -34,6 +34,145 @@ where T: AsRef<str> + PartialEq
     }
 }
 
+/// CaseStyleMatcher offers a flexible way to assert that a string follows an identifier casing style.
+///
+/// The rules for each style (only ASCII characters are allowed):
+/// - `snake_case`: lowercase letters, digits and `_`; begins with a lowercase letter.
+/// - `kebab-case`: lowercase letters, digits and `-`; begins with a lowercase letter.
+/// - `SCREAMING_SNAKE_CASE`: uppercase letters, digits and `_`; begins with an uppercase letter.
+/// - `PascalCase`: letters and digits; begins with an uppercase letter.
+/// - `camelCase`: letters and digits; begins with a lowercase letter.
+///
+/// A separator (`_` or `-`) must sit between two non-separator characters: it can neither end the string
+/// nor follow another separator.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::Matcher;
+/// use clearcheck::matchers::string::case::be_snake_case;
+///
+/// let matcher = be_snake_case();
+/// assert!(matcher.test(&"max_retry_count").passed());
+/// ```
+pub enum CaseStyleMatcher {
+    Snake,
+    Kebab,
+    ScreamingSnake,
+    Pascal,
+    Camel,
+}
+
+struct CaseStyleRules {
+    name: &'static str,
+    first: fn(&char) -> bool,
+    first_description: &'static str,
+    allowed: fn(&char) -> bool,
+    allowed_description: &'static str,
+    separator: Option<char>,
+}
+
+impl CaseStyleMatcher {
+    fn rules(&self) -> CaseStyleRules {
+        match self {
+            CaseStyleMatcher::Snake => CaseStyleRules {
+                name: "snake_case",
+                first: char::is_ascii_lowercase,
+                first_description: "a lowercase letter",
+                allowed: |ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || *ch == '_',
+                allowed_description: "lowercase letters, digits and '_'",
+                separator: Some('_'),
+            },
+            CaseStyleMatcher::Kebab => CaseStyleRules {
+                name: "kebab-case",
+                first: char::is_ascii_lowercase,
+                first_description: "a lowercase letter",
+                allowed: |ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || *ch == '-',
+                allowed_description: "lowercase letters, digits and '-'",
+                separator: Some('-'),
+            },
+            CaseStyleMatcher::ScreamingSnake => CaseStyleRules {
+                name: "SCREAMING_SNAKE_CASE",
+                first: char::is_ascii_uppercase,
+                first_description: "an uppercase letter",
+                allowed: |ch| ch.is_ascii_uppercase() || ch.is_ascii_digit() || *ch == '_',
+                allowed_description: "uppercase letters, digits and '_'",
+                separator: Some('_'),
+            },
+            CaseStyleMatcher::Pascal => CaseStyleRules {
+                name: "PascalCase",
+                first: char::is_ascii_uppercase,
+                first_description: "an uppercase letter",
+                allowed: char::is_ascii_alphanumeric,
+                allowed_description: "letters and digits",
+                separator: None,
+            },
+            CaseStyleMatcher::Camel => CaseStyleRules {
+                name: "camelCase",
+                first: char::is_ascii_lowercase,
+                first_description: "a lowercase letter",
+                allowed: char::is_ascii_alphanumeric,
+                allowed_description: "letters and digits",
+                separator: None,
+            },
+        }
+    }
+
+    fn violation(rules: &CaseStyleRules, value: &str) -> Option<String> {
+        let chars = value.chars().collect::<Vec<_>>();
+        let Some(first) = chars.first() else {
+            return Some("it was empty".to_string());
+        };
+        if !(rules.first)(first) {
+            return Some(format!(
+                "the character {:?} at index 0 should be {}",
+                first, rules.first_description
+            ));
+        }
+        for (index, ch) in chars.iter().enumerate() {
+            if !(rules.allowed)(ch) {
+                return Some(format!(
+                    "the character {:?} at index {} is not allowed (allowed: {})",
+                    ch, index, rules.allowed_description
+                ));
+            }
+            if Some(*ch) == rules.separator {
+                if index > 0 && chars[index - 1] == *ch {
+                    return Some(format!(
+                        "the separator {:?} at index {} follows another separator",
+                        ch, index
+                    ));
+                }
+                if index == chars.len() - 1 {
+                    return Some(format!(
+                        "the separator {:?} at index {} ends the string",
+                        ch, index
+                    ));
+                }
+            }
+        }
+        None
+    }
+}
+
+impl<T> Matcher<T> for CaseStyleMatcher
+    where T: AsRef<str>
+{
+    fn test(&self, value: &T) -> MatcherResult {
+        let rules = self.rules();
+        let violation = Self::violation(&rules, value.as_ref());
+        MatcherResult::formatted(
+            violation.is_none(),
+            format!(
+                "{:?} should be in {} but {}",
+                value.as_ref(),
+                rules.name,
+                violation.unwrap_or_default()
+            ),
+            format!("{:?} should not be in {}", value.as_ref(), rules.name),
+        )
+    }
+}
+
 /// Creates a CaseMatcher that asserts whether a string value is composed of lowercase letters.
 pub fn be_lowercase() -> CaseMatcher {
     CaseMatcher::Lower
@@ -44,10 +183,38 @@ pub fn be_uppercase() -> CaseMatcher {
     CaseMatcher::Upper
 }
 
+/// Creates a CaseStyleMatcher that asserts whether a string value is in snake_case.
+pub fn be_snake_case() -> CaseStyleMatcher {
+    CaseStyleMatcher::Snake
+}
+
+/// Creates a CaseStyleMatcher that asserts whether a string value is in kebab-case.
+pub fn be_kebab_case() -> CaseStyleMatcher {
+    CaseStyleMatcher::Kebab
+}
+
+/// Creates a CaseStyleMatcher that asserts whether a string value is in SCREAMING_SNAKE_CASE.
+pub fn be_screaming_snake_case() -> CaseStyleMatcher {
+    CaseStyleMatcher::ScreamingSnake
+}
+
+/// Creates a CaseStyleMatcher that asserts whether a string value is in PascalCase.
+pub fn be_pascal_case() -> CaseStyleMatcher {
+    CaseStyleMatcher::Pascal
+}
+
+/// Creates a CaseStyleMatcher that asserts whether a string value is in camelCase.
+pub fn be_camel_case() -> CaseStyleMatcher {
+    CaseStyleMatcher::Camel
+}
+
 #[cfg(test)]
 mod tests {
     use crate::assertions::bool::TrueFalseAssertion;
-    use crate::matchers::string::case::{be_lowercase, be_uppercase};
+    use crate::matchers::string::case::{
+        be_camel_case, be_kebab_case, be_lowercase, be_pascal_case, be_screaming_snake_case,
+        be_snake_case, be_uppercase,
+    };
     use crate::matchers::Matcher;
 
     #[test]
@@ -75,4 +242,69 @@ mod tests {
         let matcher = be_uppercase();
         matcher.test(&"GoSelect").passed.should_be_true();
     }
+
+    #[test]
+    fn should_be_snake_case() {
+        let matcher = be_snake_case();
+        matcher.test(&"max_retry_count2").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_snake_case_but_had_consecutive_separators() {
+        let matcher = be_snake_case();
+        matcher.test(&"max__retry").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_kebab_case() {
+        let matcher = be_kebab_case();
+        matcher.test(&"max-retry-count").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_kebab_case_but_had_a_trailing_separator() {
+        let matcher = be_kebab_case();
+        matcher.test(&"max-retry-").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_screaming_snake_case() {
+        let matcher = be_screaming_snake_case();
+        matcher.test(&"MAX_RETRY_COUNT").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_screaming_snake_case_but_had_a_lowercase_letter() {
+        let matcher = be_screaming_snake_case();
+        matcher.test(&"MAX_Retry").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_pascal_case() {
+        let matcher = be_pascal_case();
+        matcher.test(&"MaxRetryCount").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_pascal_case_but_began_with_a_lowercase_letter() {
+        let matcher = be_pascal_case();
+        matcher.test(&"maxRetryCount").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_camel_case() {
+        let matcher = be_camel_case();
+        matcher.test(&"maxRetryCount").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_camel_case_but_was_empty() {
+        let matcher = be_camel_case();
+        matcher.test(&"").passed.should_be_true();
+    }
 }
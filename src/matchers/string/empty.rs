@@ -1,6 +1,7 @@
 use crate::matchers::{Matcher, MatcherResult};
 
-/// StringEmptyMatcher offers a flexible way to assert whether a string is empty (no characters).
+/// StringEmptyMatcher offers a flexible way to assert whether a string is empty (no characters)
+/// or blank (empty or containing only whitespace).
 ///
 /// # Example
 ///```
@@ -13,6 +14,8 @@ use crate::matchers::{Matcher, MatcherResult};
 pub enum StringEmptyMatcher {
     Empty,
     NotEmpty,
+    Blank,
+    NotBlank,
 }
 
 impl<T> Matcher<T> for StringEmptyMatcher
@@ -30,6 +33,16 @@ impl<T> Matcher<T> for StringEmptyMatcher
                 "Value should not be empty",
                 "Value should be empty",
             ),
+            StringEmptyMatcher::Blank => MatcherResult::formatted(
+                value.as_ref().trim().is_empty(),
+                format!("{:?} should be blank (empty or only whitespace)", value.as_ref()),
+                format!("{:?} should not be blank (empty or only whitespace)", value.as_ref()),
+            ),
+            StringEmptyMatcher::NotBlank => MatcherResult::formatted(
+                !value.as_ref().trim().is_empty(),
+                format!("{:?} should not be blank (empty or only whitespace)", value.as_ref()),
+                format!("{:?} should be blank (empty or only whitespace)", value.as_ref()),
+            ),
         }
     }
 }
@@ -44,11 +57,21 @@ pub fn not_be_empty() -> StringEmptyMatcher {
     StringEmptyMatcher::NotEmpty
 }
 
+/// Creates a StringEmptyMatcher that asserts whether a string is blank (empty or containing only whitespace).
+pub fn be_blank() -> StringEmptyMatcher {
+    StringEmptyMatcher::Blank
+}
+
+/// Creates a StringEmptyMatcher that asserts whether a string is not blank (contains at least one non-whitespace character).
+pub fn not_be_blank() -> StringEmptyMatcher {
+    StringEmptyMatcher::NotBlank
+}
+
 #[cfg(test)]
 mod string_tests {
     use crate::assertions::bool::TrueFalseAssertion;
     use crate::matchers::Matcher;
-    use crate::matchers::string::empty::{be_empty, not_be_empty};
+    use crate::matchers::string::empty::{be_blank, be_empty, not_be_blank, not_be_empty};
 
     #[test]
     fn should_be_empty() {
@@ -75,4 +98,30 @@ mod string_tests {
         let matcher = not_be_empty();
         matcher.test(&"").passed.should_be_true();
     }
+
+    #[test]
+    fn should_be_blank() {
+        let matcher = be_blank();
+        matcher.test(&" \t\n").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_blank_but_was_not() {
+        let matcher = be_blank();
+        matcher.test(&" goselect ").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_blank() {
+        let matcher = not_be_blank();
+        matcher.test(&" goselect ").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_blank_but_was() {
+        let matcher = not_be_blank();
+        matcher.test(&"   ").passed.should_be_true();
+    }
 }
@@ -1,4 +1,4 @@
-//! provides [IgnoreCaseEqualityMatcher] for &str.
+//! provides [IgnoreCaseEqualityMatcher] for &str, [UnicodeIgnoreCaseEqualityMatcher] and [IgnoreWhitespaceEqualityMatcher].
 
 use crate::matchers::equal::IgnoreCaseEqualityMatcher;
 use crate::matchers::{Matcher, MatcherResult};
@@ -15,11 +15,93 @@ where T: AsRef<str>
     }
 }
 
+/// UnicodeIgnoreCaseEqualityMatcher offers a flexible way to assert the equality between two strings, ignoring Unicode case differences.
+///
+/// Unlike [IgnoreCaseEqualityMatcher], which only folds ASCII letters, both the strings are compared using
+/// [str::to_lowercase], so "ÜBER" equals "über". Lowercasing is not locale-aware: the Turkish dotted capital 'İ'
+/// lowercases to "i\u{307}", so "İ" does not equal "i".
+///
+/// # Example
+///```
+/// use clearcheck::matchers::string::equal::be_equal_ignoring_unicode_case;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = be_equal_ignoring_unicode_case("STRASSE ÜBER");
+/// assert!(matcher.test(&"strasse über").passed());
+/// ```
+pub struct UnicodeIgnoreCaseEqualityMatcher<'a> {
+    pub other: &'a str,
+}
+
+impl<T> Matcher<T> for UnicodeIgnoreCaseEqualityMatcher<'_>
+where T: AsRef<str>
+{
+    fn test(&self, value: &T) -> MatcherResult {
+        MatcherResult::formatted(
+            value.as_ref().to_lowercase() == self.other.to_lowercase(),
+            format!("{:?} should equal {:?} ignoring Unicode case", value.as_ref(), self.other),
+            format!("{:?} should not equal {:?} ignoring Unicode case", value.as_ref(), self.other),
+        )
+    }
+}
+
+/// Creates a UnicodeIgnoreCaseEqualityMatcher that asserts whether a string equals the given string, ignoring Unicode case differences.
+pub fn be_equal_ignoring_unicode_case(other: &str) -> UnicodeIgnoreCaseEqualityMatcher<'_> {
+    UnicodeIgnoreCaseEqualityMatcher { other }
+}
+
+/// IgnoreWhitespaceEqualityMatcher offers a flexible way to assert the equality between two strings, ignoring insignificant whitespace differences.
+///
+/// Both the strings are normalized before comparison: leading and trailing whitespace is trimmed, and every run of
+/// whitespace (as defined by [char::is_whitespace], including newlines and tabs) is collapsed into a single space.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::string::equal::be_equal_ignoring_whitespace;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = be_equal_ignoring_whitespace("fn main() {}");
+/// assert!(matcher.test(&"fn  main()\t{}\n").passed());
+/// ```
+pub struct IgnoreWhitespaceEqualityMatcher<'a> {
+    pub other: &'a str,
+}
+
+impl<T> Matcher<T> for IgnoreWhitespaceEqualityMatcher<'_>
+where T: AsRef<str>
+{
+    fn test(&self, value: &T) -> MatcherResult {
+        let normalized_value = normalize_whitespace(value.as_ref());
+        let normalized_other = normalize_whitespace(self.other);
+        MatcherResult::formatted(
+            normalized_value == normalized_other,
+            format!(
+                "{:?} should equal {:?} ignoring whitespace (normalized: {:?} and {:?})",
+                value.as_ref(), self.other, normalized_value, normalized_other
+            ),
+            format!(
+                "{:?} should not equal {:?} ignoring whitespace (normalized: {:?} and {:?})",
+                value.as_ref(), self.other, normalized_value, normalized_other
+            ),
+        )
+    }
+}
+
+fn normalize_whitespace(value: &str) -> String {
+    value.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Creates an IgnoreWhitespaceEqualityMatcher that asserts whether a string equals the given string, ignoring insignificant whitespace differences.
+pub fn be_equal_ignoring_whitespace(other: &str) -> IgnoreWhitespaceEqualityMatcher<'_> {
+    IgnoreWhitespaceEqualityMatcher { other }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::assertions::bool::TrueFalseAssertion;
     use crate::matchers::equal::be_equal_ignoring_case;
     use crate::matchers::Matcher;
+    use crate::matchers::string::equal::{be_equal_ignoring_unicode_case, be_equal_ignoring_whitespace};
 
     #[test]
     fn should_equal() {
@@ -33,4 +115,35 @@ mod tests {
         let matcher = be_equal_ignoring_case("assert");
         matcher.test(&"assert4J").passed.should_be_true();
     }
+
+    #[test]
+    fn should_not_equal_non_ascii_ignoring_ascii_case() {
+        let matcher = be_equal_ignoring_case("ÜBER");
+        matcher.test(&"über").passed.should_be_false();
+    }
+
+    #[test]
+    fn should_equal_non_ascii_ignoring_unicode_case() {
+        let matcher = be_equal_ignoring_unicode_case("ÜBER");
+        matcher.test(&"über").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_equal_turkish_dotted_capital_i_to_ascii_i_ignoring_unicode_case() {
+        let matcher = be_equal_ignoring_unicode_case("İ");
+        matcher.test(&"i").passed.should_be_false();
+    }
+
+    #[test]
+    fn should_equal_ignoring_whitespace() {
+        let matcher = be_equal_ignoring_whitespace("assert  equal");
+        matcher.test(&"\tassert\tequal\n").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_equal_ignoring_whitespace_but_was_not() {
+        let matcher = be_equal_ignoring_whitespace("assert equal");
+        matcher.test(&"assertequal").passed.should_be_true();
+    }
 }
@@ -0,0 +1,283 @@
+use serde_json::Value;
+
+use crate::matchers::{Matcher, MatcherResult};
+
+/// JsonMatcher offers a flexible way to assert whether two JSON strings are structurally equivalent.
+///
+/// Both the strings are parsed into [serde_json::Value] trees before comparison, so the order of object keys and
+/// the insignificant whitespace do not matter. If either side is not a valid JSON, the matcher fails and the failure
+/// message carries the parse error.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::string::json::be_json_equivalent;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = be_json_equivalent(r#"{"name": "clearcheck", "stars": 10}"#);
+/// assert!(matcher.test(&r#"{"stars":10,"name":"clearcheck"}"#).passed());
+/// ```
+pub struct JsonMatcher {
+    other: String,
+}
+
+impl<T> Matcher<T> for JsonMatcher
+    where T: AsRef<str>
+{
+    fn test(&self, value: &T) -> MatcherResult {
+        let inverted_failure_message = format!(
+            "{:?} should not be JSON equivalent to {:?}",
+            value.as_ref(), self.other
+        );
+        match (parse(value.as_ref(), "value"), parse(&self.other, "expected")) {
+            (Ok(actual), Ok(expected)) => {
+                let difference = first_difference(&actual, &expected, "$".to_string());
+                MatcherResult::formatted(
+                    difference.is_none(),
+                    format!(
+                        "{:?} should be JSON equivalent to {:?} but differed at {}",
+                        value.as_ref(), self.other, difference.unwrap_or_default()
+                    ),
+                    inverted_failure_message,
+                )
+            }
+            (Err(parse_error), _) | (_, Err(parse_error)) => MatcherResult::formatted(
+                false,
+                format!(
+                    "{:?} should be JSON equivalent to {:?} but {}",
+                    value.as_ref(), self.other, parse_error
+                ),
+                inverted_failure_message,
+            ),
+        }
+    }
+}
+
+fn parse(json: &str, side: &str) -> Result<Value, String> {
+    serde_json::from_str(json)
+        .map_err(|err| format!("the {} {:?} is not a valid JSON: {}", side, json, err))
+}
+
+fn first_difference(actual: &Value, expected: &Value, path: String) -> Option<String> {
+    match (actual, expected) {
+        (Value::Object(actual), Value::Object(expected)) => {
+            let mut keys = actual.keys().chain(expected.keys()).collect::<Vec<_>>();
+            keys.sort();
+            keys.dedup();
+            keys.into_iter().find_map(|key| match (actual.get(key), expected.get(key)) {
+                (Some(actual), Some(expected)) => {
+                    first_difference(actual, expected, format!("{}.{}", path, key))
+                }
+                _ => Some(format!("{}.{}", path, key)),
+            })
+        }
+        (Value::Array(actual), Value::Array(expected)) => {
+            if actual.len() != expected.len() {
+                return Some(format!(
+                    "{} (length {} vs {})",
+                    path,
+                    actual.len(),
+                    expected.len()
+                ));
+            }
+            actual
+                .iter()
+                .zip(expected.iter())
+                .enumerate()
+                .find_map(|(index, (actual, expected))| {
+                    first_difference(actual, expected, format!("{}[{}]", path, index))
+                })
+        }
+        _ if actual == expected => None,
+        _ => Some(path),
+    }
+}
+
+/// Creates a JsonMatcher that asserts whether a JSON string is structurally equivalent to the given JSON string.
+pub fn be_json_equivalent(other: &str) -> JsonMatcher {
+    JsonMatcher {
+        other: other.to_string(),
+    }
+}
+
+/// JsonPathMatcher offers a flexible way to assert whether a JSON string contains the given value at a path.
+///
+/// The path is a dotted path with an optional leading `$`, like `$.data.id` or `items.0.name`. A numeric segment
+/// indexes into an array, any other segment is looked up as an object key. The expected value is itself a JSON
+/// string, so a string value needs to be quoted (`"\"clearcheck\""`). If either side is not a valid JSON, the matcher
+/// fails and the failure message carries the parse error.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::string::json::have_json_value_at;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = have_json_value_at("$.data.items.0.id", "42");
+/// assert!(matcher.test(&r#"{"data": {"items": [{"id": 42}]}}"#).passed());
+/// ```
+pub struct JsonPathMatcher {
+    path: String,
+    expected: String,
+}
+
+impl<T> Matcher<T> for JsonPathMatcher
+    where T: AsRef<str>
+{
+    fn test(&self, value: &T) -> MatcherResult {
+        let (document, expected) = match (parse(value.as_ref(), "value"), parse(&self.expected, "expected")) {
+            (Ok(document), Ok(expected)) => (document, expected),
+            (Err(parse_error), _) | (_, Err(parse_error)) => {
+                return MatcherResult::formatted(
+                    false,
+                    format!(
+                        "{:?} should have the JSON value {} at {:?} but {}",
+                        value.as_ref(), self.expected, self.path, parse_error
+                    ),
+                    format!(
+                        "{:?} should not have the JSON value {} at {:?}",
+                        value.as_ref(), self.expected, self.path
+                    ),
+                );
+            }
+        };
+
+        match navigate(&document, &self.path) {
+            Ok(actual) => MatcherResult::formatted(
+                actual == &expected,
+                format!(
+                    "{:?} should have the JSON value {} at {:?} but was {}",
+                    value.as_ref(), expected, self.path, actual
+                ),
+                format!(
+                    "{:?} should not have the JSON value {} at {:?}",
+                    value.as_ref(), expected, self.path
+                ),
+            ),
+            Err(missing_segment) => MatcherResult::formatted(
+                false,
+                format!(
+                    "{:?} should have the JSON value {} at {:?} but the path was not found (missing segment {:?})",
+                    value.as_ref(), expected, self.path, missing_segment
+                ),
+                format!(
+                    "{:?} should not have the JSON value {} at {:?}",
+                    value.as_ref(), expected, self.path
+                ),
+            ),
+        }
+    }
+}
+
+fn navigate<'a>(document: &'a Value, path: &'a str) -> Result<&'a Value, &'a str> {
+    let path = path.strip_prefix('$').unwrap_or(path);
+    path.split('.')
+        .filter(|segment| !segment.is_empty())
+        .try_fold(document, |current, segment| {
+            let next = match current {
+                Value::Array(elements) => segment
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|index| elements.get(index)),
+                Value::Object(entries) => entries.get(segment),
+                _ => None,
+            };
+            next.ok_or(segment)
+        })
+}
+
+/// Creates a JsonPathMatcher that asserts whether a JSON string contains the given JSON value at the given dotted path.
+pub fn have_json_value_at(path: &str, expected: &str) -> JsonPathMatcher {
+    JsonPathMatcher {
+        path: path.to_string(),
+        expected: expected.to_string(),
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod tests {
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::matchers::Matcher;
+    use crate::matchers::string::json::{be_json_equivalent, have_json_value_at};
+
+    #[test]
+    fn should_be_json_equivalent_with_reordered_keys() {
+        let matcher = be_json_equivalent(r#"{"name": "clearcheck", "tags": ["rust", "test"]}"#);
+        matcher
+            .test(&r#"{ "tags": ["rust", "test"], "name": "clearcheck" }"#)
+            .passed
+            .should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_json_equivalent_but_a_value_differed() {
+        let matcher = be_json_equivalent(r#"{"name": "clearcheck", "stars": 10}"#);
+        matcher
+            .test(&r#"{"name": "clearcheck", "stars": 11}"#)
+            .passed
+            .should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_json_equivalent_but_the_array_order_differed() {
+        let matcher = be_json_equivalent(r#"[1, 2]"#);
+        matcher.test(&r#"[2, 1]"#).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_fail_for_an_invalid_value() {
+        let matcher = be_json_equivalent(r#"{}"#);
+        let result = matcher.test(&"{");
+        result.passed.should_be_false();
+        result.failure_message.contains("but the value \"{\" is not a valid JSON").should_be_true();
+    }
+
+    #[test]
+    fn should_fail_for_an_invalid_expected() {
+        let matcher = be_json_equivalent(r#"[1,"#);
+        let result = matcher.test(&"[1]");
+        result.passed.should_be_false();
+        result.failure_message.contains("but the expected \"[1,\" is not a valid JSON").should_be_true();
+    }
+
+    #[test]
+    fn should_have_json_value_at_a_nested_path() {
+        let matcher = have_json_value_at("$.data.id", "42");
+        matcher.test(&r#"{"data": {"id": 42}}"#).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_json_value_at_an_array_index() {
+        let matcher = have_json_value_at("items.1.name", r#""assert""#);
+        matcher
+            .test(&r#"{"items": [{"name": "rust"}, {"name": "assert"}]}"#)
+            .passed
+            .should_be_true();
+    }
+
+    #[test]
+    fn should_fail_to_have_json_value_at_for_an_invalid_value() {
+        let matcher = have_json_value_at("$.data.id", "42");
+        let result = matcher.test(&r#"{"data": "#);
+        result.passed.should_be_false();
+        result.failure_message.contains("but the value \"{\\\"data\\\": \" is not a valid JSON").should_be_true();
+    }
+
+    #[test]
+    fn should_fail_to_have_json_value_at_for_an_invalid_expected() {
+        let matcher = have_json_value_at("$.data.id", "forty-two");
+        let result = matcher.test(&r#"{"data": {"id": 42}}"#);
+        result.passed.should_be_false();
+        result.failure_message.contains("but the expected \"forty-two\" is not a valid JSON").should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_json_value_at_but_the_path_was_missing() {
+        let matcher = have_json_value_at("items.2.name", r#""assert""#);
+        matcher
+            .test(&r#"{"items": [{"name": "rust"}, {"name": "assert"}]}"#)
+            .passed
+            .should_be_true();
+    }
+}
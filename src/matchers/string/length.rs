@@ -58,6 +58,109 @@ impl<T> Matcher<T> for StringLengthMatcher
     }
 }
 
+/// CharLengthMatcher offers a flexible way to assert various length properties of string, measured in characters.
+///
+/// Unlike [StringLengthMatcher], which measures the length in bytes, CharLengthMatcher counts the `chars()` of the string,
+/// so a multibyte character counts once.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::string::length::have_same_char_length;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = have_same_char_length(5);
+/// assert!(matcher.test(&"héllo").passed());
+/// ```
+pub enum CharLengthMatcher {
+    Same(usize),
+    Atleast(usize),
+    Atmost(usize),
+}
+
+impl<T> Matcher<T> for CharLengthMatcher
+    where T: AsRef<str>
+{
+    fn test(&self, value: &T) -> MatcherResult {
+        let actual_length = value.as_ref().chars().count();
+        match self {
+            CharLengthMatcher::Same(length) => MatcherResult::formatted(
+                actual_length == *length,
+                format!(
+                    "{:?} length {:?} should be {:?}",
+                    value.as_ref(), actual_length, length,
+                ),
+                format!(
+                    "{:?} length {:?} should not be {:?}",
+                    value.as_ref(), actual_length, length,
+                ),
+            ),
+            CharLengthMatcher::Atleast(length) => MatcherResult::formatted(
+                actual_length >= *length,
+                format!(
+                    "{:?} length {:?} should be atleast {:?}",
+                    value.as_ref(), actual_length, length,
+                ),
+                format!(
+                    "{:?} length {:?} should not be atleast {:?}",
+                    value.as_ref(), actual_length, length,
+                ),
+            ),
+            CharLengthMatcher::Atmost(length) => MatcherResult::formatted(
+                actual_length <= *length,
+                format!(
+                    "{:?} length {:?} should be atmost {:?}",
+                    value.as_ref(), actual_length, length,
+                ),
+                format!(
+                    "{:?} length {:?} should not be atmost {:?}",
+                    value.as_ref(), actual_length, length,
+                ),
+            ),
+        }
+    }
+}
+
+/// GraphemeCountMatcher offers a flexible way to assert the number of user-perceived characters (extended grapheme
+/// clusters) in a string.
+///
+/// Unlike [CharLengthMatcher], a character followed by combining marks or an emoji with modifiers counts once. The
+/// failure message reports both the grapheme count and the char count.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::string::length::have_grapheme_count;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = have_grapheme_count(1);
+/// assert!(matcher.test(&"\u{1F44D}\u{1F3FD}").passed());
+/// ```
+#[cfg(feature = "unicode")]
+pub struct GraphemeCountMatcher {
+    count: usize,
+}
+
+#[cfg(feature = "unicode")]
+impl<T> Matcher<T> for GraphemeCountMatcher
+    where T: AsRef<str>
+{
+    fn test(&self, value: &T) -> MatcherResult {
+        use unicode_segmentation::UnicodeSegmentation;
+
+        let graphemes = value.as_ref().graphemes(true).count();
+        MatcherResult::formatted(
+            graphemes == self.count,
+            format!(
+                "{:?} should have {} graphemes but had {} graphemes ({} chars)",
+                value.as_ref(), self.count, graphemes, value.as_ref().chars().count(),
+            ),
+            format!(
+                "{:?} should not have {} graphemes",
+                value.as_ref(), self.count,
+            ),
+        )
+    }
+}
+
 /// Creates a StringLengthMatcher that asserts whether the length of a string is same as the given length.
 pub fn have_same_length(length: usize) -> StringLengthMatcher {
     StringLengthMatcher::Same(length)
@@ -73,11 +176,35 @@ pub fn have_atmost_same_length(length: usize) -> StringLengthMatcher {
     StringLengthMatcher::Atmost(length)
 }
 
+/// Creates a CharLengthMatcher that asserts whether the number of characters in a string is same as the given length.
+pub fn have_same_char_length(length: usize) -> CharLengthMatcher {
+    CharLengthMatcher::Same(length)
+}
+
+/// Creates a CharLengthMatcher that asserts whether the number of characters in a string is greater than or equal to the given length.
+pub fn have_atleast_same_char_length(length: usize) -> CharLengthMatcher {
+    CharLengthMatcher::Atleast(length)
+}
+
+/// Creates a CharLengthMatcher that asserts whether the number of characters in a string is less than or equal to the given length.
+pub fn have_atmost_same_char_length(length: usize) -> CharLengthMatcher {
+    CharLengthMatcher::Atmost(length)
+}
+
+/// Creates a GraphemeCountMatcher that asserts whether the number of grapheme clusters in a string is same as the given count.
+#[cfg(feature = "unicode")]
+pub fn have_grapheme_count(count: usize) -> GraphemeCountMatcher {
+    GraphemeCountMatcher { count }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::assertions::bool::TrueFalseAssertion;
     use crate::matchers::Matcher;
-    use crate::matchers::string::length::{have_atleast_same_length, have_atmost_same_length, have_same_length};
+    use crate::matchers::string::length::{
+        have_atleast_same_char_length, have_atleast_same_length, have_atmost_same_char_length,
+        have_atmost_same_length, have_same_char_length, have_same_length,
+    };
 
     #[test]
     fn should_have_same_length() {
@@ -117,4 +244,49 @@ mod tests {
         let matcher = have_atmost_same_length(3);
         matcher.test(&"junit").passed.should_be_true();
     }
+
+    #[test]
+    fn should_have_same_char_length() {
+        let matcher = have_same_char_length(5);
+        matcher.test(&"héllo").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_same_char_length_but_was_not() {
+        let matcher = have_same_char_length(6);
+        matcher.test(&"héllo").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_atleast_same_char_length() {
+        let matcher = have_atleast_same_char_length(2);
+        matcher.test(&"日本").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_atmost_same_char_length() {
+        let matcher = have_atmost_same_char_length(2);
+        matcher.test(&"日本").passed.should_be_true();
+    }
+}
+
+#[cfg(all(test, feature = "unicode"))]
+mod grapheme_tests {
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::matchers::Matcher;
+    use crate::matchers::string::length::have_grapheme_count;
+
+    #[test]
+    fn should_have_grapheme_count() {
+        let matcher = have_grapheme_count(4);
+        matcher.test(&"cafe\u{301}").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_grapheme_count_but_counted_chars() {
+        let matcher = have_grapheme_count(5);
+        matcher.test(&"cafe\u{301}").passed.should_be_true();
+    }
 }
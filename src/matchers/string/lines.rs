@@ -0,0 +1,222 @@
+use crate::matchers::{Matcher, MatcherResult};
+
+/// LineCountMatcher offers a flexible way to assert the number of lines in a string.
+///
+/// Lines are counted using [str::lines], so a trailing line ending does not start a new line:
+/// "a\n" has one line, "a\nb" has two lines and an empty string has no lines.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::string::lines::have_line_count;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = have_line_count(2);
+/// assert!(matcher.test(&"clear\ncheck\n").passed());
+/// ```
+pub enum LineCountMatcher {
+    Same(usize),
+    Atleast(usize),
+    Atmost(usize),
+}
+
+impl<T> Matcher<T> for LineCountMatcher
+    where T: AsRef<str>
+{
+    fn test(&self, value: &T) -> MatcherResult {
+        let line_count = value.as_ref().lines().count();
+        match self {
+            LineCountMatcher::Same(count) => MatcherResult::formatted(
+                line_count == *count,
+                format!(
+                    "{:?} should have {:?} lines but had {:?}",
+                    value.as_ref(), count, line_count,
+                ),
+                format!(
+                    "{:?} should not have {:?} lines",
+                    value.as_ref(), count,
+                ),
+            ),
+            LineCountMatcher::Atleast(count) => MatcherResult::formatted(
+                line_count >= *count,
+                format!(
+                    "{:?} should have atleast {:?} lines but had {:?}",
+                    value.as_ref(), count, line_count,
+                ),
+                format!(
+                    "{:?} should not have atleast {:?} lines but had {:?}",
+                    value.as_ref(), count, line_count,
+                ),
+            ),
+            LineCountMatcher::Atmost(count) => MatcherResult::formatted(
+                line_count <= *count,
+                format!(
+                    "{:?} should have atmost {:?} lines but had {:?}",
+                    value.as_ref(), count, line_count,
+                ),
+                format!(
+                    "{:?} should not have atmost {:?} lines but had {:?}",
+                    value.as_ref(), count, line_count,
+                ),
+            ),
+        }
+    }
+}
+
+/// Creates a LineCountMatcher that asserts whether the number of lines in a string is same as the given count.
+pub fn have_line_count(count: usize) -> LineCountMatcher {
+    LineCountMatcher::Same(count)
+}
+
+/// Creates a LineCountMatcher that asserts whether the number of lines in a string is greater than or equal to the given count.
+pub fn have_atleast_line_count(count: usize) -> LineCountMatcher {
+    LineCountMatcher::Atleast(count)
+}
+
+/// Creates a LineCountMatcher that asserts whether the number of lines in a string is less than or equal to the given count.
+pub fn have_atmost_line_count(count: usize) -> LineCountMatcher {
+    LineCountMatcher::Atmost(count)
+}
+
+/// LineWhitespaceMatcher offers a flexible way to assert that no line of a string begins or ends with whitespace.
+///
+/// Lines are split using [str::lines], so the line endings ("\n" or "\r\n") are not treated as trailing whitespace.
+/// Whitespace is determined by [char::is_whitespace] and line numbers in the failure messages start at 1.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::string::lines::have_no_trailing_whitespace;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = have_no_trailing_whitespace();
+/// assert!(matcher.test(&"fn main() {\n    println!();\n}\n").passed());
+/// ```
+pub enum LineWhitespaceMatcher {
+    Trailing,
+    Leading,
+}
+
+impl LineWhitespaceMatcher {
+    fn has_whitespace(&self, line: &str) -> bool {
+        match self {
+            LineWhitespaceMatcher::Trailing => line.ends_with(char::is_whitespace),
+            LineWhitespaceMatcher::Leading => line.starts_with(char::is_whitespace),
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            LineWhitespaceMatcher::Trailing => "trailing whitespace",
+            LineWhitespaceMatcher::Leading => "leading whitespace",
+        }
+    }
+}
+
+impl<T> Matcher<T> for LineWhitespaceMatcher
+    where T: AsRef<str>
+{
+    fn test(&self, value: &T) -> MatcherResult {
+        let offending_lines = value
+            .as_ref()
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| self.has_whitespace(line))
+            .map(|(index, _)| index + 1)
+            .collect::<Vec<_>>();
+
+        MatcherResult::formatted(
+            offending_lines.is_empty(),
+            format!(
+                "{:?} should have no {} but it was found on lines {:?}",
+                value.as_ref(), self.description(), offending_lines,
+            ),
+            format!(
+                "{:?} should have {} on at least one line",
+                value.as_ref(), self.description(),
+            ),
+        )
+    }
+}
+
+/// Creates a LineWhitespaceMatcher that asserts whether no line of a string ends with whitespace.
+pub fn have_no_trailing_whitespace() -> LineWhitespaceMatcher {
+    LineWhitespaceMatcher::Trailing
+}
+
+/// Creates a LineWhitespaceMatcher that asserts whether no line of a string begins with whitespace.
+pub fn have_no_leading_whitespace() -> LineWhitespaceMatcher {
+    LineWhitespaceMatcher::Leading
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::matchers::Matcher;
+    use crate::matchers::string::lines::{
+        have_atleast_line_count, have_atmost_line_count, have_line_count, have_no_leading_whitespace,
+        have_no_trailing_whitespace,
+    };
+
+    #[test]
+    fn should_have_no_lines_for_an_empty_string() {
+        let matcher = have_line_count(0);
+        matcher.test(&"").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_one_line() {
+        let matcher = have_line_count(1);
+        matcher.test(&"clearcheck").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_one_line_with_a_trailing_newline() {
+        let matcher = have_line_count(1);
+        matcher.test(&"clearcheck\n").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_two_lines_but_had_one() {
+        let matcher = have_line_count(2);
+        matcher.test(&"clearcheck\n").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_atleast_two_lines() {
+        let matcher = have_atleast_line_count(2);
+        matcher.test(&"clear\r\ncheck\r\nrust").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_atmost_one_line_but_had_more() {
+        let matcher = have_atmost_line_count(1);
+        matcher.test(&"clear\ncheck").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_no_trailing_whitespace() {
+        let matcher = have_no_trailing_whitespace();
+        matcher.test(&"clear\r\ncheck\n").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_no_trailing_whitespace_but_a_line_had_a_tab() {
+        let matcher = have_no_trailing_whitespace();
+        matcher.test(&"clear\t\ncheck").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_no_leading_whitespace() {
+        let matcher = have_no_leading_whitespace();
+        matcher.test(&"clear\ncheck ").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_no_leading_whitespace_but_a_line_was_indented() {
+        let matcher = have_no_leading_whitespace();
+        matcher.test(&"clear\n  check").passed.should_be_true();
+    }
+}
@@ -1,3 +1,5 @@
+use std::ops::RangeInclusive;
+
 use crate::matchers::{Matcher, MatcherResult};
 
 /// MembershipMatcher offers a flexible way to assert the presence or absence of characters in a string.
@@ -17,6 +19,12 @@ pub enum MembershipMatcher {
     Char(char),
     AllChars(Vec<char>),
     AnyChars(Vec<char>),
+    CharCount(char, usize),
+    CharCountInRange(char, RangeInclusive<usize>),
+    Whitespace,
+    NoWhitespace,
+    ControlCharacter,
+    NoControlCharacter,
 }
 
 /// SubstringMatcher offers a flexible way to assert whether a string contains a substring.
@@ -32,6 +40,7 @@ pub enum MembershipMatcher {
 pub enum SubstringMatcher {
     Substr(&'static str),
     SubstrIgnoringCase(&'static str),
+    Between(&'static str, &'static str, &'static str),
 }
 
 impl<T> Matcher<T> for MembershipMatcher
@@ -69,10 +78,94 @@ impl<T> Matcher<T> for MembershipMatcher
                 format!("{:?} should contain any of the characters {:?}", value.as_ref(), chars),
                 format!("{:?} should not contain any of the characters {:?}", value.as_ref(), chars),
             ),
+            MembershipMatcher::CharCount(ch, count) => {
+                let actual = value.as_ref().chars().filter(|source| source == ch).count();
+                MatcherResult::formatted(
+                    actual == *count,
+                    format!(
+                        "{:?} should contain the character {:?} {} times but contained it {} times",
+                        value.as_ref(), ch, count, actual
+                    ),
+                    format!("{:?} should not contain the character {:?} {} times", value.as_ref(), ch, count),
+                )
+            }
+            MembershipMatcher::CharCountInRange(ch, range) => {
+                let actual = value.as_ref().chars().filter(|source| source == ch).count();
+                MatcherResult::formatted(
+                    range.contains(&actual),
+                    format!(
+                        "{:?} should contain the character {:?} a number of times in the range {:?} but contained it {} times",
+                        value.as_ref(), ch, range, actual
+                    ),
+                    format!(
+                        "{:?} should not contain the character {:?} a number of times in the range {:?} but contained it {} times",
+                        value.as_ref(), ch, range, actual
+                    ),
+                )
+            }
+            MembershipMatcher::Whitespace => {
+                let index = first_whitespace_index(value.as_ref());
+                MatcherResult::formatted(
+                    index.is_some(),
+                    format!("{:?} should contain a whitespace", value.as_ref()),
+                    format!(
+                        "{:?} should not contain a whitespace but found one at index {}",
+                        value.as_ref(), index.unwrap_or_default()
+                    ),
+                )
+            }
+            MembershipMatcher::NoWhitespace => {
+                let index = first_whitespace_index(value.as_ref());
+                MatcherResult::formatted(
+                    index.is_none(),
+                    format!(
+                        "{:?} should not contain a whitespace but found one at index {}",
+                        value.as_ref(), index.unwrap_or_default()
+                    ),
+                    format!("{:?} should contain a whitespace", value.as_ref()),
+                )
+            }
+            MembershipMatcher::ControlCharacter => {
+                let control = first_control_character(value.as_ref());
+                MatcherResult::formatted(
+                    control.is_some(),
+                    format!("{:?} should contain a control character", value.as_ref()),
+                    format!(
+                        "{:?} should not contain a control character but found {}",
+                        value.as_ref(), describe_control_character(control)
+                    ),
+                )
+            }
+            MembershipMatcher::NoControlCharacter => {
+                let control = first_control_character(value.as_ref());
+                MatcherResult::formatted(
+                    control.is_none(),
+                    format!(
+                        "{:?} should not contain a control character but found {}",
+                        value.as_ref(), describe_control_character(control)
+                    ),
+                    format!("{:?} should contain a control character", value.as_ref()),
+                )
+            }
         }
     }
 }
 
+// Reports a char index (not a byte index) so that it lines up with what a reader counts in the failure message.
+fn first_whitespace_index(value: &str) -> Option<usize> {
+    value.chars().position(char::is_whitespace)
+}
+
+fn first_control_character(value: &str) -> Option<(usize, char)> {
+    value.chars().enumerate().find(|(_, ch)| ch.is_control())
+}
+
+fn describe_control_character(control: Option<(usize, char)>) -> String {
+    control
+        .map(|(index, ch)| format!("U+{:04X} at index {}", ch as u32, index))
+        .unwrap_or_default()
+}
+
 impl<T> Matcher<T> for SubstringMatcher
     where T: AsRef<str>
 {
@@ -93,7 +186,33 @@ impl<T> Matcher<T> for SubstringMatcher
                     "{:?} should not contain the substring ignoring case {:?}",
                     value.as_ref(), substr
                 ),
-            )
+            ),
+            SubstringMatcher::Between(start, end, expected) => {
+                let value = value.as_ref();
+                let start_index = value.find(start).map(|index| index + start.len());
+                let text = start_index.and_then(|start_index| {
+                    value[start_index..]
+                        .find(end)
+                        .map(|length| &value[start_index..start_index + length])
+                });
+
+                let reason = match (start_index, text) {
+                    (None, _) => format!("the start marker {:?} was not found", start),
+                    (Some(_), None) => format!("the end marker {:?} was not found after the start marker", end),
+                    (Some(_), Some(text)) => format!("found {:?}", text),
+                };
+                MatcherResult::formatted(
+                    text == Some(expected),
+                    format!(
+                        "{:?} should contain {:?} between {:?} and {:?} but {}",
+                        value, expected, start, end, reason
+                    ),
+                    format!(
+                        "{:?} should not contain {:?} between {:?} and {:?}",
+                        value, expected, start, end
+                    ),
+                )
+            }
         }
     }
 }
@@ -129,6 +248,38 @@ pub fn contain_any_of_characters(chars: Vec<char>) -> MembershipMatcher {
     MembershipMatcher::AnyChars(chars)
 }
 
+/// Creates a MembershipMatcher that asserts whether a string contains the given character exactly the given number of times.
+pub fn have_char_count(ch: char, count: usize) -> MembershipMatcher {
+    MembershipMatcher::CharCount(ch, count)
+}
+
+/// Creates a MembershipMatcher that asserts whether the number of times a string contains the given character falls within the given inclusive range.
+pub fn have_char_count_in_inclusive_range(ch: char, range: RangeInclusive<usize>) -> MembershipMatcher {
+    MembershipMatcher::CharCountInRange(ch, range)
+}
+
+/// Creates a MembershipMatcher that asserts whether a string contains a whitespace (as defined by [char::is_whitespace]).
+pub fn contain_whitespace() -> MembershipMatcher {
+    MembershipMatcher::Whitespace
+}
+
+/// Creates a MembershipMatcher that asserts whether a string does not contain any whitespace (as defined by [char::is_whitespace]).
+///
+/// On failure, the message reports the char index (not the byte index) of the first whitespace found.
+pub fn not_contain_whitespace() -> MembershipMatcher {
+    MembershipMatcher::NoWhitespace
+}
+
+/// Creates a MembershipMatcher that asserts whether a string contains a control character (as defined by [char::is_control]).
+pub fn contain_control_characters() -> MembershipMatcher {
+    MembershipMatcher::ControlCharacter
+}
+
+/// Creates a MembershipMatcher that asserts whether a string does not contain any control character (as defined by [char::is_control]).
+pub fn not_contain_control_characters() -> MembershipMatcher {
+    MembershipMatcher::NoControlCharacter
+}
+
 /// Creates a SubstringMatcher that asserts whether a string contains the given substring.
 pub fn contain(substr: &'static str) -> SubstringMatcher {
     SubstringMatcher::Substr(substr)
@@ -139,11 +290,20 @@ pub fn contain_ignoring_case(substr: &'static str) -> SubstringMatcher {
     SubstringMatcher::SubstrIgnoringCase(substr)
 }
 
+/// Creates a SubstringMatcher that asserts whether the text between the first start marker and the next end marker equals the expected text.
+///
+/// It locates the first occurrence of the start marker and the first occurrence of the end marker after it,
+/// and compares the text between them with the expected text. On failure, the message tells whether the start marker was not found,
+/// the end marker was not found, or the text between them differed.
+pub fn contain_text_between(start: &'static str, end: &'static str, expected: &'static str) -> SubstringMatcher {
+    SubstringMatcher::Between(start, end, expected)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::assertions::bool::TrueFalseAssertion;
     use crate::matchers::Matcher;
-    use crate::matchers::string::membership::{contain, contain_all_characters, contain_any_of_characters, contain_character, contain_ignoring_case, contain_only_digits, not_contain_digits};
+    use crate::matchers::string::membership::{contain, contain_all_characters, contain_any_of_characters, contain_character, contain_control_characters, contain_ignoring_case, contain_only_digits, contain_text_between, contain_whitespace, have_char_count, have_char_count_in_inclusive_range, not_contain_control_characters, not_contain_digits, not_contain_whitespace};
 
     #[test]
     fn should_contains_only_digits() {
@@ -248,4 +408,94 @@ mod tests {
         let matcher = contain_ignoring_case("ETCD");
         matcher.test(&"goselect").passed.should_be_true();
     }
+
+    #[test]
+    fn should_contain_whitespace() {
+        let matcher = contain_whitespace();
+        matcher.test(&"clear\tcheck").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_contain_non_breaking_space_as_whitespace() {
+        let matcher = contain_whitespace();
+        matcher.test(&"clear\u{00A0}check").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_whitespace_but_it_did_not() {
+        let matcher = contain_whitespace();
+        matcher.test(&"clearcheck").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_contain_whitespace() {
+        let matcher = not_contain_whitespace();
+        matcher.test(&"clear-check").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_contain_whitespace_but_it_did() {
+        let matcher = not_contain_whitespace();
+        matcher.test(&"clear\u{00A0}check").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_contain_control_character() {
+        let matcher = contain_control_characters();
+        matcher.test(&"clear\u{0007}check").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_control_character_but_it_did_not() {
+        let matcher = contain_control_characters();
+        matcher.test(&"clearcheck").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_contain_control_characters() {
+        let matcher = not_contain_control_characters();
+        matcher.test(&"clear check").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_contain_control_characters_but_it_did() {
+        let matcher = not_contain_control_characters();
+        matcher.test(&"clear\u{001B}[0m").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_contain_text_between() {
+        let matcher = contain_text_between("<b>", "</b>", "hello");
+        matcher.test(&"say <b>hello</b> world").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_text_between_but_the_end_marker_was_missing() {
+        let matcher = contain_text_between("<b>", "</b>", "hello");
+        matcher.test(&"say <b>hello").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_char_count() {
+        let matcher = have_char_count('a', 3);
+        matcher.test(&"banana").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_char_count_but_it_did_not() {
+        let matcher = have_char_count('n', 3);
+        matcher.test(&"banana").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_char_count_in_inclusive_range() {
+        let matcher = have_char_count_in_inclusive_range('z', 0..=1);
+        matcher.test(&"banana").passed.should_be_true();
+    }
 }
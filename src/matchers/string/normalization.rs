@@ -0,0 +1,101 @@
+use unicode_normalization::UnicodeNormalization;
+
+use crate::matchers::{Matcher, MatcherResult};
+
+/// NormalizationMatcher offers a flexible way to assert whether a string is already in a Unicode normalization form.
+///
+/// The string is normalized into the given form and compared with the original, so a string in NFC or NFD form is
+/// left unchanged by the normalization. The failure message shows both the original and the normalized form.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::string::normalization::be_nfc_normalized;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = be_nfc_normalized();
+/// assert!(matcher.test(&"caf\u{e9}").passed());
+/// ```
+pub enum NormalizationMatcher {
+    Nfc,
+    Nfd,
+}
+
+impl NormalizationMatcher {
+    fn name(&self) -> &'static str {
+        match self {
+            NormalizationMatcher::Nfc => "NFC",
+            NormalizationMatcher::Nfd => "NFD",
+        }
+    }
+
+    fn normalize(&self, value: &str) -> String {
+        match self {
+            NormalizationMatcher::Nfc => value.nfc().collect(),
+            NormalizationMatcher::Nfd => value.nfd().collect(),
+        }
+    }
+}
+
+impl<T> Matcher<T> for NormalizationMatcher
+    where T: AsRef<str>
+{
+    fn test(&self, value: &T) -> MatcherResult {
+        let value = value.as_ref();
+        let normalized = self.normalize(value);
+
+        MatcherResult::formatted(
+            value == normalized,
+            format!(
+                "{:?} should be {} normalized but its {} form was {:?}",
+                value, self.name(), self.name(), normalized
+            ),
+            format!(
+                "{:?} should not be {} normalized",
+                value, self.name()
+            ),
+        )
+    }
+}
+
+/// Creates a NormalizationMatcher that asserts whether a string is in the Unicode Normalization Form C (NFC).
+pub fn be_nfc_normalized() -> NormalizationMatcher {
+    NormalizationMatcher::Nfc
+}
+
+/// Creates a NormalizationMatcher that asserts whether a string is in the Unicode Normalization Form D (NFD).
+pub fn be_nfd_normalized() -> NormalizationMatcher {
+    NormalizationMatcher::Nfd
+}
+
+#[cfg(all(test, feature = "unicode"))]
+mod tests {
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::matchers::Matcher;
+    use crate::matchers::string::normalization::{be_nfc_normalized, be_nfd_normalized};
+
+    #[test]
+    fn should_be_nfc_normalized() {
+        let matcher = be_nfc_normalized();
+        matcher.test(&"caf\u{e9}").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_nfc_normalized_but_was_not() {
+        let matcher = be_nfc_normalized();
+        matcher.test(&"cafe\u{301}").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_nfd_normalized() {
+        let matcher = be_nfd_normalized();
+        matcher.test(&"cafe\u{301}").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_nfd_normalized_but_was_not() {
+        let matcher = be_nfd_normalized();
+        matcher.test(&"caf\u{e9}").passed.should_be_true();
+    }
+}
@@ -1,3 +1,5 @@
+use std::any::type_name;
+use std::fmt::{Debug, Display};
 use std::marker::PhantomData;
 use std::str::FromStr;
 
@@ -35,11 +37,198 @@ pub fn be_numeric<M: FromStr>() -> NumericMatcher<M> {
     }
 }
 
+/// ParseMatcher offers a flexible way to assert whether a string parses into a value of the given type,
+/// optionally comparing the parsed value with an expected value.
+///
+/// The parse error is included in the failure message.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::string::numeric::{be_parsable_as, parse_to};
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = be_parsable_as::<i64>();
+/// assert!(matcher.test(&"42").passed());
+///
+/// let matcher = parse_to(42.0);
+/// assert!(matcher.test(&"4.2e1").passed());
+/// ```
+pub enum ParseMatcher<M: FromStr> {
+    Parsable(PhantomData<M>),
+    ParseTo(M),
+}
+
+impl<T, M> Matcher<T> for ParseMatcher<M>
+    where
+        T: AsRef<str>,
+        M: FromStr + PartialEq + Debug,
+        M::Err: Display,
+{
+    fn test(&self, value: &T) -> MatcherResult {
+        let parse_result = value.as_ref().parse::<M>();
+        match self {
+            ParseMatcher::Parsable(_) => MatcherResult::formatted(
+                parse_result.is_ok(),
+                format!(
+                    "{:?} should be parsable as {} but failed with {}",
+                    value.as_ref(),
+                    type_name::<M>(),
+                    describe_error(&parse_result)
+                ),
+                format!("{:?} should not be parsable as {}", value.as_ref(), type_name::<M>()),
+            ),
+            ParseMatcher::ParseTo(expected) => MatcherResult::formatted(
+                parse_result.as_ref().is_ok_and(|parsed| parsed == expected),
+                match &parse_result {
+                    Ok(parsed) => format!(
+                        "{:?} should parse to {:?} but parsed to {:?}",
+                        value.as_ref(), expected, parsed
+                    ),
+                    Err(err) => format!(
+                        "{:?} should parse to {:?} but failed with {}",
+                        value.as_ref(), expected, err
+                    ),
+                },
+                format!("{:?} should not parse to {:?}", value.as_ref(), expected),
+            ),
+        }
+    }
+}
+
+fn describe_error<M, E: Display>(parse_result: &Result<M, E>) -> String {
+    parse_result
+        .as_ref()
+        .err()
+        .map(|err| err.to_string())
+        .unwrap_or_default()
+}
+
+/// Creates a ParseMatcher that asserts whether a string is parsable as the given type.
+pub fn be_parsable_as<M: FromStr>() -> ParseMatcher<M> {
+    ParseMatcher::Parsable(PhantomData)
+}
+
+/// Creates a ParseMatcher that asserts whether a string parses into the given expected value.
+pub fn parse_to<M: FromStr>(expected: M) -> ParseMatcher<M> {
+    ParseMatcher::ParseTo(expected)
+}
+
+/// RadixDigitsMatcher offers a flexible way to assert whether a string consists only of digits in the given radix.
+///
+/// Each character is checked with [`char::is_digit`], so letters are accepted in either case for radices above 10.
+/// An empty string never matches.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::string::numeric::be_digits_in_radix;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = be_digits_in_radix(16);
+/// assert!(matcher.test(&"1A3F").passed());
+/// ```
+pub struct RadixDigitsMatcher {
+    radix: u32,
+}
+
+impl<T: AsRef<str>> Matcher<T> for RadixDigitsMatcher {
+    fn test(&self, value: &T) -> MatcherResult {
+        let value = value.as_ref();
+        let invalid = value
+            .chars()
+            .enumerate()
+            .find(|(_, ch)| !ch.is_digit(self.radix));
+
+        let reason = match invalid {
+            Some((index, ch)) => format!("found {:?} at index {}", ch, index),
+            None => String::from("it was empty"),
+        };
+        MatcherResult::formatted(
+            !value.is_empty() && invalid.is_none(),
+            format!(
+                "{:?} should only contain digits in radix {} but {}",
+                value, self.radix, reason
+            ),
+            format!(
+                "{:?} should not only contain digits in radix {}",
+                value, self.radix
+            ),
+        )
+    }
+}
+
+/// Creates a RadixDigitsMatcher that asserts whether a string is non-empty and consists only of digits in the given radix.
+///
+/// Panics if the radix is not in the range 2..=36.
+pub fn be_digits_in_radix(radix: u32) -> RadixDigitsMatcher {
+    assert!(
+        (2..=36).contains(&radix),
+        "radix should be in the range 2..=36 but was {}",
+        radix
+    );
+    RadixDigitsMatcher { radix }
+}
+
+/// DecimalPlacesMatcher offers a flexible way to assert that a string is a plain decimal number with exactly the given number of decimal places.
+///
+/// A plain decimal number has an optional sign, ASCII digits and at most one `.` followed by the fractional digits.
+/// A number without a decimal point has 0 decimal places. Scientific notation (for example, "1.5e3") is rejected.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::string::numeric::have_decimal_places;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = have_decimal_places(2);
+/// assert!(matcher.test(&"12.34").passed());
+/// ```
+pub struct DecimalPlacesMatcher {
+    places: usize,
+}
+
+impl<T: AsRef<str>> Matcher<T> for DecimalPlacesMatcher {
+    fn test(&self, value: &T) -> MatcherResult {
+        let value = value.as_ref();
+        let actual_places = decimal_places(value);
+
+        let reason = match actual_places {
+            Some(places) => format!("had {}", places),
+            None => String::from("it was not a plain decimal number"),
+        };
+        MatcherResult::formatted(
+            actual_places == Some(self.places),
+            format!(
+                "{:?} should have {} decimal places but {}",
+                value, self.places, reason
+            ),
+            format!("{:?} should not have {} decimal places", value, self.places),
+        )
+    }
+}
+
+fn decimal_places(value: &str) -> Option<usize> {
+    let unsigned = value.strip_prefix(['+', '-']).unwrap_or(value);
+    let (integral, fractional) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let all_digits = |part: &str| part.chars().all(|ch| ch.is_ascii_digit());
+
+    if integral.is_empty() && fractional.is_empty() {
+        return None;
+    }
+    if !all_digits(integral) || !all_digits(fractional) {
+        return None;
+    }
+    Some(fractional.len())
+}
+
+/// Creates a DecimalPlacesMatcher that asserts whether a string is a plain decimal number with exactly the given number of decimal places.
+pub fn have_decimal_places(places: usize) -> DecimalPlacesMatcher {
+    DecimalPlacesMatcher { places }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::assertions::bool::TrueFalseAssertion;
     use crate::matchers::Matcher;
-    use crate::matchers::string::numeric::be_numeric;
+    use crate::matchers::string::numeric::{be_digits_in_radix, be_numeric, be_parsable_as, have_decimal_places, parse_to};
 
     #[test]
     fn should_be_numeric_i32() {
@@ -70,4 +259,76 @@ mod tests {
         let value = "123.45a";
         matcher.test(&value).passed.should_be_true();
     }
+
+    #[test]
+    fn should_be_parsable_as_i64() {
+        let matcher = be_parsable_as::<i64>();
+        matcher.test(&"42").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_parsable_as_i64_but_was_not() {
+        let matcher = be_parsable_as::<i64>();
+        matcher.test(&"4.2e1").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_parse_to() {
+        let matcher = parse_to(42.0);
+        matcher.test(&"4.2e1").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_parse_to_but_parsed_to_a_different_value() {
+        let matcher = parse_to(42);
+        matcher.test(&"43").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_digits_in_radix_16() {
+        let matcher = be_digits_in_radix(16);
+        matcher.test(&"1a3F").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_digits_in_radix_2_but_was_not() {
+        let matcher = be_digits_in_radix(2);
+        matcher.test(&"1021").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_digits_in_radix_but_was_empty() {
+        let matcher = be_digits_in_radix(10);
+        matcher.test(&"").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_decimal_places() {
+        let matcher = have_decimal_places(2);
+        matcher.test(&"-12.34").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_zero_decimal_places() {
+        let matcher = have_decimal_places(0);
+        matcher.test(&"12").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_decimal_places_but_had_more() {
+        let matcher = have_decimal_places(2);
+        matcher.test(&"12.340").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_decimal_places_but_was_in_scientific_notation() {
+        let matcher = have_decimal_places(1);
+        matcher.test(&"1.5e3").passed.should_be_true();
+    }
 }
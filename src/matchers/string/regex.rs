@@ -1,4 +1,6 @@
-use regex::Regex;
+use std::sync::OnceLock;
+
+use regex::{Regex, RegexBuilder};
 
 use crate::matchers::{Matcher, MatcherResult};
 
@@ -40,10 +42,223 @@ pub fn match_with(regular_expression: Regex) -> RegexMatcher {
     }
 }
 
+/// FullRegexMatcher offers a flexible way to assert whether an entire string matches a regular expression.
+///
+/// Unlike [RegexMatcher], which passes when any part of the string matches, FullRegexMatcher anchors the regular
+/// expression at both ends of the string, as if it were written `^(?:pattern)$`. The failure message tells whether the
+/// string matched only partially or did not match at all.
+///
+/// FullRegexMatcher is built from the pattern, rather than from a `Regex`, because the anchored regular expression
+/// has to be compiled with the same options as the given one. Use [fully_match_with_options] to set the options of
+/// `RegexBuilder` (like case insensitivity), or inline flags like `(?i)` in the pattern.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::string::regex::fully_match_with;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = fully_match_with(r"\w+");
+/// assert!(matcher.test(&"abc123").passed());
+/// ```
+pub struct FullRegexMatcher {
+    regexp: Regex,
+    anchored: Regex,
+}
+
+impl<T: AsRef<str>> Matcher<T> for FullRegexMatcher {
+    fn test(&self, value: &T) -> MatcherResult {
+        let reason = if self.regexp.is_match(value.as_ref()) {
+            "it matched only partially"
+        } else {
+            "it did not match at all"
+        };
+        MatcherResult::formatted(
+            self.anchored.is_match(value.as_ref()),
+            format!(
+                "{:?} should fully match the regular expression {:?} but {}",
+                value.as_ref(), self.regexp, reason
+            ),
+            format!(
+                "{:?} should not fully match the regular expression {:?}",
+                value.as_ref(), self.regexp
+            ),
+        )
+    }
+}
+
+/// Creates a FullRegexMatcher that asserts whether an entire string matches the given pattern.
+///
+/// Panics if the pattern is not a valid regular expression.
+pub fn fully_match_with(pattern: &str) -> FullRegexMatcher {
+    fully_match_with_options(pattern, |builder| builder)
+}
+
+/// Creates a FullRegexMatcher that asserts whether an entire string matches the given pattern, compiled with the
+/// options set by configure on a `RegexBuilder`.
+///
+/// Panics if the pattern is not a valid regular expression.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::string::regex::fully_match_with_options;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = fully_match_with_options(r"[a-z]+", |builder| builder.case_insensitive(true));
+/// assert!(matcher.test(&"ABC").passed());
+/// ```
+pub fn fully_match_with_options<F>(pattern: &str, configure: F) -> FullRegexMatcher
+    where F: Fn(&mut RegexBuilder) -> &mut RegexBuilder
+{
+    let build = |pattern: &str| {
+        configure(&mut RegexBuilder::new(pattern))
+            .build()
+            .expect("the pattern should be a valid regular expression")
+    };
+    FullRegexMatcher {
+        regexp: build(pattern),
+        anchored: build(&format!(r"\A(?:{})\z", pattern)),
+    }
+}
+
+/// CaptureMatcher offers a flexible way to assert whether a capture group of a regular expression captures the expected
+/// value in a string.
+///
+/// The first match of the regular expression is used. The failure message tells whether the regular expression did not
+/// match, the group was absent from the match or the group captured a different value.
+///
+/// # Example
+///```
+/// use regex::Regex;
+/// use clearcheck::matchers::string::regex::capture;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = capture(Regex::new(r"(\d{4})-(\d{2})").unwrap(), 2, "01");
+/// assert!(matcher.test(&"2024-01-10").passed());
+/// ```
+pub struct CaptureMatcher {
+    regexp: Regex,
+    group_index: usize,
+    expected: String,
+}
+
+impl<T: AsRef<str>> Matcher<T> for CaptureMatcher {
+    fn test(&self, value: &T) -> MatcherResult {
+        let captures = self.regexp.captures(value.as_ref());
+        let group = captures
+            .as_ref()
+            .and_then(|captures| captures.get(self.group_index));
+
+        let reason = match (&captures, group) {
+            (None, _) => String::from("the regular expression did not match"),
+            (Some(_), None) => format!("group {} was absent from the match", self.group_index),
+            (Some(_), Some(group)) => format!("group {} captured {:?}", self.group_index, group.as_str()),
+        };
+        MatcherResult::formatted(
+            group.is_some_and(|group| group.as_str() == self.expected),
+            format!(
+                "{:?} should capture {:?} in group {} of the regular expression {:?} but {}",
+                value.as_ref(), self.expected, self.group_index, self.regexp, reason
+            ),
+            format!(
+                "{:?} should not capture {:?} in group {} of the regular expression {:?}",
+                value.as_ref(), self.expected, self.group_index, self.regexp
+            ),
+        )
+    }
+}
+
+/// Creates a CaptureMatcher that asserts whether the given group of the first match of the regular expression captures the expected value.
+pub fn capture(regular_expression: Regex, group_index: usize, expected: &str) -> CaptureMatcher {
+    CaptureMatcher {
+        regexp: regular_expression,
+        group_index,
+        expected: expected.to_string(),
+    }
+}
+
+/// FormatMatcher offers a way to assert whether a string is a valid email address or URL, using built-in regular expressions.
+///
+/// The patterns are pragmatic rather than RFC-exhaustive:
+/// - An email address is a local part of letters, digits, dots (not leading, trailing or consecutive) and the symbols
+///   allowed by RFC 5322, followed by `@` and a domain of at least two dot-separated labels. Quoted local parts and IP
+///   address literals are not supported.
+/// - A URL has an `http`, `https` or `ftp` scheme, a host made of dot-separated labels (which covers `localhost` and
+///   IPv4 addresses), an optional port, and an optional path, query and fragment without whitespace. User information and
+///   IPv6 hosts are not supported.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::string::regex::be_valid_email;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = be_valid_email();
+/// assert!(matcher.test(&"clearcheck@example.com").passed());
+/// ```
+pub enum FormatMatcher {
+    Email,
+    Url,
+}
+
+impl FormatMatcher {
+    fn regexp(&self) -> &'static Regex {
+        static EMAIL: OnceLock<Regex> = OnceLock::new();
+        static URL: OnceLock<Regex> = OnceLock::new();
+
+        let compiled = match self {
+            FormatMatcher::Email => &EMAIL,
+            FormatMatcher::Url => &URL,
+        };
+        compiled.get_or_init(|| {
+            Regex::new(self.pattern()).expect("the built-in pattern should be a valid regular expression")
+        })
+    }
+
+    fn pattern(&self) -> &'static str {
+        match self {
+            FormatMatcher::Email => concat!(
+                r"\A[A-Za-z0-9!#$%&'*+/=?^_`{|}~-]+(?:\.[A-Za-z0-9!#$%&'*+/=?^_`{|}~-]+)*",
+                r"@[A-Za-z0-9](?:[A-Za-z0-9-]{0,61}[A-Za-z0-9])?(?:\.[A-Za-z0-9](?:[A-Za-z0-9-]{0,61}[A-Za-z0-9])?)+\z",
+            ),
+            FormatMatcher::Url => concat!(
+                r"\A(?i:https?|ftp)://",
+                r"[A-Za-z0-9](?:[A-Za-z0-9-]{0,61}[A-Za-z0-9])?(?:\.[A-Za-z0-9](?:[A-Za-z0-9-]{0,61}[A-Za-z0-9])?)*",
+                r"(?::[0-9]{1,5})?(?:[/?#]\S*)?\z",
+            ),
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            FormatMatcher::Email => "a valid email address",
+            FormatMatcher::Url => "a valid URL",
+        }
+    }
+}
+
+impl<T: AsRef<str>> Matcher<T> for FormatMatcher {
+    fn test(&self, value: &T) -> MatcherResult {
+        MatcherResult::formatted(
+            self.regexp().is_match(value.as_ref()),
+            format!("{:?} should be {}", value.as_ref(), self.description()),
+            format!("{:?} should not be {}", value.as_ref(), self.description()),
+        )
+    }
+}
+
+/// Creates a FormatMatcher that asserts whether a string is a valid email address.
+pub fn be_valid_email() -> FormatMatcher {
+    FormatMatcher::Email
+}
+
+/// Creates a FormatMatcher that asserts whether a string is a valid http, https or ftp URL.
+pub fn be_valid_url() -> FormatMatcher {
+    FormatMatcher::Url
+}
+
 #[cfg(all(test, feature = "regex"))]
 mod tests {
     use crate::assertions::bool::TrueFalseAssertion;
-    use crate::matchers::string::regex::match_with;
+    use crate::matchers::string::regex::{be_valid_email, be_valid_url, capture, fully_match_with, fully_match_with_options, match_with};
     use crate::matchers::Matcher;
     use regex::Regex;
 
@@ -65,4 +280,81 @@ mod tests {
         let matcher = match_with(regex);
         matcher.test(&str).passed.should_be_true();
     }
+
+    #[test]
+    fn should_fully_match_regular_expression() {
+        let matcher = fully_match_with(r"\w+");
+        matcher.test(&"abc123").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_fully_match_regular_expression_but_it_matched_partially() {
+        let matcher = fully_match_with(r"\d+");
+        matcher.test(&"abc123").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_fully_match_regular_expression_with_alternation() {
+        let matcher = fully_match_with(r"a|ab");
+        matcher.test(&"ab").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_fully_match_regular_expression_with_lazy_quantifier() {
+        let matcher = fully_match_with(r"\w+?");
+        matcher.test(&"abc").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_fully_match_regular_expression_built_case_insensitive() {
+        let matcher = fully_match_with_options(r"[a-z]+", |builder| builder.case_insensitive(true));
+        matcher.test(&"ABC").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_fully_match_regular_expression_built_case_sensitive_but_it_did_not() {
+        let matcher = fully_match_with(r"[a-z]+");
+        matcher.test(&"ABC").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_capture_the_expected_value() {
+        let matcher = capture(Regex::new(r"(\d{4})-").unwrap(), 1, "2024");
+        matcher.test(&"2024-01-10").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_capture_the_expected_value_but_it_did_not() {
+        let matcher = capture(Regex::new(r"(\d{4})-").unwrap(), 1, "2023");
+        matcher.test(&"2024-01-10").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_a_valid_email() {
+        let matcher = be_valid_email();
+        matcher.test(&"clear.check+tests@example.co.in").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_a_valid_email_but_it_was_not() {
+        let matcher = be_valid_email();
+        matcher.test(&"clearcheck@localhost").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_a_valid_url() {
+        let matcher = be_valid_url();
+        matcher.test(&"https://docs.rs/regex/latest/regex/?search=Regex#examples").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_a_valid_url_but_it_was_not() {
+        let matcher = be_valid_url();
+        matcher.test(&"mailto:clearcheck@example.com").passed.should_be_true();
+    }
 }
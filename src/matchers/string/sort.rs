@@ -0,0 +1,102 @@
+use crate::matchers::{Matcher, MatcherResult};
+
+/// CharacterSortMatcher offers a flexible way to assert whether the characters of a string are in ascending order.
+///
+/// Characters are compared by their Unicode scalar values, as produced by [str::chars], so uppercase letters sort
+/// before lowercase letters.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::string::sort::have_sorted_characters;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = have_sorted_characters();
+/// assert!(matcher.test(&"abcd").passed());
+/// ```
+pub enum CharacterSortMatcher {
+    Ascending,
+    StrictlyAscending,
+}
+
+impl CharacterSortMatcher {
+    fn in_order(&self, previous: char, next: char) -> bool {
+        match self {
+            CharacterSortMatcher::Ascending => previous <= next,
+            CharacterSortMatcher::StrictlyAscending => previous < next,
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            CharacterSortMatcher::Ascending => "sorted characters",
+            CharacterSortMatcher::StrictlyAscending => "strictly sorted characters",
+        }
+    }
+}
+
+impl<T> Matcher<T> for CharacterSortMatcher
+    where T: AsRef<str>
+{
+    fn test(&self, value: &T) -> MatcherResult {
+        let characters = value.as_ref().chars().collect::<Vec<_>>();
+        let out_of_order = characters
+            .windows(2)
+            .position(|pair| !self.in_order(pair[0], pair[1]));
+
+        let failure_message = match out_of_order {
+            Some(index) => format!(
+                "{:?} should have {} but {:?} at index {} was followed by {:?}",
+                value.as_ref(), self.description(), characters[index], index, characters[index + 1],
+            ),
+            None => format!("{:?} should have {}", value.as_ref(), self.description()),
+        };
+        MatcherResult::formatted(
+            out_of_order.is_none(),
+            failure_message,
+            format!("{:?} should not have {}", value.as_ref(), self.description()),
+        )
+    }
+}
+
+/// Creates a CharacterSortMatcher that asserts whether the characters of a string are in non-decreasing order.
+pub fn have_sorted_characters() -> CharacterSortMatcher {
+    CharacterSortMatcher::Ascending
+}
+
+/// Creates a CharacterSortMatcher that asserts whether the characters of a string are in strictly increasing order.
+pub fn have_strictly_sorted_characters() -> CharacterSortMatcher {
+    CharacterSortMatcher::StrictlyAscending
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::matchers::Matcher;
+    use crate::matchers::string::sort::{have_sorted_characters, have_strictly_sorted_characters};
+
+    #[test]
+    fn should_have_sorted_characters() {
+        let matcher = have_sorted_characters();
+        matcher.test(&"aabcd").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_sorted_characters_but_did_not() {
+        let matcher = have_sorted_characters();
+        matcher.test(&"abdc").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_strictly_sorted_characters() {
+        let matcher = have_strictly_sorted_characters();
+        matcher.test(&"abcd").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_strictly_sorted_characters_but_had_a_repeated_character() {
+        let matcher = have_strictly_sorted_characters();
+        matcher.test(&"aabcd").passed.should_be_true();
+    }
+}
@@ -0,0 +1,109 @@
+use crate::matchers::{Matcher, MatcherResult};
+
+/// DelimiterBalanceMatcher offers a flexible way to assert that the delimiters of a string are balanced.
+///
+/// Each opening delimiter must be closed by its own closing delimiter, and the pairs must nest properly.
+/// By default, the matcher considers `()`, `[]` and `{}`; other pairs can be provided instead.
+/// Indexes in the failure messages are character indexes, as produced by [str::chars].
+///
+/// # Example
+///```
+/// use clearcheck::matchers::string::structure::have_balanced_delimiters;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = have_balanced_delimiters();
+/// assert!(matcher.test(&"fn main() { let values = [1, 2]; }").passed());
+/// ```
+pub struct DelimiterBalanceMatcher {
+    pairs: Vec<(char, char)>,
+}
+
+impl DelimiterBalanceMatcher {
+    fn first_imbalance(&self, value: &str) -> Option<String> {
+        let mut open_delimiters: Vec<(usize, char)> = Vec::new();
+        for (index, character) in value.chars().enumerate() {
+            let closes_top = open_delimiters.last().is_some_and(|(_, open)| {
+                self.pairs.iter().any(|pair| pair.0 == *open && pair.1 == character)
+            });
+            if closes_top {
+                open_delimiters.pop();
+            } else if self.pairs.iter().any(|pair| pair.0 == character) {
+                open_delimiters.push((index, character));
+            } else if self.pairs.iter().any(|pair| pair.1 == character) {
+                return Some(match open_delimiters.last() {
+                    Some((open_index, open)) => format!(
+                        "the closing {:?} at index {} did not match the opening {:?} at index {}",
+                        character, index, open, open_index
+                    ),
+                    None => format!(
+                        "the closing {:?} at index {} had no matching opening delimiter",
+                        character, index
+                    ),
+                });
+            }
+        }
+        open_delimiters
+            .first()
+            .map(|(index, open)| format!("the opening {:?} at index {} was never closed", open, index))
+    }
+}
+
+impl<T> Matcher<T> for DelimiterBalanceMatcher
+    where T: AsRef<str>
+{
+    fn test(&self, value: &T) -> MatcherResult {
+        let imbalance = self.first_imbalance(value.as_ref());
+        let failure_message = match &imbalance {
+            Some(reason) => format!("{:?} should have balanced delimiters but {}", value.as_ref(), reason),
+            None => format!("{:?} should have balanced delimiters", value.as_ref()),
+        };
+        MatcherResult::formatted(
+            imbalance.is_none(),
+            failure_message,
+            format!("{:?} should not have balanced delimiters", value.as_ref()),
+        )
+    }
+}
+
+/// Creates a DelimiterBalanceMatcher that asserts whether the parentheses, brackets and braces of a string are balanced.
+pub fn have_balanced_delimiters() -> DelimiterBalanceMatcher {
+    have_balanced_delimiters_of(vec![('(', ')'), ('[', ']'), ('{', '}')])
+}
+
+/// Creates a DelimiterBalanceMatcher that asserts whether the given (opening, closing) delimiter pairs of a string are balanced.
+pub fn have_balanced_delimiters_of(pairs: Vec<(char, char)>) -> DelimiterBalanceMatcher {
+    DelimiterBalanceMatcher { pairs }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::matchers::Matcher;
+    use crate::matchers::string::structure::{have_balanced_delimiters, have_balanced_delimiters_of};
+
+    #[test]
+    fn should_have_balanced_delimiters() {
+        let matcher = have_balanced_delimiters();
+        matcher.test(&"{[()()]}").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_balanced_delimiters_but_they_were_interleaved() {
+        let matcher = have_balanced_delimiters();
+        matcher.test(&"([)]").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_balanced_delimiters_of_the_given_pairs() {
+        let matcher = have_balanced_delimiters_of(vec![('<', '>')]);
+        matcher.test(&"Vec<Option<u8>>").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_balanced_delimiters_of_the_given_pairs_but_they_were_not() {
+        let matcher = have_balanced_delimiters_of(vec![('<', '>')]);
+        matcher.test(&"Vec<Option<u8>").passed.should_be_true();
+    }
+}
@@ -0,0 +1,126 @@
+use crate::matchers::{Matcher, MatcherResult};
+
+/// TransformMatcher offers a flexible way to assert properties of a string that hold after rearranging its characters,
+/// like being a palindrome or an anagram of another string.
+///
+/// All the checks operate on [str::chars].
+///
+/// # Example
+///```
+/// use clearcheck::matchers::Matcher;
+/// use clearcheck::matchers::string::transform::be_anagram_of;
+///
+/// let matcher = be_anagram_of("silent");
+/// assert!(matcher.test(&"listen").passed());
+/// ```
+pub enum TransformMatcher {
+    Palindrome,
+    PalindromeIgnoringNonAlphanumerics,
+    AnagramOf(String),
+}
+
+impl<T> Matcher<T> for TransformMatcher
+    where T: AsRef<str>
+{
+    fn test(&self, value: &T) -> MatcherResult {
+        match self {
+            TransformMatcher::Palindrome => MatcherResult::formatted(
+                is_palindrome(value.as_ref().chars()),
+                format!("{:?} should be a palindrome", value.as_ref()),
+                format!("{:?} should not be a palindrome", value.as_ref()),
+            ),
+            TransformMatcher::PalindromeIgnoringNonAlphanumerics => MatcherResult::formatted(
+                is_palindrome(value.as_ref().chars().filter(|ch| ch.is_alphanumeric())),
+                format!("{:?} should be a palindrome ignoring non-alphanumerics", value.as_ref()),
+                format!("{:?} should not be a palindrome ignoring non-alphanumerics", value.as_ref()),
+            ),
+            TransformMatcher::AnagramOf(other) => MatcherResult::formatted(
+                sorted_chars(value.as_ref()) == sorted_chars(other),
+                format!("{:?} should be an anagram of {:?}", value.as_ref(), other),
+                format!("{:?} should not be an anagram of {:?}", value.as_ref(), other),
+            ),
+        }
+    }
+}
+
+fn is_palindrome<I: Iterator<Item = char>>(chars: I) -> bool {
+    let lowercased: Vec<char> = chars.flat_map(|ch| ch.to_lowercase()).collect();
+    lowercased.iter().eq(lowercased.iter().rev())
+}
+
+fn sorted_chars(value: &str) -> Vec<char> {
+    let mut chars: Vec<char> = value.chars().collect();
+    chars.sort_unstable();
+    chars
+}
+
+/// Creates a TransformMatcher that asserts whether a string reads the same backward as forward, ignoring case differences.
+/// Every other character, including whitespace, is significant.
+pub fn be_palindrome() -> TransformMatcher {
+    TransformMatcher::Palindrome
+}
+
+/// Creates a TransformMatcher that asserts whether a string reads the same backward as forward, ignoring case differences
+/// and all the non-alphanumeric characters (spaces, punctuation etc.).
+pub fn be_palindrome_ignoring_non_alphanumerics() -> TransformMatcher {
+    TransformMatcher::PalindromeIgnoringNonAlphanumerics
+}
+
+/// Creates a TransformMatcher that asserts whether a string is an anagram of the given string.
+/// The comparison is case-sensitive and considers every character, including whitespace.
+pub fn be_anagram_of(other: &str) -> TransformMatcher {
+    TransformMatcher::AnagramOf(other.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::matchers::Matcher;
+    use crate::matchers::string::transform::{be_anagram_of, be_palindrome, be_palindrome_ignoring_non_alphanumerics};
+
+    #[test]
+    fn should_be_palindrome() {
+        let matcher = be_palindrome();
+        matcher.test(&"Racecar").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_palindrome_but_was_not() {
+        let matcher = be_palindrome();
+        matcher.test(&"clearcheck").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_palindrome_but_spaces_were_not_ignored() {
+        let matcher = be_palindrome();
+        matcher.test(&"A man a plan a canal Panama").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_palindrome_ignoring_non_alphanumerics() {
+        let matcher = be_palindrome_ignoring_non_alphanumerics();
+        matcher.test(&"A man a plan a canal Panama").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_palindrome_ignoring_non_alphanumerics_but_was_not() {
+        let matcher = be_palindrome_ignoring_non_alphanumerics();
+        matcher.test(&"A man a plan").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_anagram_of() {
+        let matcher = be_anagram_of("silent");
+        matcher.test(&"listen").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_anagram_of_but_was_not() {
+        let matcher = be_anagram_of("silence");
+        matcher.test(&"listen").passed.should_be_true();
+    }
+}
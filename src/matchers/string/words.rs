@@ -0,0 +1,106 @@
+use crate::matchers::{Matcher, MatcherResult};
+
+/// WordMatcher offers a flexible way to make assertions about the words of a string.
+///
+/// Words are the whitespace separated tokens of the string, as produced by [str::split_whitespace].
+/// Punctuation is not stripped, so "cat," is a different word than "cat".
+///
+/// # Example
+///```
+/// use clearcheck::matchers::string::words::{contain_word, have_word_count};
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = have_word_count(3);
+/// assert!(matcher.test(&"the cat sat").passed());
+///
+/// let matcher = contain_word("cat");
+/// assert!(matcher.test(&"the cat sat").passed());
+/// ```
+pub enum WordMatcher {
+    Count(usize),
+    Contain(&'static str),
+}
+
+impl<T> Matcher<T> for WordMatcher
+    where T: AsRef<str>
+{
+    fn test(&self, value: &T) -> MatcherResult {
+        match self {
+            WordMatcher::Count(count) => {
+                let word_count = value.as_ref().split_whitespace().count();
+                MatcherResult::formatted(
+                    word_count == *count,
+                    format!(
+                        "{:?} should have {:?} words but had {:?}",
+                        value.as_ref(), count, word_count,
+                    ),
+                    format!("{:?} should not have {:?} words", value.as_ref(), count),
+                )
+            }
+            WordMatcher::Contain(word) => {
+                let failure_message = if value.as_ref().contains(word) {
+                    format!(
+                        "{:?} should contain the word {:?} but it only appeared as a part of another word",
+                        value.as_ref(), word,
+                    )
+                } else {
+                    format!("{:?} should contain the word {:?}", value.as_ref(), word)
+                };
+                MatcherResult::formatted(
+                    value.as_ref().split_whitespace().any(|token| token == *word),
+                    failure_message,
+                    format!("{:?} should not contain the word {:?}", value.as_ref(), word),
+                )
+            }
+        }
+    }
+}
+
+/// Creates a WordMatcher that asserts whether the number of whitespace separated words in a string is same as the given count.
+pub fn have_word_count(count: usize) -> WordMatcher {
+    WordMatcher::Count(count)
+}
+
+/// Creates a WordMatcher that asserts whether a string contains the given word as a whole whitespace separated token.
+pub fn contain_word(word: &'static str) -> WordMatcher {
+    WordMatcher::Contain(word)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::matchers::Matcher;
+    use crate::matchers::string::words::{contain_word, have_word_count};
+
+    #[test]
+    fn should_have_word_count() {
+        let matcher = have_word_count(3);
+        matcher.test(&"  the cat\n\tsat ").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_no_words_for_a_blank_string() {
+        let matcher = have_word_count(0);
+        matcher.test(&" \n ").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_word_count_but_was_not() {
+        let matcher = have_word_count(2);
+        matcher.test(&"the cat sat").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_contain_word() {
+        let matcher = contain_word("cat");
+        matcher.test(&"the cat sat").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_word_but_it_was_only_a_substring() {
+        let matcher = contain_word("cat");
+        matcher.test(&"category").passed.should_be_true();
+    }
+}
@@ -0,0 +1,32 @@
+#![cfg(feature = "stats")]
+
+use clearcheck::assertions::collection::membership::MembershipAssertion;
+use clearcheck::assertions::collection::size::SizeAssertion;
+use clearcheck::assertions::string::length::LengthAssertion;
+use clearcheck::matchers::{assertion_count, drain_results, install_collector, reset_assertion_count};
+
+// The count is shared by all the threads of the process, so the scenarios run in a single test
+// to keep them from observing each other's assertions.
+#[test]
+fn should_count_assertions() {
+    reset_assertion_count();
+    assert_eq!(0, assertion_count());
+
+    let libraries = vec!["clearcheck", "junit", "assert4j"];
+    libraries
+        .should_not_be_empty()
+        .should_have_size(3)
+        .should_contain(&"junit");
+    assert_eq!(3, assertion_count());
+
+    "clearcheck".should_have_length(10);
+    assert_eq!(4, assertion_count());
+
+    install_collector();
+    "clearcheck".should_have_length(5);
+    drain_results();
+    assert_eq!(5, assertion_count());
+
+    reset_assertion_count();
+    assert_eq!(0, assertion_count());
+}